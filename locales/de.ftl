@@ -0,0 +1,267 @@
+# German labels for the default report.
+
+header-font-information = SCHRIFTINFORMATIONEN
+label-file = Datei
+
+section-names = SCHRIFTNAMEN
+section-name-table = NAME-TABELLE
+section-format = SCHRIFTFORMAT
+section-health = PARSER-STATUS
+section-footprint = SPEICHERBEDARF
+section-tables = TABELLENVERZEICHNIS
+section-size = GRÖSSENANALYSE
+section-head = HEAD-TABELLE
+section-metrics = SCHRIFTMETRIKEN
+section-os2 = OS/2-TABELLE
+section-embedding = EINBETTUNG / LIZENZRECHTE
+section-post = POST-TABELLE
+section-axes = VARIATIONSACHSEN
+section-instances = BENANNTE INSTANZEN
+section-feature-variations = FEATURE-VARIATIONEN
+section-opsz = OPTISCHE GRÖSSE
+section-gsub = OPENTYPE-FEATURES (GSUB - Glyphenersetzung)
+section-gpos = OPENTYPE-FEATURES (GPOS - Glyphenpositionierung)
+section-scripts = UNTERSTÜTZTE SCHRIFTSYSTEME
+section-math = MATHEMATIK
+section-color = FARBGLYPHEN
+section-emoji = EMOJI-DARSTELLUNG
+section-flags = FLAGGEN-EMOJIS
+section-skin-tones = HAUTTÖNE
+section-unicode = UNICODE-VERSION
+section-planes = UNICODE-EBENEN
+section-blocks = UNICODE-BLÖCKE
+section-advances = AUFFÄLLIGE GLYPHENMETRIKEN
+section-tabular = TABELLENZIFFERN
+section-disambiguation = ZEICHENUNTERSCHEIDUNG
+section-ink = SCHWÄRZUNG
+section-stems = STRICHSTÄRKEN
+section-ignorables = STANDARDMÄSSIG IGNORIERBARE ZEICHEN
+section-mirroring = BIDI-SPIEGELUNG
+section-marks = KOMBINIERENDE ZEICHEN
+section-normalization = NORMALISIERUNG
+section-case = GROSS-/KLEINBUCHSTABEN
+section-dotted-i = PUNKT-I (TÜRKISCH, ASERBAIDSCHANISCH)
+section-special-cases = SONDERFÄLLE
+section-greek = GRIECHISCH
+section-cyrillic = KYRILLISCH
+section-vietnamese = VIETNAMESISCH
+section-african = AFRIKANISCHES LATEIN UND IPA
+
+label-family-name = Familienname
+label-subfamily = Unterfamilie
+label-full-name = Voller Name
+label-postscript-name = PostScript-Name
+label-version = Version
+names-none = Keine Standard-Namenseinträge gefunden
+names-available = Verfügbare Namen
+
+label-sfnt-version = sfnt-Version
+label-outline-flavor = Konturformat
+label-other-sources = Weitere Quellen
+label-note = Hinweis
+label-warning = Warnung
+format-glyphs-by-source = Glyphen nach Quelle
+format-mixed = Mischt Umrisse mit Farb-/Bitmap-Glyphen; Renderer
+format-mixed-fallback = ohne Unterstützung dieser Tabellen greifen auf Umrisse zurück
+format-missing-fallback = Farbglyphen haben keinen Ersatzumriss und bleiben
+format-missing-fallback-detail = ohne Farbunterstützung leer:
+
+label-units-per-em = EM-Einheiten
+label-ascender = Oberlänge
+label-descender = Unterlänge
+label-line-gap = Zeilenabstand
+label-glyph-count = Glyphenanzahl
+label-is-monospaced = Dicktengleich
+label-is-bold = Fett
+label-is-italic = Kursiv
+label-is-oblique = Schräg
+label-weight = Strichstärke
+label-width = Breite
+
+axes-none = Nicht variabel
+instances-none = Keine benannten Instanzen
+opsz-none = Keine Angaben zur optischen Größe gefunden
+axis-custom = Benutzerdefinierte Achse
+opsz-designed-for = Entworfen für
+label-size-feature = size-Feature
+label-opsz-axis = opsz-Achse
+label-os2-range = OS/2-Bereich
+label-stat-value = STAT-Wert
+
+label-features = Features
+gsub-none = Keine GSUB-Features gefunden
+gpos-none = Keine GPOS-Features gefunden
+feature-unknown = Unbekanntes Feature
+label-scripts = Schriftsysteme
+scripts-none = Keine Angaben zu Schriftsystemen gefunden
+
+label-emoji-covered = Abgedeckt
+label-emoji-default = Standardstil
+label-emoji-both = Beide Stile
+label-emoji-only = Nur Emoji
+label-text-only = Nur Text
+label-text-default = Standard Text
+
+label-regional-indicators = Regionalindikatoren
+label-country-flags = Länderflaggen
+label-subdivision-flags = Regionsflaggen
+label-unformed-flags = Nicht gebildet
+flags-none = Keine
+
+label-skin-tone-modifiers = Modifikatoren
+label-modifier-bases = Basiszeichen
+label-skin-tone-combinations = Kombinationen
+label-dangling-swatch = Einzelnes Farbfeld
+
+label-unicode-newest = Abdeckung bis
+label-unicode-additions = Ergänzungen
+label-unicode-strays = Vereinzelte Ergänzungen
+
+label-planes = Ebenen
+planes-none = Keine Unicode-cmap-Untertabelle gefunden
+
+advances-none = Keine auffälligen Dickten oder Vor-/Nachbreiten gefunden
+label-avg-char-width = Mittlere Breite
+
+label-ignorables-empty = Leere Glyphen
+label-ignorables-unmapped = Nicht zugeordnet
+label-ignorables-ink = Breite null, sichtbar
+label-ignorables-visible = Sichtbare Glyphen
+label-risk = Risiko
+
+label-mirrored-pairs = Vollständige Paare
+label-asymmetric-pairs = Fehlende Spiegel
+label-rtlm = rtlm-Feature
+
+label-marks-covered = Abgedeckt
+label-marks-attaching = An Basis verankert
+label-marks-stacking = Stapelbar
+label-verdict = Bewertung
+
+label-round-trip = Beide Formen
+label-precomposed-only = Nur vorkomponiert
+label-decomposed-only = Nur zerlegt
+
+label-case-pairs = Vollständige Paare
+label-missing-upper = Ohne Großbuchstaben
+label-missing-lower = Ohne Kleinbuchstaben
+
+label-letters = Buchstaben
+label-locl = locl
+label-fi-ligature = fi-Ligatur
+label-small-caps = Kapitälchen
+
+label-checks-passed = Bestanden
+
+name-table-undecodable = (Kodierung nicht unterstützt)
+
+label-greek-monotonic = Monotonisch
+label-greek-polytonic = Polytonisch
+label-greek-final-sigma = Schluss-Sigma
+label-greek-oxia = Oxia-Formen
+greek-monotonic-only = Nur monotonisch: polytonisches (klassisches) Griechisch ist unvollständig
+
+label-blocks = Blöcke
+
+label-precomposed = Vorkomponiert
+label-decomposed = Zerlegt
+label-horn-tone = Horn + Ton
+
+label-key-letters = Kernbuchstaben
+label-languages = Sprachen
+
+label-mapped-codepoints = Codepunkte
+
+label-figures = Ziffern
+label-figure-width = Ziffernbreite
+label-tabular = Tabellarisch
+tabular-offenders = Dickte weicht von der Ziffernbreite ab:
+
+label-weight-class = Gewichtsklasse
+label-width-class = Breitenklasse
+label-fs-type = fsType
+label-fs-selection = fsSelection
+label-typo-ascender = Typo-Oberlänge
+label-typo-descender = Typo-Unterlänge
+label-typo-line-gap = Typo-Zeilenabstand
+label-win-ascent = Win-Oberlänge
+label-win-descent = Win-Unterlänge
+label-x-height = x-Höhe
+label-cap-height = Versalhöhe
+label-vendor-id = Hersteller-ID
+label-unicode-ranges = Unicode-Bereiche
+label-code-pages = Codepages
+
+label-default-zero = Standard-Null
+label-zero-features = Null-Features
+label-zero-alternates = Null-Varianten
+label-similarity = Ähnlichkeit
+disambiguation-zero-plain = 0 und O unterscheiden sich nur in den Proportionen
+
+label-pdf-embedding = PDF-Einbettung
+embedding-full-font = Die ganze Schrift muss eingebettet werden; Teilmengen sind nicht erlaubt
+embedding-bitmap-only = Nur Bitmaps dürfen eingebettet werden, keine Umrisse
+
+label-font-revision = Schriftrevision
+label-created = Erstellt
+label-modified = Geändert
+label-head-flags = Flags
+label-mac-style = macStyle
+label-lowest-rec-ppem = Min. empf. PPEM
+label-loca-format = indexToLocFormat
+
+label-post-format = Post-Format
+label-italic-angle = Kursivwinkel
+label-underline-position = Unterstr.-Pos.
+label-underline-thickness = Unterstr.-Dicke
+label-fixed-pitch = isFixedPitch
+label-named-glyphs = Benannte Glyphen
+
+label-darkness = Grauwert
+label-glyph-ink = Tinte je Glyphe
+
+label-vertical-stem = Senkrechter Stamm
+label-horizontal-stem = Waagr. Strich
+label-stem-measurements = Gemessen
+
+label-color-formats = Formate
+label-colr = COLR-Glyphen
+label-cpal = Paletten
+label-first-palette = Erste Palette
+label-svg = SVG-Glyphen
+label-sbix = sbix-Größen
+label-cbdt = CBDT-Größen
+
+label-ssty = Indexformen
+label-flac = Flache Akzente
+label-dtls = Punktlose Formen
+label-size-variants = Größenvarianten
+label-missing-operators = Nicht in cmap
+label-table-health = Tabellen
+label-table-problems = Probleme
+health-decoded = dekodiert
+health-partially = teilweise
+health-failed = fehlgeschlagen
+health-partial = teilweise
+label-decompressed = Entpackt
+label-glyph-data = Glyphdaten
+label-per-glyph = Pro Glyphe
+label-hinting-size = Hinting
+
+label-location = Position
+label-rules = Regeln
+label-swapped-glyphs = Getauschte Glyphen
+feature-variations-active = aktiv
+feature-variations-inactive = Kein Bereich gilt an dieser Position; die Glyphen entsprechen der Standardinstanz
+feature-variations-no-swaps = Der aktive Bereich tauscht keine Glyphen durch einfache Ersetzungen
+
+label-file-size = Dateigröße
+label-checksums = Prüfsummen
+
+label-largest-tables = Größte Tabellen
+label-bitmaps = Bitmaps
+label-unused-cmap = Unbenutzte cmap
+label-suggestions = Vorschläge
+size-no-suggestions = Nichts offensichtlich Entbehrliches
+
+value-none = keine
@@ -0,0 +1,268 @@
+# English labels for the default report. Other catalogs fall back to
+# these messages for any key they don't define.
+
+header-font-information = FONT INFORMATION
+label-file = File
+
+section-names = FONT NAMES
+section-name-table = NAME TABLE
+section-format = FONT FORMAT
+section-health = PARSE HEALTH
+section-footprint = LOAD FOOTPRINT
+section-tables = TABLE DIRECTORY
+section-size = SIZE REPORT
+section-head = HEAD TABLE
+section-metrics = FONT METRICS
+section-os2 = OS/2 TABLE
+section-embedding = EMBEDDING / LICENSING PERMISSIONS
+section-post = POST TABLE
+section-axes = VARIATION AXES
+section-instances = NAMED INSTANCES
+section-feature-variations = FEATURE VARIATIONS
+section-opsz = OPTICAL SIZE
+section-gsub = OPENTYPE FEATURES (GSUB - Glyph Substitution)
+section-gpos = OPENTYPE FEATURES (GPOS - Glyph Positioning)
+section-scripts = SUPPORTED SCRIPTS
+section-math = MATH
+section-color = COLOR GLYPHS
+section-emoji = EMOJI PRESENTATION
+section-flags = FLAG EMOJI
+section-skin-tones = SKIN TONES
+section-unicode = UNICODE VERSION
+section-planes = UNICODE PLANES
+section-blocks = UNICODE BLOCKS
+section-advances = GLYPH METRIC ANOMALIES
+section-tabular = TABULAR FIGURES
+section-disambiguation = CHARACTER DISAMBIGUATION
+section-ink = INK DENSITY
+section-stems = STEM WIDTHS
+section-ignorables = DEFAULT IGNORABLES
+section-mirroring = BIDI MIRRORING
+section-marks = COMBINING MARKS
+section-normalization = NORMALIZATION
+section-case = CASE PAIRS
+section-dotted-i = DOTTED I (TURKISH, AZERBAIJANI)
+section-special-cases = SPECIAL CASES
+section-greek = GREEK
+section-cyrillic = CYRILLIC
+section-vietnamese = VIETNAMESE
+section-african = AFRICAN LATIN AND IPA
+
+label-family-name = Family Name
+label-subfamily = Subfamily
+label-full-name = Full Name
+label-postscript-name = PostScript Name
+label-version = Version
+names-none = No standard name entries found
+names-available = Available names
+
+label-sfnt-version = sfnt Version
+label-outline-flavor = Outline Flavor
+label-other-sources = Other Sources
+label-note = Note
+label-warning = Warning
+format-glyphs-by-source = Glyphs by source
+format-mixed = Mixes outlines with color/bitmap glyphs; renderers
+format-mixed-fallback = without support for those tables fall back to outlines
+format-missing-fallback = color glyphs have no fallback outline and render blank
+format-missing-fallback-detail = without color support:
+
+label-units-per-em = Units per EM
+label-ascender = Ascender
+label-descender = Descender
+label-line-gap = Line Gap
+label-glyph-count = Glyph Count
+label-is-monospaced = Is Monospaced
+label-is-bold = Is Bold
+label-is-italic = Is Italic
+label-is-oblique = Is Oblique
+label-weight = Weight
+label-width = Width
+
+axes-none = Not variable
+instances-none = No named instances
+opsz-none = No optical size information found
+axis-custom = Custom axis
+opsz-designed-for = Designed for
+label-size-feature = size Feature
+label-opsz-axis = opsz Axis
+label-os2-range = OS/2 Range
+label-stat-value = STAT Value
+
+label-features = Features
+gsub-none = No GSUB features found
+gpos-none = No GPOS features found
+feature-unknown = Unknown feature
+label-scripts = Scripts
+scripts-none = No script information found
+
+label-emoji-covered = Covered
+label-emoji-default = Default Style
+label-emoji-both = Both Styles
+label-emoji-only = Emoji Only
+label-text-only = Text Only
+label-text-default = Text Default
+
+label-regional-indicators = Regional Indicators
+label-country-flags = Country Flags
+label-subdivision-flags = Subdivision Flags
+label-unformed-flags = Unformed Flags
+flags-none = None
+
+label-skin-tone-modifiers = Modifiers
+label-modifier-bases = Modifier Bases
+label-skin-tone-combinations = Combinations
+label-dangling-swatch = Dangling Swatch
+
+label-unicode-newest = Covers Up To
+label-unicode-additions = Additions
+label-unicode-strays = Stray Additions
+
+label-planes = Planes
+planes-none = No Unicode cmap subtable found
+
+advances-none = No advance or side bearing anomalies found
+label-avg-char-width = Avg Char Width
+
+label-ignorables-empty = Empty Glyphs
+label-ignorables-unmapped = Unmapped
+label-ignorables-ink = Zero-Width Ink
+label-ignorables-visible = Visible Glyphs
+label-risk = Risk
+
+label-mirrored-pairs = Complete Pairs
+label-asymmetric-pairs = Missing Mirrors
+label-rtlm = rtlm Feature
+
+label-marks-covered = Covered
+label-marks-attaching = Attach to Bases
+label-marks-stacking = Stack on Marks
+label-verdict = Verdict
+
+label-round-trip = Round Trip
+label-precomposed-only = Precomposed Only
+label-decomposed-only = Decomposed Only
+
+label-case-pairs = Complete Pairs
+label-missing-upper = Missing Upper
+label-missing-lower = Missing Lower
+
+label-letters = Letters
+label-locl = locl
+label-fi-ligature = fi Ligature
+label-small-caps = Small Caps
+
+label-checks-passed = Checks Passed
+
+name-table-undecodable = (encoding not supported)
+
+label-greek-monotonic = Monotonic
+label-greek-polytonic = Polytonic
+label-greek-final-sigma = Final Sigma
+label-greek-oxia = Oxia Forms
+greek-monotonic-only = Monotonic only: polytonic (classical) Greek is incomplete
+
+label-blocks = Blocks
+
+label-precomposed = Precomposed
+label-decomposed = Decomposed
+label-horn-tone = Horn + Tone
+
+label-key-letters = Key Letters
+label-languages = Languages
+
+label-mapped-codepoints = Mapped Codepoints
+
+label-figures = Figures
+label-figure-width = Figure Width
+label-tabular = Tabular
+tabular-offenders = Advance differs from the figure width:
+
+label-weight-class = Weight Class
+label-width-class = Width Class
+label-fs-type = fsType
+label-fs-selection = fsSelection
+label-typo-ascender = Typo Ascender
+label-typo-descender = Typo Descender
+label-typo-line-gap = Typo Line Gap
+label-win-ascent = Win Ascent
+label-win-descent = Win Descent
+label-x-height = x-Height
+label-cap-height = Cap Height
+label-vendor-id = Vendor ID
+label-unicode-ranges = Unicode Ranges
+label-code-pages = Code Pages
+
+label-default-zero = Default Zero
+label-zero-features = Zero Features
+label-zero-alternates = Zero Alternates
+label-similarity = Similarity
+disambiguation-zero-plain = No way to tell 0 from O apart from their proportions
+
+label-pdf-embedding = PDF Embedding
+embedding-full-font = The full font must be embedded; subsetting is not allowed
+embedding-bitmap-only = Only bitmaps may be embedded; outlines must not be
+
+label-font-revision = Font Revision
+label-created = Created
+label-modified = Modified
+label-head-flags = Flags
+label-mac-style = macStyle
+label-lowest-rec-ppem = Lowest Rec. PPEM
+label-loca-format = indexToLocFormat
+
+label-post-format = Post Format
+label-italic-angle = Italic Angle
+label-underline-position = Underline Pos.
+label-underline-thickness = Underline Thick.
+label-fixed-pitch = isFixedPitch
+label-named-glyphs = Named Glyphs
+
+label-darkness = Darkness
+label-glyph-ink = Ink per Glyph
+
+label-vertical-stem = Vertical Stem
+label-horizontal-stem = Horizontal Stem
+label-stem-measurements = Measured
+
+label-color-formats = Formats
+label-colr = COLR Glyphs
+label-cpal = Palettes
+label-first-palette = First Palette
+label-svg = SVG Glyphs
+label-sbix = sbix Strikes
+label-cbdt = CBDT Strikes
+
+label-ssty = Script Styles
+label-flac = Flat Accents
+label-dtls = Dotless Forms
+label-size-variants = Size Variants
+label-missing-operators = Not in cmap
+label-table-health = Tables
+label-table-problems = Problems
+health-decoded = decoded
+health-partially = partially
+health-failed = failed
+health-partial = partial
+label-decompressed = Decompressed
+label-glyph-data = Glyph Data
+label-per-glyph = Per Glyph
+label-hinting-size = Hinting
+
+label-location = Location
+label-rules = Rules
+label-swapped-glyphs = Swapped Glyphs
+feature-variations-active = active
+feature-variations-inactive = No region applies at this location; glyphs match the default
+feature-variations-no-swaps = The active region swaps no glyphs through single substitutions
+
+label-file-size = File Size
+label-checksums = Checksums
+
+label-largest-tables = Largest Tables
+label-bitmaps = Bitmaps
+label-unused-cmap = Unused cmap
+label-suggestions = Suggestions
+size-no-suggestions = Nothing obvious to drop
+
+value-none = none
@@ -0,0 +1,51 @@
+# German descriptions of common OpenType features. Features missing here
+# are described in English.
+
+aalt = Zugriff auf alle Alternativen
+afrc = Alternative Brüche
+c2sc = Kapitälchen aus Großbuchstaben
+calt = Kontextbedingte Alternativen
+case = Versalienformen
+ccmp = Glyphenzusammensetzung/-zerlegung
+clig = Kontextbedingte Ligaturen
+cpsp = Versalabstand
+cswh = Kontextbedingte Schwungbuchstaben
+curs = Kursive Positionierung
+dlig = Bedingte Ligaturen
+dnom = Nenner
+fina = Endformen
+frac = Brüche
+fwid = Volle Breiten
+hist = Historische Formen
+hlig = Historische Ligaturen
+hwid = Halbe Breiten
+init = Anfangsformen
+isol = Isolierte Formen
+ital = Kursive
+kern = Unterschneidung
+liga = Standardligaturen
+lnum = Versalziffern
+locl = Lokalisierte Formen
+mark = Positionierung diakritischer Zeichen
+medi = Mittelformen
+mkmk = Positionierung von Zeichen an Zeichen
+numr = Zähler
+onum = Mediävalziffern
+ordn = Ordinalzeichen
+ornm = Ornamente
+pcap = Kleine Kapitälchen
+pnum = Proportionalziffern
+rlig = Erforderliche Ligaturen
+salt = Stilistische Alternativen
+sinf = Wissenschaftliche tiefgestellte Zeichen
+size = Optische Größe
+smcp = Kapitälchen
+subs = Tiefgestellt
+sups = Hochgestellt
+swsh = Schwungbuchstaben
+titl = Titelschrift
+tnum = Tabellenziffern
+unic = Unicase
+vert = Vertikale Schreibweise
+vkrn = Vertikale Unterschneidung
+zero = Null mit Schrägstrich
@@ -0,0 +1,51 @@
+# French descriptions of common OpenType features. Features missing here
+# are described in English.
+
+aalt = Accès à toutes les variantes
+afrc = Fractions alternatives
+c2sc = Petites capitales à partir des capitales
+calt = Variantes contextuelles
+case = Formes sensibles à la casse
+ccmp = Composition/décomposition de glyphes
+clig = Ligatures contextuelles
+cpsp = Espacement des capitales
+cswh = Lettres ornées contextuelles
+curs = Positionnement cursif
+dlig = Ligatures facultatives
+dnom = Dénominateurs
+fina = Formes finales
+frac = Fractions
+fwid = Pleine chasse
+hist = Formes historiques
+hlig = Ligatures historiques
+hwid = Demi-chasse
+init = Formes initiales
+isol = Formes isolées
+ital = Italiques
+kern = Crénage
+liga = Ligatures standard
+lnum = Chiffres alignés
+locl = Formes localisées
+mark = Positionnement des diacritiques
+medi = Formes médianes
+mkmk = Positionnement diacritique sur diacritique
+numr = Numérateurs
+onum = Chiffres elzéviriens
+ordn = Ordinaux
+ornm = Ornements
+pcap = Petites capitales réduites
+pnum = Chiffres proportionnels
+rlig = Ligatures obligatoires
+salt = Variantes stylistiques
+sinf = Indices scientifiques
+size = Taille optique
+smcp = Petites capitales
+subs = Indices
+sups = Exposants
+swsh = Lettres ornées
+titl = Titrage
+tnum = Chiffres tabulaires
+unic = Unicase
+vert = Écriture verticale
+vkrn = Crénage vertical
+zero = Zéro barré
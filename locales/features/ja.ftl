@@ -0,0 +1,65 @@
+# Japanese descriptions of common OpenType features. Features missing here
+# are described in English.
+
+aalt = すべての字形切り替え
+afrc = 代替分数
+c2sc = 大文字からスモールキャップス
+calt = 前後関係に依存する字形
+case = 大文字用の字形
+ccmp = 字形の合成・分解
+clig = 前後関係に依存する合字
+cpsp = 大文字用のスペーシング
+curs = 筆記体の位置調整
+dlig = 任意の合字
+dnom = 分母
+expt = エキスパート字形
+frac = 分数
+fwid = 全角字形
+halt = 半角メトリクス
+hist = 歴史的字形
+hkna = 横組み用かな
+hlig = 歴史的合字
+hojo = 補助漢字字形
+hwid = 半角字形
+ital = イタリック
+jp04 = JIS2004字形
+jp78 = JIS78字形
+jp83 = JIS83字形
+jp90 = JIS90字形
+kern = カーニング
+liga = 標準合字
+lnum = ライニング数字
+locl = 地域別字形
+mark = マークの位置調整
+mkmk = マーク同士の位置調整
+nalt = 注釈字形
+nlck = 印刷標準字体
+numr = 分子
+onum = オールドスタイル数字
+ordn = 序数表記
+ornm = 装飾記号
+palt = プロポーショナルメトリクス
+pkna = プロポーショナルかな
+pnum = プロポーショナル数字
+pwid = プロポーショナル字形
+qwid = 1/4幅字形
+ruby = ルビ用字形
+salt = スタイルセット字形
+sinf = 科学用下付き文字
+size = オプティカルサイズ
+smcp = スモールキャップス
+smpl = 簡体字形
+subs = 下付き文字
+sups = 上付き文字
+swsh = スワッシュ字形
+titl = タイトル用字形
+tnum = 等幅数字
+trad = 旧字体
+twid = 1/3幅字形
+vert = 縦書き字形
+vhal = 縦組み半角メトリクス
+vkna = 縦組み用かな
+vkrn = 縦組みカーニング
+vpal = 縦組みプロポーショナルメトリクス
+vrt2 = 縦組み用字形と回転
+zero = スラッシュ付きゼロ
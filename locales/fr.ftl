@@ -0,0 +1,267 @@
+# French labels for the default report.
+
+header-font-information = INFORMATIONS SUR LA POLICE
+label-file = Fichier
+
+section-names = NOMS DE LA POLICE
+section-name-table = TABLE NAME
+section-format = FORMAT DE LA POLICE
+section-health = ÉTAT DU DÉCODAGE
+section-footprint = EMPREINTE MÉMOIRE
+section-tables = RÉPERTOIRE DES TABLES
+section-size = ANALYSE DE LA TAILLE
+section-head = TABLE HEAD
+section-metrics = MÉTRIQUES DE LA POLICE
+section-os2 = TABLE OS/2
+section-embedding = INCORPORATION / DROITS DE LICENCE
+section-post = TABLE POST
+section-axes = AXES DE VARIATION
+section-instances = INSTANCES NOMMÉES
+section-feature-variations = VARIATIONS DE FONCTIONNALITÉS
+section-opsz = TAILLE OPTIQUE
+section-gsub = FONCTIONNALITÉS OPENTYPE (GSUB - Substitution de glyphes)
+section-gpos = FONCTIONNALITÉS OPENTYPE (GPOS - Positionnement de glyphes)
+section-scripts = ÉCRITURES PRISES EN CHARGE
+section-math = MATHÉMATIQUES
+section-color = GLYPHES EN COULEUR
+section-emoji = PRÉSENTATION DES ÉMOJIS
+section-flags = ÉMOJIS DE DRAPEAUX
+section-skin-tones = TEINTES DE PEAU
+section-unicode = VERSION UNICODE
+section-planes = PLANS UNICODE
+section-blocks = BLOCS UNICODE
+section-advances = ANOMALIES DES MÉTRIQUES DE GLYPHES
+section-tabular = CHIFFRES TABULAIRES
+section-disambiguation = DISTINCTION DES CARACTÈRES
+section-ink = DENSITÉ D'ENCRE
+section-stems = ÉPAISSEUR DES FÛTS
+section-ignorables = CARACTÈRES IGNORABLES PAR DÉFAUT
+section-mirroring = SYMÉTRISATION BIDI
+section-marks = DIACRITIQUES COMBINANTS
+section-normalization = NORMALISATION
+section-case = PAIRES DE CASSE
+section-dotted-i = I POINTÉ (TURC, AZERBAÏDJANAIS)
+section-special-cases = CAS PARTICULIERS
+section-greek = GREC
+section-cyrillic = CYRILLIQUE
+section-vietnamese = VIETNAMIEN
+section-african = LATIN AFRICAIN ET API
+
+label-family-name = Nom de famille
+label-subfamily = Sous-famille
+label-full-name = Nom complet
+label-postscript-name = Nom PostScript
+label-version = Version
+names-none = Aucune entrée de nom standard trouvée
+names-available = Noms disponibles
+
+label-sfnt-version = Version sfnt
+label-outline-flavor = Type de contours
+label-other-sources = Autres sources
+label-note = Remarque
+label-warning = Avertissement
+format-glyphs-by-source = Glyphes par source
+format-mixed = Mélange contours et glyphes couleur/bitmap ; les moteurs
+format-mixed-fallback = sans prise en charge de ces tables se rabattent sur les contours
+format-missing-fallback = glyphes couleur sans contour de repli restent vides
+format-missing-fallback-detail = sans prise en charge de la couleur :
+
+label-units-per-em = Unités par em
+label-ascender = Ascendante
+label-descender = Descendante
+label-line-gap = Interligne
+label-glyph-count = Glyphes
+label-is-monospaced = Chasse fixe
+label-is-bold = Gras
+label-is-italic = Italique
+label-is-oblique = Oblique
+label-weight = Graisse
+label-width = Largeur
+
+axes-none = Non variable
+instances-none = Aucune instance nommée
+opsz-none = Aucune information de taille optique trouvée
+axis-custom = Axe personnalisé
+opsz-designed-for = Conçu pour
+label-size-feature = Fonction size
+label-opsz-axis = Axe opsz
+label-os2-range = Plage OS/2
+label-stat-value = Valeur STAT
+
+label-features = Fonctionnalités
+gsub-none = Aucune fonctionnalité GSUB trouvée
+gpos-none = Aucune fonctionnalité GPOS trouvée
+feature-unknown = Fonctionnalité inconnue
+label-scripts = Écritures
+scripts-none = Aucune information d'écriture trouvée
+
+label-emoji-covered = Couverts
+label-emoji-default = Style par défaut
+label-emoji-both = Deux styles
+label-emoji-only = Émoji seul
+label-text-only = Texte seul
+label-text-default = Texte par défaut
+
+label-regional-indicators = Indicateurs régionaux
+label-country-flags = Drapeaux de pays
+label-subdivision-flags = Drapeaux de subdivisions
+label-unformed-flags = Non formés
+flags-none = Aucun
+
+label-skin-tone-modifiers = Modificateurs
+label-modifier-bases = Bases modifiables
+label-skin-tone-combinations = Combinaisons
+label-dangling-swatch = Nuancier isolé
+
+label-unicode-newest = Couvre jusqu'à
+label-unicode-additions = Ajouts
+label-unicode-strays = Ajouts isolés
+
+label-planes = Plans
+planes-none = Aucune sous-table cmap Unicode trouvée
+
+advances-none = Aucune anomalie d'avance ou d'approche trouvée
+label-avg-char-width = Largeur moyenne
+
+label-ignorables-empty = Glyphes vides
+label-ignorables-unmapped = Non associés
+label-ignorables-ink = Chasse nulle, encrés
+label-ignorables-visible = Glyphes visibles
+label-risk = Risque
+
+label-mirrored-pairs = Paires complètes
+label-asymmetric-pairs = Symétriques absents
+label-rtlm = Fonction rtlm
+
+label-marks-covered = Couverts
+label-marks-attaching = Ancrés sur la base
+label-marks-stacking = Empilables
+label-verdict = Verdict
+
+label-round-trip = Deux formes
+label-precomposed-only = Précomposé seul
+label-decomposed-only = Décomposé seul
+
+label-case-pairs = Paires complètes
+label-missing-upper = Sans majuscule
+label-missing-lower = Sans minuscule
+
+label-letters = Lettres
+label-locl = locl
+label-fi-ligature = Ligature fi
+label-small-caps = Petites capitales
+
+label-checks-passed = Réussis
+
+name-table-undecodable = (encodage non pris en charge)
+
+label-greek-monotonic = Monotonique
+label-greek-polytonic = Polytonique
+label-greek-final-sigma = Sigma final
+label-greek-oxia = Formes oxia
+greek-monotonic-only = Monotonique seulement : le grec polytonique (classique) est incomplet
+
+label-blocks = Blocs
+
+label-precomposed = Précomposés
+label-decomposed = Décomposés
+label-horn-tone = Cornu + ton
+
+label-key-letters = Lettres clés
+label-languages = Langues
+
+label-mapped-codepoints = Points de code
+
+label-figures = Chiffres
+label-figure-width = Chasse des chiffres
+label-tabular = Tabulaires
+tabular-offenders = Chasse différente de celle des chiffres :
+
+label-weight-class = Classe de graisse
+label-width-class = Classe de chasse
+label-fs-type = fsType
+label-fs-selection = fsSelection
+label-typo-ascender = Ascendante typo
+label-typo-descender = Descendante typo
+label-typo-line-gap = Interligne typo
+label-win-ascent = Ascendante Win
+label-win-descent = Descendante Win
+label-x-height = Hauteur d’x
+label-cap-height = Hauteur capitale
+label-vendor-id = ID fabricant
+label-unicode-ranges = Plages Unicode
+label-code-pages = Pages de code
+
+label-default-zero = Zéro par défaut
+label-zero-features = Features du zéro
+label-zero-alternates = Variantes du zéro
+label-similarity = Similarité
+disambiguation-zero-plain = Seules les proportions distinguent 0 de O
+
+label-pdf-embedding = Incorporation PDF
+embedding-full-font = La police entière doit être incorporée ; les sous-ensembles ne sont pas autorisés
+embedding-bitmap-only = Seuls les bitmaps peuvent être incorporés, pas les contours
+
+label-font-revision = Révision
+label-created = Créée
+label-modified = Modifiée
+label-head-flags = Indicateurs
+label-mac-style = macStyle
+label-lowest-rec-ppem = PPEM min. conseillé
+label-loca-format = indexToLocFormat
+
+label-post-format = Format post
+label-italic-angle = Angle italique
+label-underline-position = Pos. soulign.
+label-underline-thickness = Ép. soulign.
+label-fixed-pitch = isFixedPitch
+label-named-glyphs = Glyphes nommés
+
+label-darkness = Gris typo.
+label-glyph-ink = Encre par glyphe
+
+label-vertical-stem = Fût vertical
+label-horizontal-stem = Délié horizontal
+label-stem-measurements = Mesures
+
+label-color-formats = Formats
+label-colr = Glyphes COLR
+label-cpal = Palettes
+label-first-palette = 1re palette
+label-svg = Glyphes SVG
+label-sbix = Tailles sbix
+label-cbdt = Tailles CBDT
+
+label-ssty = Formes d'indice
+label-flac = Accents aplatis
+label-dtls = Sans point
+label-size-variants = Tailles
+label-missing-operators = Absents de cmap
+label-table-health = Tables
+label-table-problems = Problèmes
+health-decoded = décodées
+health-partially = partiellement
+health-failed = en échec
+health-partial = partielle
+label-decompressed = Décompressé
+label-glyph-data = Données glyphes
+label-per-glyph = Par glyphe
+label-hinting-size = Hinting
+
+label-location = Position
+label-rules = Règles
+label-swapped-glyphs = Glyphes échangés
+feature-variations-active = active
+feature-variations-inactive = Aucune région ne s'applique à cette position ; les glyphes sont ceux par défaut
+feature-variations-no-swaps = La région active n'échange aucun glyphe par substitution simple
+
+label-file-size = Taille du fichier
+label-checksums = Sommes de contrôle
+
+label-largest-tables = Plus grandes tables
+label-bitmaps = Bitmaps
+label-unused-cmap = cmap inutilisées
+label-suggestions = Suggestions
+size-no-suggestions = Rien d'évident à supprimer
+
+value-none = aucun
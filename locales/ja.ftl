@@ -0,0 +1,267 @@
+# Japanese labels for the default report.
+
+header-font-information = フォント情報
+label-file = ファイル
+
+section-names = フォント名
+section-name-table = NAMEテーブル
+section-format = フォント形式
+section-health = 解析状況
+section-footprint = 読み込みコスト
+section-tables = テーブルディレクトリ
+section-size = サイズ分析
+section-head = HEAD テーブル
+section-metrics = フォントメトリクス
+section-os2 = OS/2 テーブル
+section-embedding = 埋め込み／ライセンス許可
+section-post = POST テーブル
+section-axes = バリエーション軸
+section-instances = 名前付きインスタンス
+section-feature-variations = フィーチャーバリエーション
+section-opsz = オプティカルサイズ
+section-gsub = OpenType機能 (GSUB - グリフ置換)
+section-gpos = OpenType機能 (GPOS - グリフ配置)
+section-scripts = 対応スクリプト
+section-math = 数式
+section-color = カラーグリフ
+section-emoji = 絵文字の表示
+section-flags = 国旗の絵文字
+section-skin-tones = 肌の色
+section-unicode = Unicodeバージョン
+section-planes = Unicode面
+section-blocks = Unicodeブロック
+section-advances = グリフメトリクスの異常
+section-tabular = 等幅数字
+section-disambiguation = 文字の判別
+section-ink = インク濃度
+section-stems = ステム幅
+section-ignorables = デフォルト無視可能文字
+section-mirroring = 双方向ミラーリング
+section-marks = 結合文字
+section-normalization = 正規化
+section-case = 大文字・小文字の対
+section-dotted-i = 点付きi（トルコ語・アゼルバイジャン語）
+section-special-cases = 特殊ケース
+section-greek = ギリシア文字
+section-cyrillic = キリル文字
+section-vietnamese = ベトナム語
+section-african = アフリカのラテン文字とIPA
+
+label-family-name = ファミリー名
+label-subfamily = サブファミリー
+label-full-name = フルネーム
+label-postscript-name = PostScript名
+label-version = バージョン
+names-none = 標準の名前エントリが見つかりません
+names-available = 利用可能な名前
+
+label-sfnt-version = sfntバージョン
+label-outline-flavor = アウトライン形式
+label-other-sources = その他のソース
+label-note = 注記
+label-warning = 警告
+format-glyphs-by-source = ソース別グリフ
+format-mixed = アウトラインとカラー/ビットマップグリフが混在しています。これらの
+format-mixed-fallback = テーブルに対応しないレンダラーはアウトラインで表示します
+format-missing-fallback = 個のカラーグリフにフォールバックのアウトラインがなく、
+format-missing-fallback-detail = カラー非対応の環境では空白になります:
+
+label-units-per-em = UPM
+label-ascender = アセンダー
+label-descender = ディセンダー
+label-line-gap = 行間
+label-glyph-count = グリフ数
+label-is-monospaced = 等幅
+label-is-bold = 太字
+label-is-italic = イタリック
+label-is-oblique = 斜体
+label-weight = ウェイト
+label-width = 幅
+
+axes-none = 可変フォントではありません
+instances-none = 名前付きインスタンスはありません
+opsz-none = オプティカルサイズ情報が見つかりません
+axis-custom = カスタム軸
+opsz-designed-for = 設計サイズ
+label-size-feature = sizeフィーチャー
+label-opsz-axis = opsz軸
+label-os2-range = OS/2範囲
+label-stat-value = STAT値
+
+label-features = 機能
+gsub-none = GSUB機能が見つかりません
+gpos-none = GPOS機能が見つかりません
+feature-unknown = 不明な機能
+label-scripts = スクリプト
+scripts-none = スクリプト情報が見つかりません
+
+label-emoji-covered = 対応文字
+label-emoji-default = 既定の表示
+label-emoji-both = 両方の表示
+label-emoji-only = 絵文字のみ
+label-text-only = テキストのみ
+label-text-default = 既定がテキスト
+
+label-regional-indicators = 地域指示記号
+label-country-flags = 国旗
+label-subdivision-flags = 地域の旗
+label-unformed-flags = 未形成の旗
+flags-none = なし
+
+label-skin-tone-modifiers = 修飾子
+label-modifier-bases = 修飾可能な絵文字
+label-skin-tone-combinations = 組み合わせ
+label-dangling-swatch = 分離した色見本
+
+label-unicode-newest = 対応バージョン
+label-unicode-additions = 追加文字
+label-unicode-strays = 散発的な追加
+
+label-planes = 面
+planes-none = Unicodeのcmapサブテーブルがありません
+
+advances-none = 送り幅とサイドベアリングに異常はありません
+label-avg-char-width = 平均文字幅
+
+label-ignorables-empty = 空のグリフ
+label-ignorables-unmapped = 未割り当て
+label-ignorables-ink = 幅ゼロで描画あり
+label-ignorables-visible = 可視グリフ
+label-risk = リスク
+
+label-mirrored-pairs = 揃ったペア
+label-asymmetric-pairs = 欠けた対
+label-rtlm = rtlm機能
+
+label-marks-covered = 収録
+label-marks-attaching = 基底への配置
+label-marks-stacking = 積み重ね
+label-verdict = 判定
+
+label-round-trip = 両形式
+label-precomposed-only = 合成済みのみ
+label-decomposed-only = 分解形のみ
+
+label-case-pairs = 揃った対
+label-missing-upper = 大文字なし
+label-missing-lower = 小文字なし
+
+label-letters = 文字
+label-locl = locl
+label-fi-ligature = fi合字
+label-small-caps = スモールキャップ
+
+label-checks-passed = 合格
+
+name-table-undecodable = （未対応のエンコーディング）
+
+label-greek-monotonic = 単調式
+label-greek-polytonic = 多調式
+label-greek-final-sigma = 語末シグマ
+label-greek-oxia = オクシア形
+greek-monotonic-only = 単調式のみ：多調式（古典）ギリシア語は不完全です
+
+label-blocks = ブロック
+
+label-precomposed = 合成済み
+label-decomposed = 分解形
+label-horn-tone = ホーン＋声調
+
+label-key-letters = 主要文字
+label-languages = 言語
+
+label-mapped-codepoints = コードポイント数
+
+label-figures = 数字
+label-figure-width = 数字幅
+label-tabular = 等幅
+tabular-offenders = 送り幅が数字幅と異なる:
+
+label-weight-class = ウェイト区分
+label-width-class = 幅区分
+label-fs-type = fsType
+label-fs-selection = fsSelection
+label-typo-ascender = Typo アセンダー
+label-typo-descender = Typo ディセンダー
+label-typo-line-gap = Typo 行間
+label-win-ascent = Win アセント
+label-win-descent = Win ディセント
+label-x-height = x ハイト
+label-cap-height = キャップハイト
+label-vendor-id = ベンダー ID
+label-unicode-ranges = Unicode 範囲
+label-code-pages = コードページ
+
+label-default-zero = 既定のゼロ
+label-zero-features = ゼロの機能
+label-zero-alternates = ゼロの異体
+label-similarity = 類似度
+disambiguation-zero-plain = 0 と O は比率以外に区別がありません
+
+label-pdf-embedding = PDF 埋め込み
+embedding-full-font = フォント全体を埋め込む必要があります。サブセット化は許可されていません
+embedding-bitmap-only = 埋め込めるのはビットマップのみで、アウトラインは埋め込めません
+
+label-font-revision = フォント改訂
+label-created = 作成日時
+label-modified = 更新日時
+label-head-flags = フラグ
+label-mac-style = macStyle
+label-lowest-rec-ppem = 最小推奨 PPEM
+label-loca-format = indexToLocFormat
+
+label-post-format = post 形式
+label-italic-angle = イタリック角度
+label-underline-position = 下線の位置
+label-underline-thickness = 下線の太さ
+label-fixed-pitch = isFixedPitch
+label-named-glyphs = 名前付きグリフ
+
+label-darkness = 黒み
+label-glyph-ink = グリフ別インク
+
+label-vertical-stem = 垂直ステム
+label-horizontal-stem = 水平ステム
+label-stem-measurements = 計測値
+
+label-color-formats = 形式
+label-colr = COLR グリフ
+label-cpal = パレット
+label-first-palette = 最初のパレット
+label-svg = SVG グリフ
+label-sbix = sbix サイズ
+label-cbdt = CBDT サイズ
+
+label-ssty = 添字スタイル
+label-flac = 平坦アクセント
+label-dtls = 点なし字形
+label-size-variants = サイズ異体字
+label-missing-operators = cmap にない
+label-table-health = テーブル
+label-table-problems = 問題
+health-decoded = デコード済み
+health-partially = 部分的
+health-failed = 失敗
+health-partial = 部分的
+label-decompressed = 展開後サイズ
+label-glyph-data = グリフデータ
+label-per-glyph = グリフ平均
+label-hinting-size = ヒンティング
+
+label-location = 位置
+label-rules = ルール
+label-swapped-glyphs = 置換グリフ
+feature-variations-active = 適用中
+feature-variations-inactive = この位置に適用される領域はありません。グリフはデフォルトと同じです
+feature-variations-no-swaps = 適用中の領域は単純置換でグリフを置き換えません
+
+label-file-size = ファイルサイズ
+label-checksums = チェックサム
+
+label-largest-tables = 大きいテーブル
+label-bitmaps = ビットマップ
+label-unused-cmap = 未使用のcmap
+label-suggestions = 提案
+size-no-suggestions = 削除できる明らかな部分はありません
+
+value-none = なし
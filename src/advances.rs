@@ -0,0 +1,227 @@
+use crate::reader::Reader;
+use serde::Serialize;
+use ttf_parser::{Face, GlyphId, Rect, Tag};
+
+/// Advances wider than this many times xAvgCharWidth are flagged.
+const EXTREME_ADVANCE_FACTOR: i32 = 10;
+
+/// A glyph whose horizontal metrics are likely to break layout.
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Anomaly {
+    /// The hmtx advance has the sign bit set, so engines that read it as
+    /// a signed value move the pen backwards.
+    NegativeAdvance { advance: u16 },
+    /// A side bearing wider than the em.
+    HugeSidebearing { lsb: i32, rsb: i32 },
+    /// The hmtx left side bearing doesn't match the outline's xMin.
+    LsbMismatch { lsb: i16, x_min: i16 },
+    /// The advance is an extreme multiple of OS/2 xAvgCharWidth.
+    ExtremeAdvance { advance: u16, average: i16 },
+}
+
+impl Anomaly {
+    /// Short name used to group anomalies in the report.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Anomaly::NegativeAdvance { .. } => "Negative advance",
+            Anomaly::HugeSidebearing { .. } => "Huge sidebearing",
+            Anomaly::LsbMismatch { .. } => "LSB differs from xMin",
+            Anomaly::ExtremeAdvance { .. } => "Extreme advance",
+        }
+    }
+
+    pub fn details(&self) -> String {
+        match self {
+            Anomaly::NegativeAdvance { advance } => {
+                format!("advance {} ({} as int16)", advance, *advance as i16)
+            }
+            Anomaly::HugeSidebearing { lsb, rsb } => format!("lsb {}, rsb {}", lsb, rsb),
+            Anomaly::LsbMismatch { lsb, x_min } => format!("lsb {}, xMin {}", lsb, x_min),
+            Anomaly::ExtremeAdvance { advance, average } => {
+                format!("advance {}, xAvgCharWidth {}", advance, average)
+            }
+        }
+    }
+}
+
+/// An anomaly found in one glyph.
+#[derive(Serialize)]
+pub struct GlyphAnomaly {
+    pub glyph_id: u16,
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub anomaly: Anomaly,
+}
+
+/// Horizontal metric anomalies across every glyph, in glyph order.
+#[derive(Serialize)]
+pub struct AnomalyReport {
+    pub anomalies: Vec<GlyphAnomaly>,
+}
+
+impl AnomalyReport {
+    pub fn new(face: &Face) -> Self {
+        let units_per_em = i32::from(face.units_per_em());
+        let average = x_avg_char_width(face).filter(|&avg| avg > 0);
+
+        let mut anomalies = Vec::new();
+        for id in 0..face.number_of_glyphs() {
+            let glyph_id = GlyphId(id);
+            let name = || face.glyph_name(glyph_id).map(str::to_string);
+            for anomaly in check_glyph(face, glyph_id, units_per_em, average) {
+                anomalies.push(GlyphAnomaly {
+                    glyph_id: id,
+                    name: name(),
+                    anomaly,
+                });
+            }
+        }
+
+        AnomalyReport { anomalies }
+    }
+}
+
+fn check_glyph(
+    face: &Face,
+    glyph_id: GlyphId,
+    units_per_em: i32,
+    average: Option<i16>,
+) -> Vec<Anomaly> {
+    let mut anomalies = Vec::new();
+    let Some(advance) = face.glyph_hor_advance(glyph_id) else {
+        return anomalies;
+    };
+    if advance > i16::MAX as u16 {
+        anomalies.push(Anomaly::NegativeAdvance { advance });
+    }
+    if let Some(average) = average
+        && i32::from(advance) > i32::from(average) * EXTREME_ADVANCE_FACTOR
+    {
+        anomalies.push(Anomaly::ExtremeAdvance { advance, average });
+    }
+
+    // Empty glyphs such as the space have no bounds to compare to.
+    let (Some((bbox, tolerance)), Some(lsb)) = (
+        bounds(face, glyph_id),
+        face.glyph_hor_side_bearing(glyph_id),
+    ) else {
+        return anomalies;
+    };
+    if (i32::from(lsb) - i32::from(bbox.x_min)).abs() > tolerance {
+        anomalies.push(Anomaly::LsbMismatch {
+            lsb,
+            x_min: bbox.x_min,
+        });
+    }
+    let lsb = i32::from(lsb);
+    let rsb = i32::from(advance) - lsb - i32::from(bbox.width());
+    if lsb.abs() > units_per_em || rsb.abs() > units_per_em {
+        anomalies.push(Anomaly::HugeSidebearing { lsb, rsb });
+    }
+    anomalies
+}
+
+/// Returns the glyph's bounds and how far the LSB may differ from xMin.
+/// TrueType glyphs store their bounds, which the LSB must match exactly;
+/// CFF bounds are computed from the outline and may round either way.
+fn bounds(face: &Face, glyph_id: GlyphId) -> Option<(Rect, i32)> {
+    match face.tables().glyf {
+        Some(glyf) => glyf.bbox(glyph_id).map(|bbox| (bbox, 0)),
+        None => face.glyph_bounding_box(glyph_id).map(|bbox| (bbox, 1)),
+    }
+}
+
+/// Reads xAvgCharWidth from the OS/2 table.
+pub fn x_avg_char_width(face: &Face) -> Option<i16> {
+    let os2 = face.raw_face().table(Tag::from_bytes(b"OS/2"))?;
+    Reader::at(os2, 2).read_i16()
+}
+
+/// Weights per thousand of the lowercase letters and space in the OS/2
+/// version 0–2 definition of xAvgCharWidth.
+const LETTER_WEIGHTS: [(char, i32); 27] = [
+    ('a', 64),
+    ('b', 14),
+    ('c', 27),
+    ('d', 35),
+    ('e', 100),
+    ('f', 20),
+    ('g', 14),
+    ('h', 42),
+    ('i', 63),
+    ('j', 3),
+    ('k', 6),
+    ('l', 35),
+    ('m', 20),
+    ('n', 56),
+    ('o', 56),
+    ('p', 17),
+    ('q', 4),
+    ('r', 49),
+    ('s', 56),
+    ('t', 71),
+    ('u', 31),
+    ('v', 10),
+    ('w', 18),
+    ('x', 3),
+    ('y', 18),
+    ('z', 2),
+    (' ', 166),
+];
+
+/// The stored OS/2 xAvgCharWidth next to the value recomputed from hmtx.
+#[derive(Serialize)]
+pub struct AverageWidth {
+    pub os2_version: u16,
+    pub stored: i16,
+    pub computed: i32,
+}
+
+impl AverageWidth {
+    /// Recomputes xAvgCharWidth the way the OS/2 version in the font
+    /// defines it: the mean of all non-zero advances from version 3 on, a
+    /// frequency-weighted mean of a–z and space before that. Returns `None`
+    /// without an OS/2 table, or when the glyphs the definition needs are
+    /// missing.
+    pub fn new(face: &Face) -> Option<Self> {
+        let os2 = face.raw_face().table(Tag::from_bytes(b"OS/2"))?;
+        let mut r = Reader::new(os2);
+        let os2_version = r.read_u16()?;
+        let stored = r.read_i16()?;
+
+        let computed = if os2_version >= 3 {
+            let advances: Vec<i64> = (0..face.number_of_glyphs())
+                .filter_map(|id| face.glyph_hor_advance(GlyphId(id)))
+                .filter(|&advance| advance > 0)
+                .map(i64::from)
+                .collect();
+            if advances.is_empty() {
+                return None;
+            }
+            let count = advances.len() as i64;
+            // Rounded to the nearest unit, as font compilers do.
+            ((advances.iter().sum::<i64>() + count / 2) / count) as i32
+        } else {
+            let mut total = 0;
+            for (c, weight) in LETTER_WEIGHTS {
+                let advance = face
+                    .glyph_index(c)
+                    .and_then(|g| face.glyph_hor_advance(g))?;
+                total += i32::from(advance) * weight;
+            }
+            (total + 500) / 1000
+        };
+
+        Some(AverageWidth {
+            os2_version,
+            stored,
+            computed,
+        })
+    }
+
+    /// How far the stored value is from the recomputed one.
+    pub fn delta(&self) -> i32 {
+        i32::from(self.stored) - self.computed
+    }
+}
@@ -0,0 +1,92 @@
+use crate::languages::uppercase;
+use crate::unicode::BlockCoverage;
+use serde::Serialize;
+use ttf_parser::Face;
+
+/// Blocks holding most African Latin and phonetic letters, all of whose
+/// code points are assigned.
+const BLOCKS: [(&str, &[(u32, u32)]); 2] = [
+    ("Latin Extended-B", &[(0x0180, 0x024F)]),
+    ("IPA Extensions", &[(0x0250, 0x02AF)]),
+];
+
+/// Letters shared by many African orthographies: eng, open e and o, and
+/// the hooked and other borrowed IPA letters. Lowercase only; the
+/// uppercase forms are required as well.
+const KEY_LETTERS: &str = "ŋɛɔɓɗƙƴɖƒɣʋɲ";
+
+/// Major African languages written in Latin script with the letters their
+/// orthography needs, after CLDR's main exemplar sets. Precomposed letters
+/// only: tone marks on ɛ, ɔ and the dotted vowels need combining marks.
+/// Lowercase only, as in `languages::LANGUAGES`.
+const LANGUAGES: &[(&str, &str, &str)] = &[
+    ("ha", "Hausa", "abɓcdɗefghijkƙlmnorstuwyƴz"),
+    ("yo", "Yoruba", "abdeẹfghijklmnoọprsṣtuwyàáèéìíòóùúńǹ"),
+    ("ig", "Igbo", "abdefghịijklmnṅoọprstuụvwyz"),
+    ("ak", "Akan", "abdeɛfghiklmnoɔprstuwy"),
+    ("ee", "Ewe", "abdɖeɛfƒgɣhiklmnŋoɔprstuʋvwxyz"),
+    ("wo", "Wolof", "abcdefgijklmnñŋopqrstuwxyàéëó"),
+    ("ff", "Fula", "abɓcdɗefghijklmnŋɲoprstuwyƴ"),
+    ("bm", "Bambara", "abcdeɛfghijklmnɲŋoɔprstuwyz"),
+    ("ln", "Lingala", "abcdeɛfghiklmnoɔprstuvwyzáâǎéêěíîǐóôǒúûǔ"),
+    ("din", "Dinka", "abcdeäëɛgɣiïjklmnŋoöɔprtuwy"),
+];
+
+#[derive(Serialize)]
+pub struct LanguageCoverage {
+    pub code: &'static str,
+    pub name: &'static str,
+    /// Letters of the orthography the cmap lacks, in either case.
+    pub missing: Vec<char>,
+}
+
+/// Coverage of the Latin and IPA letters African orthographies use.
+#[derive(Serialize)]
+pub struct AfricanReport {
+    pub blocks: Vec<BlockCoverage>,
+    /// Letters from `KEY_LETTERS`, in both cases, that the cmap lacks.
+    pub missing_key_letters: Vec<char>,
+    pub languages: Vec<LanguageCoverage>,
+}
+
+impl AfricanReport {
+    /// Returns `None` for fonts without a Latin a.
+    pub fn new(face: &Face) -> Option<Self> {
+        face.glyph_index('a')?;
+        let blocks = BLOCKS
+            .iter()
+            .map(|&(name, ranges)| BlockCoverage::new(face, name, ranges))
+            .collect();
+        let languages = LANGUAGES
+            .iter()
+            .map(|&(code, name, exemplars)| LanguageCoverage {
+                code,
+                name,
+                missing: missing(face, exemplars),
+            })
+            .collect();
+        Some(AfricanReport {
+            blocks,
+            missing_key_letters: missing(face, KEY_LETTERS),
+            languages,
+        })
+    }
+
+    /// Number of languages with every letter covered.
+    pub fn supported(&self) -> usize {
+        self.languages
+            .iter()
+            .filter(|language| language.missing.is_empty())
+            .count()
+    }
+}
+
+/// The letters of `lowercase`, and their uppercase forms, that the cmap
+/// lacks.
+fn missing(face: &Face, lowercase: &str) -> Vec<char> {
+    lowercase
+        .chars()
+        .flat_map(|c| [c, uppercase(c)])
+        .filter(|&c| face.glyph_index(c).is_none())
+        .collect()
+}
@@ -0,0 +1,186 @@
+use crate::dump::parse_tag;
+use crate::{parse_face, read_font_file};
+use fontinfo::render::{self, Canvas};
+use fontinfo::{gif, png};
+use std::fs;
+use std::process;
+use ttf_parser::Face;
+
+/// Text rendered unless `--text` is given.
+const DEFAULT_TEXT: &str = "Rag";
+/// Default `--size`, in pixels per em.
+const DEFAULT_SIZE: f32 = 96.0;
+/// Default `--frames`: one pass from the minimum to the maximum and back.
+const DEFAULT_FRAMES: usize = 30;
+/// How long each frame shows, in hundredths of a second.
+const FRAME_DELAY_CS: u16 = 8;
+/// Blank margin around the rendered text, in pixels.
+const PADDING: usize = 16;
+
+/// Runs `fontinfo animate <font-file> [--axis <tag>] [--text <text>]
+/// [--size <px>] [--frames <n>] [-o <file>]`.
+pub fn run(program: &str, args: &[String]) {
+    let mut paths = Vec::new();
+    let mut axis = None;
+    let mut text = DEFAULT_TEXT.to_string();
+    let mut size = DEFAULT_SIZE;
+    let mut frames = DEFAULT_FRAMES;
+    let mut output = "preview.gif".to_string();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage(program));
+        match arg.as_str() {
+            "--axis" => match parse_tag(value()) {
+                Some(tag) => axis = Some(tag),
+                None => usage(program),
+            },
+            "--text" => text = value().clone(),
+            "--size" => match value().parse() {
+                Ok(value) if value > 0.0 => size = value,
+                _ => usage(program),
+            },
+            "--frames" => match value().parse() {
+                Ok(value) if value >= 2 => frames = value,
+                _ => usage(program),
+            },
+            "-o" | "--output" => output = value().clone(),
+            _ if !arg.starts_with('-') => paths.push(arg),
+            _ => usage(program),
+        }
+    }
+    let [font_path] = paths.as_slice() else {
+        usage(program);
+    };
+    let apng = match output.rsplit_once('.').map(|(_, e)| e.to_ascii_lowercase()) {
+        Some(extension) if extension == "gif" => false,
+        Some(extension) if extension == "png" => true,
+        _ => {
+            eprintln!("Output must be a .gif or .png (APNG) file: {}", output);
+            process::exit(1);
+        }
+    };
+
+    let font_data = read_font_file(font_path);
+    let mut face = parse_face(&font_data, font_path);
+    let axes: Vec<_> = face.variation_axes().into_iter().collect();
+    let Some(first) = axes.first() else {
+        eprintln!("'{}' is not a variable font", font_path);
+        process::exit(1);
+    };
+    let tag = axis.unwrap_or(first.tag);
+    let Some(axis) = axes.iter().find(|axis| axis.tag == tag) else {
+        let tags: Vec<String> = axes.iter().map(|axis| axis.tag.to_string()).collect();
+        eprintln!(
+            "'{}' has no '{}' axis; its axes are {}",
+            font_path,
+            tag,
+            tags.join(", ")
+        );
+        process::exit(1);
+    };
+
+    // Up from the minimum to the maximum over the first half of the frames
+    // and back down over the second, so the animation loops smoothly.
+    let values: Vec<f32> = (0..frames)
+        .map(|i| {
+            let phase = 2.0 * i as f32 / frames as f32;
+            let t = if phase <= 1.0 { phase } else { 2.0 - phase };
+            axis.min_value + (axis.max_value - axis.min_value) * t
+        })
+        .collect();
+
+    let scale = size / f32::from(face.units_per_em());
+    // Size the image for the widest and tallest instance.
+    let (mut text_width, mut above, mut below) = (0.0f32, 0.0f32, 0.0f32);
+    for &value in &values {
+        face.set_variation(tag, value);
+        text_width = text_width.max(render::text_width(&face, &text, scale));
+        above = above.max(f32::from(face.ascender()) * scale);
+        below = below.max(-f32::from(face.descender()) * scale);
+    }
+    let width = text_width.ceil().max(1.0) as usize + 2 * PADDING;
+    let height = (above.ceil() + below.ceil()) as usize + 2 * PADDING;
+    let baseline = PADDING as f32 + above.ceil();
+
+    let precise = axis.max_value - axis.min_value < 10.0;
+    let frames: Vec<Vec<u8>> = values
+        .iter()
+        .map(|&value| {
+            face.set_variation(tag, value);
+            let label = if precise {
+                format!("{:.1}", value)
+            } else {
+                format!("{:.0}", value)
+            };
+            render_frame(&face, &text, &label, width, height, baseline, scale)
+        })
+        .collect();
+
+    let data = if apng {
+        let rgb: Vec<Vec<u8>> = frames
+            .iter()
+            .map(|frame| frame.iter().flat_map(|&gray| [gray; 3]).collect())
+            .collect();
+        png::encode_rgb_animation(width, height, &rgb, FRAME_DELAY_CS * 10)
+    } else {
+        gif::encode_gray_animation(width, height, &frames, FRAME_DELAY_CS)
+    };
+    if let Err(e) = fs::write(&output, data) {
+        eprintln!("Error writing {}: {}", output, e);
+        process::exit(1);
+    }
+    println!(
+        "Wrote {} ({} frames, {}×{}): {} {}–{}",
+        output,
+        values.len(),
+        width,
+        height,
+        tag,
+        axis.min_value,
+        axis.max_value
+    );
+}
+
+/// Renders one instance in black on white, as grayscale pixels, with the
+/// axis value in the top left corner.
+fn render_frame(
+    face: &Face,
+    text: &str,
+    label: &str,
+    width: usize,
+    height: usize,
+    baseline: f32,
+    scale: f32,
+) -> Vec<u8> {
+    let mut canvas = Canvas::new(width, height);
+    canvas.draw_text(face, text, PADDING as f32, baseline, scale);
+    canvas.draw_label(label, 4, 4);
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| ((1.0 - canvas.coverage(x, y)) * 255.0).round() as u8)
+        .collect()
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} animate <font-file> [options]", program);
+    eprintln!("  --axis <tag>     Axis to animate (default the first axis)");
+    eprintln!(
+        "  --text <text>    Text to render (default \"{}\")",
+        DEFAULT_TEXT
+    );
+    eprintln!(
+        "  --size <px>      Pixels per em (default {})",
+        DEFAULT_SIZE
+    );
+    eprintln!(
+        "  --frames <n>     Frames from the minimum to the maximum and back (default {})",
+        DEFAULT_FRAMES
+    );
+    eprintln!("  -o <file>        Output file, .gif or .png for APNG (default preview.gif)");
+    eprintln!(
+        "Example: {} animate font.ttf --axis wght --text \"Rag\" -o preview.gif",
+        program
+    );
+    process::exit(1);
+}
@@ -0,0 +1,97 @@
+use crate::{VIOLATIONS_SHOWN, read_font_file};
+use fontinfo::sarif;
+use fontinfo::security::SecurityReport;
+use std::process;
+
+/// Runs `fontinfo audit-security <font-file>... [--format sarif]`, exiting
+/// with status 1 when any file has findings.
+pub fn run(program: &str, args: &[String]) {
+    let mut paths = Vec::new();
+    let mut sarif = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next().map(String::as_str) {
+                Some("sarif") => sarif = true,
+                _ => usage(program),
+            },
+            _ if !arg.starts_with('-') => paths.push(arg),
+            _ => usage(program),
+        }
+    }
+    if paths.is_empty() {
+        usage(program);
+    }
+    if sarif {
+        print_sarif(&paths);
+        return;
+    }
+
+    let mut flagged = 0;
+    for (i, path) in paths.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let report = SecurityReport::new(&read_font_file(path));
+        println!("{}", path);
+        for scan in &report.scans {
+            println!("{:<4}  {}", scan.status.name().to_uppercase(), scan.name);
+            for finding in scan.findings.iter().take(VIOLATIONS_SHOWN) {
+                println!("      {}", finding);
+            }
+            if scan.findings.len() > VIOLATIONS_SHOWN {
+                println!(
+                    "      ... and {} more",
+                    scan.findings.len() - VIOLATIONS_SHOWN
+                );
+            }
+        }
+        match report.findings() {
+            0 => println!("No anomalies found"),
+            n => {
+                flagged += 1;
+                println!("{} anomal{} found", n, if n == 1 { "y" } else { "ies" });
+            }
+        }
+    }
+    if flagged > 0 {
+        process::exit(1);
+    }
+}
+
+/// Prints one SARIF log covering every file, with a warning per finding.
+fn print_sarif(paths: &[&String]) {
+    let mut log = sarif::Log::default();
+    let mut flagged = false;
+    for path in paths {
+        let report = SecurityReport::new(&read_font_file(path));
+        for scan in &report.scans {
+            let index = log.rule(&sarif::rule_id(scan.name), scan.name, None);
+            for finding in &scan.findings {
+                log.result(index, sarif::Level::Warning, finding, path);
+            }
+        }
+        flagged |= report.findings() > 0;
+    }
+    match serde_json::to_string_pretty(&log) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            eprintln!("Error serializing SARIF: {}", e);
+            process::exit(1);
+        }
+    }
+    if flagged {
+        process::exit(1);
+    }
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} audit-security <font-file>... [--format sarif]",
+        program
+    );
+    eprintln!("Flags table directory, size, offset and hinting anomalies seen in");
+    eprintln!("malicious fonts. Exits with status 1 when any file has findings.");
+    eprintln!("  --format sarif   Print the findings as a SARIF 2.1.0 log");
+    process::exit(1);
+}
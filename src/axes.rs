@@ -0,0 +1,249 @@
+use crate::config::Config;
+use ttf_parser::VariationAxis;
+
+/// What fontinfo knows about a registered or widely used axis tag.
+pub struct KnownAxis {
+    pub tag: &'static str,
+    pub description: &'static str,
+    /// True for axes registered in the OpenType spec, false for de-facto
+    /// axes from the Google Fonts axis registry.
+    pub registered: bool,
+    /// The range of values that makes sense for this axis.
+    pub valid_range: Option<(f32, f32)>,
+}
+
+const KNOWN_AXES: &[KnownAxis] = &[
+    KnownAxis {
+        tag: "wght",
+        description: "Weight: stroke thickness, matching usWeightClass (1–1000)",
+        registered: true,
+        valid_range: Some((1.0, 1000.0)),
+    },
+    KnownAxis {
+        tag: "wdth",
+        description: "Width: percentage of normal width (100 = normal)",
+        registered: true,
+        valid_range: Some((f32::MIN_POSITIVE, 1000.0)),
+    },
+    KnownAxis {
+        tag: "ital",
+        description: "Italic: 0 = upright, 1 = italic",
+        registered: true,
+        valid_range: Some((0.0, 1.0)),
+    },
+    KnownAxis {
+        tag: "slnt",
+        description: "Slant: degrees, negative values lean right (clockwise)",
+        registered: true,
+        valid_range: Some((-90.0, 90.0)),
+    },
+    KnownAxis {
+        tag: "opsz",
+        description: "Optical size: text size in points the design is tuned for",
+        registered: true,
+        valid_range: Some((f32::MIN_POSITIVE, f32::MAX)),
+    },
+    KnownAxis {
+        tag: "GRAD",
+        description: "Grade: changes weight without changing glyph widths",
+        registered: false,
+        valid_range: Some((-1000.0, 1000.0)),
+    },
+    KnownAxis {
+        tag: "CASL",
+        description: "Casual: 0 = linear, 1 = casual letterforms",
+        registered: false,
+        valid_range: Some((0.0, 1.0)),
+    },
+    KnownAxis {
+        tag: "MONO",
+        description: "Monospace: 0 = proportional, 1 = monospaced",
+        registered: false,
+        valid_range: Some((0.0, 1.0)),
+    },
+    KnownAxis {
+        tag: "CRSV",
+        description: "Cursive: 0 = never, 0.5 = auto, 1 = always cursive forms",
+        registered: false,
+        valid_range: Some((0.0, 1.0)),
+    },
+    KnownAxis {
+        tag: "SOFT",
+        description: "Softness: rounds corners and terminals (0–100)",
+        registered: false,
+        valid_range: Some((0.0, 100.0)),
+    },
+    KnownAxis {
+        tag: "WONK",
+        description: "Wonky: 0 = normal, 1 = substitute wonky forms",
+        registered: false,
+        valid_range: Some((0.0, 1.0)),
+    },
+    KnownAxis {
+        tag: "FILL",
+        description: "Fill: 0 = outlined, 1 = filled icon forms",
+        registered: false,
+        valid_range: Some((0.0, 1.0)),
+    },
+    KnownAxis {
+        tag: "XOPQ",
+        description: "Parametric thick stroke (x opaque)",
+        registered: false,
+        valid_range: None,
+    },
+    KnownAxis {
+        tag: "YOPQ",
+        description: "Parametric thin stroke (y opaque)",
+        registered: false,
+        valid_range: None,
+    },
+    KnownAxis {
+        tag: "XTRA",
+        description: "Parametric counter width (x transparent)",
+        registered: false,
+        valid_range: None,
+    },
+    KnownAxis {
+        tag: "YTUC",
+        description: "Parametric uppercase height",
+        registered: false,
+        valid_range: None,
+    },
+    KnownAxis {
+        tag: "YTLC",
+        description: "Parametric lowercase height",
+        registered: false,
+        valid_range: None,
+    },
+    KnownAxis {
+        tag: "YTAS",
+        description: "Parametric ascender height",
+        registered: false,
+        valid_range: None,
+    },
+    KnownAxis {
+        tag: "YTDE",
+        description: "Parametric descender depth",
+        registered: false,
+        valid_range: None,
+    },
+    KnownAxis {
+        tag: "YTFI",
+        description: "Parametric figure height",
+        registered: false,
+        valid_range: None,
+    },
+];
+
+/// Where an axis description came from.
+#[derive(Clone, Copy, PartialEq)]
+pub enum AxisSource {
+    Registered,
+    DeFacto,
+    /// The user's config file.
+    Config,
+}
+
+impl AxisSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            AxisSource::Registered => "registered",
+            AxisSource::DeFacto => "de-facto",
+            AxisSource::Config => "from config",
+        }
+    }
+}
+
+pub struct AxisDescription<'a> {
+    pub source: AxisSource,
+    pub description: &'a str,
+    pub valid_range: Option<(f32, f32)>,
+}
+
+/// Describes an axis tag, preferring the user's config over the built-in
+/// registry so teams can document (or override) their own axes.
+pub fn lookup<'a>(tag: &str, config: &'a Config) -> Option<AxisDescription<'a>> {
+    if let Some(entry) = config.axes.get(tag) {
+        let valid_range = match (entry.min, entry.max) {
+            (None, None) => None,
+            (min, max) => Some((min.unwrap_or(f32::MIN), max.unwrap_or(f32::MAX))),
+        };
+        return Some(AxisDescription {
+            source: AxisSource::Config,
+            description: &entry.description,
+            valid_range,
+        });
+    }
+
+    KNOWN_AXES
+        .iter()
+        .find(|a| a.tag == tag)
+        .map(|known| AxisDescription {
+            source: if known.registered {
+                AxisSource::Registered
+            } else {
+                AxisSource::DeFacto
+            },
+            description: known.description,
+            valid_range: known.valid_range,
+        })
+}
+
+/// Checks an axis definition against the spec, known conventions and the
+/// ranges given in the config, returning a message for each suspicious
+/// detail.
+pub fn check_axis(axis: &VariationAxis, config: &Config) -> Vec<String> {
+    let tag = axis.tag.to_string();
+    let (min, def, max) = (axis.min_value, axis.def_value, axis.max_value);
+    let mut warnings = Vec::new();
+
+    if min > max {
+        warnings.push(format!("minimum {} is greater than maximum {}", min, max));
+    }
+    if def < min || def > max {
+        warnings.push(format!(
+            "default {} is outside the range {}–{}",
+            def, min, max
+        ));
+    }
+    if min == max {
+        warnings.push("axis has no range (minimum equals maximum)".to_string());
+    }
+
+    let description = lookup(&tag, config);
+    if let Some(known) = &description
+        && let Some((lo, hi)) = known.valid_range
+        && (min < lo || max > hi)
+    {
+        warnings.push(format!(
+            "range {}–{} is outside the expected range for '{}'",
+            min, max, tag
+        ));
+    }
+
+    if tag == "slnt" && min >= 0.0 && max > 0.0 {
+        warnings.push(
+            "only positive slnt values: these lean left; right-leaning obliques \
+             should use negative values"
+                .to_string(),
+        );
+    }
+    if tag == "ital" && (min != 0.0 || max != 1.0) {
+        warnings.push("ital is expected to span exactly 0–1".to_string());
+    }
+    if tag == "wght" && !(1.0..=1000.0).contains(&def) {
+        warnings.push(format!(
+            "default weight {} is not a valid weight class",
+            def
+        ));
+    }
+    if !KNOWN_AXES.iter().any(|a| a.tag == tag) && tag.chars().any(|c| c.is_ascii_lowercase()) {
+        warnings.push(
+            "unregistered tag uses lowercase letters, which are reserved for \
+             registered axes; custom axes should be uppercase"
+                .to_string(),
+        );
+    }
+
+    warnings
+}
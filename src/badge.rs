@@ -0,0 +1,85 @@
+use crate::get_name;
+use crate::languages::LanguageSupport;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use ttf_parser::Face;
+
+/// A shields.io endpoint badge.
+#[derive(Serialize)]
+pub struct Badge {
+    #[serde(rename = "schemaVersion")]
+    pub schema_version: u8,
+    pub label: &'static str,
+    pub message: String,
+    pub color: &'static str,
+}
+
+impl Badge {
+    fn new(label: &'static str, message: String, color: &'static str) -> Self {
+        Badge {
+            schema_version: 1,
+            label,
+            message,
+            color,
+        }
+    }
+}
+
+/// Well-known licenses, matched against the name table's license
+/// description and URL.
+const LICENSES: [(&str, &str); 6] = [
+    ("SIL Open Font License", "OFL-1.1"),
+    ("scripts.sil.org/OFL", "OFL-1.1"),
+    ("openfontlicense.org", "OFL-1.1"),
+    ("Apache License", "Apache-2.0"),
+    ("Ubuntu Font Licence", "UFL-1.0"),
+    ("Bitstream Vera", "Bitstream Vera"),
+];
+
+/// Builds the badges for a font, keyed by the name to publish each under.
+pub fn build(face: &Face) -> BTreeMap<&'static str, Badge> {
+    let mut badges = BTreeMap::new();
+
+    badges.insert(
+        "glyphs",
+        Badge::new("glyphs", face.number_of_glyphs().to_string(), "blue"),
+    );
+
+    let languages = LanguageSupport::new(face);
+    let percent = languages.percent();
+    let color = match percent {
+        p if p >= 75.0 => "brightgreen",
+        p if p >= 40.0 => "green",
+        p if p >= 15.0 => "yellow",
+        _ => "orange",
+    };
+    badges.insert(
+        "languages",
+        Badge::new("languages", format!("{:.0}%", percent), color),
+    );
+
+    let (license, color) = match license(face) {
+        Some(license) => (license, "blue"),
+        None => ("unknown".to_string(), "lightgrey"),
+    };
+    badges.insert("license", Badge::new("license", license, color));
+
+    badges
+}
+
+/// Identifies the license from name IDs 13 (description) and 14 (URL),
+/// falling back to "custom" for license text that isn't recognized.
+fn license(face: &Face) -> Option<String> {
+    let text: Vec<String> = [13, 14]
+        .into_iter()
+        .filter_map(|id| get_name(face, id))
+        .collect();
+    if text.is_empty() {
+        return None;
+    }
+    let id = LICENSES
+        .iter()
+        .find(|(needle, _)| text.iter().any(|t| t.contains(needle)))
+        .map_or("custom", |&(_, id)| id);
+    Some(id.to_string())
+}
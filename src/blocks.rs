@@ -0,0 +1,71 @@
+use crate::unicode::{BlockCoverage, covered_chars};
+use serde::Serialize;
+use ttf_parser::Face;
+
+/// Every Unicode block with its number of assigned code points.
+const BLOCKS: &str = include_str!("../data/blocks.txt");
+
+/// The cmap's code points, counted per Unicode block.
+#[derive(Serialize)]
+pub struct BlockReport {
+    /// Code points mapped by any Unicode cmap subtable.
+    pub mapped: usize,
+    /// Blocks with any mapped code point, in code point order. `total` is
+    /// the block's assigned characters.
+    pub blocks: Vec<BlockCoverage>,
+    /// Mapped code points outside the bundled blocks, i.e. in blocks added
+    /// after Unicode 14.0.
+    pub outside_blocks: usize,
+}
+
+impl BlockReport {
+    /// Returns `None` for fonts without a Unicode cmap.
+    pub fn new(face: &Face) -> Option<Self> {
+        let covered = covered_chars(face);
+        if covered.is_empty() {
+            return None;
+        }
+
+        let mut blocks = Vec::new();
+        let mut in_blocks = 0;
+        for (start, end, total, name) in blocks_list() {
+            // Surrogate blocks can't be mapped by a cmap.
+            let (Some(start), Some(end)) = (char::from_u32(start), char::from_u32(end)) else {
+                continue;
+            };
+            let count = covered.range(start..=end).count();
+            if count > 0 {
+                in_blocks += count;
+                blocks.push(BlockCoverage {
+                    name,
+                    covered: count,
+                    total,
+                });
+            }
+        }
+        Some(BlockReport {
+            mapped: covered.len(),
+            blocks,
+            outside_blocks: covered.len() - in_blocks,
+        })
+    }
+}
+
+/// Parses the bundled block list.
+fn blocks_list() -> impl Iterator<Item = (u32, u32, usize, &'static str)> {
+    BLOCKS.lines().filter_map(|line| {
+        if line.starts_with('#') {
+            return None;
+        }
+        let mut fields = line.splitn(3, ';');
+        let (start, end) = fields.next()?.split_once("..")?;
+        let total = fields.next()?.trim().parse().ok()?;
+        let name = fields.next()?.trim();
+        Some((
+            u32::from_str_radix(start, 16).ok()?,
+            u32::from_str_radix(end, 16).ok()?,
+            total,
+            name,
+        ))
+    })
+}
@@ -0,0 +1,104 @@
+use crate::{Mode, ReportArgs, TextSource};
+use fontinfo::config::Config;
+use fontinfo::i18n::Lang;
+use fontinfo::profile::Profile;
+use fontinfo::sha256::{self, Sha256};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+/// The revision of the report output, part of every key. Bump it with any
+/// change to what a report prints, so reports cached by earlier builds are
+/// never returned for the same font.
+const REPORT_FORMAT: u32 = 1;
+
+/// Reports written by earlier runs, in `$XDG_CACHE_HOME/fontinfo`
+/// (`~/.cache/fontinfo`). Each is a file named by the hash of the font's
+/// content and everything else its output depends on: the report format,
+/// the options, the config file, the report language and the font's path,
+/// which reports print. Nothing is ever evicted; the directory can be
+/// deleted at any time.
+pub struct ReportCache {
+    dir: PathBuf,
+    /// The hash of everything but the path and the font, shared by every
+    /// font of a run.
+    options: Sha256,
+}
+
+impl ReportCache {
+    /// Returns `None` when `report` turns caching off, or its mode writes
+    /// files, reads files other than the font or depends on the terminal.
+    pub fn open(args: &[String], report: &ReportArgs, config: &Config) -> Option<Self> {
+        if !report.cache
+            || matches!(
+                report.mode,
+                Mode::Preview(..)
+                    | Mode::ExportSvg(..)
+                    | Mode::ExportSvgAll(_)
+                    | Mode::RenderSpecimen(..)
+                    | Mode::CheckText(TextSource::File(_))
+                    | Mode::Emoji(Some(_))
+                    | Mode::EmojiSequences(Some(_))
+                    // SMuFL metadata and italic siblings next to the font.
+                    | Mode::Profile(Profile::Music | Profile::Coding, _)
+            )
+        {
+            return None;
+        }
+        let mut options = Sha256::default();
+        field(&mut options, &REPORT_FORMAT.to_le_bytes());
+        // Font paths are hashed per font, so the same font is found again
+        // when it's checked alongside others.
+        for arg in args {
+            if arg != "--no-cache" && !report.font_paths.contains(arg) {
+                field(&mut options, arg.as_bytes());
+            }
+        }
+        field(&mut options, config.source.as_bytes());
+        field(&mut options, Lang::from_env().code().as_bytes());
+        Some(ReportCache {
+            dir: cache_dir()?,
+            options,
+        })
+    }
+
+    /// The name the report of the font at `path` is cached under.
+    pub fn key(&self, path: &str, font_data: &[u8]) -> String {
+        let mut hasher = self.options.clone();
+        field(&mut hasher, path.as_bytes());
+        field(&mut hasher, font_data);
+        sha256::hex(&hasher.finish())
+    }
+
+    pub fn load(&self, key: &str) -> Option<Vec<u8>> {
+        fs::read(self.dir.join(key)).ok()
+    }
+
+    /// Saves a report, ignoring failures: without the cache the report is
+    /// just written again next time. The file is renamed into place so a
+    /// concurrent run never reads half a report.
+    pub fn store(&self, key: &str, output: &[u8]) {
+        let temp = self.dir.join(format!("{}.{}.tmp", key, process::id()));
+        let stored = fs::create_dir_all(&self.dir)
+            .and_then(|()| fs::write(&temp, output))
+            .and_then(|()| fs::rename(&temp, self.dir.join(key)));
+        if stored.is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+    }
+}
+
+/// Hashes a length-prefixed value, so `["ab", "c"]` and `["a", "bc"]`
+/// differ.
+fn field(hasher: &mut Sha256, value: &[u8]) {
+    hasher.update(&(value.len() as u64).to_be_bytes());
+    hasher.update(value);
+}
+
+fn cache_dir() -> Option<PathBuf> {
+    let cache_dir = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+    Some(cache_dir.join("fontinfo"))
+}
@@ -0,0 +1,64 @@
+use crate::unicode::covered_chars;
+use serde::Serialize;
+use ttf_parser::Face;
+
+/// Upper/lowercase pairs among the characters the cmap covers.
+#[derive(Serialize)]
+pub struct CaseReport {
+    /// Pairs with both letters covered.
+    pub complete: usize,
+    /// Covered lowercase letters whose uppercase is missing, as
+    /// (lowercase, uppercase).
+    pub missing_upper: Vec<(char, char)>,
+    /// Covered uppercase letters whose lowercase is missing, as
+    /// (uppercase, lowercase).
+    pub missing_lower: Vec<(char, char)>,
+}
+
+impl CaseReport {
+    /// Pairs letters through Unicode's simple case mappings; letters whose
+    /// case mapping needs several characters, such as ß → SS, are left
+    /// out. Returns `None` for fonts without any cased letters.
+    pub fn new(face: &Face) -> Option<Self> {
+        let covered = covered_chars(face);
+        let mut report = CaseReport {
+            complete: 0,
+            missing_upper: Vec::new(),
+            missing_lower: Vec::new(),
+        };
+        for &c in &covered {
+            if c.is_lowercase()
+                && let Some(upper) = single(c.to_uppercase())
+                && upper != c
+            {
+                if covered.contains(&upper) {
+                    report.complete += 1;
+                } else {
+                    report.missing_upper.push((c, upper));
+                }
+            } else if c.is_uppercase()
+                && let Some(lower) = single(c.to_lowercase())
+                && lower != c
+                && !covered.contains(&lower)
+            {
+                // Complete pairs were already counted from the lowercase
+                // side.
+                report.missing_lower.push((c, lower));
+            }
+        }
+
+        let empty = report.complete == 0
+            && report.missing_upper.is_empty()
+            && report.missing_lower.is_empty();
+        (!empty).then_some(report)
+    }
+}
+
+/// The only character of a case mapping, or `None` for mappings that
+/// expand into several.
+fn single(mut mapping: impl Iterator<Item = char>) -> Option<char> {
+    match (mapping.next(), mapping.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
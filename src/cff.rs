@@ -0,0 +1,210 @@
+use crate::reader::Reader;
+use ttf_parser::{Face, Tag};
+
+/// DICT operators, with two-byte operators as 1200 + their second byte.
+const PRIVATE: u16 = 18;
+const BLUE_VALUES: u16 = 6;
+const OTHER_BLUES: u16 = 7;
+const STD_HW: u16 = 10;
+const STD_VW: u16 = 11;
+const FD_ARRAY: u16 = 1236;
+
+/// The PostScript hinting data in a CFF or CFF2 Private DICT.
+pub struct PrivateHints {
+    /// Alignment zones from BlueValues and OtherBlues.
+    pub alignment_zones: usize,
+    /// The dominant horizontal and vertical stem widths, StdHW and StdVW,
+    /// in font units.
+    pub std_hw: Option<f32>,
+    pub std_vw: Option<f32>,
+}
+
+impl PrivateHints {
+    /// Reads the first Private DICT with alignment zones, or the first one
+    /// at all; for CID-keyed and CFF2 fonts, every Font DICT's Private DICT
+    /// is considered. Returns `None` for fonts without CFF outlines.
+    pub fn new(face: &Face) -> Option<Self> {
+        let (table, privates) = if let Some(cff) = face.raw_face().table(Tag::from_bytes(b"CFF ")) {
+            (cff, cff_privates(cff))
+        } else {
+            let cff2 = face.raw_face().table(Tag::from_bytes(b"CFF2"))?;
+            (cff2, cff2_privates(cff2))
+        };
+        let hints: Vec<PrivateHints> = privates
+            .into_iter()
+            .filter_map(|(size, offset)| table.get(offset..offset.checked_add(size)?))
+            .map(|data| {
+                let dict = parse_dict(data);
+                let operands = |op| {
+                    dict.iter()
+                        .find(|(operator, _)| *operator == op)
+                        .map_or(&[][..], |(_, operands)| operands.as_slice())
+                };
+                PrivateHints {
+                    alignment_zones: (operands(BLUE_VALUES).len() + operands(OTHER_BLUES).len())
+                        / 2,
+                    std_hw: operands(STD_HW).first().map(|&width| width as f32),
+                    std_vw: operands(STD_VW).first().map(|&width| width as f32),
+                }
+            })
+            .collect();
+        let index = hints
+            .iter()
+            .position(|hints| hints.alignment_zones > 0)
+            .unwrap_or(0);
+        Some(hints.into_iter().nth(index).unwrap_or(PrivateHints {
+            alignment_zones: 0,
+            std_hw: None,
+            std_vw: None,
+        }))
+    }
+}
+
+/// The (size, offset) of each Private DICT in a CFF table: the Top DICT's
+/// own, or those of the FDArray in CID-keyed fonts.
+fn cff_privates(cff: &[u8]) -> Vec<(usize, usize)> {
+    let Some(header_size) = Reader::at(cff, 2).read_u8() else {
+        return Vec::new();
+    };
+    // The Name INDEX comes first, then the Top DICT INDEX.
+    let Some((_, after_names)) = read_index(cff, usize::from(header_size), false) else {
+        return Vec::new();
+    };
+    let Some((top_dicts, _)) = read_index(cff, after_names, false) else {
+        return Vec::new();
+    };
+    let Some(top) = top_dicts
+        .first()
+        .map(|&(start, end)| parse_dict(&cff[start..end]))
+    else {
+        return Vec::new();
+    };
+    privates(cff, &top, false)
+}
+
+/// The (size, offset) of the Private DICT of each Font DICT in a CFF2
+/// table's FDArray.
+fn cff2_privates(cff2: &[u8]) -> Vec<(usize, usize)> {
+    let mut r = Reader::at(cff2, 2);
+    let (Some(header_size), Some(top_length)) = (r.read_u8(), r.read_u16()) else {
+        return Vec::new();
+    };
+    let start = usize::from(header_size);
+    let Some(top) = cff2.get(start..start + usize::from(top_length)) else {
+        return Vec::new();
+    };
+    privates(cff2, &parse_dict(top), true)
+}
+
+fn privates(table: &[u8], top: &[(u16, Vec<f64>)], cff2: bool) -> Vec<(usize, usize)> {
+    let operand = |dict: &[(u16, Vec<f64>)], op: u16| {
+        dict.iter()
+            .find(|(operator, _)| *operator == op)
+            .map(|(_, operands)| operands.clone())
+    };
+    let private = |dict: &[(u16, Vec<f64>)]| match operand(dict, PRIVATE)?.as_slice() {
+        &[size, offset] => Some((size as usize, offset as usize)),
+        _ => None,
+    };
+    if let Some(private) = private(top) {
+        return vec![private];
+    }
+    let Some(&[fd_array]) = operand(top, FD_ARRAY).as_deref() else {
+        return Vec::new();
+    };
+    let Some((font_dicts, _)) = read_index(table, fd_array as usize, cff2) else {
+        return Vec::new();
+    };
+    font_dicts
+        .iter()
+        .filter_map(|&(start, end)| private(&parse_dict(&table[start..end])))
+        .collect()
+}
+
+/// Reads an INDEX at `offset`, returning the byte range of each element and
+/// the offset just past the INDEX. CFF2 INDEXes have a 32-bit count.
+fn read_index(data: &[u8], offset: usize, cff2: bool) -> Option<(Vec<(usize, usize)>, usize)> {
+    let mut r = Reader::at(data, offset);
+    let count = if cff2 {
+        r.read_u32()? as usize
+    } else {
+        usize::from(r.read_u16()?)
+    };
+    let count_size = if cff2 { 4 } else { 2 };
+    if count == 0 {
+        return Some((Vec::new(), offset + count_size));
+    }
+    let off_size = usize::from(r.read_u8()?);
+    let mut offsets = Vec::with_capacity(count + 1);
+    for _ in 0..=count {
+        let bytes = r.read_bytes(off_size)?;
+        offsets.push(
+            bytes
+                .iter()
+                .fold(0usize, |value, &b| value << 8 | usize::from(b)),
+        );
+    }
+    // Offsets are 1-based from the byte before the element data.
+    let base = offset + count_size + 1 + (count + 1) * off_size - 1;
+    let elements = offsets
+        .windows(2)
+        .map(|pair| (base + pair[0], base + pair[1]))
+        .filter(|&(start, end)| start <= end && end <= data.len())
+        .collect();
+    Some((elements, base + offsets[count]))
+}
+
+/// Parses a DICT into (operator, operands) entries.
+fn parse_dict(data: &[u8]) -> Vec<(u16, Vec<f64>)> {
+    let mut entries = Vec::new();
+    let mut operands = Vec::new();
+    let mut r = Reader::new(data);
+    while let Some(b0) = r.read_u8() {
+        let value = match b0 {
+            0..=11 | 13..=21 | 22..=24 => {
+                entries.push((u16::from(b0), std::mem::take(&mut operands)));
+                continue;
+            }
+            12 => {
+                let Some(b1) = r.read_u8() else { break };
+                entries.push((1200 + u16::from(b1), std::mem::take(&mut operands)));
+                continue;
+            }
+            28 => r.read_i16().map(f64::from),
+            29 => r.read_u32().map(|v| f64::from(v as i32)),
+            30 => read_real(&mut r),
+            32..=246 => Some(f64::from(i32::from(b0) - 139)),
+            247..=250 => r
+                .read_u8()
+                .map(|b1| f64::from((i32::from(b0) - 247) * 256 + i32::from(b1) + 108)),
+            251..=254 => r
+                .read_u8()
+                .map(|b1| f64::from(-(i32::from(b0) - 251) * 256 - i32::from(b1) - 108)),
+            _ => None,
+        };
+        match value {
+            Some(value) => operands.push(value),
+            None => break,
+        }
+    }
+    entries
+}
+
+/// Reads a real number operand, packed as decimal nibbles.
+fn read_real(r: &mut Reader) -> Option<f64> {
+    let mut text = String::new();
+    loop {
+        let byte = r.read_u8()?;
+        for nibble in [byte >> 4, byte & 0x0F] {
+            match nibble {
+                0..=9 => text.push(char::from(b'0' + nibble)),
+                0xA => text.push('.'),
+                0xB => text.push('E'),
+                0xC => text.push_str("E-"),
+                0xE => text.push('-'),
+                0xF => return Some(text.parse().unwrap_or(0.0)),
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Unicode character names, with derived names listed as ranges.
+const NAMES: &str = include_str!("../data/names.txt");
+
+/// Short names of the leading consonants, vowels and trailing consonants
+/// that make up Hangul syllable names.
+const JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+const JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+const JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+/// Looks up the names of `chars` in one pass over the bundled list.
+/// Characters without a name, such as controls and unassigned code points,
+/// are left out.
+pub fn names(chars: &BTreeSet<char>) -> BTreeMap<char, String> {
+    let mut names = BTreeMap::new();
+    for line in NAMES.lines().filter(|line| !line.starts_with('#')) {
+        let Some((code_points, name)) = line.split_once(';') else {
+            continue;
+        };
+        let (start, end) = code_points
+            .split_once("..")
+            .unwrap_or((code_points, code_points));
+        let (Some(start), Some(end)) = (parse(start), parse(end)) else {
+            continue;
+        };
+        for &c in chars.range(start..=end) {
+            let name = match name.strip_suffix('*') {
+                Some("HANGUL SYLLABLE ") => hangul_syllable(c),
+                Some(prefix) => format!("{}{:04X}", prefix, c as u32),
+                None => name.to_string(),
+            };
+            names.insert(c, name);
+        }
+    }
+    names
+}
+
+fn parse(code_point: &str) -> Option<char> {
+    u32::from_str_radix(code_point, 16)
+        .ok()
+        .and_then(char::from_u32)
+}
+
+/// Composes a Hangul syllable's name from its jamo, as Unicode's chapter
+/// 3.12 describes.
+fn hangul_syllable(c: char) -> String {
+    let index = c as usize - 0xAC00;
+    format!(
+        "HANGUL SYLLABLE {}{}{}",
+        JAMO_L[index / (21 * 28)],
+        JAMO_V[index % (21 * 28) / 28],
+        JAMO_T[index % 28]
+    )
+}
@@ -0,0 +1,157 @@
+use crate::{VIOLATIONS_SHOWN, contain_panic};
+use fontinfo::checks::{CheckReport, Severity};
+use fontinfo::sarif;
+use serde::Serialize;
+use std::fs;
+use std::process;
+use ttf_parser::Face;
+
+#[derive(Serialize)]
+struct FileReport<'a> {
+    file: &'a str,
+    #[serde(flatten)]
+    report: CheckReport,
+}
+
+/// Runs `fontinfo check <font-file>... [--json | --format sarif]`, exiting
+/// with status 1 when any file fails an error-severity rule or can't be
+/// checked. Files that can't be read or parsed are reported after the
+/// others rather than stopping the run.
+pub fn run(program: &str, args: &[String]) {
+    let mut paths = Vec::new();
+    let mut json = false;
+    let mut sarif = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--format" => match args.next().map(String::as_str) {
+                Some("sarif") => sarif = true,
+                _ => usage(program),
+            },
+            _ if !arg.starts_with('-') => paths.push(arg),
+            _ => usage(program),
+        }
+    }
+    if paths.is_empty() || (json && sarif) {
+        usage(program);
+    }
+
+    let mut failed = false;
+    let mut errors = Vec::new();
+    let mut reports: Vec<FileReport> = Vec::new();
+    for path in paths {
+        match check_file(path) {
+            Ok(report) => {
+                failed |= report.failures(Severity::Error) > 0;
+                reports.push(FileReport { file: path, report });
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+
+    if json {
+        match serde_json::to_string_pretty(&reports) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Error serializing JSON: {}", e);
+                process::exit(1);
+            }
+        }
+    } else if sarif {
+        print_sarif(&reports);
+    } else {
+        for (i, file) in reports.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            print_report(file);
+        }
+    }
+    for e in &errors {
+        eprintln!("{}", e);
+    }
+    if failed || !errors.is_empty() {
+        process::exit(1);
+    }
+}
+
+fn check_file(path: &str) -> Result<CheckReport, String> {
+    let data = fs::read(path).map_err(|e| format!("Error reading font file '{}': {}", path, e))?;
+    let face =
+        Face::parse(&data, 0).map_err(|e| format!("Error parsing font file '{}': {}", path, e))?;
+    contain_panic(|| CheckReport::new(&face))
+        .map_err(|e| format!("Error checking '{}': {}", path, e))
+}
+
+fn print_report(file: &FileReport) {
+    println!("{}", file.file);
+    let width = file
+        .report
+        .rules
+        .iter()
+        .map(|r| r.id.len())
+        .max()
+        .unwrap_or(0);
+    for rule in &file.report.rules {
+        println!(
+            "{:<4}  {:<7}  {:<width$}  {}",
+            rule.status.name().to_uppercase(),
+            rule.severity.name(),
+            rule.id,
+            rule.description,
+        );
+        for message in rule.messages.iter().take(VIOLATIONS_SHOWN) {
+            println!("      {}", message);
+        }
+        if rule.messages.len() > VIOLATIONS_SHOWN {
+            println!(
+                "      ... and {} more",
+                rule.messages.len() - VIOLATIONS_SHOWN
+            );
+        }
+    }
+    let count = |n: usize, noun: &str| format!("{} {}{}", n, noun, if n == 1 { "" } else { "s" });
+    println!(
+        "{}, {}",
+        count(file.report.failures(Severity::Error), "error"),
+        count(file.report.failures(Severity::Warning), "warning")
+    );
+}
+
+/// Prints one SARIF log covering every file, with a result per message.
+fn print_sarif(reports: &[FileReport]) {
+    let mut log = sarif::Log::default();
+    for file in reports {
+        for rule in &file.report.rules {
+            let index = log.rule(rule.id, rule.description, None);
+            let level = match rule.severity {
+                Severity::Error => sarif::Level::Error,
+                Severity::Warning => sarif::Level::Warning,
+            };
+            for message in &rule.messages {
+                log.result(index, level, message, file.file);
+            }
+        }
+    }
+    match serde_json::to_string_pretty(&log) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            eprintln!("Error serializing SARIF: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} check <font-file>... [--json | --format sarif]",
+        program
+    );
+    eprintln!("Runs lint rules for names, vertical metrics, style flags, .notdef and");
+    eprintln!("cmap. Exits with status 1 when any file fails an error-severity rule or");
+    eprintln!("can't be read or parsed.");
+    eprintln!("  --json           Print the results as JSON");
+    eprintln!("  --format sarif   Print the failures as a SARIF 2.1.0 log");
+    process::exit(1);
+}
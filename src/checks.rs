@@ -0,0 +1,357 @@
+use crate::get_name;
+use crate::os2::Os2Report;
+use crate::special_cases::Status;
+use crate::unicode::{MAX_CODE_POINT, cmap_groups, subtable_codepoints};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use ttf_parser::{Face, GlyphId, Tag, name_id};
+
+/// How much a failed rule matters: errors break rendering or installation
+/// somewhere, warnings are likely mistakes.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn name(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// One rule of `fontinfo check` and every place the font breaks it.
+#[derive(Serialize)]
+pub struct Rule {
+    /// Stable ID such as `name/required-ids`, for filtering and
+    /// suppressing findings in CI.
+    pub id: &'static str,
+    pub severity: Severity,
+    pub description: &'static str,
+    pub status: Status,
+    pub messages: Vec<String>,
+}
+
+/// The results of `fontinfo check`: a small fontbakery-style rule set for
+/// mistakes in names, metrics, style flags, `.notdef` and `cmap` that
+/// break fonts in some environment.
+#[derive(Serialize)]
+pub struct CheckReport {
+    pub rules: Vec<Rule>,
+}
+
+/// Name IDs every font needs, with what they hold.
+const REQUIRED_NAMES: [(u16, &str); 6] = [
+    (name_id::FAMILY, "family"),
+    (name_id::SUBFAMILY, "subfamily"),
+    (name_id::UNIQUE_ID, "unique identifier"),
+    (name_id::FULL_NAME, "full name"),
+    (name_id::VERSION, "version"),
+    (name_id::POST_SCRIPT_NAME, "PostScript name"),
+];
+
+/// Weight words in subfamily names and the usWeightClass they stand for,
+/// longest first so `extrabold` isn't read as `bold`.
+const WEIGHT_WORDS: [(&str, u16); 17] = [
+    ("extralight", 200),
+    ("ultralight", 200),
+    ("extrabold", 800),
+    ("ultrabold", 800),
+    ("semibold", 600),
+    ("demibold", 600),
+    ("hairline", 100),
+    ("regular", 400),
+    ("medium", 500),
+    ("normal", 400),
+    ("black", 900),
+    ("heavy", 900),
+    ("light", 300),
+    ("thin", 100),
+    ("bold", 700),
+    ("book", 400),
+    ("italic", 400),
+];
+
+impl CheckReport {
+    pub fn new(face: &Face) -> Self {
+        let os2 = Os2Report::new(face);
+        let os2 = os2.as_ref();
+        let bbox = face.global_bounding_box();
+        CheckReport {
+            rules: vec![
+                rule(
+                    "name/required-ids",
+                    Severity::Error,
+                    "Name IDs 1 to 6 present",
+                    Some(required_names(face)),
+                ),
+                rule(
+                    "name/family-length",
+                    Severity::Warning,
+                    "Family name at most 31 characters, as Windows menus need",
+                    get_name(face, name_id::FAMILY).map(|family| {
+                        let length = family.chars().count();
+                        (length > 31)
+                            .then(|| format!("{:?} is {} characters", family, length))
+                            .into_iter()
+                            .collect()
+                    }),
+                ),
+                rule(
+                    "name/postscript-name",
+                    Severity::Error,
+                    "PostScript name at most 63 printable ASCII characters",
+                    get_name(face, name_id::POST_SCRIPT_NAME).map(|name| postscript_name(&name)),
+                ),
+                rule(
+                    "head/units-per-em",
+                    Severity::Error,
+                    "unitsPerEm between 16 and 16384",
+                    Some(
+                        (!(16..=16384).contains(&face.units_per_em()))
+                            .then(|| format!("unitsPerEm is {}", face.units_per_em()))
+                            .into_iter()
+                            .collect(),
+                    ),
+                ),
+                rule(
+                    "os2/win-ascent",
+                    Severity::Error,
+                    "usWinAscent reaches the highest glyph, or Windows clips it",
+                    os2.map(|os2| {
+                        (i32::from(os2.win_ascent) < i32::from(bbox.y_max))
+                            .then(|| {
+                                format!(
+                                    "usWinAscent is {} but glyphs reach {}",
+                                    os2.win_ascent, bbox.y_max
+                                )
+                            })
+                            .into_iter()
+                            .collect()
+                    }),
+                ),
+                rule(
+                    "os2/win-descent",
+                    Severity::Error,
+                    "usWinDescent reaches the lowest glyph, or Windows clips it",
+                    os2.map(|os2| {
+                        (i32::from(os2.win_descent) < -i32::from(bbox.y_min))
+                            .then(|| {
+                                format!(
+                                    "usWinDescent is {} but glyphs reach {}",
+                                    os2.win_descent, bbox.y_min
+                                )
+                            })
+                            .into_iter()
+                            .collect()
+                    }),
+                ),
+                rule(
+                    "style/fs-selection-mac-style",
+                    Severity::Error,
+                    "OS/2 fsSelection and head macStyle agree on bold and italic",
+                    os2.and_then(|os2| style_flags(face, os2.fs_selection)),
+                ),
+                rule(
+                    "style/weight-class-subfamily",
+                    Severity::Warning,
+                    "usWeightClass matches the weight the subfamily name states",
+                    os2.and_then(|os2| weight_class(face, os2.weight_class)),
+                ),
+                rule(
+                    "post/italic-angle",
+                    Severity::Warning,
+                    "Italic angle between -45° and 0°, leaning right",
+                    face.tables().post.map(|_| {
+                        let angle = face.italic_angle();
+                        (!(-45.0..=0.0).contains(&angle))
+                            .then(|| format!("Italic angle is {}°", angle))
+                            .into_iter()
+                            .collect()
+                    }),
+                ),
+                rule(
+                    "glyphs/notdef-not-empty",
+                    Severity::Warning,
+                    ".notdef draws a visible glyph, so missing characters show",
+                    Some(
+                        face.glyph_bounding_box(GlyphId(0))
+                            .is_none()
+                            .then(|| ".notdef has no outline".to_string())
+                            .into_iter()
+                            .collect(),
+                    ),
+                ),
+                rule(
+                    "cmap/broken-entries",
+                    Severity::Error,
+                    "cmap maps only scalar values to glyphs that exist",
+                    face.tables().cmap.map(|_| broken_cmap_entries(face)),
+                ),
+                rule(
+                    "cmap/space",
+                    Severity::Warning,
+                    "Space and no-break space mapped, with the same advance",
+                    Some(space(face)),
+                ),
+            ],
+        }
+    }
+
+    /// Failed rules of `severity`.
+    pub fn failures(&self, severity: Severity) -> usize {
+        self.rules
+            .iter()
+            .filter(|rule| rule.severity == severity && !rule.messages.is_empty())
+            .count()
+    }
+}
+
+/// `None` messages means the rule doesn't apply, e.g. there's no OS/2.
+fn rule(
+    id: &'static str,
+    severity: Severity,
+    description: &'static str,
+    messages: Option<Vec<String>>,
+) -> Rule {
+    let status = match &messages {
+        None => Status::NotApplicable,
+        Some(messages) if messages.is_empty() => Status::Pass,
+        Some(_) => Status::Fail,
+    };
+    Rule {
+        id,
+        severity,
+        description,
+        status,
+        messages: messages.unwrap_or_default(),
+    }
+}
+
+fn required_names(face: &Face) -> Vec<String> {
+    REQUIRED_NAMES
+        .iter()
+        .filter(|&&(id, _)| get_name(face, id).is_none())
+        .map(|(id, what)| format!("Name ID {} ({}) is missing", id, what))
+        .collect()
+}
+
+fn postscript_name(name: &str) -> Vec<String> {
+    let mut messages = Vec::new();
+    if name.len() > 63 {
+        messages.push(format!("{:?} is {} characters", name, name.len()));
+    }
+    let invalid: BTreeSet<char> = name
+        .chars()
+        .filter(|&c| !('!'..='~').contains(&c) || "[](){}<>/%".contains(c))
+        .collect();
+    if !invalid.is_empty() {
+        let invalid: String = invalid.into_iter().collect();
+        messages.push(format!("{:?} contains {:?}", name, invalid));
+    }
+    messages
+}
+
+fn style_flags(face: &Face, fs_selection: u16) -> Option<Vec<String>> {
+    let head = face.raw_face().table(Tag::from_bytes(b"head"))?;
+    let mac_style = u16::from_be_bytes([*head.get(44)?, *head.get(45)?]);
+    let mut messages = Vec::new();
+    for (name, selection_bit, mac_bit) in [("bold", 5, 0), ("italic", 0, 1)] {
+        let selection = fs_selection & 1 << selection_bit != 0;
+        let mac = mac_style & 1 << mac_bit != 0;
+        if selection != mac {
+            messages.push(format!(
+                "fsSelection {} is {} but macStyle {} is {}",
+                name, selection, name, mac
+            ));
+        }
+    }
+    Some(messages)
+}
+
+/// `None` when the subfamily names no weight.
+fn weight_class(face: &Face, weight_class: u16) -> Option<Vec<String>> {
+    let subfamily = get_name(face, name_id::TYPOGRAPHIC_SUBFAMILY)
+        .or_else(|| get_name(face, name_id::SUBFAMILY))?;
+    let words: String = subfamily
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .collect();
+    let &(word, expected) = WEIGHT_WORDS.iter().find(|(word, _)| words.contains(word))?;
+    // "Italic" alone only implies Regular when no other weight is named.
+    let matches = weight_class == expected || (word == "italic" && weight_class == 400);
+    Some(
+        (!matches)
+            .then(|| {
+                format!(
+                    "Subfamily {:?} implies {} but usWeightClass is {}",
+                    subfamily, expected, weight_class
+                )
+            })
+            .into_iter()
+            .collect(),
+    )
+}
+
+fn broken_cmap_entries(face: &Face) -> Vec<String> {
+    let glyphs = face.number_of_glyphs();
+    let mut messages = Vec::new();
+    let Some(cmap) = face.tables().cmap else {
+        return messages;
+    };
+    let mut seen = BTreeSet::new();
+    let mut overlong = BTreeSet::new();
+    for (index, subtable) in cmap.subtables.into_iter().enumerate() {
+        if !subtable.is_unicode() {
+            continue;
+        }
+        let index = index as u16;
+        // The walk below stops at U+10FFFF, so ranges past it are
+        // reported from the groups themselves.
+        for (start, end) in cmap_groups(face, index).unwrap_or_default() {
+            if end > MAX_CODE_POINT && start <= end && overlong.insert((start, end)) {
+                messages.push(format!(
+                    "Group U+{:04X}..0x{:X} maps non-Unicode values past U+10FFFF",
+                    start, end
+                ));
+            }
+        }
+        subtable_codepoints(face, index, &subtable, |code_point| {
+            if !seen.insert(code_point) {
+                return;
+            }
+            if (0xD800..=0xDFFF).contains(&code_point) {
+                messages.push(format!("U+{:04X} is a surrogate", code_point));
+            } else if let Some(glyph) = subtable.glyph_index(code_point)
+                && glyph.0 >= glyphs
+            {
+                messages.push(format!(
+                    "U+{:04X} maps to glyph {}, past the {} glyphs",
+                    code_point, glyph.0, glyphs
+                ));
+            }
+        });
+    }
+    messages
+}
+
+fn space(face: &Face) -> Vec<String> {
+    let advance = |c| {
+        face.glyph_index(c)
+            .map(|glyph| face.glyph_hor_advance(glyph).unwrap_or(0))
+    };
+    match (advance(' '), advance('\u{A0}')) {
+        (None, _) => vec!["U+0020 SPACE is not mapped".to_string()],
+        (_, None) => vec!["U+00A0 NO-BREAK SPACE is not mapped".to_string()],
+        (Some(space), Some(nbsp)) if space != nbsp => vec![format!(
+            "No-break space advances {} but space advances {}",
+            nbsp, space
+        )],
+        _ => Vec::new(),
+    }
+}
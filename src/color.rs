@@ -0,0 +1,233 @@
+use crate::reader::Reader;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use ttf_parser::{Face, Tag};
+
+/// Color glyphs in the `COLR` table.
+#[derive(Serialize)]
+pub struct Colr {
+    pub version: u16,
+    /// Base glyphs with a color definition, from the version 0 records and
+    /// the version 1 paint list together.
+    pub glyphs: usize,
+    /// Version 0 layer records plus version 1 layer list entries.
+    pub layers: usize,
+}
+
+/// Color palettes in the `CPAL` table.
+#[derive(Serialize)]
+pub struct Cpal {
+    pub version: u16,
+    /// Colors of each palette as `#RRGGBB`, or `#RRGGBBAA` when not opaque.
+    pub palettes: Vec<Vec<String>>,
+}
+
+/// SVG documents in the `SVG ` table.
+#[derive(Serialize)]
+pub struct Svg {
+    /// Distinct documents; several ranges may share one.
+    pub documents: usize,
+    pub ranges: usize,
+    pub glyphs: usize,
+}
+
+/// One size of bitmap glyphs in `sbix` or `CBDT`.
+#[derive(Serialize)]
+pub struct Strike {
+    pub ppem: u16,
+    /// Glyphs with a bitmap: those with data in `sbix`, or the glyph ID
+    /// range `CBLC` indexes.
+    pub glyphs: usize,
+}
+
+/// The color glyph technologies a font ships.
+#[derive(Serialize)]
+pub struct ColorReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colr: Option<Colr>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpal: Option<Cpal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub svg: Option<Svg>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sbix: Vec<Strike>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cbdt: Vec<Strike>,
+}
+
+impl ColorReport {
+    /// Returns `None` for fonts without any color table.
+    pub fn new(face: &Face) -> Option<Self> {
+        let table = |tag: &[u8; 4]| face.raw_face().table(Tag::from_bytes(tag));
+        let report = ColorReport {
+            colr: table(b"COLR").and_then(colr),
+            cpal: table(b"CPAL").and_then(cpal),
+            svg: table(b"SVG ").and_then(svg),
+            sbix: table(b"sbix")
+                .map(|data| sbix(data, face.number_of_glyphs()))
+                .unwrap_or_default(),
+            cbdt: table(b"CBDT")
+                .and(table(b"CBLC"))
+                .map(cblc)
+                .unwrap_or_default(),
+        };
+        let empty = report.colr.is_none()
+            && report.cpal.is_none()
+            && report.svg.is_none()
+            && report.sbix.is_empty()
+            && report.cbdt.is_empty();
+        (!empty).then_some(report)
+    }
+
+    /// The technologies present, e.g. `COLRv1, CPAL, SVG`.
+    pub fn formats(&self) -> String {
+        let mut formats = Vec::new();
+        if let Some(colr) = &self.colr {
+            formats.push(format!("COLRv{}", colr.version));
+        }
+        for (present, name) in [
+            (self.cpal.is_some(), "CPAL"),
+            (self.svg.is_some(), "SVG"),
+            (!self.sbix.is_empty(), "sbix"),
+            (!self.cbdt.is_empty(), "CBDT"),
+        ] {
+            if present {
+                formats.push(name.to_string());
+            }
+        }
+        formats.join(", ")
+    }
+}
+
+fn colr(data: &[u8]) -> Option<Colr> {
+    let mut r = Reader::new(data);
+    let version = r.read_u16()?;
+    let base_records = r.read_u16()?;
+    let base_records_offset = r.read_u32()? as usize;
+    r.skip(4); // layerRecordsOffset
+    let mut layers = usize::from(r.read_u16()?);
+
+    let mut glyphs = BTreeSet::new();
+    let mut records = Reader::at(data, base_records_offset);
+    for _ in 0..base_records {
+        glyphs.insert(records.read_u16()?);
+        records.skip(4); // firstLayerIndex, numLayers
+    }
+
+    if version >= 1 {
+        let base_glyph_list = r.read_u32()? as usize;
+        let layer_list = r.read_u32()? as usize;
+        if base_glyph_list != 0 {
+            let mut list = Reader::at(data, base_glyph_list);
+            for _ in 0..list.read_u32()? {
+                glyphs.insert(list.read_u16()?);
+                list.skip(4); // paintOffset
+            }
+        }
+        if layer_list != 0 {
+            layers += Reader::at(data, layer_list).read_u32()? as usize;
+        }
+    }
+
+    Some(Colr {
+        version,
+        glyphs: glyphs.len(),
+        layers,
+    })
+}
+
+fn cpal(data: &[u8]) -> Option<Cpal> {
+    let mut r = Reader::new(data);
+    let version = r.read_u16()?;
+    let entries = r.read_u16()?;
+    let palettes = r.read_u16()?;
+    r.skip(2); // numColorRecords
+    let colors_offset = r.read_u32()? as usize;
+    let palettes = (0..palettes)
+        .map(|_| {
+            let first = usize::from(r.read_u16()?);
+            let mut colors = Reader::at(data, colors_offset + first * 4);
+            (0..entries)
+                .map(|_| {
+                    // Color records are stored blue, green, red, alpha.
+                    let [b, g, red, a] = colors.read_bytes(4)?.try_into().ok()?;
+                    Some(if a == 255 {
+                        format!("#{:02X}{:02X}{:02X}", red, g, b)
+                    } else {
+                        format!("#{:02X}{:02X}{:02X}{:02X}", red, g, b, a)
+                    })
+                })
+                .collect()
+        })
+        .collect::<Option<_>>()?;
+    Some(Cpal { version, palettes })
+}
+
+fn svg(data: &[u8]) -> Option<Svg> {
+    let mut r = Reader::new(data);
+    r.skip(2); // version
+    let mut list = Reader::at(data, r.read_u32()? as usize);
+    let ranges = list.read_u16()?;
+    let mut documents = BTreeSet::new();
+    let mut glyphs = 0;
+    for _ in 0..ranges {
+        let start = list.read_u16()?;
+        let end = list.read_u16()?;
+        documents.insert(list.read_u32()?);
+        list.skip(4); // svgDocLength
+        glyphs += usize::from(end.saturating_sub(start)) + 1;
+    }
+    Some(Svg {
+        documents: documents.len(),
+        ranges: usize::from(ranges),
+        glyphs,
+    })
+}
+
+fn sbix(data: &[u8], number_of_glyphs: u16) -> Vec<Strike> {
+    let mut r = Reader::new(data);
+    r.skip(4); // version, flags
+    let count = r.read_u32().unwrap_or(0);
+    let mut strikes = Vec::new();
+    for _ in 0..count {
+        let Some(offset) = r.read_u32() else {
+            break;
+        };
+        let mut strike = Reader::at(data, offset as usize);
+        let Some(ppem) = strike.read_u16() else {
+            continue;
+        };
+        strike.skip(2); // ppi
+        // Glyphs whose data offset differs from the next one's have a bitmap.
+        let offsets: Vec<u32> = (0..=number_of_glyphs)
+            .map_while(|_| strike.read_u32())
+            .collect();
+        let glyphs = offsets.windows(2).filter(|pair| pair[1] > pair[0]).count();
+        strikes.push(Strike { ppem, glyphs });
+    }
+    strikes.sort_by_key(|strike| strike.ppem);
+    strikes
+}
+
+fn cblc(data: &[u8]) -> Vec<Strike> {
+    let mut r = Reader::new(data);
+    r.skip(4); // majorVersion, minorVersion
+    let count = r.read_u32().unwrap_or(0);
+    let mut strikes = Vec::new();
+    for i in 0..count as usize {
+        // BitmapSize records are 48 bytes; the glyph range and ppem come
+        // after the offsets, counts and line metrics.
+        let mut size = Reader::at(data, 8 + i * 48 + 40);
+        let (Some(start), Some(end), Some(ppem)) =
+            (size.read_u16(), size.read_u16(), size.read_u8())
+        else {
+            break;
+        };
+        strikes.push(Strike {
+            ppem: u16::from(ppem),
+            glyphs: usize::from(end.saturating_sub(start)) + 1,
+        });
+    }
+    strikes.sort_by_key(|strike| strike.ppem);
+    strikes
+}
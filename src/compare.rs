@@ -0,0 +1,87 @@
+use crate::{parse_face, read_font_file};
+use fontinfo::proportions::Proportions;
+use std::process;
+
+/// Runs `fontinfo compare-proportions <font-a> <font-b>`.
+pub fn run(program: &str, args: &[String]) {
+    let [a_path, b_path] = args else {
+        usage(program);
+    };
+    if a_path.starts_with("--") || b_path.starts_with("--") {
+        usage(program);
+    }
+
+    let a_data = read_font_file(a_path);
+    let b_data = read_font_file(b_path);
+    let a = Proportions::new(&parse_face(&a_data, a_path));
+    let b = Proportions::new(&parse_face(&b_data, b_path));
+
+    println!("┌─ PROPORTIONS ─────────────────────────────────────────────────");
+    println!("│ A: {}", a_path);
+    println!("│ B: {}", b_path);
+    println!("│");
+    println!("│ {:<20} {:>7} {:>7}  {:>7}", "", "A", "B", "B vs A");
+    let ratio =
+        |numerator: f32, denominator: f32| (denominator > 0.0).then(|| numerator / denominator);
+    let rows = [
+        ("x-height / em", Some(a.x_height), Some(b.x_height)),
+        ("cap height / em", Some(a.cap_height), Some(b.cap_height)),
+        (
+            "x-height / cap",
+            ratio(a.x_height, a.cap_height),
+            ratio(b.x_height, b.cap_height),
+        ),
+        ("ascender / em", Some(a.ascender), Some(b.ascender)),
+        ("descender / em", Some(a.descender), Some(b.descender)),
+        (
+            "ascender / descender",
+            ratio(a.ascender, a.descender),
+            ratio(b.ascender, b.descender),
+        ),
+        ("lowercase width / em", a.lowercase_width, b.lowercase_width),
+    ];
+    for (name, a, b) in rows {
+        println!(
+            "│ {:<20} {:>7} {:>7}  {:>7}",
+            name,
+            format_value(a),
+            format_value(b),
+            format_difference(a, b)
+        );
+    }
+
+    // font-size-adjust scales the fallback so its x-height matches.
+    if let Some(scale) = ratio(a.x_height, b.x_height) {
+        println!("│");
+        println!(
+            "│ Set B at {:.1}% of A's size to match x-heights (font-size-adjust: {:.3})",
+            scale * 100.0,
+            a.x_height
+        );
+    }
+    println!("└───────────────────────────────────────────────────────────────");
+}
+
+fn format_value(value: Option<f32>) -> String {
+    match value {
+        Some(value) => format!("{:.3}", value),
+        None => "-".to_string(),
+    }
+}
+
+/// B's value relative to A's, as a signed percentage.
+fn format_difference(a: Option<f32>, b: Option<f32>) -> String {
+    match (a, b) {
+        (Some(a), Some(b)) if a != 0.0 => format!("{:+.1}%", (b - a) / a * 100.0),
+        _ => "-".to_string(),
+    }
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} compare-proportions <font-a> <font-b>", program);
+    eprintln!(
+        "Example: {} compare-proportions body.ttf fallback.ttf",
+        program
+    );
+    process::exit(1);
+}
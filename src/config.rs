@@ -0,0 +1,83 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// User configuration, read from `$FONTINFO_CONFIG`, or else
+/// `$XDG_CONFIG_HOME/fontinfo/config.toml` (`~/.config/fontinfo/config.toml`).
+///
+/// ```toml
+/// [axes.BLND]
+/// description = "Blend between the display and text masters"
+/// min = 0
+/// max = 100
+///
+/// [features.ss01]
+/// description = "Single-storey a"
+/// translations = { de = "Einstöckiges a" }
+/// ```
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Descriptions of private axes, keyed by axis tag.
+    #[serde(default)]
+    pub axes: BTreeMap<String, AxisEntry>,
+    /// Descriptions of OpenType features, keyed by feature tag.
+    #[serde(default)]
+    pub features: BTreeMap<String, FeatureEntry>,
+    /// The file as read, so cached reports are keyed by what they were
+    /// written with.
+    #[serde(skip)]
+    pub source: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AxisEntry {
+    pub description: String,
+    /// Expected axis range; fonts whose axis exceeds it are flagged.
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeatureEntry {
+    pub description: String,
+    /// Descriptions in other report languages, keyed by language code.
+    #[serde(default)]
+    pub translations: BTreeMap<String, String>,
+}
+
+impl Config {
+    /// Loads the given config file, falling back to `$FONTINFO_CONFIG` and
+    /// then the default location. A missing default file yields an empty
+    /// config.
+    pub fn load(path: Option<&str>) -> Result<Config, String> {
+        let path = match path
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("FONTINFO_CONFIG").map(PathBuf::from))
+        {
+            Some(path) => path,
+            None => match default_path() {
+                Some(path) if path.exists() => path,
+                _ => return Ok(Config::default()),
+            },
+        };
+
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("Error reading config '{}': {}", path.display(), e))?;
+        let mut config: Config = toml::from_str(&text)
+            .map_err(|e| format!("Error parsing config '{}': {}", path.display(), e))?;
+        config.source = text;
+        Ok(config)
+    }
+}
+
+fn default_path() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("fontinfo").join("config.toml"))
+}
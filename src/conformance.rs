@@ -0,0 +1,425 @@
+use crate::reader::Reader;
+use crate::special_cases::Status;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use ttf_parser::{Face, Tag};
+
+/// Where the OpenType specification states each rule.
+const SPEC: &str = "https://learn.microsoft.com/typography/opentype/spec/";
+
+/// One structural rule of the OpenType specification and every place the
+/// font breaks it.
+#[derive(Serialize)]
+pub struct Rule {
+    pub name: &'static str,
+    /// The spec section stating the rule, as a URL.
+    pub reference: String,
+    pub status: Status,
+    pub violations: Vec<String>,
+}
+
+/// The results of `--strict`: structural constraints the spec places on
+/// the table directory, `cmap`, `loca` and `name`, which parsers tend to
+/// tolerate but stricter consumers such as OTS in browsers reject.
+#[derive(Serialize)]
+pub struct ConformanceReport {
+    pub rules: Vec<Rule>,
+}
+
+impl ConformanceReport {
+    pub fn new(face: &Face) -> Self {
+        let raw = face.raw_face();
+        let table = |tag: &[u8; 4]| raw.table(Tag::from_bytes(tag));
+        let mut rules = vec![
+            rule(
+                "Table records sorted by tag",
+                "otff#table-directory",
+                Some(directory_order(face)),
+            ),
+            rule(
+                "Tables within the file",
+                "otff#table-directory",
+                Some(table_bounds(face)),
+            ),
+            rule(
+                "Tables 4-byte aligned and zero-padded",
+                "otff#table-directory",
+                Some(table_alignment(face)),
+            ),
+        ];
+        let cmap = table(b"cmap");
+        rules.push(rule(
+            "cmap encoding records sorted",
+            "cmap#cmap-header",
+            cmap.map(cmap_record_order),
+        ));
+        rules.push(rule(
+            "cmap format 4 segments sorted, ending at U+FFFF",
+            "cmap#format-4-segment-mapping-to-delta-values",
+            cmap.and_then(|cmap| cmap_subtables(cmap, 4, format4_segments)),
+        ));
+        rules.push(rule(
+            "cmap format 12 groups sorted and disjoint",
+            "cmap#format-12-segmented-coverage",
+            cmap.and_then(|cmap| cmap_subtables(cmap, 12, format12_groups)),
+        ));
+        rules.push(rule(
+            "loca offsets ascending and within glyf",
+            "loca",
+            loca_offsets(face),
+        ));
+        let name = table(b"name");
+        rules.push(rule(
+            "name records sorted",
+            "name#name-records",
+            name.map(name_record_order),
+        ));
+        rules.push(rule(
+            "name strings within string storage",
+            "name#naming-table-version-0",
+            name.map(name_string_bounds),
+        ));
+        ConformanceReport { rules }
+    }
+
+    pub fn violations(&self) -> usize {
+        self.rules.iter().map(|rule| rule.violations.len()).sum()
+    }
+}
+
+/// `None` violations means the rule doesn't apply, e.g. there's no `loca`.
+fn rule(name: &'static str, section: &str, violations: Option<Vec<String>>) -> Rule {
+    let status = match &violations {
+        None => Status::NotApplicable,
+        Some(violations) if violations.is_empty() => Status::Pass,
+        Some(_) => Status::Fail,
+    };
+    Rule {
+        name,
+        reference: format!("{}{}", SPEC, section),
+        status,
+        violations: violations.unwrap_or_default(),
+    }
+}
+
+fn directory_order(face: &Face) -> Vec<String> {
+    let tags: Vec<Tag> = face
+        .raw_face()
+        .table_records
+        .into_iter()
+        .map(|record| record.tag)
+        .collect();
+    tags.windows(2)
+        .filter(|pair| pair[0] >= pair[1])
+        .map(|pair| {
+            if pair[0] == pair[1] {
+                format!("'{}' appears twice", pair[0])
+            } else {
+                format!("'{}' comes before '{}'", pair[0], pair[1])
+            }
+        })
+        .collect()
+}
+
+fn table_bounds(face: &Face) -> Vec<String> {
+    let file_len = face.raw_face().data.len() as u64;
+    face.raw_face()
+        .table_records
+        .into_iter()
+        .filter(|record| u64::from(record.offset) + u64::from(record.length) > file_len)
+        .map(|record| {
+            format!(
+                "'{}' spans {}–{}, past the end of the {}-byte file",
+                record.tag,
+                record.offset,
+                u64::from(record.offset) + u64::from(record.length),
+                file_len
+            )
+        })
+        .collect()
+}
+
+/// Every table starts on a 4-byte boundary and the bytes up to the next
+/// boundary after it are zero. Padding at the very end of the file may be
+/// missing.
+fn table_alignment(face: &Face) -> Vec<String> {
+    let data = face.raw_face().data;
+    let mut violations = Vec::new();
+    for record in face.raw_face().table_records {
+        if record.offset % 4 != 0 {
+            violations.push(format!(
+                "'{}' starts at offset {}, not a multiple of 4",
+                record.tag, record.offset
+            ));
+        }
+        let end = record.offset as usize + record.length as usize;
+        let padded = end.next_multiple_of(4).min(data.len());
+        if let Some(padding) = data.get(end..padded)
+            && padding.iter().any(|&byte| byte != 0)
+        {
+            violations.push(format!(
+                "'{}' is followed by non-zero padding at offset {}",
+                record.tag, end
+            ));
+        }
+    }
+    violations
+}
+
+fn cmap_record_order(cmap: &[u8]) -> Vec<String> {
+    let mut r = Reader::at(cmap, 2);
+    let count = r.read_u16().unwrap_or(0);
+    let records: Vec<(u16, u16)> = (0..count)
+        .map_while(|_| {
+            let record = (r.read_u16()?, r.read_u16()?);
+            r.skip(4); // subtableOffset
+            Some(record)
+        })
+        .collect();
+    records
+        .windows(2)
+        .filter(|pair| pair[0] >= pair[1])
+        .map(|pair| {
+            format!(
+                "platform {} encoding {} comes before platform {} encoding {}",
+                pair[0].0, pair[0].1, pair[1].0, pair[1].1
+            )
+        })
+        .collect()
+}
+
+/// Runs `check` on each distinct subtable of the given format, prefixing
+/// its violations with the subtable's offset. `None` when there is none.
+fn cmap_subtables(
+    cmap: &[u8],
+    format: u16,
+    check: fn(&[u8]) -> Vec<String>,
+) -> Option<Vec<String>> {
+    let mut r = Reader::at(cmap, 2);
+    let count = r.read_u16()?;
+    let offsets: BTreeSet<usize> = (0..count)
+        .map_while(|_| {
+            r.skip(4); // platformID, encodingID
+            r.read_u32()
+        })
+        .map(|offset| offset as usize)
+        .filter(|&offset| Reader::at(cmap, offset).read_u16() == Some(format))
+        .collect();
+    if offsets.is_empty() {
+        return None;
+    }
+    Some(
+        offsets
+            .into_iter()
+            .flat_map(|offset| {
+                let subtable = cmap.get(offset..).unwrap_or_default();
+                check(subtable)
+                    .into_iter()
+                    .map(move |violation| format!("subtable at {}: {}", offset, violation))
+            })
+            .collect(),
+    )
+}
+
+fn format4_segments(subtable: &[u8]) -> Vec<String> {
+    let segments = || -> Option<Vec<(u16, u16)>> {
+        let seg_count = usize::from(Reader::at(subtable, 6).read_u16()? / 2);
+        let mut ends = Reader::at(subtable, 14);
+        // endCode, reservedPad, then startCode.
+        let mut starts = Reader::at(subtable, 14 + seg_count * 2 + 2);
+        (0..seg_count)
+            .map(|_| Some((starts.read_u16()?, ends.read_u16()?)))
+            .collect()
+    };
+    let Some(segments) = segments() else {
+        return vec!["truncated".to_string()];
+    };
+    let mut violations: Vec<String> = segments
+        .iter()
+        .filter(|(start, end)| start > end)
+        .map(|(start, end)| format!("segment U+{:04X}–U+{:04X} runs backwards", start, end))
+        .collect();
+    violations.extend(
+        segments
+            .windows(2)
+            .filter(|pair| pair[0].1 >= pair[1].1)
+            .map(|pair| {
+                format!(
+                    "segment ending U+{:04X} comes before one ending U+{:04X}",
+                    pair[0].1, pair[1].1
+                )
+            }),
+    );
+    if segments.last().map(|&(_, end)| end) != Some(0xFFFF) {
+        violations.push("last segment doesn't end at U+FFFF".to_string());
+    }
+    violations
+}
+
+fn format12_groups(subtable: &[u8]) -> Vec<String> {
+    let groups = || -> Option<Vec<(u32, u32)>> {
+        let mut r = Reader::at(subtable, 12);
+        let count = r.read_u32()?;
+        (0..count)
+            .map(|_| {
+                let group = (r.read_u32()?, r.read_u32()?);
+                r.skip(4); // startGlyphID
+                Some(group)
+            })
+            .collect()
+    };
+    let Some(groups) = groups() else {
+        return vec!["truncated".to_string()];
+    };
+    let mut violations: Vec<String> = groups
+        .iter()
+        .filter(|(start, end)| start > end)
+        .map(|(start, end)| format!("group U+{:04X}–U+{:04X} runs backwards", start, end))
+        .collect();
+    violations.extend(
+        groups
+            .windows(2)
+            .filter(|pair| pair[0].1 >= pair[1].0)
+            .map(|pair| {
+                format!(
+                    "group U+{:04X}–U+{:04X} overlaps or follows U+{:04X}–U+{:04X}",
+                    pair[1].0, pair[1].1, pair[0].0, pair[0].1
+                )
+            }),
+    );
+    violations
+}
+
+/// `numGlyphs + 1` offsets that never decrease, the last no further than
+/// the end of `glyf`. `None` for fonts without `loca`.
+fn loca_offsets(face: &Face) -> Option<Vec<String>> {
+    let raw = face.raw_face();
+    let loca = raw.table(Tag::from_bytes(b"loca"))?;
+    let glyf_len = raw.table(Tag::from_bytes(b"glyf")).map_or(0, <[u8]>::len);
+    let long = Reader::at(raw.table(Tag::from_bytes(b"head"))?, 50).read_u16() == Some(1);
+    let count = usize::from(face.number_of_glyphs()) + 1;
+    let mut r = Reader::new(loca);
+    let offsets: Vec<usize> = (0..count)
+        .map_while(|_| {
+            if long {
+                r.read_u32().map(|offset| offset as usize)
+            } else {
+                // Short offsets are stored divided by two.
+                r.read_u16().map(|offset| usize::from(offset) * 2)
+            }
+        })
+        .collect();
+    let mut violations = Vec::new();
+    if offsets.len() < count {
+        violations.push(format!(
+            "holds {} offsets, but maxp's {} glyphs need {}",
+            offsets.len(),
+            count - 1,
+            count
+        ));
+    }
+    violations.extend(
+        offsets
+            .windows(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[1] < pair[0])
+            .map(|(glyph, pair)| {
+                format!(
+                    "glyph {} ends at {}, before it starts at {}",
+                    glyph, pair[1], pair[0]
+                )
+            }),
+    );
+    if let Some(&last) = offsets.last()
+        && last > glyf_len
+    {
+        violations.push(format!(
+            "last offset {} is past the end of the {}-byte glyf",
+            last, glyf_len
+        ));
+    }
+    Some(violations)
+}
+
+/// Name records in (platform, encoding, language, nameID) order.
+fn name_records(name: &[u8]) -> Vec<[u16; 6]> {
+    let mut r = Reader::at(name, 2);
+    let count = r.read_u16().unwrap_or(0);
+    r.skip(2); // storageOffset
+    (0..count)
+        .map_while(|_| {
+            Some([
+                r.read_u16()?,
+                r.read_u16()?,
+                r.read_u16()?,
+                r.read_u16()?,
+                r.read_u16()?,
+                r.read_u16()?,
+            ])
+        })
+        .collect()
+}
+
+fn name_record_order(name: &[u8]) -> Vec<String> {
+    name_records(name)
+        .windows(2)
+        .filter(|pair| pair[0][..4] >= pair[1][..4])
+        .map(|pair| {
+            format!(
+                "record {}/{}/{:#06X}/{} comes before {}/{}/{:#06X}/{}",
+                pair[0][0],
+                pair[0][1],
+                pair[0][2],
+                pair[0][3],
+                pair[1][0],
+                pair[1][1],
+                pair[1][2],
+                pair[1][3]
+            )
+        })
+        .collect()
+}
+
+/// Each record's string, and each language-tag record's in version 1,
+/// lies within the table.
+fn name_string_bounds(name: &[u8]) -> Vec<String> {
+    let storage = Reader::at(name, 4).read_u16().map_or(0, usize::from);
+    let out_of_bounds =
+        |offset: u16, length: u16| storage + usize::from(offset) + usize::from(length) > name.len();
+    let records = name_records(name);
+    let mut violations: Vec<String> = records
+        .iter()
+        .filter(|record| out_of_bounds(record[5], record[4]))
+        .map(|record| {
+            format!(
+                "record {}/{}/{:#06X}/{}: {} bytes at {} run past the {}-byte table",
+                record[0],
+                record[1],
+                record[2],
+                record[3],
+                record[4],
+                storage + usize::from(record[5]),
+                name.len()
+            )
+        })
+        .collect();
+    if Reader::new(name).read_u16() == Some(1) {
+        let mut r = Reader::at(name, 6 + records.len() * 12);
+        let count = r.read_u16().unwrap_or(0);
+        for i in 0..count {
+            let (Some(length), Some(offset)) = (r.read_u16(), r.read_u16()) else {
+                break;
+            };
+            if out_of_bounds(offset, length) {
+                violations.push(format!(
+                    "language tag {}: {} bytes at {} run past the {}-byte table",
+                    i,
+                    length,
+                    storage + usize::from(offset),
+                    name.len()
+                ));
+            }
+        }
+    }
+    violations
+}
@@ -0,0 +1,98 @@
+use crate::get_name;
+use crate::variations::named_instances;
+use std::fmt::Write;
+use ttf_parser::{Face, name_id};
+
+/// Generates one CSS rule per named instance, setting the high-level
+/// properties that have a registered-axis equivalent plus the exact
+/// `font-variation-settings`. Returns `None` for static fonts.
+pub fn instance_rules(face: &Face) -> Option<String> {
+    let axes = face.variation_axes();
+    if axes.is_empty() {
+        return None;
+    }
+
+    let family = get_name(face, name_id::TYPOGRAPHIC_FAMILY)
+        .or_else(|| get_name(face, name_id::FAMILY))
+        .unwrap_or_default();
+
+    let mut css = String::new();
+    for (i, instance) in named_instances(face).iter().enumerate() {
+        let name = get_name(face, instance.subfamily_name_id)
+            .unwrap_or_else(|| format!("Instance {}", i + 1));
+        let values: Vec<(String, f32)> = axes
+            .into_iter()
+            .zip(&instance.coordinates)
+            .map(|(axis, &value)| (axis.tag.to_string(), value))
+            .collect();
+
+        if !css.is_empty() {
+            css.push('\n');
+        }
+        let _ = writeln!(css, "/* {} {} */", family, name);
+        let _ = writeln!(css, ".{} {{", class_name(&family, &name));
+        let _ = writeln!(css, "  font-family: \"{}\";", family);
+        let mut has_style = false;
+        for (tag, value) in &values {
+            if let Some(property) = high_level_property(tag, *value) {
+                has_style |= property.starts_with("font-style");
+                let _ = writeln!(css, "  {};", property);
+            }
+        }
+        // Italic-only files of a family have no ital axis to set the style.
+        if !has_style && face.is_italic() {
+            let _ = writeln!(css, "  font-style: italic;");
+        }
+        let settings: Vec<String> = values
+            .iter()
+            .map(|(tag, value)| format!("\"{}\" {}", tag, format_value(*value)))
+            .collect();
+        let _ = writeln!(css, "  font-variation-settings: {};", settings.join(", "));
+        css.push_str("}\n");
+    }
+
+    Some(css)
+}
+
+/// Maps a registered axis value onto the CSS property that controls it.
+fn high_level_property(tag: &str, value: f32) -> Option<String> {
+    match tag {
+        "wght" => Some(format!("font-weight: {}", format_value(value))),
+        "wdth" => Some(format!("font-stretch: {}%", format_value(value))),
+        "ital" if value >= 1.0 => Some("font-style: italic".to_string()),
+        // CSS oblique angles are positive for clockwise slant, the
+        // opposite of the slnt axis.
+        "slnt" if value != 0.0 => Some(format!("font-style: oblique {}deg", format_value(-value))),
+        "opsz" => Some("font-optical-sizing: auto".to_string()),
+        _ => None,
+    }
+}
+
+/// Builds a class name such as `inter-semibold-italic`.
+fn class_name(family: &str, instance: &str) -> String {
+    let mut class = String::new();
+    for c in format!("{} {}", family, instance).chars() {
+        if c.is_ascii_alphanumeric() {
+            class.push(c.to_ascii_lowercase());
+        } else if !class.is_empty() && !class.ends_with('-') {
+            class.push('-');
+        }
+    }
+    let class = class.trim_end_matches('-');
+    if class.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("font-{}", class)
+    } else {
+        class.to_string()
+    }
+}
+
+/// Formats an axis value with at most three decimals and no trailing zeros.
+fn format_value(value: f32) -> String {
+    let text = format!("{:.3}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
@@ -0,0 +1,122 @@
+use crate::layout;
+use crate::unicode::BlockCoverage;
+use serde::Serialize;
+use ttf_parser::{Face, Tag};
+
+/// Cyrillic blocks with their assigned characters as inclusive ranges, as
+/// of Unicode 15.
+const BLOCKS: [(&str, &[(u32, u32)]); 6] = [
+    ("Cyrillic", &[(0x0400, 0x04FF)]),
+    ("Cyrillic Supplement", &[(0x0500, 0x052F)]),
+    ("Cyrillic Extended-A", &[(0x2DE0, 0x2DFF)]),
+    ("Cyrillic Extended-B", &[(0xA640, 0xA69F)]),
+    ("Cyrillic Extended-C", &[(0x1C80, 0x1C88)]),
+    (
+        "Cyrillic Extended-D",
+        &[(0x1E030, 0x1E06D), (0x1E08F, 0x1E08F)],
+    ),
+];
+
+/// OpenType language systems whose orthography expects letterforms that
+/// differ from the Russian defaults.
+const LANGUAGES: [(&[u8; 4], &str); 3] = [
+    (b"BGR ", "Bulgarian"),
+    (b"SRB ", "Serbian"),
+    (b"MKD ", "Macedonian"),
+];
+
+/// Basic lowercase Cyrillic, а through џ, where the Bulgarian and Serbian
+/// alternates live.
+const LOWERCASE: (u32, u32) = (0x0430, 0x045F);
+
+/// What a language system's `locl` does with Cyrillic letters.
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind", content = "letters")]
+pub enum LocalForms {
+    /// The `cyrl` script has no such language system.
+    NoLanguageSystem,
+    /// The language system has no `locl` feature.
+    NoLocl,
+    /// `locl` substitutes these lowercase letters, possibly none.
+    Substitutes(Vec<char>),
+}
+
+impl LocalForms {
+    pub fn description(&self) -> String {
+        match self {
+            LocalForms::NoLanguageSystem => "no language system".to_string(),
+            LocalForms::NoLocl => "no locl feature".to_string(),
+            LocalForms::Substitutes(letters) if letters.is_empty() => {
+                "locl leaves the letters unchanged".to_string()
+            }
+            LocalForms::Substitutes(letters) => {
+                let letters: Vec<String> = letters.iter().map(char::to_string).collect();
+                format!("locl substitutes {}", letters.join(" "))
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LanguageForms {
+    pub tag: String,
+    pub name: &'static str,
+    pub forms: LocalForms,
+}
+
+/// Coverage of each Cyrillic block and the localized forms of Balkan
+/// languages.
+#[derive(Serialize)]
+pub struct CyrillicReport {
+    pub blocks: Vec<BlockCoverage>,
+    pub languages: Vec<LanguageForms>,
+}
+
+impl CyrillicReport {
+    /// Returns `None` for fonts that cover no Cyrillic.
+    pub fn new(face: &Face) -> Option<Self> {
+        let blocks: Vec<_> = BLOCKS
+            .iter()
+            .map(|&(name, ranges)| BlockCoverage::new(face, name, ranges))
+            .collect();
+        if blocks.iter().all(|block| block.covered == 0) {
+            return None;
+        }
+
+        let gsub = face.tables().gsub;
+        let languages = LANGUAGES
+            .iter()
+            .map(|&(tag, name)| {
+                let lookups = gsub.as_ref().and_then(|gsub| {
+                    let lookups = layout::lang_sys_lookups(
+                        gsub,
+                        Tag::from_bytes(b"cyrl"),
+                        Tag::from_bytes(tag),
+                        Tag::from_bytes(b"locl"),
+                    )?;
+                    Some((gsub, lookups))
+                });
+                let forms = match lookups {
+                    None => LocalForms::NoLanguageSystem,
+                    Some((_, lookups)) if lookups.is_empty() => LocalForms::NoLocl,
+                    Some((gsub, lookups)) => LocalForms::Substitutes(
+                        (LOWERCASE.0..=LOWERCASE.1)
+                            .filter_map(char::from_u32)
+                            .filter(|&c| {
+                                face.glyph_index(c)
+                                    .is_some_and(|glyph| layout::gsub_covers(gsub, &lookups, glyph))
+                            })
+                            .collect(),
+                    ),
+                };
+                LanguageForms {
+                    tag: Tag::from_bytes(tag).to_string().trim_end().to_string(),
+                    name,
+                    forms,
+                }
+            })
+            .collect();
+
+        Some(CyrillicReport { blocks, languages })
+    }
+}
@@ -0,0 +1,552 @@
+use crate::{Mode, ReportArgs, TextSource, contain_panic, write_report, write_reports};
+use fontinfo::config::Config;
+use fontinfo::i18n::Lang;
+use fontinfo::profile::Profile;
+use owned_ttf_parser::{AsFaceRef, OwnedFace};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, ToSocketAddrs};
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, MetadataExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Number of parsed faces kept in memory by default.
+const DEFAULT_CACHE_SIZE: usize = 64;
+
+/// Largest request accepted, a socket request line or an HTTP request
+/// with its headers; requests are only a few arguments.
+const MAX_REQUEST_LEN: u64 = 64 * 1024;
+
+/// How long a connection may sit idle while its request is read or its
+/// reply written.
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a client waits on the daemon's reply before analyzing the font
+/// itself; reports of large fonts take a while.
+const REPLY_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// A report request: the same arguments the CLI accepts, plus the
+/// client's working directory for resolving relative paths.
+#[derive(Serialize, Deserialize)]
+struct Request {
+    args: Vec<String>,
+    #[serde(default)]
+    cwd: Option<PathBuf>,
+}
+
+/// Why a request produced no report.
+enum Failure {
+    /// Bad arguments, a font that can't be read or parsed, or a failed
+    /// check. `output` keeps what was written before the failure, such as
+    /// the findings of `--locales`, as a direct run prints it too.
+    Request { output: Vec<u8>, errors: String },
+    /// Analysis panicked on malformed data; the daemon keeps running.
+    Crash(String),
+}
+
+impl Failure {
+    fn request(errors: impl Into<String>) -> Self {
+        Failure::Request {
+            output: Vec::new(),
+            errors: errors.into(),
+        }
+    }
+}
+
+struct CachedFace {
+    modified: Option<SystemTime>,
+    len: u64,
+    /// Shared so analysis can run without holding the cache's lock.
+    face: Arc<OwnedFace>,
+    last_used: u64,
+}
+
+/// Parsed faces keyed by path, evicting the least recently used face once
+/// full. Entries are re-read when the file's size or mtime changes.
+struct FaceCache {
+    entries: HashMap<PathBuf, CachedFace>,
+    capacity: usize,
+    clock: u64,
+}
+
+impl FaceCache {
+    fn new(capacity: usize) -> Self {
+        FaceCache {
+            entries: HashMap::new(),
+            capacity,
+            clock: 0,
+        }
+    }
+
+    fn get(&mut self, path: &Path) -> Result<Arc<OwnedFace>, String> {
+        let metadata = fs::metadata(path)
+            .map_err(|e| format!("Error reading font file '{}': {}", path.display(), e))?;
+        let modified = metadata.modified().ok();
+        let len = metadata.len();
+
+        let fresh = self
+            .entries
+            .get(path)
+            .is_some_and(|entry| entry.modified == modified && entry.len == len);
+        if !fresh {
+            let data = fs::read(path)
+                .map_err(|e| format!("Error reading font file '{}': {}", path.display(), e))?;
+            let face = OwnedFace::from_vec(data, 0)
+                .map_err(|e| format!("Error parsing font file '{}': {}", path.display(), e))?;
+            if !self.entries.contains_key(path) && self.entries.len() >= self.capacity {
+                self.evict();
+            }
+            self.entries.insert(
+                path.to_path_buf(),
+                CachedFace {
+                    modified,
+                    len,
+                    face: Arc::new(face),
+                    last_used: 0,
+                },
+            );
+        }
+
+        self.clock += 1;
+        let entry = self.entries.get_mut(path).unwrap();
+        entry.last_used = self.clock;
+        Ok(Arc::clone(&entry.face))
+    }
+
+    fn evict(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(path, _)| path.clone());
+        if let Some(path) = oldest {
+            self.entries.remove(&path);
+        }
+    }
+}
+
+/// Runs `fontinfo daemon [--socket <path>] [--http <addr> [--allow-remote]]
+/// [--cache-size <n>]`.
+pub fn run(program: &str, args: &[String]) {
+    let mut socket = None;
+    let mut http = None;
+    let mut allow_remote = false;
+    let mut cache_size = DEFAULT_CACHE_SIZE;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--allow-remote" {
+            allow_remote = true;
+            continue;
+        }
+        match (arg.as_str(), iter.next()) {
+            ("--socket", Some(path)) => socket = Some(PathBuf::from(path)),
+            ("--http", Some(addr)) => http = Some(addr.clone()),
+            ("--cache-size", Some(n)) => match n.parse() {
+                Ok(n) if n > 0 => cache_size = n,
+                _ => usage(program),
+            },
+            _ => usage(program),
+        }
+    }
+
+    let cache = Arc::new(Mutex::new(FaceCache::new(cache_size)));
+    let mut listeners = Vec::new();
+
+    if let Some(addr) = http {
+        // Requests are unauthenticated, so other hosts are only served
+        // when asked for explicitly.
+        let loopback = addr
+            .to_socket_addrs()
+            .is_ok_and(|mut addrs| addrs.all(|a| a.ip().is_loopback()));
+        if !loopback && !allow_remote {
+            eprintln!(
+                "Refusing to listen on '{}', which isn't a loopback address; pass --allow-remote to serve other hosts",
+                addr
+            );
+            process::exit(1);
+        }
+        let listener = TcpListener::bind(&addr).unwrap_or_else(|e| {
+            eprintln!("Error listening on '{}': {}", addr, e);
+            process::exit(1);
+        });
+        eprintln!("Listening on http://{}", addr);
+        let cache = Arc::clone(&cache);
+        listeners.push(thread::spawn(move || {
+            // Each connection gets its own thread, so a slow or idle client
+            // doesn't hold up the others.
+            for stream in listener.incoming().flatten() {
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    let result = stream
+                        .set_read_timeout(Some(IO_TIMEOUT))
+                        .and_then(|()| stream.set_write_timeout(Some(IO_TIMEOUT)))
+                        .and_then(|()| serve_http(stream, &cache));
+                    if let Err(e) = result {
+                        eprintln!("Error handling HTTP request: {}", e);
+                    }
+                });
+            }
+        }));
+    }
+
+    if socket.is_some() || listeners.is_empty() {
+        let path = socket.unwrap_or_else(default_socket_path);
+        let listener = bind_socket(&path);
+        eprintln!("Listening on {}", path.display());
+        let cache = Arc::clone(&cache);
+        listeners.push(thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    let result = stream
+                        .set_read_timeout(Some(IO_TIMEOUT))
+                        .and_then(|()| stream.set_write_timeout(Some(IO_TIMEOUT)))
+                        .and_then(|()| serve_socket(stream, &cache));
+                    if let Err(e) = result {
+                        eprintln!("Error handling request: {}", e);
+                    }
+                });
+            }
+        }));
+    }
+
+    for listener in listeners {
+        let _ = listener.join();
+    }
+}
+
+/// Binds the Unix socket, readable and writable only by its owner. A stale
+/// socket left behind by a daemon that didn't shut down cleanly is
+/// replaced, but only if it's the current user's.
+fn bind_socket(path: &Path) -> UnixListener {
+    if let Ok(metadata) = fs::symlink_metadata(path)
+        && metadata.file_type().is_socket()
+        && current_uid() == Some(metadata.uid())
+        && UnixStream::connect(path).is_err()
+    {
+        let _ = fs::remove_file(path);
+    }
+    let listener = UnixListener::bind(path).unwrap_or_else(|e| {
+        eprintln!("Error listening on '{}': {}", path.display(), e);
+        process::exit(1);
+    });
+    if let Err(e) = fs::set_permissions(path, fs::Permissions::from_mode(0o600)) {
+        eprintln!("Error restricting '{}': {}", path.display(), e);
+        process::exit(1);
+    }
+    listener
+}
+
+/// `$XDG_RUNTIME_DIR/fontinfo.sock`, or otherwise a socket in a private
+/// per-user directory of the shared temporary directory.
+fn default_socket_path() -> PathBuf {
+    if let Some(dir) = env::var_os("XDG_RUNTIME_DIR") {
+        return PathBuf::from(dir).join("fontinfo.sock");
+    }
+    let dir = current_uid()
+        .ok_or_else(|| io::Error::other("can't determine the current user"))
+        .and_then(|uid| {
+            let dir = env::temp_dir().join(format!("fontinfo-{}", uid));
+            private_dir(&dir, uid).map(|()| dir)
+        })
+        .unwrap_or_else(|e| {
+            eprintln!("Error creating a socket directory: {}", e);
+            process::exit(1);
+        });
+    dir.join("fontinfo.sock")
+}
+
+/// Creates `dir` accessible only to its owner, or checks that an existing
+/// one is a directory owned by `uid` that nobody else can enter.
+fn private_dir(dir: &Path, uid: u32) -> io::Result<()> {
+    match fs::DirBuilder::new().mode(0o700).create(dir) {
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+        result => return result,
+    }
+    let metadata = fs::symlink_metadata(dir)?;
+    if !metadata.is_dir() || metadata.uid() != uid || metadata.mode() & 0o077 != 0 {
+        return Err(io::Error::other(format!(
+            "'{}' isn't a private directory of the current user",
+            dir.display()
+        )));
+    }
+    Ok(())
+}
+
+/// The current user's ID, as the owner of a file created to find out;
+/// the standard library has no `getuid`.
+fn current_uid() -> Option<u32> {
+    let probe = env::temp_dir().join(format!(".fontinfo-uid-{}", process::id()));
+    let file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+        .ok()?;
+    let uid = file.metadata().map(|metadata| metadata.uid());
+    let _ = fs::remove_file(&probe);
+    uid.ok()
+}
+
+/// Answers one request on a Unix socket connection. The request is a JSON
+/// line; the reply is `ok`, `error <n>` or `crash` on the first line,
+/// followed by the report or the error message. After `error <n>` come `n`
+/// bytes of output written before the failure, then the errors.
+fn serve_socket(stream: UnixStream, cache: &Mutex<FaceCache>) -> io::Result<()> {
+    let mut line = String::new();
+    BufReader::new((&stream).take(MAX_REQUEST_LEN)).read_line(&mut line)?;
+    let request = if line.ends_with('\n') {
+        parse_request(line.as_bytes())
+    } else {
+        Err(too_long())
+    };
+    let mut stream = stream;
+    match request.and_then(|request| answer(&request, cache)) {
+        Ok(output) => {
+            stream.write_all(b"ok\n")?;
+            stream.write_all(&output)
+        }
+        Err(Failure::Request { output, errors }) => {
+            writeln!(stream, "error {}", output.len())?;
+            stream.write_all(&output)?;
+            writeln!(stream, "{}", errors)
+        }
+        Err(Failure::Crash(e)) => writeln!(stream, "crash\n{}", e),
+    }
+}
+
+/// Answers one HTTP request whose body is a JSON request.
+fn serve_http(stream: std::net::TcpStream, cache: &Mutex<FaceCache>) -> io::Result<()> {
+    let mut reader = BufReader::new((&stream).take(MAX_REQUEST_LEN));
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    // Lines cut short by the end of input or the length limit leave the
+    // request incomplete.
+    let mut complete = request_line.ends_with('\n');
+    let mut content_length = 0;
+    while complete {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+        complete = header.ends_with('\n');
+        if header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+    }
+    let mut body = Vec::new();
+    if complete {
+        (&mut reader).take(content_length).read_to_end(&mut body)?;
+        complete = body.len() as u64 == content_length;
+    }
+
+    let result = if !complete {
+        Err(too_long())
+    } else if request_line.starts_with("POST ") {
+        parse_request(&body).and_then(|request| answer(&request, cache))
+    } else {
+        Err(Failure::request("Only POST requests are supported"))
+    };
+    let (status, body) = match result {
+        Ok(output) => ("200 OK", output),
+        Err(Failure::Request { mut output, errors }) => {
+            output.extend(format!("{}\n", errors).into_bytes());
+            ("400 Bad Request", output)
+        }
+        Err(Failure::Crash(e)) => ("500 Internal Server Error", format!("{}\n", e).into_bytes()),
+    };
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        body.len()
+    )?;
+    stream.write_all(&body)
+}
+
+fn too_long() -> Failure {
+    Failure::request(format!(
+        "Incomplete request, or longer than {} bytes",
+        MAX_REQUEST_LEN
+    ))
+}
+
+fn parse_request(data: &[u8]) -> Result<Request, Failure> {
+    serde_json::from_slice(data).map_err(|e| Failure::request(format!("Invalid request: {}", e)))
+}
+
+/// Whether the daemon serves a request. Anyone who can reach it can send
+/// one, so only reports that read nothing but the font files named are
+/// answered; modes that write files or read others, such as
+/// `--export-svg`, `--check-file` or `--config`, and directories, which
+/// would start a recursive scan, are left to the CLI.
+fn allowed(report: &ReportArgs) -> bool {
+    if report.config_path.is_some() || report.font_paths.iter().any(|p| Path::new(p).is_dir()) {
+        return false;
+    }
+    match &report.mode {
+        Mode::ExportSvg(..) | Mode::ExportSvgAll(_) | Mode::RenderSpecimen(..) => false,
+        Mode::CheckText(TextSource::File(_)) => false,
+        // SMuFL metadata and italic siblings next to the font.
+        Mode::Profile(Profile::Music | Profile::Coding, _) => false,
+        Mode::EmojiSequences(file) | Mode::Emoji(file) => file.is_none(),
+        Mode::Report(_)
+        | Mode::Css
+        | Mode::Designspace
+        | Mode::InstanceMetrics
+        | Mode::GlyphSources
+        | Mode::Slice(_)
+        | Mode::Split(_)
+        | Mode::Badge
+        | Mode::Locales(_)
+        | Mode::CheckText(TextSource::Inline(_))
+        | Mode::Profile(..)
+        | Mode::Kerning(..)
+        | Mode::Ligatures
+        | Mode::Substitutions(_)
+        | Mode::FeaturesByScript
+        | Mode::Glyph(_)
+        | Mode::GlyphId(_)
+        | Mode::Preview(..)
+        | Mode::Strict(_) => true,
+    }
+}
+
+/// Produces the report for a request, parsing the font only if it isn't
+/// already cached.
+fn answer(request: &Request, cache: &Mutex<FaceCache>) -> Result<Vec<u8>, Failure> {
+    let mut report =
+        ReportArgs::parse(&request.args).ok_or_else(|| Failure::request("Invalid arguments"))?;
+    if let Some(cwd) = &request.cwd {
+        for path in &mut report.font_paths {
+            *path = cwd.join(&*path).to_string_lossy().into_owned();
+        }
+    }
+    if !allowed(&report) {
+        return Err(Failure::request(
+            "The daemon only answers reports that read nothing but the font files named",
+        ));
+    }
+    let config = Config::load(None).map_err(Failure::request)?;
+
+    let mut crash = None;
+    let mut output = Vec::new();
+    let errors = write_reports(&mut output, &report, |out, path| {
+        let result = contain_panic(|| {
+            // The lock is only held to look the face up, so one slow
+            // analysis doesn't hold up other clients.
+            let face = cache
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .get(Path::new(path))?;
+            write_report(out, face.as_face_ref(), path, &report, &config)
+        });
+        result.unwrap_or_else(|e| {
+            let message = format!("Error analyzing '{}': {}", path, e);
+            crash = Some(message.clone());
+            Err(message)
+        })
+    })
+    .map_err(|e| Failure::request(e.to_string()))?;
+
+    // A crash is reported as such even if other fonts in the request
+    // succeeded, so clients can tell malformed fonts from bad requests.
+    if let Some(crash) = crash {
+        return Err(Failure::Crash(crash));
+    }
+    if !errors.is_empty() {
+        return Err(Failure::Request {
+            output,
+            errors: errors.join("\n"),
+        });
+    }
+    Ok(output)
+}
+
+/// Sends a report request to the daemon named by `$FONTINFO_DAEMON` and
+/// prints its reply. Returns false when no daemon is configured, it can't
+/// be reached or it doesn't serve the request, so the caller can run the
+/// report itself.
+pub fn forward(args: &[String], report: &ReportArgs) -> bool {
+    let Some(path) = env::var_os("FONTINFO_DAEMON") else {
+        return false;
+    };
+    if !allowed(report) {
+        return false;
+    }
+    let Ok(mut stream) = UnixStream::connect(&path) else {
+        return false;
+    };
+    if stream.set_write_timeout(Some(IO_TIMEOUT)).is_err()
+        || stream.set_read_timeout(Some(REPLY_TIMEOUT)).is_err()
+    {
+        return false;
+    }
+
+    // Labels follow the client's locale, not the daemon's.
+    let mut args = args.to_vec();
+    if !args.iter().any(|arg| arg == "--lang") {
+        args.extend(["--lang".to_string(), Lang::from_env().code().to_string()]);
+    }
+    let request = Request {
+        args,
+        cwd: env::current_dir().ok(),
+    };
+    let Ok(mut line) = serde_json::to_string(&request) else {
+        return false;
+    };
+    line.push('\n');
+    if stream.write_all(line.as_bytes()).is_err() {
+        return false;
+    }
+
+    let mut reply = Vec::new();
+    if stream.read_to_end(&mut reply).is_err() {
+        return false;
+    }
+    let Some(end) = reply.iter().position(|&b| b == b'\n') else {
+        return false;
+    };
+    let (status, body) = (&reply[..end], &reply[end + 1..]);
+    if status == b"ok" {
+        let _ = io::stdout().write_all(body);
+        return true;
+    }
+    // Output written before a failure goes to stdout, as in a direct run.
+    let output_len = match status {
+        b"crash" => 0,
+        _ => match status.strip_prefix(b"error ") {
+            Some(len) => match std::str::from_utf8(len).ok().and_then(|n| n.parse().ok()) {
+                Some(len) if len <= body.len() => len,
+                _ => return false,
+            },
+            None => return false,
+        },
+    };
+    let _ = io::stdout().write_all(&body[..output_len]);
+    let _ = io::stdout().flush();
+    let _ = io::stderr().write_all(&body[output_len..]);
+    process::exit(1);
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} daemon [--socket <path>] [--http <addr> [--allow-remote]] [--cache-size <n>]",
+        program
+    );
+    eprintln!("Example: {} daemon --http 127.0.0.1:7878", program);
+    process::exit(1);
+}
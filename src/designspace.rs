@@ -0,0 +1,99 @@
+use crate::get_name;
+use crate::variations::{avar_mappings, denormalize, named_instances};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use ttf_parser::Face;
+
+#[derive(Serialize)]
+pub struct DesignSpace {
+    pub axes: Vec<Axis>,
+    pub instances: Vec<Instance>,
+}
+
+#[derive(Serialize)]
+pub struct Axis {
+    pub tag: String,
+    pub name: String,
+    pub minimum: f32,
+    pub default: f32,
+    pub maximum: f32,
+    pub hidden: bool,
+    /// avar mappings expressed in user space, as `input` -> `output`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub map: Vec<AxisMap>,
+}
+
+#[derive(Serialize)]
+pub struct AxisMap {
+    pub input: f32,
+    pub output: f32,
+}
+
+#[derive(Serialize)]
+pub struct Instance {
+    pub name: Option<String>,
+    #[serde(rename = "postscriptName", skip_serializing_if = "Option::is_none")]
+    pub postscript_name: Option<String>,
+    /// User-space location keyed by axis name.
+    pub location: BTreeMap<String, f32>,
+}
+
+/// Builds a designspace-like description of a variable font's axes,
+/// named instances and avar mappings. Returns `None` for static fonts.
+pub fn build(face: &Face) -> Option<DesignSpace> {
+    let fvar_axes = face.variation_axes();
+    if fvar_axes.is_empty() {
+        return None;
+    }
+
+    let mappings = avar_mappings(face);
+    let axes: Vec<Axis> = fvar_axes
+        .into_iter()
+        .enumerate()
+        .map(|(i, axis)| {
+            let (min, def, max) = (axis.min_value, axis.def_value, axis.max_value);
+            let map = mappings
+                .get(i)
+                .map(|pairs| {
+                    pairs
+                        .iter()
+                        .map(|&(from, to)| AxisMap {
+                            input: round(denormalize(from, min, def, max)),
+                            output: round(denormalize(to, min, def, max)),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            Axis {
+                tag: axis.tag.to_string(),
+                name: get_name(face, axis.name_id).unwrap_or_else(|| axis.tag.to_string()),
+                minimum: min,
+                default: def,
+                maximum: max,
+                hidden: axis.hidden,
+                map,
+            }
+        })
+        .collect();
+
+    let instances = named_instances(face)
+        .into_iter()
+        .map(|instance| Instance {
+            name: get_name(face, instance.subfamily_name_id),
+            postscript_name: instance
+                .postscript_name_id
+                .and_then(|id| get_name(face, id)),
+            location: axes
+                .iter()
+                .zip(&instance.coordinates)
+                .map(|(axis, &value)| (axis.name.clone(), value))
+                .collect(),
+        })
+        .collect();
+
+    Some(DesignSpace { axes, instances })
+}
+
+fn round(value: f32) -> f32 {
+    (value * 1000.0).round() / 1000.0
+}
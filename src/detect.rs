@@ -0,0 +1,214 @@
+use crate::reader::Reader;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::process;
+
+/// Number of leading bytes needed to classify any supported format.
+const HEADER_LEN: usize = 64;
+
+/// A font container format recognized from its magic number.
+pub enum FileKind {
+    TrueType { version: u32 },
+    OpenTypeCff,
+    Collection { major: u16, minor: u16, faces: u32 },
+    Woff { flavor: u32, major: u16, minor: u16 },
+    Woff2 { flavor: u32, major: u16, minor: u16 },
+    Eot { version: u32 },
+    Type1Ascii,
+    Type1Binary,
+    Dfont,
+    Bdf { version: String },
+    Pcf,
+    NotAFont,
+}
+
+impl FileKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FileKind::TrueType { .. } => "TTF",
+            FileKind::OpenTypeCff => "OTF/CFF",
+            FileKind::Collection { .. } => "TTC",
+            FileKind::Woff { .. } => "WOFF",
+            FileKind::Woff2 { .. } => "WOFF2",
+            FileKind::Eot { .. } => "EOT",
+            FileKind::Type1Ascii | FileKind::Type1Binary => "Type1",
+            FileKind::Dfont => "dfont",
+            FileKind::Bdf { .. } => "BDF",
+            FileKind::Pcf => "PCF",
+            FileKind::NotAFont => "not-a-font",
+        }
+    }
+
+    /// Describes the version fields found in the header, if any.
+    pub fn details(&self) -> String {
+        match self {
+            FileKind::TrueType { version } => format!("sfnt version {}", sfnt_version(*version)),
+            FileKind::OpenTypeCff => "sfnt version 'OTTO'".to_string(),
+            FileKind::Collection {
+                major,
+                minor,
+                faces,
+            } => format!("version {}.{}, {} faces", major, minor, faces),
+            FileKind::Woff {
+                flavor,
+                major,
+                minor,
+            }
+            | FileKind::Woff2 {
+                flavor,
+                major,
+                minor,
+            } => format!(
+                "flavor {}, font version {}.{}",
+                sfnt_version(*flavor),
+                major,
+                minor
+            ),
+            FileKind::Eot { version } => format!("version {:#010x}", version),
+            FileKind::Type1Ascii => "PFA (ASCII)".to_string(),
+            FileKind::Type1Binary => "PFB (binary segments)".to_string(),
+            FileKind::Dfont => "Mac resource-fork suitcase".to_string(),
+            FileKind::Bdf { version } => format!("STARTFONT {}", version),
+            FileKind::Pcf => "X11 portable compiled format".to_string(),
+            FileKind::NotAFont => String::new(),
+        }
+    }
+}
+
+/// Runs `fontinfo detect <file>...`.
+pub fn run(program: &str, args: &[String]) {
+    if args.is_empty() || args.iter().any(|a| a.starts_with("--")) {
+        usage(program);
+    }
+
+    for path in args {
+        match read_header(Path::new(path)) {
+            Ok((header, file_len)) => {
+                let kind = classify(&header, file_len);
+                let details = kind.details();
+                if details.is_empty() {
+                    println!("{}: {}", path, kind.name());
+                } else {
+                    println!("{}: {} ({})", path, kind.name(), details);
+                }
+            }
+            Err(e) => eprintln!("Error reading '{}': {}", path, e),
+        }
+    }
+}
+
+/// Reads the leading bytes needed by `classify`, along with the file size.
+pub fn read_header(path: &Path) -> std::io::Result<(Vec<u8>, u64)> {
+    let mut file = File::open(path)?;
+    let file_len = file.metadata()?.len();
+    let mut header = Vec::with_capacity(HEADER_LEN);
+    file.by_ref()
+        .take(HEADER_LEN as u64)
+        .read_to_end(&mut header)?;
+    Ok((header, file_len))
+}
+
+/// Classifies a file from its leading bytes and total length.
+pub fn classify(header: &[u8], file_len: u64) -> FileKind {
+    let mut r = Reader::new(header);
+    let Some(magic) = r.read_u32() else {
+        return FileKind::NotAFont;
+    };
+
+    match &magic.to_be_bytes() {
+        [0x00, 0x01, 0x00, 0x00] | b"true" | b"typ1" => {
+            return FileKind::TrueType { version: magic };
+        }
+        b"OTTO" => return FileKind::OpenTypeCff,
+        b"ttcf" => {
+            let major = r.read_u16().unwrap_or(0);
+            let minor = r.read_u16().unwrap_or(0);
+            let faces = r.read_u32().unwrap_or(0);
+            return FileKind::Collection {
+                major,
+                minor,
+                faces,
+            };
+        }
+        b"wOFF" | b"wOF2" => {
+            let is_woff1 = magic == u32::from_be_bytes(*b"wOFF");
+            let flavor = r.read_u32().unwrap_or(0);
+            // The font version follows length/numTables/reserved/totalSfntSize,
+            // and in WOFF2 also totalCompressedSize.
+            let mut r = Reader::at(header, if is_woff1 { 20 } else { 24 });
+            let major = r.read_u16().unwrap_or(0);
+            let minor = r.read_u16().unwrap_or(0);
+            return if is_woff1 {
+                FileKind::Woff {
+                    flavor,
+                    major,
+                    minor,
+                }
+            } else {
+                FileKind::Woff2 {
+                    flavor,
+                    major,
+                    minor,
+                }
+            };
+        }
+        [0x01, b'f', b'c', b'p'] => return FileKind::Pcf,
+        _ => {}
+    }
+
+    if header.starts_with(b"%!PS-AdobeFont") || header.starts_with(b"%!FontType1") {
+        return FileKind::Type1Ascii;
+    }
+    if header.starts_with(&[0x80, 0x01]) && header.get(6..8) == Some(b"%!") {
+        return FileKind::Type1Binary;
+    }
+    if let Some(rest) = header.strip_prefix(b"STARTFONT ") {
+        let version = rest
+            .iter()
+            .take_while(|b| !b.is_ascii_whitespace())
+            .map(|&b| b as char)
+            .collect();
+        return FileKind::Bdf { version };
+    }
+    if header.get(34..36) == Some(&[0x4C, 0x50]) {
+        let version = header
+            .get(8..12)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .unwrap_or(0);
+        return FileKind::Eot { version };
+    }
+    if is_resource_fork(header, file_len) {
+        return FileKind::Dfont;
+    }
+
+    FileKind::NotAFont
+}
+
+/// Checks that a resource-fork header describes a file of exactly this size.
+fn is_resource_fork(header: &[u8], file_len: u64) -> bool {
+    let mut r = Reader::new(header);
+    let (Some(data_offset), Some(map_offset), Some(data_len), Some(map_len)) =
+        (r.read_u32(), r.read_u32(), r.read_u32(), r.read_u32())
+    else {
+        return false;
+    };
+    data_offset == 0x100
+        && u64::from(data_offset) + u64::from(data_len) <= u64::from(map_offset)
+        && u64::from(map_offset) + u64::from(map_len) == file_len
+}
+
+fn sfnt_version(version: u32) -> String {
+    let bytes = version.to_be_bytes();
+    if bytes.iter().all(|b| b.is_ascii_alphanumeric()) {
+        format!("'{}'", String::from_utf8_lossy(&bytes))
+    } else {
+        format!("{:#010x}", version)
+    }
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} detect <file>...", program);
+    eprintln!("Example: {} detect unknown/*", program);
+    process::exit(1);
+}
@@ -0,0 +1,464 @@
+use crate::{VIOLATIONS_SHOWN, parse_face, read_font_file};
+use fontinfo::get_name;
+use fontinfo::png;
+use fontinfo::render::{self, Canvas};
+use fontinfo::unicode::covered_chars;
+use std::collections::BTreeSet;
+use std::process;
+use ttf_parser::{Face, Tag, name_id};
+
+/// Text rendered by `--render` unless `--text` is given.
+const DEFAULT_TEXT: &str = "Hamburgefonstiv";
+/// Default `--size`, in pixels per em.
+const DEFAULT_SIZE: f32 = 96.0;
+/// Blank margin around the rendered text, in pixels.
+const PADDING: usize = 16;
+
+/// Name IDs compared by the summary, with their labels.
+const NAMES: [(u16, &str); 8] = [
+    (name_id::FAMILY, "Family"),
+    (name_id::SUBFAMILY, "Subfamily"),
+    (name_id::UNIQUE_ID, "Unique ID"),
+    (name_id::FULL_NAME, "Full name"),
+    (name_id::VERSION, "Version"),
+    (name_id::POST_SCRIPT_NAME, "PostScript name"),
+    (name_id::TYPOGRAPHIC_FAMILY, "Typo family"),
+    (name_id::TYPOGRAPHIC_SUBFAMILY, "Typo subfamily"),
+];
+
+/// Runs `fontinfo diff <old-font> <new-font> [--summary] [--tables]
+/// [--render ...]`. With none of the three, prints the summary.
+pub fn run(program: &str, args: &[String]) {
+    let mut paths = Vec::new();
+    let mut summary = false;
+    let mut tables = false;
+    let mut render = false;
+    let mut text = DEFAULT_TEXT.to_string();
+    let mut size = DEFAULT_SIZE;
+    let mut output = "diff.png".to_string();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--summary" => summary = true,
+            "--tables" => tables = true,
+            "--render" => render = true,
+            "--text" => match args.next() {
+                Some(value) => text = value.clone(),
+                None => usage(program),
+            },
+            "--size" => match args.next().and_then(|value| value.parse().ok()) {
+                Some(value) if value > 0.0 => size = value,
+                _ => usage(program),
+            },
+            "-o" => match args.next() {
+                Some(value) => output = value.clone(),
+                None => usage(program),
+            },
+            _ if !arg.starts_with('-') => paths.push(arg),
+            _ => usage(program),
+        }
+    }
+
+    let [old_path, new_path] = paths.as_slice() else {
+        usage(program);
+    };
+    if !tables && !render {
+        summary = true;
+    }
+
+    let old_data = read_font_file(old_path);
+    let new_data = read_font_file(new_path);
+    let old_face = parse_face(&old_data, old_path);
+    let new_face = parse_face(&new_data, new_path);
+
+    if summary {
+        print_summary(&old_face, &new_face, old_path, new_path);
+    }
+    if tables {
+        if summary {
+            println!();
+        }
+        print_table_diff(&old_face, &new_face, old_path, new_path);
+    }
+    if render {
+        if summary || tables {
+            println!();
+        }
+        render_overlay(&old_face, &new_face, &text, size, &output);
+    }
+}
+
+/// Renders `text` with both fonts on the same baseline and writes them
+/// overlaid to a PNG: the old font in red, the new one in blue, so that
+/// ink both share comes out black and any change shows in color.
+fn render_overlay(old: &Face, new: &Face, text: &str, size: f32, output: &str) {
+    let scale = |face: &Face| size / f32::from(face.units_per_em());
+    // Pixels above and below the baseline the taller of the two fonts needs.
+    let ascent = |face: &Face| f32::from(face.ascender()) * scale(face);
+    let descent = |face: &Face| -f32::from(face.descender()) * scale(face);
+    let above = ascent(old).max(ascent(new)).ceil();
+    let below = descent(old).max(descent(new)).ceil();
+
+    let text_width =
+        render::text_width(old, text, scale(old)).max(render::text_width(new, text, scale(new)));
+    let width = text_width.ceil().max(1.0) as usize + 2 * PADDING;
+    let height = (above + below) as usize + 2 * PADDING;
+
+    let baseline = PADDING as f32 + above;
+    let mut old_canvas = Canvas::new(width, height);
+    let mut new_canvas = Canvas::new(width, height);
+    old_canvas.draw_text(old, text, PADDING as f32, baseline, scale(old));
+    new_canvas.draw_text(new, text, PADDING as f32, baseline, scale(new));
+
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        for x in 0..width {
+            let a = old_canvas.coverage(x, y);
+            let b = new_canvas.coverage(x, y);
+            // Each font takes its own color out of white.
+            for channel in [1.0 - b, (1.0 - a) * (1.0 - b), 1.0 - a] {
+                pixels.push((channel * 255.0).round() as u8);
+            }
+        }
+    }
+
+    if let Err(e) = std::fs::write(output, png::encode_rgb(width, height, &pixels)) {
+        eprintln!("Error writing {}: {}", output, e);
+        process::exit(1);
+    }
+    println!(
+        "Wrote {} ({}×{}): red = old, blue = new, black = both",
+        output, width, height
+    );
+}
+
+/// Prints what a font upgrade changes: names, metrics, glyph count, cmap
+/// coverage, layout features, axes and table sizes.
+fn print_summary(old: &Face, new: &Face, old_path: &str, new_path: &str) {
+    println!("┌─ FONT DIFF ───────────────────────────────────────────────────");
+    println!("│ Old: {}", old_path);
+    println!("│ New: {}", new_path);
+
+    let names: Vec<(&str, String, String)> = NAMES
+        .iter()
+        .map(|&(id, label)| {
+            let name = |face: &Face| get_name(face, id).unwrap_or_else(|| "-".to_string());
+            (label, name(old), name(new))
+        })
+        .collect();
+    print_changes("Names", &names);
+
+    let metrics: Vec<(&str, String, String)> = METRICS
+        .into_iter()
+        .zip(metrics(old).into_iter().zip(metrics(new)))
+        .map(|(label, (old, new))| (label, old, new))
+        .collect();
+    print_changes("Metrics", &metrics);
+
+    println!("│");
+    let (old_glyphs, new_glyphs) = (old.number_of_glyphs(), new.number_of_glyphs());
+    if old_glyphs == new_glyphs {
+        println!("│ Glyphs: {} (unchanged)", old_glyphs);
+    } else {
+        println!(
+            "│ Glyphs: {} -> {} ({:+})",
+            old_glyphs,
+            new_glyphs,
+            i32::from(new_glyphs) - i32::from(old_glyphs)
+        );
+    }
+
+    let old_chars = covered_chars(old);
+    let new_chars = covered_chars(new);
+    let added: Vec<char> = new_chars.difference(&old_chars).copied().collect();
+    let removed: Vec<char> = old_chars.difference(&new_chars).copied().collect();
+    if added.is_empty() && removed.is_empty() {
+        println!("│ Code points: {} (unchanged)", old_chars.len());
+    } else {
+        println!(
+            "│ Code points: {} -> {} ({} added, {} removed)",
+            old_chars.len(),
+            new_chars.len(),
+            added.len(),
+            removed.len()
+        );
+        for (label, chars) in [("Added", &added), ("Removed", &removed)] {
+            if !chars.is_empty() {
+                println!(
+                    "│   {:<8} {}",
+                    format!("{}:", label),
+                    code_point_runs(chars)
+                );
+            }
+        }
+    }
+
+    println!("│");
+    println!("│ Features");
+    let mut features_changed = false;
+    for (table, tags) in [
+        ("GSUB", gsub_features as fn(&Face) -> _),
+        ("GPOS", gpos_features),
+    ] {
+        let (old_tags, new_tags) = (tags(old), tags(new));
+        for (label, set) in [
+            ("added", new_tags.difference(&old_tags)),
+            ("removed", old_tags.difference(&new_tags)),
+        ] {
+            let list: Vec<String> = set.map(Tag::to_string).collect();
+            if !list.is_empty() {
+                features_changed = true;
+                println!("│   {} {}: {}", table, label, list.join(", "));
+            }
+        }
+    }
+    if !features_changed {
+        println!("│   unchanged");
+    }
+
+    let (old_axes, new_axes) = (axes(old), axes(new));
+    let tags: BTreeSet<&String> = old_axes
+        .iter()
+        .chain(&new_axes)
+        .map(|(tag, _)| tag)
+        .collect();
+    let range = |axes: &[(String, String)], tag: &str| {
+        axes.iter()
+            .find(|(t, _)| t == tag)
+            .map_or("-".to_string(), |(_, range)| range.clone())
+    };
+    let axis_changes: Vec<(&str, String, String)> = tags
+        .into_iter()
+        .map(|tag| (tag.as_str(), range(&old_axes, tag), range(&new_axes, tag)))
+        .collect();
+    print_changes("Axes", &axis_changes);
+
+    println!("│");
+    println!("│ Table sizes");
+    let mut sizes_changed = false;
+    for (tag, change) in compare_tables(old, new) {
+        let line = match change {
+            TableChange::Resized { old_len, new_len } => format!(
+                "{} -> {} bytes ({:+})",
+                old_len,
+                new_len,
+                new_len as i64 - old_len as i64
+            ),
+            TableChange::Added(len) => format!("added, {} bytes", len),
+            TableChange::Removed(len) => format!("removed, {} bytes", len),
+            TableChange::Identical | TableChange::Modified { .. } => continue,
+        };
+        sizes_changed = true;
+        println!("│   {}  {}", tag, line);
+    }
+    if !sizes_changed {
+        println!("│   unchanged");
+    }
+    println!("└───────────────────────────────────────────────────────────────");
+}
+
+/// Prints a titled group of `(label, old, new)` values, listing only the
+/// ones that differ.
+fn print_changes(title: &str, values: &[(&str, String, String)]) {
+    println!("│");
+    println!("│ {}", title);
+    let mut changed = false;
+    for (label, old, new) in values.iter().filter(|(_, old, new)| old != new) {
+        changed = true;
+        println!("│   {:<16} {} -> {}", label, old, new);
+    }
+    if !changed {
+        println!("│   unchanged");
+    }
+}
+
+const METRICS: [&str; 9] = [
+    "Units per em",
+    "Ascender",
+    "Descender",
+    "Line gap",
+    "x-height",
+    "Cap height",
+    "Weight class",
+    "Width class",
+    "Italic angle",
+];
+
+/// The values of `METRICS`, in order.
+fn metrics(face: &Face) -> [String; 9] {
+    [
+        face.units_per_em().to_string(),
+        face.ascender().to_string(),
+        face.descender().to_string(),
+        face.line_gap().to_string(),
+        optional(face.x_height()),
+        optional(face.capital_height()),
+        face.weight().to_number().to_string(),
+        face.width().to_number().to_string(),
+        face.italic_angle().to_string(),
+    ]
+}
+
+/// Each axis' tag and range.
+fn axes(face: &Face) -> Vec<(String, String)> {
+    face.variation_axes()
+        .into_iter()
+        .map(|a| {
+            let range = format!("{}–{} (default {})", a.min_value, a.max_value, a.def_value);
+            (a.tag.to_string(), range)
+        })
+        .collect()
+}
+
+fn optional(value: Option<i16>) -> String {
+    value.map_or("-".to_string(), |v| v.to_string())
+}
+
+fn gsub_features(face: &Face) -> BTreeSet<Tag> {
+    let Some(gsub) = face.tables().gsub else {
+        return BTreeSet::new();
+    };
+    gsub.features.into_iter().map(|f| f.tag).collect()
+}
+
+fn gpos_features(face: &Face) -> BTreeSet<Tag> {
+    let Some(gpos) = face.tables().gpos else {
+        return BTreeSet::new();
+    };
+    gpos.features.into_iter().map(|f| f.tag).collect()
+}
+
+/// Sorted code points as `U+XXXX` or `U+XXXX-YYYY` runs, the first
+/// `VIOLATIONS_SHOWN` of them.
+fn code_point_runs(chars: &[char]) -> String {
+    let mut runs: Vec<(u32, u32)> = Vec::new();
+    for c in chars.iter().map(|&c| u32::from(c)) {
+        match runs.last_mut() {
+            Some((_, end)) if *end + 1 == c => *end = c,
+            _ => runs.push((c, c)),
+        }
+    }
+    let mut list: Vec<String> = runs
+        .iter()
+        .take(VIOLATIONS_SHOWN)
+        .map(|&(start, end)| {
+            if start == end {
+                format!("U+{:04X}", start)
+            } else {
+                format!("U+{:04X}-{:04X}", start, end)
+            }
+        })
+        .collect();
+    if runs.len() > VIOLATIONS_SHOWN {
+        list.push(format!("… and {} more", runs.len() - VIOLATIONS_SHOWN));
+    }
+    list.join(", ")
+}
+
+enum TableChange {
+    Identical,
+    Modified { len: usize, changed_bytes: usize },
+    Resized { old_len: usize, new_len: usize },
+    Added(usize),
+    Removed(usize),
+}
+
+fn compare_tables(old: &Face, new: &Face) -> Vec<(Tag, TableChange)> {
+    let tags: BTreeSet<Tag> = old
+        .raw_face()
+        .table_records
+        .into_iter()
+        .chain(new.raw_face().table_records)
+        .map(|r| r.tag)
+        .collect();
+
+    tags.into_iter()
+        .map(|tag| {
+            let change = match (old.raw_face().table(tag), new.raw_face().table(tag)) {
+                (Some(a), Some(b)) if a == b => TableChange::Identical,
+                (Some(a), Some(b)) if a.len() == b.len() => TableChange::Modified {
+                    len: a.len(),
+                    changed_bytes: a.iter().zip(b).filter(|(x, y)| x != y).count(),
+                },
+                (Some(a), Some(b)) => TableChange::Resized {
+                    old_len: a.len(),
+                    new_len: b.len(),
+                },
+                (None, Some(b)) => TableChange::Added(b.len()),
+                (Some(a), None) => TableChange::Removed(a.len()),
+                (None, None) => TableChange::Identical,
+            };
+            (tag, change)
+        })
+        .collect()
+}
+
+fn print_table_diff(old: &Face, new: &Face, old_path: &str, new_path: &str) {
+    let changes = compare_tables(old, new);
+
+    println!("┌─ TABLE DIFF ──────────────────────────────────────────────────");
+    println!("│ Old: {}", old_path);
+    println!("│ New: {}", new_path);
+    println!("│");
+
+    let mut identical = 0;
+    for (tag, change) in &changes {
+        let line = match *change {
+            TableChange::Identical => {
+                identical += 1;
+                continue;
+            }
+            TableChange::Modified { len, changed_bytes } => {
+                format!("modified   {} of {} bytes differ", changed_bytes, len)
+            }
+            TableChange::Resized { old_len, new_len } => format!(
+                "resized    {} -> {} bytes ({:+})",
+                old_len,
+                new_len,
+                new_len as i64 - old_len as i64
+            ),
+            TableChange::Added(len) => format!("added      {} bytes", len),
+            TableChange::Removed(len) => format!("removed    {} bytes", len),
+        };
+        println!("│ {}  {}", tag, line);
+    }
+
+    if identical == changes.len() {
+        println!("│ All {} tables are identical", identical);
+    } else {
+        let names: Vec<String> = changes
+            .iter()
+            .filter(|(_, c)| matches!(c, TableChange::Identical))
+            .map(|(tag, _)| tag.to_string())
+            .collect();
+        println!("│");
+        println!("│ Identical ({}): {}", identical, names.join(", "));
+    }
+    println!("└───────────────────────────────────────────────────────────────");
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} diff <old-font> <new-font> [--summary] [--tables] [--render [--text <text>] [--size <px>] [-o <file>]]",
+        program
+    );
+    eprintln!("  --summary        Compare names, metrics, glyphs, code points, features,");
+    eprintln!("                   axes and table sizes (the default without other options)");
+    eprintln!("  --tables         Compare the raw bytes of every table");
+    eprintln!("  --render         Render text with both fonts overlaid into a PNG");
+    eprintln!(
+        "  --text <text>    Text to render (default \"{}\")",
+        DEFAULT_TEXT
+    );
+    eprintln!(
+        "  --size <px>      Pixels per em (default {})",
+        DEFAULT_SIZE
+    );
+    eprintln!("  -o <file>        Output file (default diff.png)");
+    eprintln!(
+        "Example: {} diff old.ttf new.ttf --render --text \"Sample\"",
+        program
+    );
+    process::exit(1);
+}
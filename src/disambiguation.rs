@@ -0,0 +1,126 @@
+use crate::layout;
+use crate::raster::{Polygons, Raster};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use ttf_parser::{Face, GlyphId, Tag};
+
+/// Pairs of characters that coding and UI fonts are judged on telling
+/// apart.
+const PAIRS: [(char, char); 4] = [('0', 'O'), ('l', '1'), ('l', 'I'), ('1', 'I')];
+
+/// Shape similarity above which a pair counts as confusable.
+const CONFUSABLE: f32 = 0.9;
+
+/// Raster cells per em used to compare outlines; coarse enough to ignore
+/// small differences in stroke weight.
+const CELLS_PER_EM: f32 = 40.0;
+
+/// How the font tells 0 from O, and l, 1 and I from each other.
+#[derive(Serialize)]
+pub struct DisambiguationReport {
+    /// GSUB features that substitute the zero: `zero`, stylistic sets and
+    /// character variants.
+    pub zero_features: Vec<String>,
+    /// Glyphs named as zero alternates, e.g. `zero.slash` or `zero.dot`.
+    pub zero_alternates: Vec<String>,
+    /// The default zero has more contours than O, as a dot or a separate
+    /// slash adds one.
+    pub zero_marked: bool,
+    pub pairs: Vec<PairSimilarity>,
+}
+
+/// How alike two glyphs look.
+#[derive(Serialize)]
+pub struct PairSimilarity {
+    pub first: char,
+    pub second: char,
+    /// Overlap of the two rasterized outlines, centered on each other, from
+    /// 0 (disjoint) to 1 (identical).
+    pub similarity: f32,
+    pub confusable: bool,
+}
+
+impl DisambiguationReport {
+    /// Returns `None` for fonts without a zero.
+    pub fn new(face: &Face) -> Option<Self> {
+        let zero = face.glyph_index('0')?;
+        let zero_features = match face.tables().gsub {
+            Some(gsub) => {
+                let tags: BTreeSet<Tag> = gsub
+                    .features
+                    .into_iter()
+                    .map(|feature| feature.tag)
+                    .filter(|&tag| is_alternate_feature(tag))
+                    .collect();
+                tags.into_iter()
+                    .filter(|&tag| {
+                        let lookups = layout::feature_lookups(&gsub, tag);
+                        layout::gsub_covers(&gsub, &lookups, zero)
+                    })
+                    .map(|tag| tag.to_string())
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+        let zero_alternates = (0..face.number_of_glyphs())
+            .filter_map(|id| face.glyph_name(GlyphId(id)))
+            .filter(|name| name.starts_with("zero.") && !is_figure_style(name))
+            .map(str::to_string)
+            .collect();
+        let zero_marked = match face.glyph_index('O') {
+            Some(o) => contours(face, zero) > contours(face, o),
+            None => false,
+        };
+
+        let pairs = PAIRS
+            .iter()
+            .filter_map(|&(first, second)| {
+                let a = Raster::new(face, face.glyph_index(first)?)?;
+                let b = Raster::new(face, face.glyph_index(second)?)?;
+                let similarity = a.similarity(&b, f32::from(face.units_per_em()) / CELLS_PER_EM);
+                Some(PairSimilarity {
+                    first,
+                    second,
+                    similarity,
+                    confusable: similarity > CONFUSABLE,
+                })
+            })
+            .collect();
+
+        Some(DisambiguationReport {
+            zero_features,
+            zero_alternates,
+            zero_marked,
+            pairs,
+        })
+    }
+
+    /// True when the zero differs from O by default or through a feature.
+    pub fn zero_distinguishable(&self) -> bool {
+        self.zero_marked || !self.zero_features.is_empty()
+    }
+}
+
+/// `zero`, `ss01`–`ss20` and `cv01`–`cv99`.
+fn is_alternate_feature(tag: Tag) -> bool {
+    let bytes = tag.to_bytes();
+    &bytes == b"zero"
+        || (matches!(&bytes[..2], b"ss" | b"cv") && bytes[2..].iter().all(u8::is_ascii_digit))
+}
+
+/// Zero glyphs for other figure styles rather than other shapes, e.g.
+/// `zero.tf`, `zero.osf` or `zero.sups`.
+fn is_figure_style(name: &str) -> bool {
+    const STYLES: [&str; 11] = [
+        "tf", "tnum", "pnum", "lf", "osf", "onum", "sups", "subs", "numr", "dnom", "sinf",
+    ];
+    name["zero.".len()..]
+        .split('.')
+        .all(|suffix| STYLES.contains(&suffix))
+}
+
+fn contours(face: &Face, glyph: GlyphId) -> usize {
+    let mut polygons = Polygons::default();
+    face.outline_glyph(glyph, &mut polygons);
+    polygons.contours.len()
+}
@@ -0,0 +1,148 @@
+use crate::{parse_face, read_font_file};
+use fontinfo::ttx;
+use std::process;
+use ttf_parser::Tag;
+
+enum Format {
+    Xml,
+    Hex,
+}
+
+/// Runs `fontinfo dump <font-file> [--table <tag>]... [--format xml | --hex]`.
+pub fn run(program: &str, args: &[String]) {
+    let mut font_path = None;
+    let mut tables = Vec::new();
+    let mut format = Format::Xml;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--table" => match args.next().and_then(|t| parse_tag(t)) {
+                Some(tag) => tables.push(tag),
+                None => usage(program),
+            },
+            "--format" => match args.next().map(String::as_str) {
+                Some("xml") => format = Format::Xml,
+                Some("hex") => format = Format::Hex,
+                _ => usage(program),
+            },
+            "--hex" => format = Format::Hex,
+            _ if font_path.is_none() && !arg.starts_with("--") => font_path = Some(arg),
+            _ => usage(program),
+        }
+    }
+
+    let Some(font_path) = font_path else {
+        usage(program);
+    };
+
+    let font_data = read_font_file(font_path);
+    let face = parse_face(&font_data, font_path);
+    let raw = face.raw_face();
+
+    if tables.is_empty() && matches!(format, Format::Hex) {
+        tables = raw.table_records.into_iter().map(|r| r.tag).collect();
+    } else if tables.is_empty() {
+        tables = ttx::SUPPORTED_TABLES
+            .iter()
+            .filter_map(|t| parse_tag(t))
+            .filter(|&tag| raw.table(tag).is_some())
+            .collect();
+    }
+
+    match format {
+        Format::Xml => {
+            let sfnt_version = font_data
+                .get(0..4)
+                .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+                .unwrap_or(0);
+            let mut out = String::new();
+            ttx::write_header(&mut out, sfnt_version);
+            for tag in tables {
+                match raw.table(tag) {
+                    Some(data) => {
+                        if !ttx::write_table(&mut out, tag, data) {
+                            eprintln!("Skipping table '{}': not supported in XML output", tag);
+                        }
+                    }
+                    None => eprintln!("Skipping table '{}': not present in font", tag),
+                }
+            }
+            ttx::write_footer(&mut out);
+            print!("{}", out);
+        }
+        Format::Hex => {
+            for (i, tag) in tables.into_iter().enumerate() {
+                let Some(record) = raw.table_records.into_iter().find(|r| r.tag == tag) else {
+                    eprintln!("Skipping table '{}': not present in font", tag);
+                    continue;
+                };
+                let data = raw.table(tag).unwrap_or_default();
+                if i > 0 {
+                    println!();
+                }
+                println!(
+                    "Table '{}' ({} bytes at file offset {:#010x})",
+                    tag, record.length, record.offset
+                );
+                print!("{}", hex_dump(data));
+            }
+        }
+    }
+}
+
+/// Formats bytes as 16-byte rows with table-relative offsets and an
+/// ASCII column.
+pub fn hex_dump(data: &[u8]) -> String {
+    let mut out = String::new();
+    for (row, chunk) in data.chunks(16).enumerate() {
+        let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+        let (left, right) = hex.split_at(hex.len().min(8));
+        let ascii: String = chunk
+            .iter()
+            .map(|&b| {
+                if b.is_ascii_graphic() || b == b' ' {
+                    b as char
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        out.push_str(&format!(
+            "{:08x}  {:<23}  {:<23}  |{}|\n",
+            row * 16,
+            left.join(" "),
+            right.join(" "),
+            ascii
+        ));
+    }
+    out
+}
+
+/// Parses a table tag as typed on the command line, padding short tags
+/// like `cvt` with spaces.
+pub fn parse_tag(text: &str) -> Option<Tag> {
+    let bytes = text.as_bytes();
+    if bytes.is_empty() || bytes.len() > 4 || !text.is_ascii() {
+        return None;
+    }
+    let mut tag = [b' '; 4];
+    tag[..bytes.len()].copy_from_slice(bytes);
+    Some(Tag::from_bytes(&tag))
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} dump <font-file> [--table <tag>]... [--format xml | --hex]",
+        program
+    );
+    eprintln!(
+        "Example: {} dump font.ttf --table name --table OS/2 --format xml",
+        program
+    );
+    eprintln!("Example: {} dump font.ttf --table cvt --hex", program);
+    eprintln!();
+    eprintln!("XML supports: {}", ttx::SUPPORTED_TABLES.join(", "));
+    eprintln!("--hex prints the raw bytes of any table");
+    process::exit(1);
+}
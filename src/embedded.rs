@@ -0,0 +1,182 @@
+use crate::{ReportOptions, contain_panic, print_font_info};
+use fontinfo::config::Config;
+use fontinfo::i18n::Lang;
+use fontinfo::pdf::{EmbeddedFont, embedded_fonts};
+use fontinfo::report::Section;
+use fontinfo::slicing::format_size;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use ttf_parser::Face;
+
+/// Runs `fontinfo pdf <document.pdf> [-o <directory>] [--terse]`, exiting
+/// with status 1 when an embedded font can't be decoded or parsed.
+pub fn run(program: &str, args: &[String]) {
+    let mut paths = Vec::new();
+    let mut output = None;
+    let mut terse = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => match args.next() {
+                Some(path) => output = Some(PathBuf::from(path)),
+                None => usage(program),
+            },
+            "--terse" => terse = true,
+            _ if !arg.starts_with('-') => paths.push(arg),
+            _ => usage(program),
+        }
+    }
+    let [pdf_path] = paths.as_slice() else {
+        usage(program);
+    };
+
+    let data = match fs::read(pdf_path) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Error reading '{}': {}", pdf_path, e);
+            process::exit(1);
+        }
+    };
+    let fonts = match embedded_fonts(&data) {
+        Ok(fonts) => fonts,
+        Err(e) => {
+            eprintln!("Error reading '{}': {}", pdf_path, e);
+            process::exit(1);
+        }
+    };
+    let config = match Config::load(None) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    if let Some(output) = &output
+        && let Err(e) = fs::create_dir_all(output)
+    {
+        eprintln!("Error creating directory '{}': {}", output.display(), e);
+        process::exit(1);
+    }
+
+    if fonts.is_empty() {
+        println!("No embedded fonts in '{}'", pdf_path);
+        return;
+    }
+    let subsets = fonts.iter().filter(|f| f.subset_prefix.is_some()).count();
+    println!(
+        "{}: {} embedded font{}, {} subset",
+        pdf_path,
+        fonts.len(),
+        if fonts.len() == 1 { "" } else { "s" },
+        subsets
+    );
+
+    let options = ReportOptions {
+        sections: Section::ALL.to_vec(),
+        terse,
+        lang: Lang::from_env(),
+        json: false,
+        redact: false,
+    };
+    let mut failed = false;
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    for font in &fonts {
+        let _ = writeln!(out);
+        if let Err(e) = print_font(
+            &mut out,
+            pdf_path,
+            font,
+            output.as_deref(),
+            &config,
+            &options,
+        ) {
+            let _ = out.flush();
+            eprintln!("Error: {} (object {}): {}", font.name, font.object, e);
+            failed = true;
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Prints where a font came from and, for sfnt programs, the standard
+/// report, writing the program to `output` first when given.
+fn print_font(
+    out: &mut dyn Write,
+    pdf_path: &str,
+    font: &EmbeddedFont,
+    output: Option<&Path>,
+    config: &Config,
+    options: &ReportOptions,
+) -> Result<(), String> {
+    let write_error = |e: io::Error| e.to_string();
+    writeln!(out, "== {} (object {})", font.name, font.object).map_err(write_error)?;
+    match &font.subset_prefix {
+        Some(prefix) => writeln!(
+            out,
+            "Subset: yes ({}+ prefix); only the glyphs the document uses are present",
+            prefix
+        ),
+        None => writeln!(out, "Subset: no"),
+    }
+    .map_err(write_error)?;
+    let data = font.data.as_ref().map_err(String::clone)?;
+    writeln!(
+        out,
+        "Program: {}, {}",
+        font.program.name(),
+        format_size(data.len())
+    )
+    .map_err(write_error)?;
+
+    if let Some(output) = output {
+        let path = output.join(file_name(font));
+        fs::write(&path, data).map_err(|e| format!("writing '{}': {}", path.display(), e))?;
+        writeln!(out, "Written to: {}", path.display()).map_err(write_error)?;
+    }
+    if !font.program.is_sfnt() {
+        writeln!(
+            out,
+            "Not an sfnt font, so there's no report; extract it with -o for other tools"
+        )
+        .map_err(write_error)?;
+        return Ok(());
+    }
+
+    writeln!(out).map_err(write_error)?;
+    let label = format!("{}#{}", pdf_path, font.name);
+    let face = Face::parse(data, 0).map_err(|e| e.to_string())?;
+    contain_panic(|| print_font_info(out, &face, &label, config, options))?.map_err(write_error)
+}
+
+/// A file-system safe name for the extracted program, e.g.
+/// `12-ABCDEF+Helvetica.ttf`; the object number keeps fonts sharing a name
+/// apart.
+fn file_name(font: &EmbeddedFont) -> String {
+    let name: String = font
+        .name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}-{}.{}", font.object, name, font.program.extension())
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} pdf <document.pdf> [-o <directory>] [--terse]",
+        program
+    );
+    eprintln!("Example: {} pdf proof.pdf -o fonts", program);
+    process::exit(1);
+}
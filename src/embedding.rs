@@ -0,0 +1,134 @@
+use crate::reader::Reader;
+use serde::Serialize;
+use ttf_parser::{Face, Tag};
+
+const RESTRICTED: u16 = 0x0002;
+const PREVIEW_PRINT: u16 = 0x0004;
+const EDITABLE: u16 = 0x0008;
+const NO_SUBSETTING: u16 = 0x0100;
+const BITMAP_ONLY: u16 = 0x0200;
+
+/// fsType bits the spec reserves: bit 0 and bits 4–7 and 10–15.
+const RESERVED: u16 = 0xFCF1;
+
+/// The usage permission fsType bits 0–3 grant.
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Level {
+    Installable,
+    Restricted,
+    PreviewPrint,
+    Editable,
+}
+
+impl Level {
+    /// The spec makes the levels exclusive; fonts that set several anyway
+    /// get the least restrictive one, as the spec tells applications to.
+    fn from_fs_type(fs_type: u16) -> Level {
+        if fs_type & (RESTRICTED | PREVIEW_PRINT | EDITABLE) == 0 {
+            Level::Installable
+        } else if fs_type & EDITABLE != 0 {
+            Level::Editable
+        } else if fs_type & PREVIEW_PRINT != 0 {
+            Level::PreviewPrint
+        } else {
+            Level::Restricted
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Installable => "installable",
+            Level::Restricted => "restricted license",
+            Level::PreviewPrint => "preview & print",
+            Level::Editable => "editable",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Level::Installable => {
+                "May be embedded in documents and installed permanently on the reader's system"
+            }
+            Level::Restricted => {
+                "Must not be embedded, modified or passed on without the owner's permission"
+            }
+            Level::PreviewPrint => {
+                "May be embedded to view and print documents, which must then be opened read-only"
+            }
+            Level::Editable => {
+                "May be embedded to view, print and edit documents, but not installed"
+            }
+        }
+    }
+}
+
+/// The OS/2 fsType embedding permissions in plain language.
+#[derive(Serialize)]
+pub struct EmbeddingReport {
+    pub fs_type: u16,
+    pub level: Level,
+    /// The whole font must be embedded, never a subset.
+    pub no_subsetting: bool,
+    /// Only bitmaps may be embedded, not outlines.
+    pub bitmap_only: bool,
+    pub warnings: Vec<String>,
+}
+
+impl EmbeddingReport {
+    /// Returns `None` without an OS/2 table.
+    pub fn new(face: &Face) -> Option<Self> {
+        let os2 = face.raw_face().table(Tag::from_bytes(b"OS/2"))?;
+        Reader::at(os2, 8)
+            .read_u16()
+            .map(EmbeddingReport::from_fs_type)
+    }
+
+    pub fn from_fs_type(fs_type: u16) -> Self {
+        let mut warnings = Vec::new();
+        let levels = fs_type & (RESTRICTED | PREVIEW_PRINT | EDITABLE);
+        if levels.count_ones() > 1 {
+            warnings.push(format!(
+                "several usage levels are set (0x{:04X}); the least restrictive applies",
+                levels
+            ));
+        }
+        if fs_type & RESERVED != 0 {
+            warnings.push(format!(
+                "reserved bits are set (0x{:04X})",
+                fs_type & RESERVED
+            ));
+        }
+        EmbeddingReport {
+            fs_type,
+            level: Level::from_fs_type(fs_type),
+            no_subsetting: fs_type & NO_SUBSETTING != 0,
+            bitmap_only: fs_type & BITMAP_ONLY != 0,
+            warnings,
+        }
+    }
+
+    /// Short names of the level and restrictions, e.g. `editable, no
+    /// subsetting`.
+    pub fn summary(&self) -> Vec<&'static str> {
+        let mut summary = vec![self.level.name()];
+        if self.no_subsetting {
+            summary.push("no subsetting");
+        }
+        if self.bitmap_only {
+            summary.push("bitmap embedding only");
+        }
+        summary
+    }
+
+    /// What the permissions mean for embedding in a PDF, the most common
+    /// reason to ask.
+    pub fn pdf(&self) -> &'static str {
+        match (self.level, self.bitmap_only, self.no_subsetting) {
+            (Level::Restricted, _, _) => "not allowed",
+            (_, true, _) => "bitmaps only",
+            (_, false, true) => "allowed, but only the full font",
+            (_, false, false) => "allowed, including subsets",
+        }
+    }
+}
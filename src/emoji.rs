@@ -0,0 +1,803 @@
+use crate::layout::apply_ligatures;
+use crate::outlines::GlyphSources;
+use serde::Serialize;
+use ttf_parser::{Face, GlyphId};
+
+/// Selects the text presentation of the preceding character.
+const VS15: char = '\u{FE0E}';
+/// Selects the emoji presentation of the preceding character.
+const VS16: char = '\u{FE0F}';
+/// Joins emoji into a single sequence, e.g. 👨 ZWJ 👩 ZWJ 👧.
+const ZWJ: char = '\u{200D}';
+
+/// Characters with both a text and an emoji presentation, i.e. the bases of
+/// the sequences in Unicode's `emoji-variation-sequences.txt`, as inclusive
+/// ranges.
+const DUAL_PRESENTATION: &[(u32, u32)] = &[
+    (0x0023, 0x0023),
+    (0x002A, 0x002A),
+    (0x0030, 0x0039),
+    (0x00A9, 0x00A9),
+    (0x00AE, 0x00AE),
+    (0x203C, 0x203C),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21A9, 0x21AA),
+    (0x231A, 0x231B),
+    (0x2328, 0x2328),
+    (0x23CF, 0x23CF),
+    (0x23E9, 0x23EA),
+    (0x23ED, 0x23EF),
+    (0x23F1, 0x23F3),
+    (0x23F8, 0x23FA),
+    (0x24C2, 0x24C2),
+    (0x25AA, 0x25AB),
+    (0x25B6, 0x25B6),
+    (0x25C0, 0x25C0),
+    (0x25FB, 0x25FE),
+    (0x2600, 0x2604),
+    (0x260E, 0x260E),
+    (0x2611, 0x2611),
+    (0x2614, 0x2615),
+    (0x2618, 0x2618),
+    (0x261D, 0x261D),
+    (0x2620, 0x2620),
+    (0x2622, 0x2623),
+    (0x2626, 0x2626),
+    (0x262A, 0x262A),
+    (0x262E, 0x262F),
+    (0x2638, 0x263A),
+    (0x2640, 0x2640),
+    (0x2642, 0x2642),
+    (0x2648, 0x2653),
+    (0x265F, 0x2660),
+    (0x2663, 0x2663),
+    (0x2665, 0x2666),
+    (0x2668, 0x2668),
+    (0x267B, 0x267B),
+    (0x267E, 0x267F),
+    (0x2692, 0x2697),
+    (0x2699, 0x2699),
+    (0x269B, 0x269C),
+    (0x26A0, 0x26A1),
+    (0x26A7, 0x26A7),
+    (0x26AA, 0x26AB),
+    (0x26B0, 0x26B1),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26C8, 0x26C8),
+    (0x26CF, 0x26CF),
+    (0x26D1, 0x26D1),
+    (0x26D3, 0x26D4),
+    (0x26E9, 0x26EA),
+    (0x26F0, 0x26F5),
+    (0x26F7, 0x26FA),
+    (0x26FD, 0x26FD),
+    (0x2702, 0x2702),
+    (0x2708, 0x2709),
+    (0x270C, 0x270D),
+    (0x270F, 0x270F),
+    (0x2712, 0x2712),
+    (0x2714, 0x2714),
+    (0x2716, 0x2716),
+    (0x271D, 0x271D),
+    (0x2721, 0x2721),
+    (0x2733, 0x2734),
+    (0x2744, 0x2744),
+    (0x2747, 0x2747),
+    (0x2753, 0x2753),
+    (0x2757, 0x2757),
+    (0x2763, 0x2764),
+    (0x27A1, 0x27A1),
+    (0x2934, 0x2935),
+    (0x2B05, 0x2B07),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x3030, 0x3030),
+    (0x303D, 0x303D),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1F004, 0x1F004),
+    (0x1F170, 0x1F171),
+    (0x1F17E, 0x1F17F),
+    (0x1F202, 0x1F202),
+    (0x1F21A, 0x1F21A),
+    (0x1F22F, 0x1F22F),
+    (0x1F237, 0x1F237),
+    (0x1F30D, 0x1F30F),
+    (0x1F315, 0x1F315),
+    (0x1F31C, 0x1F31C),
+    (0x1F321, 0x1F321),
+    (0x1F324, 0x1F32C),
+    (0x1F336, 0x1F336),
+    (0x1F378, 0x1F378),
+    (0x1F37D, 0x1F37D),
+    (0x1F393, 0x1F393),
+    (0x1F396, 0x1F397),
+    (0x1F399, 0x1F39B),
+    (0x1F39E, 0x1F39F),
+    (0x1F3A7, 0x1F3A7),
+    (0x1F3AC, 0x1F3AE),
+    (0x1F3C2, 0x1F3C2),
+    (0x1F3C4, 0x1F3C4),
+    (0x1F3C6, 0x1F3C6),
+    (0x1F3CA, 0x1F3CE),
+    (0x1F3D4, 0x1F3E0),
+    (0x1F3ED, 0x1F3ED),
+    (0x1F3F3, 0x1F3F3),
+    (0x1F3F5, 0x1F3F5),
+    (0x1F3F7, 0x1F3F7),
+    (0x1F408, 0x1F408),
+    (0x1F415, 0x1F415),
+    (0x1F41F, 0x1F41F),
+    (0x1F426, 0x1F426),
+    (0x1F43F, 0x1F43F),
+    (0x1F441, 0x1F442),
+    (0x1F446, 0x1F449),
+    (0x1F44D, 0x1F44E),
+    (0x1F453, 0x1F453),
+    (0x1F46A, 0x1F46A),
+    (0x1F47D, 0x1F47D),
+    (0x1F4A3, 0x1F4A3),
+    (0x1F4B0, 0x1F4B0),
+    (0x1F4B3, 0x1F4B3),
+    (0x1F4BB, 0x1F4BB),
+    (0x1F4BF, 0x1F4BF),
+    (0x1F4CB, 0x1F4CB),
+    (0x1F4DA, 0x1F4DA),
+    (0x1F4DF, 0x1F4DF),
+    (0x1F4E4, 0x1F4E6),
+    (0x1F4EA, 0x1F4ED),
+    (0x1F4F7, 0x1F4F7),
+    (0x1F4F9, 0x1F4FB),
+    (0x1F4FD, 0x1F4FD),
+    (0x1F508, 0x1F508),
+    (0x1F50D, 0x1F50D),
+    (0x1F512, 0x1F513),
+    (0x1F549, 0x1F54A),
+    (0x1F550, 0x1F567),
+    (0x1F56F, 0x1F570),
+    (0x1F573, 0x1F579),
+    (0x1F587, 0x1F587),
+    (0x1F58A, 0x1F58D),
+    (0x1F590, 0x1F590),
+    (0x1F5A5, 0x1F5A5),
+    (0x1F5A8, 0x1F5A8),
+    (0x1F5B1, 0x1F5B2),
+    (0x1F5BC, 0x1F5BC),
+    (0x1F5C2, 0x1F5C4),
+    (0x1F5D1, 0x1F5D3),
+    (0x1F5DC, 0x1F5DE),
+    (0x1F5E1, 0x1F5E1),
+    (0x1F5E3, 0x1F5E3),
+    (0x1F5E8, 0x1F5E8),
+    (0x1F5EF, 0x1F5EF),
+    (0x1F5F3, 0x1F5F3),
+    (0x1F5FA, 0x1F5FA),
+    (0x1F610, 0x1F610),
+    (0x1F687, 0x1F687),
+    (0x1F68D, 0x1F68D),
+    (0x1F691, 0x1F691),
+    (0x1F694, 0x1F694),
+    (0x1F698, 0x1F698),
+    (0x1F6AD, 0x1F6AD),
+    (0x1F6B2, 0x1F6B2),
+    (0x1F6B9, 0x1F6BA),
+    (0x1F6BC, 0x1F6BC),
+    (0x1F6CB, 0x1F6CB),
+    (0x1F6CD, 0x1F6CF),
+    (0x1F6E0, 0x1F6E5),
+    (0x1F6E9, 0x1F6E9),
+    (0x1F6F0, 0x1F6F0),
+    (0x1F6F3, 0x1F6F3),
+];
+
+/// Characters with the `Emoji_Presentation` property in Unicode's
+/// `emoji-data.txt` (Emoji 15.0), which default to the emoji presentation,
+/// as inclusive ranges. Together with `DUAL_PRESENTATION` they make up the
+/// single-character emoji.
+const EMOJI_PRESENTATION: &[(u32, u32)] = &[
+    (0x231A, 0x231B),
+    (0x23E9, 0x23EC),
+    (0x23F0, 0x23F0),
+    (0x23F3, 0x23F3),
+    (0x25FD, 0x25FE),
+    (0x2614, 0x2615),
+    (0x2648, 0x2653),
+    (0x267F, 0x267F),
+    (0x2693, 0x2693),
+    (0x26A1, 0x26A1),
+    (0x26AA, 0x26AB),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26CE, 0x26CE),
+    (0x26D4, 0x26D4),
+    (0x26EA, 0x26EA),
+    (0x26F2, 0x26F3),
+    (0x26F5, 0x26F5),
+    (0x26FA, 0x26FA),
+    (0x26FD, 0x26FD),
+    (0x2705, 0x2705),
+    (0x270A, 0x270B),
+    (0x2728, 0x2728),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x1F004, 0x1F004),
+    (0x1F0CF, 0x1F0CF),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F1E6, 0x1F1FF),
+    (0x1F201, 0x1F201),
+    (0x1F21A, 0x1F21A),
+    (0x1F22F, 0x1F22F),
+    (0x1F232, 0x1F236),
+    (0x1F238, 0x1F23A),
+    (0x1F250, 0x1F251),
+    (0x1F300, 0x1F320),
+    (0x1F32D, 0x1F335),
+    (0x1F337, 0x1F37C),
+    (0x1F37E, 0x1F393),
+    (0x1F3A0, 0x1F3CA),
+    (0x1F3CF, 0x1F3D3),
+    (0x1F3E0, 0x1F3F0),
+    (0x1F3F4, 0x1F3F4),
+    (0x1F3F8, 0x1F43E),
+    (0x1F440, 0x1F440),
+    (0x1F442, 0x1F4FC),
+    (0x1F4FF, 0x1F53D),
+    (0x1F54B, 0x1F54E),
+    (0x1F550, 0x1F567),
+    (0x1F57A, 0x1F57A),
+    (0x1F595, 0x1F596),
+    (0x1F5A4, 0x1F5A4),
+    (0x1F5FB, 0x1F64F),
+    (0x1F680, 0x1F6C5),
+    (0x1F6CC, 0x1F6CC),
+    (0x1F6D0, 0x1F6D2),
+    (0x1F6D5, 0x1F6D7),
+    (0x1F6DC, 0x1F6DF),
+    (0x1F6EB, 0x1F6EC),
+    (0x1F6F4, 0x1F6FC),
+    (0x1F7E0, 0x1F7EB),
+    (0x1F7F0, 0x1F7F0),
+    (0x1F90C, 0x1F93A),
+    (0x1F93C, 0x1F945),
+    (0x1F947, 0x1F9FF),
+    (0x1FA70, 0x1FA7C),
+    (0x1FA80, 0x1FA88),
+    (0x1FA90, 0x1FABD),
+    (0x1FABF, 0x1FAC5),
+    (0x1FACE, 0x1FADB),
+    (0x1FAE0, 0x1FAE8),
+    (0x1FAF0, 0x1FAF8),
+];
+
+/// The bases of the keycap sequences, e.g. 1 VS16 U+20E3 for 1️⃣.
+const KEYCAP_BASES: &str = "#*0123456789";
+/// The combining enclosing keycap that ends a keycap sequence.
+const KEYCAP: char = '\u{20E3}';
+
+/// Characters with the `Emoji_Modifier_Base` property in Unicode's
+/// `emoji-data.txt`, which take a skin-tone modifier, as inclusive ranges.
+const MODIFIER_BASES: &[(u32, u32)] = &[
+    (0x261D, 0x261D),
+    (0x26F9, 0x26F9),
+    (0x270A, 0x270D),
+    (0x1F385, 0x1F385),
+    (0x1F3C2, 0x1F3C4),
+    (0x1F3C7, 0x1F3C7),
+    (0x1F3CA, 0x1F3CC),
+    (0x1F442, 0x1F443),
+    (0x1F446, 0x1F450),
+    (0x1F466, 0x1F478),
+    (0x1F47C, 0x1F47C),
+    (0x1F481, 0x1F483),
+    (0x1F485, 0x1F487),
+    (0x1F48F, 0x1F48F),
+    (0x1F491, 0x1F491),
+    (0x1F4AA, 0x1F4AA),
+    (0x1F574, 0x1F575),
+    (0x1F57A, 0x1F57A),
+    (0x1F590, 0x1F590),
+    (0x1F595, 0x1F596),
+    (0x1F645, 0x1F647),
+    (0x1F64B, 0x1F64F),
+    (0x1F6A3, 0x1F6A3),
+    (0x1F6B4, 0x1F6B6),
+    (0x1F6C0, 0x1F6C0),
+    (0x1F6CC, 0x1F6CC),
+    (0x1F90C, 0x1F90C),
+    (0x1F90F, 0x1F90F),
+    (0x1F918, 0x1F91F),
+    (0x1F926, 0x1F926),
+    (0x1F930, 0x1F939),
+    (0x1F93C, 0x1F93E),
+    (0x1F977, 0x1F977),
+    (0x1F9B5, 0x1F9B6),
+    (0x1F9B8, 0x1F9B9),
+    (0x1F9BB, 0x1F9BB),
+    (0x1F9CD, 0x1F9CF),
+    (0x1F9D1, 0x1F9DD),
+    (0x1FAC3, 0x1FAC5),
+    (0x1FAF0, 0x1FAF8),
+];
+
+/// The Fitzpatrick skin-tone modifiers 🏻–🏿.
+const SKIN_TONES: [char; 5] = [
+    '\u{1F3FB}',
+    '\u{1F3FC}',
+    '\u{1F3FD}',
+    '\u{1F3FE}',
+    '\u{1F3FF}',
+];
+
+/// Region codes of the RGI emoji flag sequences, each written as a pair of
+/// regional indicator symbols.
+const FLAG_REGIONS: [&str; 258] = [
+    "AC", "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX",
+    "AZ", "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR",
+    "BS", "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM",
+    "CN", "CO", "CP", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DG", "DJ", "DK", "DM", "DO",
+    "DZ", "EA", "EC", "EE", "EG", "EH", "ER", "ES", "ET", "EU", "FI", "FJ", "FK", "FM", "FO", "FR",
+    "GA", "GB", "GD", "GE", "GF", "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT",
+    "GU", "GW", "GY", "HK", "HM", "HN", "HR", "HT", "HU", "IC", "ID", "IE", "IL", "IM", "IN", "IO",
+    "IQ", "IR", "IS", "IT", "JE", "JM", "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR",
+    "KW", "KY", "KZ", "LA", "LB", "LC", "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC",
+    "MD", "ME", "MF", "MG", "MH", "MK", "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU",
+    "MV", "MW", "MX", "MY", "MZ", "NA", "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU",
+    "NZ", "OM", "PA", "PE", "PF", "PG", "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY",
+    "QA", "RE", "RO", "RS", "RU", "RW", "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK",
+    "SL", "SM", "SN", "SO", "SR", "SS", "ST", "SV", "SX", "SY", "SZ", "TA", "TC", "TD", "TF", "TG",
+    "TH", "TJ", "TK", "TL", "TM", "TN", "TO", "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "UN",
+    "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI", "VN", "VU", "WF", "WS", "XK", "YE", "YT", "ZA",
+    "ZM", "ZW",
+];
+
+/// The RGI subdivision flags, written as 🏴 followed by the subdivision
+/// code in tag characters and a cancel tag.
+const SUBDIVISION_FLAGS: [(&str, &str); 3] = [
+    ("gbeng", "England"),
+    ("gbsct", "Scotland"),
+    ("gbwls", "Wales"),
+];
+
+/// How a glyph is drawn: from a color or bitmap source, or as a plain
+/// outline in the text color.
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Style {
+    Emoji,
+    Text,
+}
+
+impl Style {
+    fn of(face: &Face, glyph_id: GlyphId) -> Style {
+        if GlyphSources::new(face, glyph_id).has_color() {
+            Style::Emoji
+        } else {
+            Style::Text
+        }
+    }
+}
+
+/// What a dual-presentation character renders as with this font.
+#[derive(Serialize)]
+pub struct Presentation {
+    pub c: char,
+    /// The style without a variation selector.
+    pub default: Style,
+    /// The styles reachable with VS15 and VS16, falling back to the default
+    /// glyph when the font has no sequence for the selector.
+    pub text_selector: Style,
+    pub emoji_selector: Style,
+    /// True when the font maps the character with VS15 or VS16 in a
+    /// format 14 cmap subtable.
+    pub has_sequences: bool,
+}
+
+impl Presentation {
+    /// True when VS15 and VS16 pick a text and an emoji glyph respectively.
+    pub fn has_both(&self) -> bool {
+        self.text_selector == Style::Text && self.emoji_selector == Style::Emoji
+    }
+
+    /// True when the character renders in `style` with or without selectors.
+    pub fn only(&self, style: Style) -> bool {
+        [self.default, self.text_selector, self.emoji_selector]
+            .iter()
+            .all(|&s| s == style)
+    }
+}
+
+/// Presentation of every dual-presentation character the font covers.
+#[derive(Serialize)]
+pub struct PresentationReport {
+    pub total: usize,
+    pub covered: Vec<Presentation>,
+}
+
+impl PresentationReport {
+    pub fn new(face: &Face) -> Self {
+        let chars = DUAL_PRESENTATION
+            .iter()
+            .flat_map(|&(start, end)| start..=end)
+            .filter_map(char::from_u32);
+
+        let mut total = 0;
+        let mut covered = Vec::new();
+        for c in chars {
+            total += 1;
+            let default = face.glyph_index(c);
+            let text = face.glyph_variation_index(c, VS15);
+            let emoji = face.glyph_variation_index(c, VS16);
+            let Some(default) = default.or(text).or(emoji) else {
+                continue;
+            };
+            let style = |glyph: Option<GlyphId>| Style::of(face, glyph.unwrap_or(default));
+            covered.push(Presentation {
+                c,
+                default: style(Some(default)),
+                text_selector: style(text),
+                emoji_selector: style(emoji),
+                has_sequences: text.is_some() || emoji.is_some(),
+            });
+        }
+
+        PresentationReport { total, covered }
+    }
+
+    /// True when the report says anything beyond "all text": the font has
+    /// color glyphs or variation sequences for some covered character.
+    pub fn is_relevant(&self) -> bool {
+        self.covered
+            .iter()
+            .any(|p| p.has_sequences || p.emoji_selector == Style::Emoji)
+    }
+}
+
+/// The bundled subset of RGI emoji ZWJ sequences, in `emoji-test.txt`
+/// format.
+pub const BUNDLED_SEQUENCES: &str = include_str!("../data/emoji-zwj-sequences.txt");
+
+/// An emoji sequence read from `emoji-test.txt`.
+pub struct Sequence {
+    pub chars: Vec<char>,
+    /// The CLDR short name, e.g. `family: man, woman, girl`.
+    pub name: String,
+}
+
+impl Sequence {
+    pub fn text(&self) -> String {
+        self.chars.iter().collect()
+    }
+}
+
+/// Reads the fully-qualified ZWJ sequences from `emoji-test.txt` data,
+/// which are exactly the RGI emoji ZWJ sequences.
+pub fn parse_zwj_sequences(data: &str) -> Vec<Sequence> {
+    data.lines()
+        .filter_map(|line| {
+            let (fields, comment) = line.split_once('#')?;
+            let (code_points, status) = fields.split_once(';')?;
+            if status.trim() != "fully-qualified" {
+                return None;
+            }
+            let chars: Vec<char> = code_points
+                .split_whitespace()
+                .map(|cp| u32::from_str_radix(cp, 16).ok().and_then(char::from_u32))
+                .collect::<Option<_>>()?;
+            if !chars.contains(&ZWJ) {
+                return None;
+            }
+            // The comment is the emoji itself, its version (`E2.0`) and
+            // then the name.
+            let mut words = comment.split_whitespace().skip(1).peekable();
+            words.next_if(|word| word.starts_with('E') && word[1..].contains('.'));
+            let name = words.collect::<Vec<_>>().join(" ");
+            Some(Sequence { chars, name })
+        })
+        .collect()
+}
+
+/// How a sequence renders with this font.
+pub enum SequenceSupport {
+    /// The font's ligatures turn the whole sequence into one glyph.
+    SingleGlyph,
+    /// Every character has a glyph, but the sequence renders as this many
+    /// separate glyphs.
+    FallsApart(usize),
+    /// Some characters have no glyph at all.
+    MissingGlyphs,
+}
+
+impl SequenceSupport {
+    pub fn new(face: &Face, chars: &[char]) -> SequenceSupport {
+        // Fonts differ in whether their ligatures include VS16 or expect
+        // shapers to drop it, so try both.
+        let with_selectors = glyphs(face, chars.iter().copied());
+        let without_selectors = glyphs(face, chars.iter().copied().filter(|&c| c != VS16));
+        let (Some(with_selectors), Some(without_selectors)) = (with_selectors, without_selectors)
+        else {
+            return SequenceSupport::MissingGlyphs;
+        };
+
+        let count = apply_ligatures(face, with_selectors)
+            .len()
+            .min(apply_ligatures(face, without_selectors).len());
+        if count == 1 {
+            SequenceSupport::SingleGlyph
+        } else {
+            SequenceSupport::FallsApart(count)
+        }
+    }
+}
+
+/// Maps characters to glyphs, skipping unmapped VS16 as shapers do.
+/// Returns `None` if any other character is unmapped.
+fn glyphs(face: &Face, chars: impl Iterator<Item = char>) -> Option<Vec<GlyphId>> {
+    chars
+        .filter_map(|c| match face.glyph_index(c) {
+            Some(glyph) => Some(Some(glyph)),
+            None if c == VS16 => None,
+            None => Some(None),
+        })
+        .collect()
+}
+
+/// Which flag emoji the font forms from regional indicator pairs and tag
+/// sequences.
+#[derive(Serialize)]
+pub struct FlagReport {
+    /// Regional indicator symbols 🇦–🇿 with a glyph.
+    pub indicators: usize,
+    /// Region codes whose indicator pair forms a single flag glyph.
+    pub countries: Vec<&'static str>,
+    /// Region codes whose indicators have glyphs but stay separate, so the
+    /// flag renders as two boxed letters.
+    pub unformed: Vec<&'static str>,
+    pub total_countries: usize,
+    /// Names of the subdivision flags that form a single glyph.
+    pub subdivisions: Vec<&'static str>,
+}
+
+impl FlagReport {
+    pub fn new(face: &Face) -> Self {
+        let indicators = ('A'..='Z')
+            .filter(|&letter| face.glyph_index(regional_indicator(letter)).is_some())
+            .count();
+
+        let mut countries = Vec::new();
+        let mut unformed = Vec::new();
+        for region in FLAG_REGIONS {
+            let chars: Vec<char> = region.chars().map(regional_indicator).collect();
+            match SequenceSupport::new(face, &chars) {
+                SequenceSupport::SingleGlyph => countries.push(region),
+                SequenceSupport::FallsApart(_) => unformed.push(region),
+                SequenceSupport::MissingGlyphs => {}
+            }
+        }
+
+        let subdivisions = SUBDIVISION_FLAGS
+            .iter()
+            .filter(|(code, _)| {
+                let chars: Vec<char> = std::iter::once(BLACK_FLAG)
+                    .chain(code.chars().map(tag_character))
+                    .chain([CANCEL_TAG])
+                    .collect();
+                matches!(
+                    SequenceSupport::new(face, &chars),
+                    SequenceSupport::SingleGlyph
+                )
+            })
+            .map(|&(_, name)| name)
+            .collect();
+
+        FlagReport {
+            indicators,
+            countries,
+            unformed,
+            total_countries: FLAG_REGIONS.len(),
+            subdivisions,
+        }
+    }
+}
+
+/// How a modifier base that the font maps combines with the skin tones.
+#[derive(Serialize)]
+pub struct ModifierBase {
+    pub c: char,
+    /// Skin tones that form a single glyph with the base.
+    pub combined: usize,
+    /// Skin tones that render after the base as a separate swatch.
+    pub dangling: usize,
+}
+
+/// Which skin-tone combinations the font forms for the modifier bases it
+/// covers.
+#[derive(Serialize)]
+pub struct SkinToneReport {
+    /// Skin-tone modifiers with a glyph of their own.
+    pub modifiers: usize,
+    pub total_bases: usize,
+    pub covered: Vec<ModifierBase>,
+}
+
+impl SkinToneReport {
+    pub fn new(face: &Face) -> Self {
+        let modifiers = SKIN_TONES
+            .iter()
+            .filter(|&&tone| face.glyph_index(tone).is_some())
+            .count();
+
+        let bases = MODIFIER_BASES
+            .iter()
+            .flat_map(|&(start, end)| start..=end)
+            .filter_map(char::from_u32);
+
+        let mut total_bases = 0;
+        let mut covered = Vec::new();
+        for c in bases {
+            total_bases += 1;
+            if face.glyph_index(c).is_none() {
+                continue;
+            }
+            let mut base = ModifierBase {
+                c,
+                combined: 0,
+                dangling: 0,
+            };
+            for tone in SKIN_TONES {
+                match SequenceSupport::new(face, &[c, tone]) {
+                    SequenceSupport::SingleGlyph => base.combined += 1,
+                    SequenceSupport::FallsApart(_) => base.dangling += 1,
+                    SequenceSupport::MissingGlyphs => {}
+                }
+            }
+            covered.push(base);
+        }
+
+        SkinToneReport {
+            modifiers,
+            total_bases,
+            covered,
+        }
+    }
+
+    /// Number of base and skin-tone pairs that form a single glyph.
+    pub fn combinations(&self) -> usize {
+        self.covered.iter().map(|base| base.combined).sum()
+    }
+}
+
+/// How much of each kind of emoji the font can display: single characters,
+/// presentation selectors, keycaps, skin tones, flags and ZWJ sequences.
+#[derive(Serialize)]
+pub struct EmojiCapability {
+    /// Single-character emoji, those with a glyph, and those whose glyph
+    /// (with VS16, if the font maps it) is in color.
+    pub characters: usize,
+    pub covered_characters: usize,
+    pub color_characters: usize,
+    /// Dual-presentation characters the font covers, and how many of them
+    /// VS15 selects a text glyph for and VS16 a color one.
+    pub dual_presentation: usize,
+    pub text_selector: usize,
+    pub emoji_selector: usize,
+    pub keycaps: usize,
+    pub formed_keycaps: usize,
+    /// Modifier base and skin-tone pairs.
+    pub skin_tones: usize,
+    pub formed_skin_tones: usize,
+    /// Country and subdivision flags.
+    pub flags: usize,
+    pub formed_flags: usize,
+    pub zwj_sequences: usize,
+    pub formed_zwj_sequences: usize,
+}
+
+impl EmojiCapability {
+    /// Checks the ZWJ `sequences` given, e.g. the bundled ones.
+    pub fn new(face: &Face, sequences: &[Sequence]) -> Self {
+        let mut characters = 0;
+        let mut covered_characters = 0;
+        let mut color_characters = 0;
+        let mut chars: Vec<char> = EMOJI_PRESENTATION
+            .iter()
+            .chain(DUAL_PRESENTATION)
+            .flat_map(|&(start, end)| start..=end)
+            .filter_map(char::from_u32)
+            .collect();
+        chars.sort_unstable();
+        chars.dedup();
+        for c in chars {
+            characters += 1;
+            let Some(glyph) = face.glyph_variation_index(c, VS16).or(face.glyph_index(c)) else {
+                continue;
+            };
+            covered_characters += 1;
+            if Style::of(face, glyph) == Style::Emoji {
+                color_characters += 1;
+            }
+        }
+
+        let presentation = PresentationReport::new(face);
+        let count = |matches: fn(&Presentation) -> bool| {
+            presentation.covered.iter().filter(|p| matches(p)).count()
+        };
+
+        let formed = |sequence: &[char]| {
+            matches!(
+                SequenceSupport::new(face, sequence),
+                SequenceSupport::SingleGlyph
+            )
+        };
+        let formed_keycaps = KEYCAP_BASES
+            .chars()
+            .filter(|&c| formed(&[c, VS16, KEYCAP]))
+            .count();
+
+        let skin_tones = SkinToneReport::new(face);
+        let flags = FlagReport::new(face);
+
+        EmojiCapability {
+            characters,
+            covered_characters,
+            color_characters,
+            dual_presentation: presentation.covered.len(),
+            text_selector: count(|p| p.text_selector == Style::Text),
+            emoji_selector: count(|p| p.emoji_selector == Style::Emoji),
+            keycaps: KEYCAP_BASES.len(),
+            formed_keycaps,
+            skin_tones: skin_tones.total_bases * SKIN_TONES.len(),
+            formed_skin_tones: skin_tones.combinations(),
+            flags: flags.total_countries + SUBDIVISION_FLAGS.len(),
+            formed_flags: flags.countries.len() + flags.subdivisions.len(),
+            zwj_sequences: sequences.len(),
+            formed_zwj_sequences: sequences
+                .iter()
+                .filter(|sequence| formed(&sequence.chars))
+                .count(),
+        }
+    }
+
+    /// The emoji checked, of every kind.
+    pub fn total(&self) -> usize {
+        self.characters + self.keycaps + self.skin_tones + self.flags + self.zwj_sequences
+    }
+
+    /// The emoji the font displays: single characters with a glyph, and
+    /// sequences it forms into one glyph.
+    pub fn displayable(&self) -> usize {
+        self.covered_characters
+            + self.formed_keycaps
+            + self.formed_skin_tones
+            + self.formed_flags
+            + self.formed_zwj_sequences
+    }
+}
+
+/// 🏴, the base of subdivision flag sequences.
+const BLACK_FLAG: char = '\u{1F3F4}';
+/// Ends a tag sequence.
+const CANCEL_TAG: char = '\u{E007F}';
+
+/// Maps `A`–`Z` to the regional indicator symbols 🇦–🇿.
+fn regional_indicator(letter: char) -> char {
+    char::from_u32(0x1F1E6 + (letter as u32 - 'A' as u32)).unwrap_or(letter)
+}
+
+/// Maps an ASCII character to its invisible tag character.
+fn tag_character(c: char) -> char {
+    char::from_u32(0xE0000 + c as u32).unwrap_or(c)
+}
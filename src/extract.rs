@@ -0,0 +1,97 @@
+use crate::dump::parse_tag;
+use crate::{parse_face, read_font_file};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use ttf_parser::Tag;
+
+/// Runs `fontinfo extract-table <font-file> (<tag> | --all) -o <path>`.
+pub fn run(program: &str, args: &[String]) {
+    let mut positional = Vec::new();
+    let mut all = false;
+    let mut output = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "-o" | "--output" => match args.next() {
+                Some(path) => output = Some(PathBuf::from(path)),
+                None => usage(program),
+            },
+            _ if !arg.starts_with('-') => positional.push(arg),
+            _ => usage(program),
+        }
+    }
+
+    let (font_path, tag) = match (positional.as_slice(), all) {
+        ([font], true) => (*font, None),
+        ([font, tag], false) => match parse_tag(tag) {
+            Some(tag) => (*font, Some(tag)),
+            None => usage(program),
+        },
+        _ => usage(program),
+    };
+    let Some(output) = output else {
+        usage(program);
+    };
+
+    let font_data = read_font_file(font_path);
+    let face = parse_face(&font_data, font_path);
+    let raw = face.raw_face();
+
+    match tag {
+        Some(tag) => {
+            let Some(data) = raw.table(tag) else {
+                eprintln!("Table '{}' not found in '{}'", tag, font_path);
+                process::exit(1);
+            };
+            write_table(&output, tag, data);
+        }
+        None => {
+            if let Err(e) = fs::create_dir_all(&output) {
+                eprintln!("Error creating directory '{}': {}", output.display(), e);
+                process::exit(1);
+            }
+            for record in raw.table_records {
+                if let Some(data) = raw.table(record.tag) {
+                    write_table(&output.join(file_name(record.tag)), record.tag, data);
+                }
+            }
+        }
+    }
+}
+
+/// Returns a file-system safe name for a table, e.g. `OS/2` -> `OS_2.bin`.
+fn file_name(tag: Tag) -> String {
+    format!("{}.bin", tag.to_string().trim_end().replace('/', "_"))
+}
+
+fn write_table(path: &Path, tag: Tag, data: &[u8]) {
+    if let Err(e) = fs::write(path, data) {
+        eprintln!(
+            "Error writing table '{}' to '{}': {}",
+            tag,
+            path.display(),
+            e
+        );
+        process::exit(1);
+    }
+    println!("{}: {} bytes -> {}", tag, data.len(), path.display());
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} extract-table <font-file> <tag> -o <file>",
+        program
+    );
+    eprintln!(
+        "       {} extract-table <font-file> --all -o <directory>",
+        program
+    );
+    eprintln!(
+        "Example: {} extract-table font.ttf glyf -o glyf.bin",
+        program
+    );
+    process::exit(1);
+}
@@ -0,0 +1,426 @@
+use crate::{contain_panic, parse_file_size};
+use fontinfo::detect::{FileKind, classify, read_header};
+use fontinfo::get_name;
+use fontinfo::reader::Reader;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use ttf_parser::{Face, Tag, name_id};
+
+/// Properties every member of a family is expected to share.
+const FIELDS: [&str; 6] = [
+    "Family Name",
+    "Version",
+    "Vendor ID",
+    "Copyright",
+    "Units per EM",
+    "Vertical Metrics",
+];
+
+/// One font file of the family and its values for each of `FIELDS`.
+struct Member {
+    file_name: String,
+    values: [Option<String>; 6],
+    style: Style,
+}
+
+impl Member {
+    fn new(file_name: String, face: &Face) -> Self {
+        Member {
+            file_name,
+            values: [
+                get_name(face, name_id::TYPOGRAPHIC_FAMILY)
+                    .or_else(|| get_name(face, name_id::FAMILY)),
+                get_name(face, name_id::VERSION),
+                vendor_id(face),
+                get_name(face, name_id::COPYRIGHT_NOTICE),
+                Some(face.units_per_em().to_string()),
+                vertical_metrics(face),
+            ],
+            style: Style::new(face),
+        }
+    }
+}
+
+/// What fallback and CSS matching pick a member of the family by.
+struct Style {
+    /// Name ID 1, the legacy family that style linking groups by.
+    family: Option<String>,
+    /// Name ID 2, one of Regular, Bold, Italic or Bold Italic.
+    subfamily: Option<String>,
+    /// Name IDs 16 and 17, falling back to 1 and 2.
+    typographic: (Option<String>, Option<String>),
+    weight: u16,
+    width: u16,
+    /// fsSelection BOLD, ITALIC and REGULAR.
+    bold: bool,
+    italic: bool,
+    regular: bool,
+    /// `head` macStyle bold and italic, when readable.
+    mac_style: Option<(bool, bool)>,
+}
+
+impl Style {
+    fn new(face: &Face) -> Self {
+        let family = get_name(face, name_id::FAMILY);
+        let subfamily = get_name(face, name_id::SUBFAMILY);
+        let fs_selection = face
+            .raw_face()
+            .table(Tag::from_bytes(b"OS/2"))
+            .and_then(|os2| Reader::at(os2, 62).read_u16())
+            .unwrap_or(0);
+        let mac_style = face
+            .raw_face()
+            .table(Tag::from_bytes(b"head"))
+            .and_then(|head| Reader::at(head, 44).read_u16())
+            .map(|bits| (bits & 1 != 0, bits & 2 != 0));
+        Style {
+            typographic: (
+                get_name(face, name_id::TYPOGRAPHIC_FAMILY).or_else(|| family.clone()),
+                get_name(face, name_id::TYPOGRAPHIC_SUBFAMILY).or_else(|| subfamily.clone()),
+            ),
+            family,
+            subfamily,
+            weight: face.weight().to_number(),
+            width: face.width().to_number(),
+            bold: fs_selection & 1 << 5 != 0,
+            italic: fs_selection & 1 != 0,
+            regular: fs_selection & 1 << 6 != 0,
+            mac_style,
+        }
+    }
+
+    /// Flag combinations that make style linking or CSS matching pick the
+    /// wrong face.
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.regular && (self.bold || self.italic) {
+            problems.push("fsSelection sets REGULAR together with BOLD or ITALIC".to_string());
+        }
+        if let Some((mac_bold, mac_italic)) = self.mac_style {
+            if mac_bold != self.bold {
+                problems.push(format!(
+                    "macStyle bold is {} but fsSelection BOLD is {}",
+                    mac_bold, self.bold
+                ));
+            }
+            if mac_italic != self.italic {
+                problems.push(format!(
+                    "macStyle italic is {} but fsSelection ITALIC is {}",
+                    mac_italic, self.italic
+                ));
+            }
+        }
+        if self.bold && self.weight < 600 {
+            problems.push(format!("BOLD is set but usWeightClass is {}", self.weight));
+        }
+        if let Some(subfamily) = &self.subfamily {
+            let expected = match (self.bold, self.italic) {
+                (false, false) => "Regular",
+                (true, false) => "Bold",
+                (false, true) => "Italic",
+                (true, true) => "Bold Italic",
+            };
+            if subfamily != expected {
+                problems.push(format!(
+                    "subfamily {:?} doesn't match the flags, which call for {:?}",
+                    subfamily, expected
+                ));
+            }
+        }
+        problems
+    }
+}
+
+/// Runs `fontinfo family <directory | font-file...> [--max-file-size
+/// <size>]`.
+pub fn run(program: &str, args: &[String]) {
+    let mut paths = Vec::new();
+    let mut max_file_size = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--max-file-size" => match iter.next().and_then(|size| parse_file_size(size)) {
+                Some(size) => max_file_size = Some(size),
+                None => usage(program),
+            },
+            _ if !arg.starts_with("--") => paths.push(arg),
+            _ => usage(program),
+        }
+    }
+    let dir = match paths.as_slice() {
+        [] => usage(program),
+        [path] if Path::new(path).is_dir() => Some(path),
+        _ => None,
+    };
+
+    let (members, skipped) = match dir {
+        Some(dir) => load_members(&list_dir(Path::new(dir)), max_file_size, false),
+        None => {
+            let files: Vec<PathBuf> = paths.iter().map(PathBuf::from).collect();
+            load_members(&files, max_file_size, true)
+        }
+    };
+    if members.is_empty() && skipped.is_empty() {
+        eprintln!("No fonts found in '{}'", paths[0]);
+        process::exit(1);
+    }
+
+    println!("┌─ FAMILY CONSISTENCY ──────────────────────────────────────────");
+    if let Some(dir) = dir {
+        println!("│ Directory:        {}", dir);
+    }
+    println!("│ Members:          {}", members.len());
+    if !skipped.is_empty() {
+        println!("│");
+        for (file, reason) in &skipped {
+            println!("│ Skipped: {} ({})", file, reason);
+        }
+    }
+
+    let mut outlier_count = 0;
+    let fields = if members.is_empty() {
+        &[][..]
+    } else {
+        &FIELDS[..]
+    };
+    for (i, label) in fields.iter().enumerate() {
+        println!("│");
+        let mut groups: BTreeMap<Option<&str>, Vec<&str>> = BTreeMap::new();
+        for member in &members {
+            groups
+                .entry(member.values[i].as_deref())
+                .or_default()
+                .push(&member.file_name);
+        }
+
+        // The most common value is taken as the family's; ties go to the
+        // first value in sort order so output is stable.
+        let (&common, _) = groups
+            .iter()
+            .max_by(|a, b| a.1.len().cmp(&b.1.len()).then(b.0.cmp(a.0)))
+            .unwrap();
+        println!("│ {:<17} {}", format!("{}:", label), display(common));
+        if groups.len() == 1 {
+            continue;
+        }
+        for (value, files) in &groups {
+            if *value == common {
+                continue;
+            }
+            for file in files {
+                println!("│   Outlier: {} has {}", file, display(*value));
+                outlier_count += 1;
+            }
+        }
+    }
+
+    println!("│");
+    println!("│ Style Linking:");
+    let mut problem_count = 0;
+    for member in &members {
+        for problem in member.style.problems() {
+            println!("│   {}: {}", member.file_name, problem);
+            problem_count += 1;
+        }
+    }
+    for collision in collisions(&members) {
+        println!("│   {}", collision);
+        problem_count += 1;
+    }
+    if problem_count == 0 {
+        println!("│   Flags, subfamily names and styles are distinct and consistent");
+    }
+
+    println!("│");
+    if outlier_count == 0 && problem_count == 0 {
+        println!("│ All members are consistent");
+    } else {
+        if outlier_count > 0 {
+            println!("│ {} outlier value(s) found", outlier_count);
+        }
+        if problem_count > 0 {
+            println!("│ {} style problem(s) found", problem_count);
+        }
+    }
+    println!("└───────────────────────────────────────────────────────────────");
+}
+
+/// Members that fallback or CSS matching can't tell apart: the same
+/// legacy family and subfamily, which style linking needs to be unique,
+/// or the same typographic family, weight, width and italic flag, which
+/// `@font-face` rules built from the fonts would share.
+fn collisions(members: &[Member]) -> Vec<String> {
+    let mut linked: BTreeMap<(Option<&str>, Option<&str>), Vec<&str>> = BTreeMap::new();
+    let mut css: BTreeMap<(Option<&str>, u16, u16, bool), Vec<&str>> = BTreeMap::new();
+    for member in members {
+        let style = &member.style;
+        linked
+            .entry((style.family.as_deref(), style.subfamily.as_deref()))
+            .or_default()
+            .push(&member.file_name);
+        css.entry((
+            style.typographic.0.as_deref(),
+            style.weight,
+            style.width,
+            style.italic,
+        ))
+        .or_default()
+        .push(&member.file_name);
+    }
+
+    let mut collisions = Vec::new();
+    for ((family, subfamily), files) in linked.iter().filter(|(_, files)| files.len() > 1) {
+        collisions.push(format!(
+            "{} share family {} and subfamily {}",
+            files.join(", "),
+            display(*family),
+            display(*subfamily)
+        ));
+    }
+    for ((_, weight, width, italic), files) in css.iter().filter(|(_, files)| files.len() > 1) {
+        collisions.push(format!(
+            "{} share weight {}, width class {} and {} style in CSS",
+            files.join(", "),
+            weight,
+            width,
+            if *italic { "italic" } else { "normal" }
+        ));
+    }
+    collisions
+}
+
+/// The files in a directory, sorted by name.
+fn list_dir(dir: &Path) -> Vec<PathBuf> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Error reading directory '{}': {}", dir.display(), e);
+            process::exit(1);
+        }
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file())
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Parses every sfnt font among `paths`. Files that aren't fonts are
+/// skipped silently unless `named` says the user listed them; fonts that
+/// fail to parse, exceed `max_file_size` or crash the analysis are
+/// returned separately with the reason.
+///
+/// Only one font's data is held in memory at a time, so directories of
+/// any size can be scanned with bounded memory.
+fn load_members(
+    paths: &[PathBuf],
+    max_file_size: Option<u64>,
+    named: bool,
+) -> (Vec<Member>, Vec<(String, String)>) {
+    let mut members = Vec::new();
+    let mut skipped = Vec::new();
+    for path in paths {
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        // Classify from the header first so non-font files are never read
+        // in full.
+        let (header, file_len) = match read_header(path) {
+            Ok(header) => header,
+            Err(e) if named => {
+                skipped.push((file_name, e.to_string()));
+                continue;
+            }
+            Err(_) => continue,
+        };
+        if !matches!(
+            classify(&header, file_len),
+            FileKind::TrueType { .. } | FileKind::OpenTypeCff | FileKind::Collection { .. }
+        ) {
+            if named {
+                skipped.push((file_name, "not an sfnt font".to_string()));
+            }
+            continue;
+        }
+        if let Some(max) = max_file_size
+            && file_len > max
+        {
+            skipped.push((
+                file_name,
+                format!("{} bytes exceeds --max-file-size", file_len),
+            ));
+            continue;
+        }
+        let data = match fs::read(path) {
+            Ok(data) => data,
+            Err(e) => {
+                skipped.push((file_name, e.to_string()));
+                continue;
+            }
+        };
+        let result = contain_panic(|| {
+            Face::parse(&data, 0)
+                .map(|face| Member::new(file_name.clone(), &face))
+                .map_err(|e| e.to_string())
+        });
+        match result.and_then(|member| member) {
+            Ok(member) => members.push(member),
+            Err(e) => skipped.push((file_name, e)),
+        }
+    }
+    (members, skipped)
+}
+
+fn vendor_id(face: &Face) -> Option<String> {
+    let os2 = face.raw_face().table(Tag::from_bytes(b"OS/2"))?;
+    let bytes = Reader::at(os2, 58).read_bytes(4)?;
+    Some(String::from_utf8_lossy(bytes).into_owned())
+}
+
+/// hhea, typo and win ascender/descender/line gap, which must match for
+/// the faces to share a line height.
+fn vertical_metrics(face: &Face) -> Option<String> {
+    let hhea = &face.tables().hhea;
+    let mut metrics = format!(
+        "hhea {}/{}/{}",
+        hhea.ascender, hhea.descender, hhea.line_gap
+    );
+    if let Some(os2) = face.tables().os2 {
+        metrics += &format!(
+            ", typo {}/{}/{}, win {}/{}",
+            os2.typographic_ascender(),
+            os2.typographic_descender(),
+            os2.typographic_line_gap(),
+            os2.windows_ascender(),
+            os2.windows_descender()
+        );
+    }
+    Some(metrics)
+}
+
+fn display(value: Option<&str>) -> String {
+    match value {
+        // Debug formatting quotes the value and escapes newlines, which are
+        // common in copyright strings.
+        Some(value) => format!("{:?}", value),
+        None => "(missing)".to_string(),
+    }
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} family <directory | font-file...> [--max-file-size <size>]",
+        program
+    );
+    eprintln!("Example: {} family fonts/Inter/", program);
+    eprintln!(
+        "         {} family Regular.ttf Bold.ttf Italic.ttf BoldItalic.ttf",
+        program
+    );
+    process::exit(1);
+}
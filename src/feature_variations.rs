@@ -0,0 +1,324 @@
+use crate::layout::{add_coverage, single_substitute};
+use crate::reader::Reader;
+use crate::variations::{avar_mappings, denormalize};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use ttf_parser::gsub::SubstitutionSubtable;
+use ttf_parser::opentype_layout::LayoutTable;
+use ttf_parser::{Face, GlyphId, Tag};
+
+/// One FeatureVariations record: a region of the design space and the
+/// features whose lookups are replaced inside it.
+#[derive(Serialize)]
+pub struct VariationRecord {
+    /// GSUB or GPOS.
+    pub table: &'static str,
+    /// All of which must hold; none means everywhere.
+    pub conditions: Vec<Condition>,
+    pub substitutions: Vec<FeatureSubstitution>,
+    /// Applied at the face's current location: the first record of its
+    /// table whose conditions hold.
+    pub active: bool,
+}
+
+/// An axis range in user space.
+#[derive(Serialize)]
+pub struct Condition {
+    pub axis: String,
+    pub min: f32,
+    pub max: f32,
+    /// Whether `min` and `max` are the axis' own limits, which make the
+    /// range one-sided.
+    #[serde(skip)]
+    open: (bool, bool),
+}
+
+impl Condition {
+    /// `wght ≥ 600`, `wght ≤ 300` or `400 ≤ wght ≤ 600`.
+    pub fn rule(&self) -> String {
+        match self.open {
+            (true, true) => format!("any {}", self.axis),
+            (false, true) => format!("{} ≥ {}", self.axis, self.min),
+            (true, false) => format!("{} ≤ {}", self.axis, self.max),
+            (false, false) => format!("{} ≤ {} ≤ {}", self.min, self.axis, self.max),
+        }
+    }
+}
+
+/// A feature whose lookups a record replaces.
+#[derive(Serialize)]
+pub struct FeatureSubstitution {
+    pub feature: String,
+    pub default_lookups: Vec<u16>,
+    pub lookups: Vec<u16>,
+}
+
+impl VariationRecord {
+    /// The record as a sentence, e.g. `when wght ≥ 600: rvrn uses lookup
+    /// 12 instead of 3`.
+    pub fn rule(&self) -> String {
+        let conditions: Vec<String> = self.conditions.iter().map(Condition::rule).collect();
+        let when = if conditions.is_empty() {
+            "always".to_string()
+        } else {
+            format!("when {}", conditions.join(" and "))
+        };
+        let substitutions: Vec<String> = self
+            .substitutions
+            .iter()
+            .map(|s| {
+                format!(
+                    "{} uses {} instead of {}",
+                    s.feature,
+                    lookups(&s.lookups),
+                    lookups(&s.default_lookups)
+                )
+            })
+            .collect();
+        format!("{}: {}", when, substitutions.join("; "))
+    }
+}
+
+fn lookups(indices: &[u16]) -> String {
+    let list: Vec<String> = indices.iter().map(u16::to_string).collect();
+    match list.len() {
+        0 => "no lookups".to_string(),
+        1 => format!("lookup {}", list[0]),
+        _ => format!("lookups {}", list.join(", ")),
+    }
+}
+
+/// A glyph that renders differently at the current location than at the
+/// default, through a substituted GSUB feature's single substitutions.
+#[derive(Serialize)]
+pub struct Swap {
+    pub feature: String,
+    pub glyph: String,
+    pub default: String,
+    pub current: String,
+}
+
+/// How GSUB and GPOS feature variations change lookups across the design
+/// space, and what GSUB swaps at the face's current location.
+#[derive(Serialize)]
+pub struct FeatureVariationReport {
+    /// Axis tag and user-space value of the face's location, in `fvar`
+    /// order.
+    pub location: Vec<(String, f32)>,
+    /// GSUB records first, each table's in the order they're tried.
+    pub records: Vec<VariationRecord>,
+    pub swaps: Vec<Swap>,
+}
+
+impl FeatureVariationReport {
+    /// Returns `None` when neither GSUB nor GPOS has a FeatureVariations
+    /// table.
+    pub fn new(face: &Face) -> Option<Self> {
+        let tables = face.tables();
+        if tables.gsub.and_then(|t| t.variations).is_none()
+            && tables.gpos.and_then(|t| t.variations).is_none()
+        {
+            return None;
+        }
+        let axes: Vec<_> = face.variation_axes().into_iter().collect();
+        let coordinates = face.variation_coordinates();
+        let avar = avar_mappings(face);
+        // Normalized coordinates, after avar, back in user space.
+        let user = |index: usize, value: f32| {
+            let axis = &axes[index];
+            let value = avar.get(index).map_or(value, |map| unmap(map, value));
+            let value = denormalize(value, axis.min_value, axis.def_value, axis.max_value);
+            (value * 10.0).round() / 10.0
+        };
+
+        let mut records = Vec::new();
+        let mut swaps = Vec::new();
+        for (name, tag, table) in [
+            ("GSUB", b"GSUB", tables.gsub),
+            ("GPOS", b"GPOS", tables.gpos),
+        ] {
+            let (Some(table), Some(data)) = (table, face.raw_face().table(Tag::from_bytes(tag)))
+            else {
+                continue;
+            };
+            let Some(variations) = table.variations else {
+                continue;
+            };
+            let active = variations.find_index(coordinates);
+            if name == "GSUB"
+                && let Some(index) = active
+            {
+                swaps = self::swaps(face, &table, index);
+            }
+            for (i, (conditions, substitutions)) in parse_records(data)
+                .unwrap_or_default()
+                .into_iter()
+                .enumerate()
+            {
+                records.push(VariationRecord {
+                    table: name,
+                    conditions: conditions
+                        .into_iter()
+                        .filter_map(|(axis, min, max)| {
+                            let index = usize::from(axis);
+                            Some(Condition {
+                                axis: axes.get(index)?.tag.to_string(),
+                                min: user(index, min),
+                                max: user(index, max),
+                                open: (min <= -1.0, max >= 1.0),
+                            })
+                        })
+                        .collect(),
+                    substitutions: substitutions
+                        .into_iter()
+                        .filter_map(|(index, lookups)| {
+                            let feature = table.features.get(index)?;
+                            Some(FeatureSubstitution {
+                                feature: feature.tag.to_string(),
+                                default_lookups: feature.lookup_indices.into_iter().collect(),
+                                lookups,
+                            })
+                        })
+                        .collect(),
+                    active: active == Some(i as u32),
+                });
+            }
+        }
+        Some(FeatureVariationReport {
+            location: axes
+                .iter()
+                .zip(coordinates)
+                .enumerate()
+                .map(|(i, (axis, value))| (axis.tag.to_string(), user(i, f2dot14(value.get()))))
+                .collect(),
+            records,
+            swaps,
+        })
+    }
+}
+
+/// Compares the single substitutions of each GSUB feature record the
+/// active variation replaces with those of its substitute. Other lookup types
+/// aren't compared.
+fn swaps(face: &Face, gsub: &LayoutTable, variation: u32) -> Vec<Swap> {
+    let Some(variations) = gsub.variations else {
+        return Vec::new();
+    };
+    let label = |glyph: GlyphId| {
+        face.glyph_name(glyph)
+            .map_or_else(|| format!("gid{}", glyph.0), str::to_string)
+    };
+    let mut swaps = Vec::new();
+    for index in 0..gsub.features.len() {
+        let (Some(feature), Some(substitute)) = (
+            gsub.features.get(index),
+            variations.find_substitute(index, variation),
+        ) else {
+            continue;
+        };
+        let default: BTreeSet<u16> = feature.lookup_indices.into_iter().collect();
+        let current: BTreeSet<u16> = substitute.lookup_indices.into_iter().collect();
+        let mut covered = BTreeSet::new();
+        for lookup in default
+            .union(&current)
+            .filter_map(|&index| gsub.lookups.get(index))
+        {
+            for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+                if let SubstitutionSubtable::Single(_) = subtable {
+                    add_coverage(subtable.coverage(), &mut covered);
+                }
+            }
+        }
+        for glyph in covered {
+            let before = single_substitute(gsub, &default, glyph);
+            let after = single_substitute(gsub, &current, glyph);
+            if before != after {
+                swaps.push(Swap {
+                    feature: feature.tag.to_string(),
+                    glyph: label(glyph),
+                    default: label(before),
+                    current: label(after),
+                });
+            }
+        }
+    }
+    swaps
+}
+
+/// Maps a coordinate back through an `avar` segment map, from its `to`
+/// values to its `from` values.
+fn unmap(map: &[(f32, f32)], value: f32) -> f32 {
+    for pair in map.windows(2) {
+        let ((from_a, to_a), (from_b, to_b)) = (pair[0], pair[1]);
+        if (to_a..=to_b).contains(&value) {
+            if to_b == to_a {
+                return from_a;
+            }
+            return from_a + (value - to_a) * (from_b - from_a) / (to_b - to_a);
+        }
+    }
+    value
+}
+
+/// A record's conditions as (axis index, normalized min, normalized max),
+/// and the feature indices it substitutes with their alternate lookups.
+type RawRecord = (Vec<(u16, f32, f32)>, Vec<(u16, Vec<u16>)>);
+
+/// Reads the FeatureVariations records of a GSUB or GPOS table, which
+/// ttf-parser only exposes through lookups by location.
+fn parse_records(data: &[u8]) -> Option<Vec<RawRecord>> {
+    let mut r = Reader::new(data);
+    r.skip(2); // majorVersion
+    // Only version 1.1 has the FeatureVariations offset.
+    if r.read_u16()? < 1 {
+        return None;
+    }
+    r.skip(6); // scriptListOffset, featureListOffset, lookupListOffset
+    let offset = r.read_u32()? as usize;
+    let table = data.get(offset..)?;
+
+    let mut r = Reader::at(table, 4);
+    let count = r.read_u32()?;
+    let mut records = Vec::new();
+    for _ in 0..count {
+        let conditions_offset = r.read_u32()? as usize;
+        let substitution_offset = r.read_u32()? as usize;
+
+        let mut c = Reader::at(table, conditions_offset);
+        let condition_count = c.read_u16()?;
+        let mut conditions = Vec::new();
+        for _ in 0..condition_count {
+            let offset = conditions_offset + c.read_u32()? as usize;
+            let mut condition = Reader::at(table, offset);
+            // Format 1, the only one defined, is an axis range.
+            if condition.read_u16()? != 1 {
+                continue;
+            }
+            let axis = condition.read_u16()?;
+            let min = f2dot14(condition.read_i16()?);
+            let max = f2dot14(condition.read_i16()?);
+            conditions.push((axis, min, max));
+        }
+
+        let mut s = Reader::at(table, substitution_offset + 4);
+        let substitution_count = s.read_u16()?;
+        let features = (0..substitution_count)
+            .map(|_| {
+                let index = s.read_u16()?;
+                let offset = substitution_offset + s.read_u32()? as usize;
+                let mut feature = Reader::at(table, offset + 2); // featureParamsOffset
+                let count = feature.read_u16()?;
+                let lookups = (0..count)
+                    .map(|_| feature.read_u16())
+                    .collect::<Option<Vec<_>>>()?;
+                Some((index, lookups))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        records.push((conditions, features));
+    }
+    Some(records)
+}
+
+fn f2dot14(value: i16) -> f32 {
+    value as f32 / 16384.0
+}
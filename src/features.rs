@@ -0,0 +1,38 @@
+use crate::config::Config;
+use crate::i18n::{self, Catalog, Lang};
+use std::collections::HashMap;
+
+/// Descriptions of OpenType features in the report language. Built-in
+/// descriptions come from the catalogs in `locales/features/`, falling back
+/// to English; entries in the config's `features` table take precedence.
+pub struct FeatureRegistry<'a> {
+    lang: Lang,
+    builtin: HashMap<&'static str, &'static str>,
+    config: &'a Config,
+    unknown: &'a str,
+}
+
+impl<'a> FeatureRegistry<'a> {
+    pub fn new(lang: Lang, catalog: &'a Catalog, config: &'a Config) -> Self {
+        let mut builtin = i18n::parse(Lang::En.feature_source());
+        if lang != Lang::En {
+            builtin.extend(i18n::parse(lang.feature_source()));
+        }
+        FeatureRegistry {
+            lang,
+            builtin,
+            config,
+            unknown: catalog.get("feature-unknown"),
+        }
+    }
+
+    pub fn describe(&self, tag: &str) -> &str {
+        if let Some(entry) = self.config.features.get(tag) {
+            return entry
+                .translations
+                .get(self.lang.code())
+                .unwrap_or(&entry.description);
+        }
+        self.builtin.get(tag).copied().unwrap_or(self.unknown)
+    }
+}
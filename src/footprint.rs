@@ -0,0 +1,100 @@
+use crate::glyph::{glyf_range, glyph_instructions};
+use serde::Serialize;
+use ttf_parser::{Face, GlyphId, Tag};
+
+/// Tables holding per-glyph outlines, bitmaps or variation deltas.
+const GLYPH_TABLES: [&[u8; 4]; 12] = [
+    b"glyf", b"loca", b"CFF ", b"CFF2", b"gvar", b"COLR", b"SVG ", b"sbix", b"CBDT", b"CBLC",
+    b"EBDT", b"EBLC",
+];
+/// Tables holding TrueType hinting shared by all glyphs.
+const HINTING_TABLES: [&[u8; 4]; 3] = [b"fpgm", b"prep", b"cvt "];
+/// Rough size of a Latin text font covering Western European languages,
+/// such as Roboto or Open Sans, as TrueType.
+pub const TYPICAL_LATIN_SIZE: usize = 150 * 1024;
+
+/// What loading the font costs a renderer, for budgeting fonts on
+/// low-memory targets. Sizes are in bytes.
+#[derive(Serialize)]
+pub struct Footprint {
+    /// The font as a plain sfnt: what a renderer holds once WOFF or WOFF2
+    /// compression is undone.
+    pub decompressed: usize,
+    /// Bytes in `GLYPH_TABLES`.
+    pub glyph_data: usize,
+    /// The tables making up `glyph_data`.
+    pub glyph_tables: Vec<String>,
+    pub glyphs: u16,
+    /// Bytes in `fpgm`, `prep` and `cvt`.
+    pub hinting_tables: usize,
+    /// Bytes of per-glyph TrueType instructions inside `glyf`; `None` in
+    /// terse mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glyph_instructions: Option<usize>,
+}
+
+impl Footprint {
+    pub fn new(face: &Face, terse: bool) -> Self {
+        let records = face.raw_face().table_records;
+        let listed = |tags: &[&[u8; 4]], tag: Tag| tags.iter().any(|&t| Tag::from_bytes(t) == tag);
+        let length = |tags: &[&[u8; 4]]| {
+            records
+                .into_iter()
+                .filter(|record| listed(tags, record.tag))
+                .map(|record| record.length as usize)
+                .sum()
+        };
+        let decompressed = 12
+            + 16 * records.len() as usize
+            + records
+                .into_iter()
+                .map(|record| (record.length as usize).next_multiple_of(4))
+                .sum::<usize>();
+        let glyph_tables = records
+            .into_iter()
+            .filter(|record| listed(&GLYPH_TABLES, record.tag))
+            .map(|record| record.tag.to_string().trim_end().to_string())
+            .collect();
+        Footprint {
+            decompressed,
+            glyph_data: length(&GLYPH_TABLES),
+            glyph_tables,
+            glyphs: face.number_of_glyphs(),
+            hinting_tables: length(&HINTING_TABLES),
+            glyph_instructions: (!terse).then(|| instruction_bytes(face)),
+        }
+    }
+
+    /// Average glyph data per glyph.
+    pub fn per_glyph(&self) -> usize {
+        self.glyph_data / usize::from(self.glyphs.max(1))
+    }
+
+    /// How the decompressed size compares to `TYPICAL_LATIN_SIZE`.
+    pub fn comparison(&self) -> String {
+        let ratio = self.decompressed as f32 / TYPICAL_LATIN_SIZE as f32;
+        if ratio >= 1.5 {
+            format!("~{:.0}× larger than a typical Latin text font", ratio)
+        } else if ratio <= 1.0 / 1.5 {
+            format!(
+                "~{:.0}× smaller than a typical Latin text font",
+                1.0 / ratio
+            )
+        } else {
+            "about the size of a typical Latin text font".to_string()
+        }
+    }
+}
+
+/// Bytes of TrueType instructions in every glyph program of `glyf`.
+pub fn instruction_bytes(face: &Face) -> usize {
+    let Some(glyf) = face.raw_face().table(Tag::from_bytes(b"glyf")) else {
+        return 0;
+    };
+    (0..face.number_of_glyphs())
+        .filter_map(|id| {
+            let (start, end) = glyf_range(face, GlyphId(id))?;
+            glyph_instructions(glyf.get(start..end)?).map(<[u8]>::len)
+        })
+        .sum()
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+/// Largest LZW code a GIF may use; the code table is reset when it fills.
+const MAX_CODES: u16 = 4096;
+/// Bits per pixel index, and so the LZW minimum code size.
+const COLOR_BITS: u8 = 8;
+
+/// Encodes frames of 8-bit grayscale pixels, row by row, as an animated GIF
+/// that loops forever, showing each frame for `delay_cs` hundredths of a
+/// second. Pixel values index a palette of 256 grays.
+pub fn encode_gray_animation(
+    width: usize,
+    height: usize,
+    frames: &[Vec<u8>],
+    delay_cs: u16,
+) -> Vec<u8> {
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend_from_slice(&(width as u16).to_le_bytes());
+    gif.extend_from_slice(&(height as u16).to_le_bytes());
+    // A global color table of 2^8 entries, 8 bits per primary.
+    gif.extend_from_slice(&[0xF7, 0, 0]);
+    for gray in 0..=255u8 {
+        gif.extend_from_slice(&[gray; 3]);
+    }
+    // The Netscape application extension, with a loop count of 0: forever.
+    gif.extend_from_slice(b"\x21\xFF\x0BNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    for frame in frames {
+        // Graphic control extension: no disposal, the delay, no transparency.
+        gif.extend_from_slice(&[0x21, 0xF9, 0x04, 0x00]);
+        gif.extend_from_slice(&delay_cs.to_le_bytes());
+        gif.extend_from_slice(&[0x00, 0x00]);
+        // Image descriptor covering the whole screen, without a local table.
+        gif.push(0x2C);
+        gif.extend_from_slice(&[0; 4]);
+        gif.extend_from_slice(&(width as u16).to_le_bytes());
+        gif.extend_from_slice(&(height as u16).to_le_bytes());
+        gif.push(0);
+        gif.push(COLOR_BITS);
+        // The compressed data goes in sub-blocks of at most 255 bytes.
+        for block in lzw(frame).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend_from_slice(block);
+        }
+        gif.push(0);
+    }
+    gif.push(0x3B);
+    gif
+}
+
+/// Compresses pixel indices with GIF's variable-width LZW.
+fn lzw(pixels: &[u8]) -> Vec<u8> {
+    let clear = 1u16 << COLOR_BITS;
+    let end = clear + 1;
+    let mut codes = CodeWriter::default();
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+    let mut size = COLOR_BITS + 1;
+
+    codes.write(clear, size);
+    let mut prefix: Option<u16> = None;
+    for &pixel in pixels {
+        let Some(current) = prefix else {
+            prefix = Some(u16::from(pixel));
+            continue;
+        };
+        if let Some(&code) = table.get(&(current, pixel)) {
+            prefix = Some(code);
+            continue;
+        }
+        codes.write(current, size);
+        if next == MAX_CODES {
+            codes.write(clear, size);
+            table.clear();
+            next = end + 1;
+            size = COLOR_BITS + 1;
+        } else {
+            // The decoder adds its entries one code behind, so the width
+            // grows once the next entry no longer fits.
+            if next >= 1 << size {
+                size += 1;
+            }
+            table.insert((current, pixel), next);
+            next += 1;
+        }
+        prefix = Some(u16::from(pixel));
+    }
+    if let Some(current) = prefix {
+        codes.write(current, size);
+        if next >= 1 << size && size < 12 {
+            size += 1;
+        }
+    }
+    codes.write(end, size);
+    codes.finish()
+}
+
+/// Packs codes least significant bit first, as GIF requires.
+#[derive(Default)]
+struct CodeWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    count: u8,
+}
+
+impl CodeWriter {
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= u32::from(code) << self.count;
+        self.count += size;
+        while self.count >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
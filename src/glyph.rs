@@ -0,0 +1,211 @@
+use crate::reader::Reader;
+use crate::unicode::covered_chars;
+use serde::Serialize;
+use ttf_parser::{Face, GlyphId, OutlineBuilder, Tag};
+
+/// Composite glyph component flags.
+const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+const WE_HAVE_A_SCALE: u16 = 0x0008;
+const MORE_COMPONENTS: u16 = 0x0020;
+const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+const WE_HAVE_INSTRUCTIONS: u16 = 0x0100;
+
+/// How a glyph's outline is stored.
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase", tag = "kind")]
+pub enum GlyphKind {
+    /// Its own contours, in `glyf` or CFF.
+    Simple { contours: usize },
+    /// Built from other glyphs, by ID, in `glyf`.
+    Composite { components: Vec<u16> },
+    /// No outline, like a space.
+    Empty,
+}
+
+/// Everything about one glyph.
+#[derive(Serialize)]
+pub struct GlyphInfo {
+    /// The character it was looked up by, for `--glyph`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub c: Option<char>,
+    pub id: u16,
+    /// Every character the cmap maps to it.
+    pub chars: Vec<char>,
+    pub name: Option<String>,
+    pub advance: Option<u16>,
+    pub left_side_bearing: Option<i16>,
+    /// xMin, yMin, xMax, yMax; `None` for empty glyphs.
+    pub bbox: Option<[i16; 4]>,
+    #[serde(flatten)]
+    pub kind: GlyphKind,
+}
+
+impl GlyphInfo {
+    /// Returns `None` when the cmap doesn't map `c`.
+    pub fn new(face: &Face, c: char) -> Option<Self> {
+        let glyph = face.glyph_index(c)?;
+        Some(GlyphInfo {
+            c: Some(c),
+            ..GlyphInfo::by_id(face, glyph)?
+        })
+    }
+
+    /// Returns `None` when the font has no glyph with this ID.
+    pub fn by_id(face: &Face, glyph: GlyphId) -> Option<Self> {
+        if glyph.0 >= face.number_of_glyphs() {
+            return None;
+        }
+        let chars = covered_chars(face)
+            .into_iter()
+            .filter(|&c| face.glyph_index(c) == Some(glyph))
+            .collect();
+        let bbox = face
+            .glyph_bounding_box(glyph)
+            .map(|rect| [rect.x_min, rect.y_min, rect.x_max, rect.y_max]);
+        Some(GlyphInfo {
+            c: None,
+            id: glyph.0,
+            chars,
+            name: face.glyph_name(glyph).map(str::to_string),
+            advance: face.glyph_hor_advance(glyph),
+            left_side_bearing: face.glyph_hor_side_bearing(glyph),
+            bbox,
+            kind: glyf_kind(face, glyph).unwrap_or_else(|| outline_kind(face, glyph)),
+        })
+    }
+}
+
+/// The byte range of the glyph's data in `glyf`, as `loca` gives it; empty
+/// for glyphs without an outline. Returns `None` for fonts without `glyf`
+/// or with a truncated `loca`.
+pub fn glyf_range(face: &Face, glyph: GlyphId) -> Option<(usize, usize)> {
+    let raw = face.raw_face();
+    raw.table(Tag::from_bytes(b"glyf"))?;
+    let loca = raw.table(Tag::from_bytes(b"loca"))?;
+    let long_offsets = Reader::at(raw.table(Tag::from_bytes(b"head"))?, 50).read_u16()? == 1;
+    let index = usize::from(glyph.0);
+    let (start, end) = if long_offsets {
+        let mut r = Reader::at(loca, index * 4);
+        (r.read_u32()? as usize, r.read_u32()? as usize)
+    } else {
+        // Short offsets are stored divided by two.
+        let mut r = Reader::at(loca, index * 2);
+        (
+            usize::from(r.read_u16()?) * 2,
+            usize::from(r.read_u16()?) * 2,
+        )
+    };
+    Some((start, end))
+}
+
+/// The glyphs a `glyf` composite is built from, by ID; empty for other
+/// glyphs.
+pub fn components(face: &Face, glyph: GlyphId) -> Vec<u16> {
+    match glyf_kind(face, glyph) {
+        Some(GlyphKind::Composite { components }) => components,
+        _ => Vec::new(),
+    }
+}
+
+/// The TrueType instructions in one glyph's `glyf` data, empty when it has
+/// none. Returns `None` when the data is truncated.
+pub fn glyph_instructions(data: &[u8]) -> Option<&[u8]> {
+    if data.is_empty() {
+        return Some(&[]);
+    }
+    let mut r = Reader::new(data);
+    let contours = r.read_i16()?;
+    r.skip(8); // xMin, yMin, xMax, yMax
+    if contours >= 0 {
+        r.skip(2 * contours as usize); // endPtsOfContours
+    } else {
+        loop {
+            let flags = r.read_u16()?;
+            r.skip(2); // glyphIndex
+            r.skip(if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+                4
+            } else {
+                2
+            });
+            if flags & WE_HAVE_A_SCALE != 0 {
+                r.skip(2);
+            } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+                r.skip(4);
+            } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+                r.skip(8);
+            }
+            if flags & MORE_COMPONENTS == 0 {
+                if flags & WE_HAVE_INSTRUCTIONS == 0 {
+                    return Some(&[]);
+                }
+                break;
+            }
+        }
+    }
+    let length = r.read_u16()?;
+    r.read_bytes(usize::from(length))
+}
+
+/// Reads the glyph's `glyf` header, found through `loca`. Returns `None`
+/// for fonts without `glyf` or with a truncated `loca`.
+fn glyf_kind(face: &Face, glyph: GlyphId) -> Option<GlyphKind> {
+    let glyf = face.raw_face().table(Tag::from_bytes(b"glyf"))?;
+    let (start, end) = glyf_range(face, glyph)?;
+    if end <= start {
+        return Some(GlyphKind::Empty);
+    }
+
+    let mut r = Reader::new(glyf.get(start..end)?);
+    let contours = r.read_i16()?;
+    if contours >= 0 {
+        return Some(GlyphKind::Simple {
+            contours: contours as usize,
+        });
+    }
+    r.skip(8); // xMin, yMin, xMax, yMax
+    let mut components = Vec::new();
+    loop {
+        let flags = r.read_u16()?;
+        components.push(r.read_u16()?);
+        r.skip(if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+            4
+        } else {
+            2
+        });
+        if flags & WE_HAVE_A_SCALE != 0 {
+            r.skip(2);
+        } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+            r.skip(4);
+        } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+            r.skip(8);
+        }
+        if flags & MORE_COMPONENTS == 0 {
+            break;
+        }
+    }
+    Some(GlyphKind::Composite { components })
+}
+
+/// Counts the contours of a CFF or CFF2 outline, which has no composites.
+fn outline_kind(face: &Face, glyph: GlyphId) -> GlyphKind {
+    let mut counter = ContourCounter(0);
+    match face.outline_glyph(glyph, &mut counter) {
+        Some(_) if counter.0 > 0 => GlyphKind::Simple {
+            contours: counter.0,
+        },
+        _ => GlyphKind::Empty,
+    }
+}
+
+struct ContourCounter(usize);
+
+impl OutlineBuilder for ContourCounter {
+    fn move_to(&mut self, _x: f32, _y: f32) {
+        self.0 += 1;
+    }
+    fn line_to(&mut self, _x: f32, _y: f32) {}
+    fn quad_to(&mut self, _x1: f32, _y1: f32, _x: f32, _y: f32) {}
+    fn curve_to(&mut self, _x1: f32, _y1: f32, _x2: f32, _y2: f32, _x: f32, _y: f32) {}
+    fn close(&mut self) {}
+}
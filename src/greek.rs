@@ -0,0 +1,149 @@
+use serde::Serialize;
+use ttf_parser::Face;
+
+/// Letters of modern monotonic Greek: the alphabet and the letters with
+/// tonos or dialytika, as inclusive ranges.
+const MONOTONIC: [(u32, u32); 5] = [
+    (0x0386, 0x0386),
+    (0x0388, 0x038A),
+    (0x038C, 0x038C),
+    (0x038E, 0x03A1),
+    (0x03A3, 0x03CE),
+];
+
+/// Letters of the Greek Extended block, which polytonic text needs for
+/// breathings, the grave and circumflex accents and iota subscript.
+const POLYTONIC: [(u32, u32); 19] = [
+    (0x1F00, 0x1F15),
+    (0x1F18, 0x1F1D),
+    (0x1F20, 0x1F45),
+    (0x1F48, 0x1F4D),
+    (0x1F50, 0x1F57),
+    (0x1F59, 0x1F59),
+    (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D),
+    (0x1F5F, 0x1F7D),
+    (0x1F80, 0x1FB4),
+    (0x1FB6, 0x1FBC),
+    (0x1FBE, 0x1FBE),
+    (0x1FC2, 0x1FC4),
+    (0x1FC6, 0x1FCC),
+    (0x1FD0, 0x1FD3),
+    (0x1FD6, 0x1FDB),
+    (0x1FE0, 0x1FEC),
+    (0x1FF2, 0x1FF4),
+    (0x1FF6, 0x1FFC),
+];
+
+/// Number of letters with oxia.
+pub const OXIA_FORMS: usize = 16;
+
+/// Letters with oxia and the tonos letters they canonically decompose to,
+/// escaped because editors normalize the oxia forms away. Unnormalized
+/// polytonic text uses them.
+const OXIA_TONOS: [(char, char); OXIA_FORMS] = [
+    ('\u{1F71}', '\u{03AC}'),
+    ('\u{1F73}', '\u{03AD}'),
+    ('\u{1F75}', '\u{03AE}'),
+    ('\u{1F77}', '\u{03AF}'),
+    ('\u{1F79}', '\u{03CC}'),
+    ('\u{1F7B}', '\u{03CD}'),
+    ('\u{1F7D}', '\u{03CE}'),
+    ('\u{1FD3}', '\u{0390}'),
+    ('\u{1FE3}', '\u{03B0}'),
+    ('\u{1FBB}', '\u{0386}'),
+    ('\u{1FC9}', '\u{0388}'),
+    ('\u{1FCB}', '\u{0389}'),
+    ('\u{1FDB}', '\u{038A}'),
+    ('\u{1FEB}', '\u{038E}'),
+    ('\u{1FF9}', '\u{038C}'),
+    ('\u{1FFB}', '\u{038F}'),
+];
+
+/// Monotonic and polytonic Greek coverage.
+#[derive(Serialize)]
+pub struct GreekReport {
+    pub monotonic: Coverage,
+    pub polytonic: Coverage,
+    /// ς has a glyph of its own rather than reusing σ's.
+    pub final_sigma: bool,
+    /// Covered oxia forms out of `OXIA_TONOS`.
+    pub oxia: usize,
+    /// Oxia forms missing although their tonos letter is covered, as
+    /// (oxia, tonos).
+    pub missing_oxia: Vec<(char, char)>,
+}
+
+#[derive(Serialize)]
+pub struct Coverage {
+    pub covered: usize,
+    pub total: usize,
+    pub missing: Vec<char>,
+}
+
+impl Coverage {
+    fn new(face: &Face, ranges: &[(u32, u32)]) -> Self {
+        let mut coverage = Coverage {
+            covered: 0,
+            total: 0,
+            missing: Vec::new(),
+        };
+        for c in ranges
+            .iter()
+            .flat_map(|&(start, end)| start..=end)
+            .filter_map(char::from_u32)
+        {
+            coverage.total += 1;
+            if face.glyph_index(c).is_some() {
+                coverage.covered += 1;
+            } else {
+                coverage.missing.push(c);
+            }
+        }
+        coverage
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl GreekReport {
+    /// Returns `None` for fonts that cover no Greek letters.
+    pub fn new(face: &Face) -> Option<Self> {
+        let monotonic = Coverage::new(face, &MONOTONIC);
+        let polytonic = Coverage::new(face, &POLYTONIC);
+        if monotonic.covered == 0 && polytonic.covered == 0 {
+            return None;
+        }
+
+        let final_sigma = match (face.glyph_index('ς'), face.glyph_index('σ')) {
+            (Some(final_sigma), Some(sigma)) => final_sigma != sigma,
+            (final_sigma, _) => final_sigma.is_some(),
+        };
+        let oxia = OXIA_TONOS
+            .iter()
+            .filter(|(oxia, _)| face.glyph_index(*oxia).is_some())
+            .count();
+        let missing_oxia = OXIA_TONOS
+            .into_iter()
+            .filter(|&(oxia, tonos)| {
+                face.glyph_index(oxia).is_none() && face.glyph_index(tonos).is_some()
+            })
+            .collect();
+
+        Some(GreekReport {
+            monotonic,
+            polytonic,
+            final_sigma,
+            oxia,
+            missing_oxia,
+        })
+    }
+
+    /// True when only monotonic Greek is fully covered, the case that
+    /// trips up classicists.
+    pub fn monotonic_only(&self) -> bool {
+        self.monotonic.is_complete() && !self.polytonic.is_complete()
+    }
+}
@@ -0,0 +1,102 @@
+use crate::reader::Reader;
+use crate::timestamp::DateTime;
+use serde::Serialize;
+use ttf_parser::{Face, Tag};
+
+/// Names of the head flags bits in use, by bit number; bits 5–10 are
+/// Apple-specific or unused.
+const FLAGS: [(u32, &str); 10] = [
+    (0, "baseline at y=0"),
+    (1, "left sidebearing point at x=0"),
+    (2, "instructions depend on point size"),
+    (3, "integer ppem"),
+    (4, "instructions may alter advance width"),
+    (5, "vertical layout"),
+    (11, "lossless font data"),
+    (12, "converted font"),
+    (13, "optimized for ClearType"),
+    (14, "last resort font"),
+];
+
+const MAC_STYLES: [&str; 7] = [
+    "Bold",
+    "Italic",
+    "Underline",
+    "Outline",
+    "Shadow",
+    "Condensed",
+    "Extended",
+];
+
+/// The `head` table's dates, revision and flags.
+#[derive(Serialize)]
+pub struct HeadReport {
+    pub font_revision: f32,
+    /// ISO 8601 timestamps, converted from seconds since 1904.
+    pub created: String,
+    pub modified: String,
+    pub units_per_em: u16,
+    pub flags: u16,
+    pub mac_style: u16,
+    pub lowest_rec_ppem: u16,
+    /// 0 for 16-bit `loca` offsets, 1 for 32-bit ones.
+    pub index_to_loc_format: i16,
+}
+
+impl HeadReport {
+    /// Returns `None` when the table is truncated.
+    pub fn new(face: &Face) -> Option<Self> {
+        let head = face.raw_face().table(Tag::from_bytes(b"head"))?;
+        let mut r = Reader::at(head, 4);
+        let font_revision = r.read_fixed()?;
+        r.skip(8);
+        let flags = r.read_u16()?;
+        let units_per_em = r.read_u16()?;
+        let created = DateTime::from_longdatetime(r.read_i64()?).to_iso8601();
+        let modified = DateTime::from_longdatetime(r.read_i64()?).to_iso8601();
+        r.skip(8);
+        let mac_style = r.read_u16()?;
+        let lowest_rec_ppem = r.read_u16()?;
+        r.skip(2);
+        let index_to_loc_format = r.read_i16()?;
+        Some(HeadReport {
+            font_revision,
+            created,
+            modified,
+            units_per_em,
+            flags,
+            mac_style,
+            lowest_rec_ppem,
+            index_to_loc_format,
+        })
+    }
+
+    /// Names of the set flags bits.
+    pub fn flag_names(&self) -> Vec<String> {
+        (0..16)
+            .filter(|bit| self.flags >> bit & 1 == 1)
+            .map(|bit| match FLAGS.iter().find(|(b, _)| *b == bit) {
+                Some((_, name)) => name.to_string(),
+                None => format!("bit {}", bit),
+            })
+            .collect()
+    }
+
+    /// Names of the set macStyle bits.
+    pub fn mac_style_names(&self) -> Vec<&'static str> {
+        MAC_STYLES
+            .iter()
+            .enumerate()
+            .filter(|&(bit, _)| self.mac_style >> bit & 1 == 1)
+            .map(|(_, &name)| name)
+            .collect()
+    }
+
+    pub fn loca_format(&self) -> &'static str {
+        match self.index_to_loc_format {
+            0 => "short offsets",
+            1 => "long offsets",
+            _ => "invalid",
+        }
+    }
+}
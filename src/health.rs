@@ -0,0 +1,249 @@
+use crate::color::ColorReport;
+use crate::glyph::glyf_range;
+use serde::Serialize;
+use ttf_parser::cmap::Format;
+use ttf_parser::gpos::PositioningSubtable;
+use ttf_parser::gsub::SubstitutionSubtable;
+use ttf_parser::opentype_layout::LayoutTable;
+use ttf_parser::{Face, GlyphId, OutlineBuilder, Tag};
+
+/// How well one table decoded.
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Health {
+    Ok,
+    /// The table parsed, but parts of it were skipped.
+    Partial,
+    /// The table is in the font but couldn't be parsed at all, so sections
+    /// relying on it report nothing.
+    Failed,
+}
+
+#[derive(Serialize)]
+pub struct TableHealth {
+    pub tag: String,
+    pub health: Health,
+    /// What was skipped, or why parsing failed; empty when all is well.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub detail: String,
+}
+
+/// The decoding status of every table in the font that fontinfo reads, in
+/// table directory order, so an empty section can be told apart from a
+/// table the parser choked on.
+#[derive(Serialize)]
+pub struct HealthReport {
+    pub tables: Vec<TableHealth>,
+}
+
+impl HealthReport {
+    pub fn new(face: &Face) -> Self {
+        let raw = face.raw_face();
+        let tables = raw
+            .table_records
+            .into_iter()
+            .filter_map(|record| {
+                let parsed = parsed(face, record.tag)?;
+                let (health, detail) = if raw.table(record.tag).is_none() {
+                    (
+                        Health::Failed,
+                        "extends past the end of the file".to_string(),
+                    )
+                } else if !parsed {
+                    (Health::Failed, failure(face, record.tag, record.length))
+                } else {
+                    match skipped(face, record.tag) {
+                        Some(detail) => (Health::Partial, detail),
+                        None => (Health::Ok, String::new()),
+                    }
+                };
+                Some(TableHealth {
+                    tag: record.tag.to_string(),
+                    health,
+                    detail,
+                })
+            })
+            .collect();
+        HealthReport { tables }
+    }
+
+    pub fn count(&self, health: Health) -> usize {
+        self.tables
+            .iter()
+            .filter(|table| table.health == health)
+            .count()
+    }
+
+    /// Tables that didn't decode in full.
+    pub fn problems(&self) -> impl Iterator<Item = &TableHealth> {
+        self.tables
+            .iter()
+            .filter(|table| table.health != Health::Ok)
+    }
+}
+
+/// Whether the parser accepted a table; `None` for tables fontinfo
+/// doesn't read.
+fn parsed(face: &Face, tag: Tag) -> Option<bool> {
+    let t = face.tables();
+    Some(match &tag.to_bytes() {
+        // A face without these doesn't parse at all.
+        b"head" | b"hhea" | b"maxp" => true,
+        b"cmap" => t.cmap.is_some(),
+        b"glyf" | b"loca" => t.glyf.is_some(),
+        b"CFF " => t.cff.is_some(),
+        b"CFF2" => t.cff2.is_some(),
+        b"hmtx" => t.hmtx.is_some(),
+        b"vhea" => t.vhea.is_some(),
+        b"vmtx" => t.vmtx.is_some(),
+        b"VORG" => t.vorg.is_some(),
+        b"name" => t.name.is_some(),
+        b"OS/2" => t.os2.is_some(),
+        b"post" => t.post.is_some(),
+        b"kern" => t.kern.is_some(),
+        b"GDEF" => t.gdef.is_some(),
+        b"GSUB" => t.gsub.is_some(),
+        b"GPOS" => t.gpos.is_some(),
+        b"MATH" => t.math.is_some(),
+        b"STAT" => t.stat.is_some(),
+        b"fvar" => t.fvar.is_some(),
+        b"avar" => t.avar.is_some(),
+        b"gvar" => t.gvar.is_some(),
+        b"HVAR" => t.hvar.is_some(),
+        b"MVAR" => t.mvar.is_some(),
+        b"VVAR" => t.vvar.is_some(),
+        b"COLR" => t.colr.is_some(),
+        b"CPAL" => ColorReport::new(face).is_some_and(|report| report.cpal.is_some()),
+        b"SVG " => t.svg.is_some(),
+        b"sbix" => t.sbix.is_some(),
+        b"CBDT" | b"CBLC" => t.cbdt.is_some(),
+        b"EBDT" | b"EBLC" => t.ebdt.is_some(),
+        b"ankr" => t.ankr.is_some(),
+        b"feat" => t.feat.is_some(),
+        b"kerx" => t.kerx.is_some(),
+        b"morx" => t.morx.is_some(),
+        b"trak" => t.trak.is_some(),
+        _ => return None,
+    })
+}
+
+/// Why a table that is present failed to parse, as far as can be told
+/// from outside the parser.
+fn failure(face: &Face, tag: Tag, length: u32) -> String {
+    let raw = face.raw_face();
+    let present = |tag: &[u8; 4]| raw.table(Tag::from_bytes(tag)).is_some();
+    match &tag.to_bytes() {
+        b"glyf" if !present(b"loca") => "no loca table".to_string(),
+        b"loca" if !present(b"glyf") => "no glyf table".to_string(),
+        b"glyf" | b"loca" => "loca doesn't match head and maxp".to_string(),
+        b"hmtx" => "shorter than hhea numberOfHMetrics requires".to_string(),
+        b"vmtx" if !present(b"vhea") => "no vhea table".to_string(),
+        b"CBDT" if !present(b"CBLC") => "no CBLC table".to_string(),
+        b"CBLC" if !present(b"CBDT") => "no CBDT table".to_string(),
+        b"EBDT" if !present(b"EBLC") => "no EBLC table".to_string(),
+        b"EBLC" if !present(b"EBDT") => "no EBDT table".to_string(),
+        _ if length == 0 => "empty".to_string(),
+        _ => format!("malformed or unsupported version ({} bytes)", length),
+    }
+}
+
+/// What the parser skipped in a table it accepted, if anything.
+fn skipped(face: &Face, tag: Tag) -> Option<String> {
+    let t = face.tables();
+    match &tag.to_bytes() {
+        b"cmap" => {
+            let subtables = t.cmap?.subtables;
+            let unsupported = (0..subtables.len())
+                .filter(|&i| {
+                    subtables
+                        .get(i)
+                        .is_none_or(|subtable| matches!(subtable.format, Format::MixedCoverage))
+                })
+                .count();
+            (unsupported > 0).then(|| {
+                format!(
+                    "{} of {} subtables unreadable or in an unsupported format",
+                    unsupported,
+                    subtables.len()
+                )
+            })
+        }
+        b"name" => {
+            let names = t.name?.names;
+            let undecodable = names
+                .into_iter()
+                .filter(|name| name.is_unicode() && name.to_string().is_none())
+                .count();
+            (undecodable > 0).then(|| {
+                format!(
+                    "{} of {} Unicode records aren't valid UTF-16",
+                    undecodable,
+                    names.len()
+                )
+            })
+        }
+        b"GSUB" => layout_skipped(&t.gsub?, |lookup, i| {
+            lookup.subtables.get::<SubstitutionSubtable>(i).is_some()
+        }),
+        b"GPOS" => layout_skipped(&t.gpos?, |lookup, i| {
+            lookup.subtables.get::<PositioningSubtable>(i).is_some()
+        }),
+        b"glyf" => {
+            let broken = (0..face.number_of_glyphs())
+                .map(GlyphId)
+                .filter(|&glyph| {
+                    glyf_range(face, glyph).is_some_and(|(start, end)| end > start)
+                        && face.outline_glyph(glyph, &mut Discard).is_none()
+                })
+                .count();
+            (broken > 0).then(|| format!("{} glyph outlines couldn't be decoded", broken))
+        }
+        _ => None,
+    }
+}
+
+/// Counts the lookups, and subtables of otherwise readable lookups, that
+/// failed to parse.
+fn layout_skipped(
+    table: &LayoutTable,
+    subtable_parses: impl Fn(&ttf_parser::opentype_layout::Lookup, u16) -> bool,
+) -> Option<String> {
+    let mut lookups = 0;
+    let mut subtables = 0;
+    for i in 0..table.lookups.len() {
+        match table.lookups.get(i) {
+            Some(lookup) => {
+                subtables += (0..lookup.subtables.len())
+                    .filter(|&j| !subtable_parses(&lookup, j))
+                    .count();
+            }
+            None => lookups += 1,
+        }
+    }
+    let mut parts = Vec::new();
+    if lookups > 0 {
+        parts.push(format!(
+            "{} of {} lookups unreadable",
+            lookups,
+            table.lookups.len()
+        ));
+    }
+    if subtables > 0 {
+        parts.push(format!(
+            "{} subtables unreadable or of an unknown type",
+            subtables
+        ));
+    }
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Drops an outline, for checking that one decodes.
+struct Discard;
+
+impl OutlineBuilder for Discard {
+    fn move_to(&mut self, _x: f32, _y: f32) {}
+    fn line_to(&mut self, _x: f32, _y: f32) {}
+    fn quad_to(&mut self, _x1: f32, _y1: f32, _x: f32, _y: f32) {}
+    fn curve_to(&mut self, _x1: f32, _y1: f32, _x2: f32, _y2: f32, _x: f32, _y: f32) {}
+    fn close(&mut self) {}
+}
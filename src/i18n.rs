@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::env;
+
+/// Width of the report's box lines, in terminal columns.
+const BOX_WIDTH: usize = 64;
+
+/// Column at which field values start, after `│ ` and the padded label.
+const LABEL_WIDTH: usize = 18;
+
+/// A language the report's labels are translated into.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Lang {
+    En,
+    De,
+    Fr,
+    Ja,
+}
+
+impl Lang {
+    pub fn from_code(code: &str) -> Option<Lang> {
+        match code {
+            "en" => Some(Lang::En),
+            "de" => Some(Lang::De),
+            "fr" => Some(Lang::Fr),
+            "ja" => Some(Lang::Ja),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::De => "de",
+            Lang::Fr => "fr",
+            Lang::Ja => "ja",
+        }
+    }
+
+    /// Picks the language from the POSIX locale variables, e.g.
+    /// `LANG=de_DE.UTF-8`, defaulting to English.
+    pub fn from_env() -> Lang {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|locale| {
+                let code = locale.split(['_', '.', '@', '-']).next()?;
+                Lang::from_code(&code.to_ascii_lowercase())
+            })
+            .unwrap_or(Lang::En)
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Lang::En => include_str!("../locales/en.ftl"),
+            Lang::De => include_str!("../locales/de.ftl"),
+            Lang::Fr => include_str!("../locales/fr.ftl"),
+            Lang::Ja => include_str!("../locales/ja.ftl"),
+        }
+    }
+
+    /// The catalog of OpenType feature descriptions for this language.
+    pub fn feature_source(self) -> &'static str {
+        match self {
+            Lang::En => include_str!("../locales/features/en.ftl"),
+            Lang::De => include_str!("../locales/features/de.ftl"),
+            Lang::Fr => include_str!("../locales/features/fr.ftl"),
+            Lang::Ja => include_str!("../locales/features/ja.ftl"),
+        }
+    }
+}
+
+/// Translated report messages, loaded from the Fluent-style catalogs in
+/// `locales/`. Messages missing from a catalog fall back to English.
+pub struct Catalog {
+    messages: HashMap<&'static str, &'static str>,
+}
+
+impl Catalog {
+    pub fn new(lang: Lang) -> Self {
+        let mut messages = parse(Lang::En.source());
+        if lang != Lang::En {
+            messages.extend(parse(lang.source()));
+        }
+        Catalog { messages }
+    }
+
+    /// Returns the message for `key`, or the key itself if no catalog has it.
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        self.messages.get(key).copied().unwrap_or(key)
+    }
+
+    /// Formats a field label padded to the value column, e.g.
+    /// `│ Family Name:      `.
+    pub fn label(&self, key: &str) -> String {
+        let label = format!("{}:", self.get(key));
+        let padding = LABEL_WIDTH.saturating_sub(display_width(&label)).max(1);
+        format!("│ {}{}", label, " ".repeat(padding))
+    }
+
+    /// Formats a section's opening line, e.g. `┌─ FONT NAMES ─────…`.
+    pub fn header(&self, key: &str) -> String {
+        let title = self.get(key);
+        let rule = BOX_WIDTH.saturating_sub(display_width(title) + 4).max(1);
+        format!("┌─ {} {}", title, "─".repeat(rule))
+    }
+}
+
+/// Parses `key = value` lines, skipping blank lines and `#` comments.
+pub fn parse(source: &'static str) -> HashMap<&'static str, &'static str> {
+    source
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .collect()
+}
+
+/// Counts terminal columns, treating CJK and fullwidth characters as two
+/// columns wide so labels still line up.
+pub fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c as u32 {
+            0x1100..=0x115F
+            | 0x2E80..=0x303E
+            | 0x3041..=0x33FF
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xA000..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6 => 2,
+            _ => 1,
+        })
+        .sum()
+}
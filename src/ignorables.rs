@@ -0,0 +1,142 @@
+use serde::Serialize;
+use ttf_parser::Face;
+
+/// Default-ignorable code points from Unicode's `DerivedCoreProperties.txt`,
+/// grouped for the report, as inclusive ranges. Renderers are expected to
+/// draw nothing for these unless they select or join something. The soft
+/// hyphen is left out: it is drawn as a hyphen when a line breaks at it, so
+/// fonts rightly map it to a visible glyph.
+const GROUPS: &[(u32, u32, &str)] = &[
+    (0x034F, 0x034F, "combining grapheme joiner"),
+    (0x061C, 0x061C, "Arabic letter mark"),
+    (0x115F, 0x1160, "Hangul filler"),
+    (0x17B4, 0x17B5, "Khmer inherent vowel"),
+    (0x180B, 0x180F, "Mongolian variation selector"),
+    (0x200B, 0x200F, "zero-width space, ZWNJ, ZWJ, LRM, RLM"),
+    (0x202A, 0x202E, "bidi embedding and override"),
+    (
+        0x2060,
+        0x206F,
+        "word joiner, invisible operator, bidi isolate",
+    ),
+    (0x3164, 0x3164, "Hangul filler"),
+    (0xFE00, 0xFE0F, "variation selector"),
+    (0xFEFF, 0xFEFF, "zero-width no-break space"),
+    (0xFFA0, 0xFFA0, "Hangul filler"),
+    (0xFFF0, 0xFFF8, "reserved"),
+    (0x1BCA0, 0x1BCA3, "shorthand format control"),
+    (0x1D173, 0x1D17A, "musical format control"),
+    (0xE0000, 0xE007F, "tag"),
+    (0xE0080, 0xE00FF, "reserved"),
+    (0xE0100, 0xE01EF, "variation selector supplement"),
+    (0xE01F0, 0xE0FFF, "reserved"),
+];
+
+/// True for default-ignorable characters, which renderers don't draw.
+pub fn is_ignorable(c: char) -> bool {
+    GROUPS
+        .iter()
+        .any(|&(start, end, _)| (start..=end).contains(&(c as u32)))
+}
+
+/// How the font handles one default-ignorable character.
+#[derive(Clone, Copy, PartialEq)]
+enum Handling {
+    /// Not in the cmap; shapers drop it, simple renderers may show tofu.
+    Unmapped,
+    /// Mapped to a zero-width glyph with no outline, the safe choice.
+    Empty,
+    /// Zero-width, but the glyph has ink that renderers which don't hide
+    /// ignorables will draw over the neighbouring character.
+    ZeroWidthInk,
+    /// Mapped to a glyph with an advance, shifting text wherever the
+    /// character isn't hidden by the shaper.
+    Visible,
+}
+
+/// How serious the font's handling of default ignorables is.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Risk {
+    Low,
+    Medium,
+    High,
+}
+
+impl Risk {
+    pub fn name(self) -> &'static str {
+        match self {
+            Risk::Low => "low",
+            Risk::Medium => "medium",
+            Risk::High => "high",
+        }
+    }
+}
+
+/// A default-ignorable character and the group it belongs to.
+#[derive(Serialize)]
+pub struct Ignorable {
+    #[serde(rename = "char")]
+    pub c: char,
+    pub group: &'static str,
+}
+
+/// How the font handles the default-ignorable characters.
+#[derive(Serialize)]
+pub struct IgnorableReport {
+    pub total: usize,
+    /// Characters mapped to empty zero-width glyphs.
+    pub empty: usize,
+    pub unmapped: usize,
+    pub zero_width_ink: Vec<Ignorable>,
+    pub visible: Vec<Ignorable>,
+    /// High when any ignorable advances the pen, medium when some draw ink
+    /// without advancing, low otherwise.
+    pub risk: Risk,
+}
+
+impl IgnorableReport {
+    pub fn new(face: &Face) -> Self {
+        let mut report = IgnorableReport {
+            total: 0,
+            empty: 0,
+            unmapped: 0,
+            zero_width_ink: Vec::new(),
+            visible: Vec::new(),
+            risk: Risk::Low,
+        };
+        let chars = GROUPS
+            .iter()
+            .flat_map(|&(start, end, group)| (start..=end).map(move |cp| (cp, group)))
+            .filter_map(|(cp, group)| Some((char::from_u32(cp)?, group)));
+        for (c, group) in chars {
+            report.total += 1;
+            match handling(face, c) {
+                Handling::Unmapped => report.unmapped += 1,
+                Handling::Empty => report.empty += 1,
+                Handling::ZeroWidthInk => report.zero_width_ink.push(Ignorable { c, group }),
+                Handling::Visible => report.visible.push(Ignorable { c, group }),
+            }
+        }
+        report.risk = if !report.visible.is_empty() {
+            Risk::High
+        } else if !report.zero_width_ink.is_empty() {
+            Risk::Medium
+        } else {
+            Risk::Low
+        };
+        report
+    }
+}
+
+fn handling(face: &Face, c: char) -> Handling {
+    let Some(glyph_id) = face.glyph_index(c) else {
+        return Handling::Unmapped;
+    };
+    let has_ink = face.glyph_bounding_box(glyph_id).is_some();
+    match face.glyph_hor_advance(glyph_id).unwrap_or(0) {
+        0 if has_ink => Handling::ZeroWidthInk,
+        0 => Handling::Empty,
+        _ => Handling::Visible,
+    }
+}
@@ -0,0 +1,66 @@
+use crate::raster::Raster;
+use serde::Serialize;
+use ttf_parser::Face;
+
+/// Glyphs whose ink sets the darkness score: straight and round capitals
+/// and lowercase, the letters type designers judge a weight's color by.
+const SAMPLE: &str = "HOnoaes";
+
+/// Sample points per em along each axis; fine enough that stroke edges
+/// average out.
+const CELLS_PER_EM: f32 = 100.0;
+
+/// How much of the em and of its own advance a glyph fills.
+#[derive(Serialize)]
+pub struct GlyphInk {
+    pub c: char,
+    /// Filled area as a share of the em square.
+    pub ink: f32,
+    /// Filled area as a share of the advance times the em, the space the
+    /// glyph takes up in a line.
+    pub density: f32,
+}
+
+/// Approximate ink coverage of the sample glyphs and how dark the weight
+/// sets overall.
+#[derive(Serialize)]
+pub struct InkReport {
+    pub glyphs: Vec<GlyphInk>,
+    /// The sample's total ink over the total of its advances times the em,
+    /// from 0 to 1. Comparable across families, unlike weight classes.
+    pub darkness: f32,
+}
+
+impl InkReport {
+    /// Returns `None` for fonts with none of the sample glyphs outlined.
+    pub fn new(face: &Face) -> Option<Self> {
+        let em = f32::from(face.units_per_em());
+        let cell = em / CELLS_PER_EM;
+        let (mut total_ink, mut total_space) = (0.0, 0.0);
+        let mut glyphs = Vec::new();
+        for c in SAMPLE.chars() {
+            let Some(glyph) = face.glyph_index(c) else {
+                continue;
+            };
+            let advance = f32::from(face.glyph_hor_advance(glyph).unwrap_or(0));
+            let Some(raster) = Raster::new(face, glyph).filter(|_| advance > 0.0) else {
+                continue;
+            };
+            let area = raster.area(cell);
+            total_ink += area;
+            total_space += advance * em;
+            glyphs.push(GlyphInk {
+                c,
+                ink: area / (em * em),
+                density: area / (advance * em),
+            });
+        }
+        if glyphs.is_empty() {
+            return None;
+        }
+        Some(InkReport {
+            glyphs,
+            darkness: total_ink / total_space,
+        })
+    }
+}
@@ -0,0 +1,85 @@
+use crate::get_name;
+use crate::variations::named_instances;
+use serde::Serialize;
+use ttf_parser::{Face, Tag};
+
+/// Vertical metrics at one point in the design space, in font units, with
+/// `MVAR` deltas applied.
+#[derive(Serialize)]
+pub struct InstanceMetrics {
+    pub name: String,
+    pub ascender: i16,
+    pub descender: i16,
+    pub line_gap: i16,
+    pub x_height: Option<i16>,
+    pub cap_height: Option<i16>,
+}
+
+impl InstanceMetrics {
+    fn at(face: &Face, name: String) -> Self {
+        InstanceMetrics {
+            name,
+            ascender: face.ascender(),
+            descender: face.descender(),
+            line_gap: face.line_gap(),
+            x_height: face.x_height(),
+            cap_height: face.capital_height(),
+        }
+    }
+
+    /// Ascender to descender plus the line gap: the default line height.
+    pub fn line_height(&self) -> i32 {
+        i32::from(self.ascender) - i32::from(self.descender) + i32::from(self.line_gap)
+    }
+}
+
+/// Vertical metrics at the default location and every named instance.
+#[derive(Serialize)]
+pub struct MetricsTable {
+    pub default: InstanceMetrics,
+    pub instances: Vec<InstanceMetrics>,
+    /// Without `MVAR` the metrics can't vary, so every row matches the
+    /// default.
+    pub has_mvar: bool,
+}
+
+impl MetricsTable {
+    /// Returns `None` for static fonts.
+    pub fn new(face: &Face) -> Option<Self> {
+        let axes = face.variation_axes();
+        if axes.is_empty() {
+            return None;
+        }
+        let instances = named_instances(face)
+            .iter()
+            .enumerate()
+            .map(|(i, instance)| {
+                let mut face = face.clone();
+                for (axis, &value) in axes.into_iter().zip(&instance.coordinates) {
+                    face.set_variation(axis.tag, value);
+                }
+                let name = get_name(&face, instance.subfamily_name_id)
+                    .unwrap_or_else(|| format!("Instance {}", i + 1));
+                InstanceMetrics::at(&face, name)
+            })
+            .collect();
+        // The face may have been set to another location.
+        let mut default = face.clone();
+        for axis in axes {
+            default.set_variation(axis.tag, axis.def_value);
+        }
+        Some(MetricsTable {
+            default: InstanceMetrics::at(&default, "Default".to_string()),
+            instances,
+            has_mvar: face.raw_face().table(Tag::from_bytes(b"MVAR")).is_some(),
+        })
+    }
+
+    /// Instances whose line height differs from the default's.
+    pub fn inconsistent(&self) -> impl Iterator<Item = &InstanceMetrics> {
+        let line_height = self.default.line_height();
+        self.instances
+            .iter()
+            .filter(move |instance| instance.line_height() != line_height)
+    }
+}
@@ -0,0 +1,187 @@
+use crate::layout;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use ttf_parser::gpos::{PairAdjustment, PositioningSubtable};
+use ttf_parser::kern::Format;
+use ttf_parser::{Face, GlyphId, Tag};
+
+/// A kerning pair and its horizontal adjustment in font units.
+#[derive(Serialize)]
+pub struct KernPair {
+    pub left: u16,
+    pub right: u16,
+    pub value: i32,
+}
+
+/// Kerning from the legacy `kern` table and GPOS pair adjustment.
+#[derive(Serialize)]
+pub struct KerningReport {
+    pub kern_table: bool,
+    /// Horizontal, non-variable `kern` subtables; others are ignored.
+    pub kern_subtables: usize,
+    /// Pair adjustment lookups the GPOS `kern` feature uses.
+    pub gpos_lookups: usize,
+    /// Pairs with a non-zero adjustment, sorted by glyph IDs. Class-based
+    /// pairs are expanded into the glyph pairs they cover.
+    pub kern_pairs: Vec<KernPair>,
+    pub gpos_pairs: Vec<KernPair>,
+}
+
+impl KerningReport {
+    pub fn new(face: &Face) -> Self {
+        let glyphs = face.number_of_glyphs();
+        let mut kern_table = false;
+        let mut kern_subtables = 0;
+        let mut kern = BTreeMap::new();
+        if let Some(table) = face.tables().kern {
+            kern_table = true;
+            // Subtables add up, like lookups do.
+            for subtable in table.subtables.into_iter().filter(|subtable| {
+                subtable.horizontal && !subtable.variable && !subtable.has_cross_stream
+            }) {
+                kern_subtables += 1;
+                match &subtable.format {
+                    Format::Format0(format0) => {
+                        for pair in format0.pairs {
+                            *kern.entry((pair.left().0, pair.right().0)).or_insert(0) +=
+                                i32::from(pair.value);
+                        }
+                    }
+                    // State machines kern runs of glyphs, not pairs.
+                    _ if subtable.has_state_machine => {}
+                    // Class-based: look up every pair.
+                    _ => {
+                        for left in 0..glyphs {
+                            for right in 0..glyphs {
+                                if let Some(value) = subtable
+                                    .glyphs_kerning(GlyphId(left), GlyphId(right))
+                                    .filter(|&value| value != 0)
+                                {
+                                    *kern.entry((left, right)).or_insert(0) += i32::from(value);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut gpos_lookups = 0;
+        let mut gpos = BTreeMap::new();
+        if let Some(table) = face.tables().gpos {
+            for index in layout::feature_lookups(&table, Tag::from_bytes(b"kern")) {
+                let Some(lookup) = table.lookups.get(index) else {
+                    continue;
+                };
+                let subtables: Vec<PairAdjustment> = lookup
+                    .subtables
+                    .into_iter::<PositioningSubtable>()
+                    .filter_map(|subtable| match subtable {
+                        PositioningSubtable::Pair(pair) => Some(pair),
+                        _ => None,
+                    })
+                    .collect();
+                if subtables.is_empty() {
+                    continue;
+                }
+                gpos_lookups += 1;
+                for (pair, value) in lookup_pairs(&subtables, glyphs) {
+                    *gpos.entry(pair).or_insert(0) += value;
+                }
+            }
+        }
+
+        KerningReport {
+            kern_table,
+            kern_subtables,
+            gpos_lookups,
+            kern_pairs: non_zero(kern),
+            gpos_pairs: non_zero(gpos),
+        }
+    }
+
+    /// Distinct pairs kerned by either source.
+    pub fn total(&self) -> usize {
+        let mut pairs: Vec<(u16, u16)> = self
+            .kern_pairs
+            .iter()
+            .chain(&self.gpos_pairs)
+            .map(|pair| (pair.left, pair.right))
+            .collect();
+        pairs.sort_unstable();
+        pairs.dedup();
+        pairs.len()
+    }
+
+    /// The adjustment `kern` and GPOS each give a pair, if any.
+    pub fn pair(&self, left: GlyphId, right: GlyphId) -> (Option<i32>, Option<i32>) {
+        let find = |pairs: &[KernPair]| {
+            pairs
+                .binary_search_by_key(&(left.0, right.0), |pair| (pair.left, pair.right))
+                .ok()
+                .map(|index| pairs[index].value)
+        };
+        (find(&self.kern_pairs), find(&self.gpos_pairs))
+    }
+}
+
+/// The non-zero pairs one lookup kerns. Within a lookup only the first
+/// subtable with a record for a pair applies, so that specific pairs can
+/// override class kerning in later subtables.
+fn lookup_pairs(subtables: &[PairAdjustment], glyphs: u16) -> Vec<((u16, u16), i32)> {
+    let mut pairs = Vec::new();
+    let mut values: Vec<Option<i16>> = vec![None; usize::from(glyphs)];
+    for left in 0..glyphs {
+        values.fill(None);
+        for subtable in subtables {
+            let Some(coverage_index) = subtable.coverage().get(GlyphId(left)) else {
+                continue;
+            };
+            let record = |right: u16| match subtable {
+                PairAdjustment::Format1 { sets, .. } => sets
+                    .get(coverage_index)?
+                    .get(GlyphId(right))
+                    .map(|(first, _)| first.x_advance),
+                PairAdjustment::Format2 {
+                    classes, matrix, ..
+                } => {
+                    let left_class = classes.0.get(GlyphId(left));
+                    let right_class = classes.1.get(GlyphId(right));
+                    matrix
+                        .get((left_class, right_class))
+                        .map(|(first, _)| first.x_advance)
+                }
+            };
+            for (right, value) in (0..glyphs).zip(values.iter_mut()) {
+                if value.is_none() {
+                    *value = record(right);
+                }
+            }
+        }
+        pairs.extend(
+            (0..glyphs)
+                .zip(&values)
+                .filter_map(|(right, value)| Some(((left, right), i32::from((*value)?))))
+                .filter(|&(_, value)| value != 0),
+        );
+    }
+    pairs
+}
+
+fn non_zero(pairs: BTreeMap<(u16, u16), i32>) -> Vec<KernPair> {
+    pairs
+        .into_iter()
+        .filter(|&(_, value)| value != 0)
+        .map(|((left, right), value)| KernPair { left, right, value })
+        .collect()
+}
+
+/// Finds the glyph a `--kern-pair` side names: a single character through
+/// the cmap, or else a glyph name such as `comma` or `A.sc`.
+pub fn find_glyph(face: &Face, side: &str) -> Option<GlyphId> {
+    let mut chars = side.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => face.glyph_index(c),
+        _ => face.glyph_index_by_name(side),
+    }
+}
@@ -0,0 +1,141 @@
+use ttf_parser::Face;
+
+/// Languages with the characters their orthography needs, after CLDR's main
+/// exemplar sets. Cased scripts list lowercase letters only; the uppercase
+/// forms are required as well.
+const LANGUAGES: &[(&str, &str, &str)] = &[
+    ("en", "English", "abcdefghijklmnopqrstuvwxyz"),
+    ("de", "German", "abcdefghijklmnopqrstuvwxyzäöüß"),
+    ("fr", "French", "abcdefghijklmnopqrstuvwxyzàâæçéèêëîïôœùûüÿ"),
+    ("es", "Spanish", "abcdefghijklmnopqrstuvwxyzáéíñóúü"),
+    ("it", "Italian", "abcdefghijklmnopqrstuvwxyzàéèìóòù"),
+    ("pt", "Portuguese", "abcdefghijklmnopqrstuvwxyzáàâãçéêíóôõú"),
+    ("nl", "Dutch", "abcdefghijklmnopqrstuvwxyzáäéëíïóöúü"),
+    ("ca", "Catalan", "abcdefghijklmnopqrstuvwxyzàçéèíïòóúü"),
+    ("sv", "Swedish", "abcdefghijklmnopqrstuvwxyzåäö"),
+    ("da", "Danish", "abcdefghijklmnopqrstuvwxyzæøå"),
+    ("nb", "Norwegian", "abcdefghijklmnopqrstuvwxyzæøå"),
+    ("fi", "Finnish", "abcdefghijklmnopqrstuvwxyzåäöšž"),
+    ("is", "Icelandic", "abcdefghijklmnopqrstuvwxyzáðéíóúýþæö"),
+    ("pl", "Polish", "abcdefghijklmnopqrstuvwxyząćęłńóśźż"),
+    ("cs", "Czech", "abcdefghijklmnopqrstuvwxyzáčďéěíňóřšťúůýž"),
+    (
+        "sk",
+        "Slovak",
+        "abcdefghijklmnopqrstuvwxyzáäčďéíĺľňóôŕšťúýž",
+    ),
+    ("hu", "Hungarian", "abcdefghijklmnopqrstuvwxyzáéíóöőúüű"),
+    ("ro", "Romanian", "abcdefghijklmnopqrstuvwxyzăâîșț"),
+    ("hr", "Croatian", "abcdefghijklmnopqrstuvwxyzčćđšž"),
+    ("sl", "Slovenian", "abcdefghijklmnopqrstuvwxyzčšž"),
+    ("tr", "Turkish", "abcdefghijklmnopqrstuvwxyzçğıöşü"),
+    ("lt", "Lithuanian", "abcdefghijklmnopqrstuvwxyząčęėįšųūž"),
+    ("lv", "Latvian", "abcdefghijklmnopqrstuvwxyzāčēģīķļņšūž"),
+    ("et", "Estonian", "abcdefghijklmnopqrstuvwxyzäöõüšž"),
+    ("id", "Indonesian", "abcdefghijklmnopqrstuvwxyz"),
+    ("sw", "Swahili", "abcdefghijklmnopqrstuvwxyz"),
+    (
+        "vi",
+        "Vietnamese",
+        "abcdefghijklmnopqrstuvwxyzàáâãèéêìíòóôõùúýăđĩũơưạảấầẩẫậắằẳẵặẹẻẽếềểễệỉịọỏốồổỗộớờởỡợụủứừửữựỳỵỷỹ",
+    ),
+    ("ru", "Russian", "абвгдеёжзийклмнопрстуфхцчшщъыьэюя"),
+    ("uk", "Ukrainian", "абвгґдеєжзиіїйклмнопрстуфхцчшщьюя"),
+    ("bg", "Bulgarian", "абвгдежзийклмнопрстуфхцчшщъьюя"),
+    ("sr", "Serbian", "абвгдђежзијклљмнњопрстћуфхцчџш"),
+    ("el", "Greek", "αβγδεζηθικλμνξοπρσςτυφχψωάέήίόύώϊϋΐΰ"),
+    ("he", "Hebrew", "אבגדהוזחטיכךלמםנןסעפףצץקרשת"),
+    ("ar", "Arabic", "ءآأؤإئابةتثجحخدذرزسشصضطظعغفقكلمنهوىي"),
+    ("fa", "Persian", "ءآأؤئابتثجحخدذرزسشصضطظعغفقلمنهوپچژکگی"),
+    (
+        "hi",
+        "Hindi",
+        "ँंःअआइईउऊऋएऐओऔकखगघङचछजझञटठडढणतथदधनपफबभमयरलवशषसहािीुूृेैोौ्",
+    ),
+    (
+        "th",
+        "Thai",
+        "กขฃคฅฆงจฉชซฌญฎฏฐฑฒณดตถทธนบปผฝพฟภมยรลวศษสหฬอฮะัาำิีึืุูเแโใไ็่้๊๋์",
+    ),
+    ("ka", "Georgian", "აბგდევზთიკლმნოპჟრსტუფქღყშჩცძწჭხჯჰ"),
+    ("hy", "Armenian", "աբգդեզէըթժիլխծկհձղճմյնշոչպջռսվտրցւփքօֆ"),
+];
+
+/// Which of the bundled languages the font has every character for.
+pub struct LanguageSupport {
+    /// Names of the supported languages, in table order.
+    pub supported: Vec<&'static str>,
+    pub total: usize,
+}
+
+impl LanguageSupport {
+    pub fn new(face: &Face) -> Self {
+        let supported = LANGUAGES
+            .iter()
+            .filter(|(_, _, exemplars)| {
+                exemplars
+                    .chars()
+                    .flat_map(|c| [c, uppercase(c)])
+                    .all(|c| face.glyph_index(c).is_some())
+            })
+            .map(|&(_, name, _)| name)
+            .collect();
+        LanguageSupport {
+            supported,
+            total: LANGUAGES.len(),
+        }
+    }
+
+    /// Share of the bundled languages that are supported, in percent.
+    pub fn percent(&self) -> f32 {
+        self.supported.len() as f32 * 100.0 / self.total as f32
+    }
+}
+
+/// The characters one bundled language needs that the font lacks.
+pub struct LocaleCoverage {
+    pub code: &'static str,
+    pub name: &'static str,
+    /// Missing letters, each lowercase letter followed by its uppercase
+    /// form where both are missing.
+    pub missing: Vec<char>,
+}
+
+impl LocaleCoverage {
+    /// Checks the language with the code `code`, e.g. `fr`, or returns
+    /// `None` for codes not in the bundled table.
+    pub fn new(face: &Face, code: &str) -> Option<Self> {
+        let &(code, name, exemplars) = LANGUAGES.iter().find(|(c, _, _)| *c == code)?;
+        let mut missing: Vec<char> = exemplars
+            .chars()
+            .flat_map(|c| [c, uppercase(c)])
+            .filter(|&c| face.glyph_index(c).is_none())
+            .collect();
+        // Letters without an uppercase form appear twice.
+        missing.dedup();
+        Some(LocaleCoverage {
+            code,
+            name,
+            missing,
+        })
+    }
+
+    pub fn passed(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// True when `code` names a bundled language.
+pub fn is_known(code: &str) -> bool {
+    LANGUAGES.iter().any(|(c, _, _)| *c == code)
+}
+
+/// The single-character uppercase form of `c`, or `c` itself for letters
+/// without one, such as ß and ΐ, whose uppercase needs several characters.
+pub fn uppercase(c: char) -> char {
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(u), None) => u,
+        _ => c,
+    }
+}
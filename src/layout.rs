@@ -0,0 +1,428 @@
+use crate::get_name;
+use crate::reader::Reader;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use ttf_parser::gpos::PositioningSubtable;
+use ttf_parser::gsub::{LigatureSubstitution, SingleSubstitution, SubstitutionSubtable};
+use ttf_parser::opentype_layout::{Coverage, LayoutTable};
+use ttf_parser::{Face, GlyphId, Tag};
+
+/// A feature record read directly from a GSUB/GPOS FeatureList, for data
+/// ttf-parser doesn't expose such as feature parameters.
+pub struct RawFeature<'a> {
+    pub tag: Tag,
+    /// The FeatureList, which some older fonts wrongly use as the base
+    /// for feature parameter offsets.
+    list: &'a [u8],
+    /// Offset of the Feature table from the start of the FeatureList.
+    offset: usize,
+}
+
+impl<'a> RawFeature<'a> {
+    /// Returns the FeatureParams data, trying the spec-mandated base (the
+    /// Feature table) first and the FeatureList as a fallback for fonts
+    /// built with the historical offset bug.
+    pub fn params(&self, is_valid: impl Fn(&[u8]) -> bool) -> Option<&'a [u8]> {
+        let params_offset = Reader::at(self.list, self.offset).read_u16()? as usize;
+        if params_offset == 0 {
+            return None;
+        }
+        [self.offset + params_offset, params_offset]
+            .into_iter()
+            .filter_map(|start| self.list.get(start..))
+            .find(|data| is_valid(data))
+    }
+}
+
+/// Reads every feature record in a GSUB or GPOS table.
+pub fn raw_features(table: &[u8]) -> Vec<RawFeature<'_>> {
+    let mut r = Reader::new(table);
+    r.skip(6); // version, scriptListOffset
+    let Some(list) = r.read_u16().and_then(|o| table.get(o as usize..)) else {
+        return Vec::new();
+    };
+
+    let mut r = Reader::new(list);
+    let count = r.read_u16().unwrap_or(0);
+    (0..count)
+        .map_while(|_| {
+            let tag = r.read_bytes(4)?;
+            let offset = r.read_u16()? as usize;
+            Some(RawFeature {
+                tag: Tag::from_bytes(&[tag[0], tag[1], tag[2], tag[3]]),
+                list,
+                offset,
+            })
+        })
+        .collect()
+}
+
+/// Designer-supplied names of the GSUB stylistic sets (`ss01`–`ss20`) and
+/// character variants (`cv01`–`cv99`), read from their FeatureParams,
+/// keyed by tag. Both kinds of params start with a zero version or format
+/// and the name ID of the UI label.
+pub fn ui_names(face: &Face) -> HashMap<Tag, String> {
+    let Some(gsub) = face.raw_face().table(Tag::from_bytes(b"GSUB")) else {
+        return HashMap::new();
+    };
+    let mut names = HashMap::new();
+    for feature in raw_features(gsub) {
+        let bytes = feature.tag.to_bytes();
+        let numbered = bytes[2..].iter().all(u8::is_ascii_digit);
+        if !numbered || !matches!(&bytes[..2], b"ss" | b"cv") || names.contains_key(&feature.tag) {
+            continue;
+        }
+        let name_id = |data: &[u8]| {
+            let mut r = Reader::new(data);
+            match (r.read_u16(), r.read_u16()) {
+                (Some(0), Some(name_id)) => Some(name_id),
+                _ => None,
+            }
+        };
+        let name = feature
+            .params(|data| name_id(data).and_then(|id| get_name(face, id)).is_some())
+            .and_then(name_id)
+            .and_then(|id| get_name(face, id));
+        if let Some(name) = name {
+            names.insert(feature.tag, name);
+        }
+    }
+    names
+}
+
+/// How much of the font a GSUB or GPOS feature acts on.
+#[derive(Serialize)]
+pub struct FeatureImpact {
+    /// Distinct lookups referenced by any feature record with the tag.
+    pub lookups: usize,
+    /// Distinct glyphs in those lookups' coverage tables, i.e. the glyphs
+    /// the feature can substitute or position. Pair and contextual lookups
+    /// only count the first glyph of each pair or context.
+    pub glyphs: usize,
+}
+
+/// Measures every GSUB feature, keyed by tag.
+pub fn gsub_impacts(table: &LayoutTable) -> HashMap<Tag, FeatureImpact> {
+    impacts(table, |subtables, glyphs| {
+        for subtable in subtables.into_iter::<SubstitutionSubtable>() {
+            add_coverage(subtable.coverage(), glyphs);
+        }
+    })
+}
+
+/// Measures every GPOS feature, keyed by tag. Mark attachment counts the
+/// base, ligature or mark glyphs as well as the attached marks.
+pub fn gpos_impacts(table: &LayoutTable) -> HashMap<Tag, FeatureImpact> {
+    impacts(table, |subtables, glyphs| {
+        for subtable in subtables.into_iter::<PositioningSubtable>() {
+            add_coverage(subtable.coverage(), glyphs);
+            match subtable {
+                PositioningSubtable::MarkToBase(t) => add_coverage(t.base_coverage, glyphs),
+                PositioningSubtable::MarkToLigature(t) => add_coverage(t.ligature_coverage, glyphs),
+                PositioningSubtable::MarkToMark(t) => add_coverage(t.mark2_coverage, glyphs),
+                _ => {}
+            }
+        }
+    })
+}
+
+/// Glyphs that GPOS lookups position as combining marks.
+#[derive(Default)]
+pub struct MarkAttachment {
+    /// Marks attached to base or ligature glyphs.
+    pub to_base: BTreeSet<GlyphId>,
+    /// Marks attached to a preceding mark, which lets them stack.
+    pub to_mark: BTreeSet<GlyphId>,
+}
+
+impl MarkAttachment {
+    /// Collects the mark coverage of every mark attachment lookup, whether
+    /// or not a feature references it.
+    pub fn new(table: &LayoutTable) -> Self {
+        let mut attachment = MarkAttachment::default();
+        for lookup in table.lookups {
+            for subtable in lookup.subtables.into_iter::<PositioningSubtable>() {
+                match subtable {
+                    PositioningSubtable::MarkToBase(t) => {
+                        add_coverage(t.mark_coverage, &mut attachment.to_base)
+                    }
+                    PositioningSubtable::MarkToLigature(t) => {
+                        add_coverage(t.mark_coverage, &mut attachment.to_base)
+                    }
+                    PositioningSubtable::MarkToMark(t) => {
+                        add_coverage(t.mark1_coverage, &mut attachment.to_mark)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        attachment
+    }
+}
+
+/// Returns true when a ligature lookup of the GSUB feature `feature`
+/// replaces exactly `glyphs`, as `ccmp` does when it composes a base and
+/// its marks into a precomposed glyph.
+pub fn has_ligature(table: &LayoutTable, feature: Tag, glyphs: &[GlyphId]) -> bool {
+    let Some((&first, rest)) = glyphs.split_first() else {
+        return false;
+    };
+    feature_lookups(table, feature)
+        .into_iter()
+        .filter_map(|index| table.lookups.get(index))
+        .flat_map(|lookup| lookup.subtables.into_iter::<SubstitutionSubtable>())
+        .any(|subtable| {
+            let SubstitutionSubtable::Ligature(t) = subtable else {
+                return false;
+            };
+            t.coverage
+                .get(first)
+                .and_then(|index| t.ligature_sets.get(index))
+                .is_some_and(|set| {
+                    set.into_iter()
+                        .any(|ligature| ligature.components.into_iter().eq(rest.iter().copied()))
+                })
+        })
+}
+
+/// Lookup indices of every feature record with the tag `feature`.
+pub fn feature_lookups(table: &LayoutTable, feature: Tag) -> BTreeSet<u16> {
+    table
+        .features
+        .into_iter()
+        .filter(|f| f.tag == feature)
+        .flat_map(|f| f.lookup_indices)
+        .collect()
+}
+
+/// Lookup indices of `feature` in one language system of a script, or
+/// `None` when the script lacks that language system. A language system
+/// without the feature gives an empty set.
+pub fn lang_sys_lookups(
+    table: &LayoutTable,
+    script: Tag,
+    lang_sys: Tag,
+    feature: Tag,
+) -> Option<BTreeSet<u16>> {
+    let script = table.scripts.into_iter().find(|s| s.tag == script)?;
+    let lang_sys = script.languages.into_iter().find(|l| l.tag == lang_sys)?;
+    Some(
+        lang_sys
+            .feature_indices
+            .into_iter()
+            .filter_map(|index| table.features.get(index))
+            .filter(|f| f.tag == feature)
+            .flat_map(|f| f.lookup_indices)
+            .collect(),
+    )
+}
+
+/// True when a GSUB subtable of one of `lookups` covers `glyph`, i.e. the
+/// lookup can substitute it.
+pub fn gsub_covers(table: &LayoutTable, lookups: &BTreeSet<u16>, glyph: GlyphId) -> bool {
+    lookups
+        .iter()
+        .filter_map(|&index| table.lookups.get(index))
+        .flat_map(|lookup| lookup.subtables.into_iter::<SubstitutionSubtable>())
+        .any(|subtable| subtable.coverage().contains(glyph))
+}
+
+/// Applies the single substitutions of `lookups`, in lookup order, to
+/// `glyph`. Other lookup types leave it unchanged.
+pub fn single_substitute(table: &LayoutTable, lookups: &BTreeSet<u16>, glyph: GlyphId) -> GlyphId {
+    let mut glyph = glyph;
+    for lookup in lookups.iter().filter_map(|&index| table.lookups.get(index)) {
+        for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+            let SubstitutionSubtable::Single(single) = subtable else {
+                continue;
+            };
+            let substitute = match single {
+                SingleSubstitution::Format1 { coverage, delta } => coverage
+                    .get(glyph)
+                    .map(|_| GlyphId((glyph.0 as i32 + delta as i32) as u16)),
+                SingleSubstitution::Format2 {
+                    coverage,
+                    substitutes,
+                } => coverage.get(glyph).and_then(|index| substitutes.get(index)),
+            };
+            // The first subtable covering the glyph applies.
+            if let Some(substitute) = substitute {
+                glyph = substitute;
+                break;
+            }
+        }
+    }
+    glyph
+}
+
+/// Applies every GSUB ligature lookup to the glyph run in lookup order,
+/// the way a shaper would when all of them are enabled. Lookup flags and
+/// contextual lookups are ignored, which is enough for the plain ligatures
+/// emoji fonts use to form sequences.
+pub fn apply_ligatures(face: &Face, mut run: Vec<GlyphId>) -> Vec<GlyphId> {
+    let Some(gsub) = face.tables().gsub else {
+        return run;
+    };
+    for lookup in gsub.lookups {
+        let mut i = 0;
+        while i < run.len() {
+            // The first subtable that matches at a position is applied.
+            let matched = lookup
+                .subtables
+                .into_iter::<SubstitutionSubtable>()
+                .find_map(|subtable| match subtable {
+                    SubstitutionSubtable::Ligature(ligatures) => {
+                        match_ligature(&ligatures, &run[i..])
+                    }
+                    _ => None,
+                });
+            if let Some((glyph, len)) = matched {
+                run.splice(i..i + len, [glyph]);
+            }
+            i += 1;
+        }
+    }
+    run
+}
+
+/// Returns the ligature glyph formed at the start of `run` and the number
+/// of glyphs it replaces.
+fn match_ligature(ligatures: &LigatureSubstitution, run: &[GlyphId]) -> Option<(GlyphId, usize)> {
+    let index = ligatures.coverage.get(run[0])?;
+    let set = ligatures.ligature_sets.get(index)?;
+    set.into_iter().find_map(|ligature| {
+        let components = &run[1..];
+        let len = ligature.components.len() as usize;
+        let matches = components.len() >= len
+            && ligature
+                .components
+                .into_iter()
+                .eq(components[..len].iter().copied());
+        matches.then_some((ligature.glyph, len + 1))
+    })
+}
+
+fn impacts<'a>(
+    table: &LayoutTable<'a>,
+    add_lookup: impl Fn(ttf_parser::opentype_layout::LookupSubtables<'a>, &mut BTreeSet<GlyphId>),
+) -> HashMap<Tag, FeatureImpact> {
+    // The same tag can appear in several feature records, one per script
+    // or language system, each with its own lookups.
+    let mut lookups: HashMap<Tag, BTreeSet<u16>> = HashMap::new();
+    for feature in table.features {
+        lookups
+            .entry(feature.tag)
+            .or_default()
+            .extend(feature.lookup_indices);
+    }
+
+    lookups
+        .into_iter()
+        .map(|(tag, indices)| {
+            let mut glyphs = BTreeSet::new();
+            for &index in &indices {
+                if let Some(lookup) = table.lookups.get(index) {
+                    add_lookup(lookup.subtables, &mut glyphs);
+                }
+            }
+            let impact = FeatureImpact {
+                lookups: indices.len(),
+                glyphs: glyphs.len(),
+            };
+            (tag, impact)
+        })
+        .collect()
+}
+
+/// Adds to `glyphs` every glyph GSUB can turn them into, following all
+/// lookups until nothing new is reached. Context and feature selection are
+/// ignored, so the closure may hold more than any text produces but never
+/// less.
+pub fn gsub_closure(table: &LayoutTable, glyphs: &mut BTreeSet<GlyphId>) {
+    loop {
+        let before = glyphs.len();
+        for lookup in table.lookups {
+            for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+                let mut reached = Vec::new();
+                for &glyph in glyphs.iter() {
+                    match &subtable {
+                        SubstitutionSubtable::Single(SingleSubstitution::Format1 {
+                            coverage,
+                            delta,
+                        }) => {
+                            if coverage.contains(glyph) {
+                                reached.push(GlyphId((glyph.0 as i32 + *delta as i32) as u16));
+                            }
+                        }
+                        SubstitutionSubtable::Single(SingleSubstitution::Format2 {
+                            coverage,
+                            substitutes,
+                        }) => reached
+                            .extend(coverage.get(glyph).and_then(|index| substitutes.get(index))),
+                        SubstitutionSubtable::Multiple(t) => {
+                            if let Some(sequence) = t
+                                .coverage
+                                .get(glyph)
+                                .and_then(|index| t.sequences.get(index))
+                            {
+                                reached.extend(sequence.substitutes);
+                            }
+                        }
+                        SubstitutionSubtable::Alternate(t) => {
+                            if let Some(set) = t
+                                .coverage
+                                .get(glyph)
+                                .and_then(|index| t.alternate_sets.get(index))
+                            {
+                                reached.extend(set.alternates);
+                            }
+                        }
+                        SubstitutionSubtable::Ligature(t) => {
+                            if let Some(set) = t
+                                .coverage
+                                .get(glyph)
+                                .and_then(|index| t.ligature_sets.get(index))
+                            {
+                                reached.extend(
+                                    set.into_iter()
+                                        .filter(|ligature| {
+                                            ligature
+                                                .components
+                                                .into_iter()
+                                                .all(|component| glyphs.contains(&component))
+                                        })
+                                        .map(|ligature| ligature.glyph),
+                                );
+                            }
+                        }
+                        SubstitutionSubtable::ReverseChainSingle(t) => reached.extend(
+                            t.coverage
+                                .get(glyph)
+                                .and_then(|index| t.substitutes.get(index)),
+                        ),
+                        // Contextual lookups only call other lookups, which
+                        // are followed on their own.
+                        SubstitutionSubtable::Context(_)
+                        | SubstitutionSubtable::ChainContext(_) => {}
+                    }
+                }
+                glyphs.extend(reached);
+            }
+        }
+        if glyphs.len() == before {
+            break;
+        }
+    }
+}
+
+/// Adds every glyph `coverage` lists to `glyphs`.
+pub fn add_coverage(coverage: Coverage, glyphs: &mut BTreeSet<GlyphId>) {
+    match coverage {
+        Coverage::Format1 { glyphs: list } => glyphs.extend(list),
+        Coverage::Format2 { records } => {
+            for record in records {
+                glyphs.extend((record.start.0..=record.end.0).map(GlyphId));
+            }
+        }
+    }
+}
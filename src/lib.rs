@@ -0,0 +1,117 @@
+//! Font inspection: names, metrics, OpenType features, scripts and the
+//! coverage audits behind the `fontinfo` command.
+//!
+//! ```no_run
+//! let data = std::fs::read("font.ttf").unwrap();
+//! let report = fontinfo::inspect(&data).unwrap();
+//! if let Some(names) = &report.names {
+//!     println!("{:?}", names.family);
+//! }
+//! ```
+
+pub mod advances;
+pub mod african;
+pub mod axes;
+pub mod badge;
+pub mod base64;
+pub mod blocks;
+pub mod case;
+pub mod cff;
+pub mod charnames;
+pub mod checks;
+pub mod color;
+pub mod config;
+pub mod conformance;
+pub mod css;
+pub mod cyrillic;
+pub mod designspace;
+pub mod detect;
+pub mod disambiguation;
+pub mod embedding;
+pub mod emoji;
+pub mod feature_variations;
+pub mod features;
+pub mod footprint;
+pub mod gif;
+pub mod glyph;
+pub mod greek;
+pub mod head;
+pub mod health;
+pub mod i18n;
+pub mod ignorables;
+pub mod inflate;
+pub mod ink;
+pub mod instance_metrics;
+pub mod kerning;
+pub mod languages;
+pub mod layout;
+pub mod ligatures;
+pub mod marks;
+pub mod math;
+pub mod mirroring;
+pub mod name_table;
+pub mod normalization;
+pub mod opsz;
+pub mod os2;
+pub mod outlines;
+pub mod pdf;
+pub mod planes;
+pub mod png;
+pub mod post;
+pub mod profile;
+pub mod proportions;
+pub mod raster;
+pub mod reader;
+pub mod render;
+pub mod report;
+pub mod sarif;
+pub mod script_features;
+pub mod security;
+pub mod sha256;
+pub mod size;
+pub mod slicing;
+pub mod special_cases;
+pub mod stems;
+pub mod subsets;
+pub mod substitutions;
+pub mod svg;
+pub mod tables;
+pub mod tabular;
+pub mod terminal;
+pub mod text;
+pub mod timestamp;
+pub mod ttx;
+pub mod turkic;
+pub mod unicode;
+pub mod variations;
+pub mod vietnamese;
+
+use config::Config;
+use features::FeatureRegistry;
+use i18n::{Catalog, Lang};
+use report::{FontReport, Section};
+use ttf_parser::{Face, FaceParsingError};
+
+/// Parses the first face in `data` and runs every section of the default
+/// report, with feature descriptions in English and no user config.
+pub fn inspect(data: &[u8]) -> Result<FontReport, FaceParsingError> {
+    let face = Face::parse(data, 0)?;
+    let config = Config::default();
+    let catalog = Catalog::new(Lang::En);
+    let features = FeatureRegistry::new(Lang::En, &catalog, &config);
+    Ok(FontReport::new(
+        &face,
+        &config,
+        &features,
+        &Section::ALL,
+        false,
+    ))
+}
+
+/// Returns the first name record with `name_id` that decodes to a string.
+pub fn get_name(face: &Face, name_id: u16) -> Option<String> {
+    face.names()
+        .into_iter()
+        .filter(|n| n.name_id == name_id)
+        .find_map(|n| n.to_string())
+}
@@ -0,0 +1,109 @@
+use crate::layout;
+use crate::unicode::covered_chars;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use ttf_parser::gsub::SubstitutionSubtable;
+use ttf_parser::{Face, GlyphId, Tag};
+
+/// GSUB features that form ligatures: standard, required, contextual,
+/// discretionary and historical.
+const FEATURES: [&[u8; 4]; 5] = [b"liga", b"rlig", b"clig", b"dlig", b"hlig"];
+
+/// One ligature substitution.
+#[derive(Serialize)]
+pub struct Ligature {
+    /// The components, each as the character the cmap maps to its glyph,
+    /// or its glyph name where no character does.
+    pub components: Vec<String>,
+    /// The ligature glyph's name, or its ID.
+    pub glyph: String,
+    /// The character the cmap maps to the ligature glyph, such as U+FB01
+    /// for fi, if any.
+    pub c: Option<char>,
+}
+
+/// The ligatures one feature forms.
+#[derive(Serialize)]
+pub struct FeatureLigatures {
+    pub feature: String,
+    pub ligatures: Vec<Ligature>,
+}
+
+/// Every ligature the GSUB ligature lookups of `FEATURES` define, in
+/// lookup order. Contextual lookups that only trigger ligatures in some
+/// surroundings aren't followed.
+#[derive(Serialize)]
+pub struct LigatureReport {
+    pub features: Vec<FeatureLigatures>,
+}
+
+impl LigatureReport {
+    pub fn new(face: &Face) -> Self {
+        let Some(gsub) = face.tables().gsub else {
+            return LigatureReport {
+                features: Vec::new(),
+            };
+        };
+        // The lowest code point of each glyph, so components read as text.
+        let mut chars: HashMap<GlyphId, char> = HashMap::new();
+        for c in covered_chars(face) {
+            if let Some(glyph) = face.glyph_index(c) {
+                chars.entry(glyph).or_insert(c);
+            }
+        }
+        let label = |glyph: GlyphId| match (chars.get(&glyph), face.glyph_name(glyph)) {
+            (Some(c), _) => c.to_string(),
+            (None, Some(name)) => name.to_string(),
+            (None, None) => format!("gid{}", glyph.0),
+        };
+        let glyph_name = |glyph: GlyphId| match face.glyph_name(glyph) {
+            Some(name) => name.to_string(),
+            None => format!("gid{}", glyph.0),
+        };
+
+        let mut features = Vec::new();
+        for tag in FEATURES {
+            let tag = Tag::from_bytes(tag);
+            let mut ligatures = Vec::new();
+            for index in layout::feature_lookups(&gsub, tag) {
+                let Some(lookup) = gsub.lookups.get(index) else {
+                    continue;
+                };
+                for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+                    let SubstitutionSubtable::Ligature(subtable) = subtable else {
+                        continue;
+                    };
+                    let mut covered = BTreeSet::new();
+                    layout::add_coverage(subtable.coverage, &mut covered);
+                    for first in covered {
+                        let Some(set) = subtable
+                            .coverage
+                            .get(first)
+                            .and_then(|index| subtable.ligature_sets.get(index))
+                        else {
+                            continue;
+                        };
+                        for ligature in set {
+                            let components = std::iter::once(first)
+                                .chain(ligature.components)
+                                .map(label)
+                                .collect();
+                            ligatures.push(Ligature {
+                                components,
+                                glyph: glyph_name(ligature.glyph),
+                                c: chars.get(&ligature.glyph).copied(),
+                            });
+                        }
+                    }
+                }
+            }
+            if !ligatures.is_empty() {
+                features.push(FeatureLigatures {
+                    feature: tag.to_string(),
+                    ligatures,
+                });
+            }
+        }
+        LigatureReport { features }
+    }
+}
@@ -1,379 +1,4268 @@
+mod animate;
+mod audit;
+mod cache;
+mod check;
+mod compare;
+#[cfg(unix)]
+mod daemon;
+mod diff;
+mod dump;
+mod embedded;
+mod extract;
+mod family;
+mod shape;
+mod sheet;
+mod specimen;
+
+use fontinfo::detect::FileKind;
+use fontinfo::features::FeatureRegistry;
+use fontinfo::i18n::{self, Catalog, Lang};
+use fontinfo::report::{self, FontReport, Section};
+use fontinfo::{
+    african, badge, blocks, case, color, config, conformance, css, cyrillic, designspace, detect,
+    disambiguation, embedding, emoji, feature_variations, footprint, get_name, glyph, greek, head,
+    health, ignorables, ink, instance_metrics, kerning, languages, ligatures, marks, math,
+    mirroring, name_table, normalization, opsz, os2, outlines, planes, png, post, profile, render,
+    sarif, script_features, size, slicing, special_cases, stems, subsets, substitutions, svg,
+    tables, tabular, terminal, text, turkic, unicode, vietnamese,
+};
+use std::borrow::Cow;
+use std::collections::HashSet;
 use std::env;
 use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
 use std::process;
-use ttf_parser::Face;
+use ttf_parser::{Face, GlyphId};
 
 fn main() {
     let args: Vec<String> = env::args().collect();
 
-    if args.len() != 2 {
-        eprintln!("Usage: {} <font-file>", args[0]);
-        eprintln!("Example: {} /path/to/font.ttf", args[0]);
+    match args.get(1).map(String::as_str) {
+        #[cfg(unix)]
+        Some("daemon") => daemon::run(&args[0], &args[2..]),
+        Some("animate") => animate::run(&args[0], &args[2..]),
+        Some("audit-security") => audit::run(&args[0], &args[2..]),
+        Some("check") => check::run(&args[0], &args[2..]),
+        Some("compare-proportions") => compare::run(&args[0], &args[2..]),
+        Some("detect") => detect::run(&args[0], &args[2..]),
+        Some("diff") => diff::run(&args[0], &args[2..]),
+        Some("dump") => dump::run(&args[0], &args[2..]),
+        Some("extract-table") => extract::run(&args[0], &args[2..]),
+        Some("family") => family::run(&args[0], &args[2..]),
+        Some("pdf") => embedded::run(&args[0], &args[2..]),
+        Some("shape") => shape::run(&args[0], &args[2..]),
+        Some("sheet") => sheet::run(&args[0], &args[2..]),
+        Some("specimen") => specimen::run(&args[0], &args[2..]),
+        _ => run_report(&args),
+    }
+}
+
+/// What the default (non-subcommand) mode prints.
+enum Mode {
+    Report(ReportOptions),
+    Css,
+    Designspace,
+    /// Vertical metrics at every named instance.
+    InstanceMetrics,
+    GlyphSources,
+    Slice(Vec<f32>),
+    /// Per-script subsets for `unicode-range` delivery, with their CSS.
+    Split(Vec<&'static subsets::Group>),
+    /// Emoji ZWJ sequence coverage, against an `emoji-test.txt` file or the
+    /// bundled list.
+    EmojiSequences(Option<String>),
+    /// How much of the emoji set the font displays, with ZWJ sequences
+    /// from an `emoji-test.txt` file or the bundled list.
+    Emoji(Option<String>),
+    Badge,
+    /// Missing characters for each of the given language codes.
+    Locales(Vec<String>),
+    /// Characters of a text that the cmap lacks.
+    CheckText(TextSource),
+    /// A scorecard for one use of the font, with the languages to check
+    /// coverage for.
+    Profile(profile::Profile, Vec<String>),
+    /// Kerning pair counts, optionally listing every pair, and the
+    /// adjustment of one pair given as its two sides.
+    Kerning(bool, Option<(String, String)>),
+    /// Every ligature the GSUB ligature features form.
+    Ligatures,
+    /// Every input→output glyph mapping of one GSUB feature's lookups.
+    Substitutions(ttf_parser::Tag),
+    /// The GSUB and GPOS features of each script and language system.
+    FeaturesByScript,
+    /// Metrics and structure of the glyph a character maps to.
+    Glyph(char),
+    /// The same for a glyph ID, with the characters that map to it.
+    GlyphId(u16),
+    /// Draws text, or the glyph a character maps to, in the terminal; with
+    /// no protocol given, picks the best the terminal supports.
+    Preview(String, Option<terminal::Protocol>),
+    /// Checks structural constraints of the OpenType spec, printing the
+    /// results as SARIF when set.
+    Strict(bool),
+    /// Writes the outline of the glyph a character maps to as an SVG file.
+    ExportSvg(char, String),
+    /// Writes every glyph's outline as an SVG file in a directory.
+    ExportSvgAll(String),
+    /// Renders a waterfall of text to a PNG file.
+    RenderSpecimen(String, String),
+}
+
+/// Where `--check-text` and `--check-file` take their text from.
+enum TextSource {
+    Inline(String),
+    /// Read when the report is written, like `--emoji-test` files.
+    File(String),
+}
+
+/// A parsed default-mode command line.
+struct ReportArgs {
+    mode: Mode,
+    config_path: Option<String>,
+    /// Axis values every mode sees the font at, from `--variation`.
+    variation: Vec<(ttf_parser::Tag, f32)>,
+    /// Whether reports may come from and go to the report cache; off with
+    /// `--no-cache`.
+    cache: bool,
+    /// The size above which the default report skips a font, from
+    /// `--max-file-size`.
+    max_file_size: Option<u64>,
+    /// One or more fonts; only the default report accepts several, or
+    /// directories to scan for fonts.
+    font_paths: Vec<String>,
+}
+
+impl ReportArgs {
+    /// Parses the arguments following the program name, returning `None`
+    /// when they don't form a valid command line.
+    fn parse(args: &[String]) -> Option<ReportArgs> {
+        let mut css = false;
+        let mut designspace = false;
+        let mut instance_metrics = false;
+        let mut glyph_sources = false;
+        let mut config_path = None;
+        let mut variation = Vec::new();
+        let mut cache = true;
+        let mut max_file_size = None;
+        let mut slice_weights = None;
+        let mut split = None;
+        let mut emoji = false;
+        let mut emoji_sequences = false;
+        let mut emoji_test = None;
+        let mut badge = false;
+        let mut sarif = false;
+        let mut locales = None;
+        let mut check_text = None;
+        let mut profile = None;
+        let mut kerning = false;
+        let mut kern_dump = false;
+        let mut kern_pair = None;
+        let mut ligatures = false;
+        let mut feature = None;
+        let mut show_substitutions = false;
+        let mut features_by_script = false;
+        let mut glyph = None;
+        let mut glyph_id = None;
+        let mut preview = None;
+        let mut preview_protocol = None;
+        let mut strict = false;
+        let mut export_svg = None;
+        let mut export_svg_all = None;
+        let mut render_specimen = None;
+        let mut text = None;
+        let mut sections = Vec::new();
+        let mut terse = false;
+        let mut name_table = false;
+        let mut table_directory = false;
+        let mut size_report = false;
+        let mut json = false;
+        let mut redact = false;
+        let mut lang = None;
+        let mut font_paths = Vec::new();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--css" => css = true,
+                "--designspace" => designspace = true,
+                "--instance-metrics" => instance_metrics = true,
+                "--glyph-sources" => glyph_sources = true,
+                "--config" => config_path = Some(iter.next()?.clone()),
+                "--variation" => variation = parse_variation(iter.next()?)?,
+                "--no-cache" => cache = false,
+                "--max-file-size" => max_file_size = Some(parse_file_size(iter.next()?)?),
+                "--terse" => terse = true,
+                "--names" => name_table = true,
+                "--tables" => table_directory = true,
+                "--size-report" => size_report = true,
+                "--recursive" => font_paths.push(iter.next()?.clone()),
+                "--json" => json = true,
+                "--redact" => redact = true,
+                "--lang" => lang = Some(Lang::from_code(iter.next()?)?),
+                "--section" => {
+                    let section = Section::from_name(iter.next()?)?;
+                    if !sections.contains(&section) {
+                        sections.push(section);
+                    }
+                }
+                "--slice" => slice_weights = Some(parse_weights(iter.next()?)?),
+                "--split" => split = Some(parse_groups(iter.next()?)?),
+                "--emoji" => emoji = true,
+                "--emoji-sequences" => emoji_sequences = true,
+                "--emoji-test" => emoji_test = Some(iter.next()?.clone()),
+                "--locales" => locales = Some(parse_locales(iter.next()?)?),
+                "--check-text" => check_text = Some(TextSource::Inline(iter.next()?.clone())),
+                "--check-file" => check_text = Some(TextSource::File(iter.next()?.clone())),
+                "--profile" => profile = Some(profile::Profile::from_name(iter.next()?)?),
+                "--kerning" => kerning = true,
+                "--kern-dump" => kern_dump = true,
+                "--kern-pair" => kern_pair = Some(parse_kern_pair(iter.next()?)?),
+                "--ligatures" => ligatures = true,
+                "--feature" => feature = Some(dump::parse_tag(iter.next()?)?),
+                "--show-substitutions" => show_substitutions = true,
+                "--features-by-script" => features_by_script = true,
+                "--glyph" => glyph = Some(parse_char(iter.next()?)?),
+                "--gid" => glyph_id = Some(iter.next()?.parse().ok()?),
+                "--preview" => {
+                    let text = iter.next()?;
+                    preview = Some(match parse_char(text) {
+                        Some(c) => c.to_string(),
+                        None if !text.is_empty() => text.clone(),
+                        None => return None,
+                    });
+                }
+                "--preview-protocol" => {
+                    preview_protocol = Some(terminal::Protocol::from_name(iter.next()?)?)
+                }
+                "--strict" => strict = true,
+                "--export-svg" => {
+                    let c = parse_char(iter.next()?)?;
+                    export_svg = Some((c, iter.next()?.clone()));
+                }
+                "--export-svg-all" => export_svg_all = Some(iter.next()?.clone()),
+                "--render-specimen" => render_specimen = Some(iter.next()?.clone()),
+                "--text" => text = Some(iter.next()?.clone()),
+                "--format" => match iter.next()?.as_str() {
+                    "badge" => badge = true,
+                    "sarif" => sarif = true,
+                    _ => return None,
+                },
+                _ if !arg.starts_with("--") => font_paths.push(arg.clone()),
+                _ => return None,
+            }
+        }
+
+        let mode = if let Some(weights) = slice_weights {
+            Mode::Slice(weights)
+        } else if let Some(groups) = split {
+            Mode::Split(groups)
+        } else if emoji {
+            Mode::Emoji(emoji_test)
+        } else if emoji_sequences || emoji_test.is_some() {
+            Mode::EmojiSequences(emoji_test)
+        } else if let Some(profile) = profile {
+            Mode::Profile(profile, locales.unwrap_or_default())
+        } else if kerning || kern_dump || kern_pair.is_some() {
+            Mode::Kerning(kern_dump, kern_pair)
+        } else if ligatures {
+            Mode::Ligatures
+        } else if show_substitutions {
+            Mode::Substitutions(feature?)
+        } else if features_by_script {
+            Mode::FeaturesByScript
+        } else if let Some(c) = glyph {
+            Mode::Glyph(c)
+        } else if let Some(id) = glyph_id {
+            Mode::GlyphId(id)
+        } else if let Some(text) = preview {
+            Mode::Preview(text, preview_protocol)
+        } else if strict {
+            Mode::Strict(sarif)
+        } else if let Some((c, file)) = export_svg {
+            Mode::ExportSvg(c, file)
+        } else if let Some(dir) = export_svg_all {
+            Mode::ExportSvgAll(dir)
+        } else if let Some(file) = render_specimen {
+            Mode::RenderSpecimen(
+                file,
+                text.unwrap_or_else(|| specimen::DEFAULT_TEXT.to_string()),
+            )
+        } else if let Some(locales) = locales {
+            Mode::Locales(locales)
+        } else if let Some(source) = check_text {
+            Mode::CheckText(source)
+        } else if badge {
+            Mode::Badge
+        } else if css {
+            Mode::Css
+        } else if designspace {
+            Mode::Designspace
+        } else if instance_metrics {
+            Mode::InstanceMetrics
+        } else if glyph_sources {
+            Mode::GlyphSources
+        } else {
+            sections.sort();
+            if sections.is_empty() {
+                sections = if terse {
+                    Section::TERSE.to_vec()
+                } else {
+                    Section::ALL.to_vec()
+                };
+            }
+            if name_table && !sections.contains(&Section::NameTable) {
+                sections.push(Section::NameTable);
+                sections.sort();
+            }
+            if table_directory && !sections.contains(&Section::Tables) {
+                sections.push(Section::Tables);
+                sections.sort();
+            }
+            if size_report && !sections.contains(&Section::Size) {
+                sections.push(Section::Size);
+                sections.sort();
+            }
+            Mode::Report(ReportOptions {
+                sections,
+                terse,
+                lang: lang.unwrap_or_else(Lang::from_env),
+                json,
+                redact,
+            })
+        };
+
+        if sarif && !matches!(mode, Mode::Strict(_)) {
+            return None;
+        }
+        if redact && !matches!(&mode, Mode::Report(options) if options.json) {
+            return None;
+        }
+        let single = matches!(mode, Mode::Report(_)) || font_paths.len() == 1;
+        if font_paths.is_empty() || !single {
+            return None;
+        }
+        Some(ReportArgs {
+            mode,
+            config_path,
+            variation,
+            cache,
+            max_file_size,
+            font_paths,
+        })
+    }
+
+    /// Expands directories into the fonts found anywhere below them. Only
+    /// the default report scans directories; other modes take the paths
+    /// as given. Directories that can't be read are returned as errors;
+    /// fonts larger than `--max-file-size` are skipped with a warning.
+    fn expand_paths(&self) -> (Vec<String>, Vec<String>) {
+        let mut paths = Vec::new();
+        let mut errors = Vec::new();
+        let report = matches!(self.mode, Mode::Report(_));
+        for path in &self.font_paths {
+            if report && Path::new(path).is_dir() {
+                let found =
+                    find_fonts(Path::new(path), self.max_file_size, &mut paths, &mut errors);
+                if found == 0 {
+                    errors.push(format!("No fonts found in '{}'", path));
+                }
+            } else if report
+                && let Some(max) = self.max_file_size
+                && let Ok(metadata) = fs::metadata(path)
+                && metadata.len() > max
+            {
+                warn_too_large(Path::new(path), metadata.len());
+            } else {
+                paths.push(path.clone());
+            }
+        }
+        (paths, errors)
+    }
+
+    /// True when JSON reports must be wrapped in an array: there are
+    /// several fonts, or a directory whose font count isn't known upfront.
+    fn is_json_array(&self) -> bool {
+        let several = self.font_paths.len() > 1 || Path::new(&self.font_paths[0]).is_dir();
+        matches!(&self.mode, Mode::Report(options) if options.json) && several
+    }
+}
+
+/// Adds the sfnt fonts in `dir` and its subdirectories to `paths`, sorted
+/// by path within each directory. Files are recognized by their magic
+/// number, so fonts with missing or wrong extensions are found too.
+/// Symbolic links to directories aren't followed, which rules out cycles.
+/// Fonts larger than `max_file_size` are skipped with a warning. Returns
+/// the number of fonts found, skipped ones included.
+fn find_fonts(
+    dir: &Path,
+    max_file_size: Option<u64>,
+    paths: &mut Vec<String>,
+    errors: &mut Vec<String>,
+) -> usize {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            errors.push(format!(
+                "Error reading directory '{}': {}",
+                dir.display(),
+                e
+            ));
+            return 0;
+        }
+    };
+    let mut entries: Vec<_> = entries.filter_map(Result::ok).collect();
+    entries.sort_by_key(|entry| entry.path());
+    let mut found = 0;
+    for entry in entries {
+        let path = entry.path();
+        if entry.file_type().is_ok_and(|t| t.is_dir()) {
+            found += find_fonts(&path, max_file_size, paths, errors);
+            continue;
+        }
+        // Only the header is read, so large non-font files cost nothing.
+        let Ok((header, file_len)) = detect::read_header(&path) else {
+            continue;
+        };
+        if !matches!(
+            detect::classify(&header, file_len),
+            FileKind::TrueType { .. } | FileKind::OpenTypeCff | FileKind::Collection { .. }
+        ) {
+            continue;
+        }
+        found += 1;
+        if let Some(max) = max_file_size
+            && file_len > max
+        {
+            warn_too_large(&path, file_len);
+            continue;
+        }
+        paths.push(path.to_string_lossy().into_owned());
+    }
+    found
+}
+
+fn warn_too_large(path: &Path, file_len: u64) {
+    eprintln!(
+        "Warning: skipping '{}': {} bytes exceeds --max-file-size",
+        path.display(),
+        file_len
+    );
+}
+
+fn run_report(args: &[String]) {
+    let Some(report) = ReportArgs::parse(&args[1..]) else {
+        usage(&args[0]);
+    };
+
+    #[cfg(unix)]
+    if daemon::forward(&args[1..], &report) {
+        return;
+    }
+
+    let config = match config::Config::load(report.config_path.as_deref()) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let cache = cache::ReportCache::open(&args[1..], &report, &config);
+    let mut stdout = io::stdout().lock();
+    let result = write_reports(&mut stdout, &report, |out, path| {
+        let font_data =
+            fs::read(path).map_err(|e| format!("Error reading font file '{}': {}", path, e))?;
+        let key = cache.as_ref().map(|cache| cache.key(path, &font_data));
+        if let (Some(cache), Some(key)) = (&cache, &key)
+            && let Some(output) = cache.load(key)
+        {
+            return out
+                .write_all(&output)
+                .map_err(|e| format!("Error writing output: {}", e));
+        }
+        let face = Face::parse(&font_data, 0)
+            .map_err(|e| format!("Error parsing font file '{}': {}", path, e))?;
+        let mut output = Vec::new();
+        let result = contain_panic(|| write_report(&mut output, &face, path, &report, &config))
+            .map_err(|e| format!("Error analyzing '{}': {}", path, e))?;
+        out.write_all(&output)
+            .map_err(|e| format!("Error writing output: {}", e))?;
+        // Failed checks write their findings too, but only clean reports
+        // are kept.
+        if let (Some(cache), Some(key), Ok(())) = (&cache, &key, &result) {
+            cache.store(key, &output);
+        }
+        result
+    });
+    let errors = result.unwrap_or_else(|e| vec![format!("Error writing output: {}", e)]);
+    for e in &errors {
+        eprintln!("{}", e);
+    }
+    if !errors.is_empty() {
         process::exit(1);
     }
+}
+
+/// Writes the output for each font in `report` with `write_one`, leaving a
+/// blank line between text reports and wrapping several JSON reports in an
+/// array. A font that fails is skipped and its error returned, so one bad
+/// file doesn't hide the reports for the rest. Text written before the
+/// failure is kept, which lets checks such as `--locales` print their
+/// findings and still fail.
+fn write_reports(
+    out: &mut dyn Write,
+    report: &ReportArgs,
+    mut write_one: impl FnMut(&mut dyn Write, &str) -> Result<(), String>,
+) -> io::Result<Vec<String>> {
+    let array = report.is_json_array();
+    let (paths, mut errors) = report.expand_paths();
+    let mut written = 0;
+    if array {
+        write!(out, "[")?;
+    }
+    for path in &paths {
+        let mut output = Vec::new();
+        if let Err(e) = write_one(&mut output, path) {
+            errors.push(e);
+            if array || output.is_empty() {
+                continue;
+            }
+        }
+        if array {
+            // Pretty-printed JSON escapes newlines inside strings, so
+            // indenting every line nests the document safely.
+            let json = String::from_utf8_lossy(&output);
+            let separator = if written > 0 { "," } else { "" };
+            write!(
+                out,
+                "{}\n  {}",
+                separator,
+                json.trim_end().replace('\n', "\n  ")
+            )?;
+        } else {
+            if written > 0 {
+                writeln!(out)?;
+            }
+            out.write_all(&output)?;
+        }
+        written += 1;
+    }
+    if array {
+        writeln!(out, "{}]", if written > 0 { "\n" } else { "" })?;
+    }
+    Ok(errors)
+}
+
+/// Writes the output selected by `report` for an already parsed face.
+fn write_report(
+    out: &mut dyn Write,
+    face: &Face,
+    path: &str,
+    report: &ReportArgs,
+    config: &config::Config,
+) -> Result<(), String> {
+    let not_variable = || format!("Font file '{}' is not a variable font", path);
+
+    let mut varied;
+    let face = if report.variation.is_empty() {
+        face
+    } else {
+        if !face.is_variable() {
+            return Err(not_variable());
+        }
+        varied = face.clone();
+        for &(tag, value) in &report.variation {
+            if !face
+                .variation_axes()
+                .into_iter()
+                .any(|axis| axis.tag == tag)
+            {
+                return Err(format!("Font file '{}' has no '{}' axis", path, tag));
+            }
+            varied.set_variation(tag, value);
+        }
+        &varied
+    };
+
+    let result = match &report.mode {
+        Mode::Slice(weights) => {
+            let plan = slicing::SlicePlan::new(face, weights)
+                .map_err(|e| format!("Cannot slice '{}': {}", path, e))?;
+            print_slice_plan(out, &plan)
+        }
+        Mode::Split(groups) => {
+            let plan = subsets::SubsetPlan::new(face, groups);
+            let family = get_name(face, ttf_parser::name_id::TYPOGRAPHIC_FAMILY)
+                .or_else(|| get_name(face, ttf_parser::name_id::FAMILY))
+                .unwrap_or_default();
+            let stem = Path::new(path)
+                .file_stem()
+                .map_or(Cow::Borrowed("font"), |stem| stem.to_string_lossy());
+            print_subset_plan(out, &plan, &plan.css(face, &family, &stem))
+        }
+        Mode::Css => {
+            let css = css::instance_rules(face).ok_or_else(not_variable)?;
+            write!(out, "{}", css)
+        }
+        Mode::Designspace => {
+            let doc = designspace::build(face).ok_or_else(not_variable)?;
+            let json = serde_json::to_string_pretty(&doc)
+                .map_err(|e| format!("Error serializing designspace: {}", e))?;
+            writeln!(out, "{}", json)
+        }
+        Mode::InstanceMetrics => {
+            let table = instance_metrics::MetricsTable::new(face).ok_or_else(not_variable)?;
+            print_instance_metrics(out, &table)
+        }
+        Mode::GlyphSources => print_glyph_sources(out, face),
+        Mode::Badge => {
+            let json = serde_json::to_string_pretty(&badge::build(face))
+                .map_err(|e| format!("Error serializing badges: {}", e))?;
+            writeln!(out, "{}", json)
+        }
+        Mode::EmojiSequences(emoji_test) => {
+            let (source, sequences) = load_zwj_sequences(emoji_test.as_deref())?;
+            print_emoji_sequences(out, face, source, &sequences)
+        }
+        Mode::Emoji(emoji_test) => {
+            let (source, sequences) = load_zwj_sequences(emoji_test.as_deref())?;
+            let capability = emoji::EmojiCapability::new(face, &sequences);
+            print_emoji_capability(out, source, &capability)
+        }
+        Mode::Locales(codes) => {
+            let checks: Vec<_> = codes
+                .iter()
+                .filter_map(|code| languages::LocaleCoverage::new(face, code))
+                .collect();
+            print_locale_checks(out, &checks)
+                .map_err(|e| format!("Error writing output: {}", e))?;
+            let failed: Vec<&str> = checks
+                .iter()
+                .filter(|check| !check.passed())
+                .map(|check| check.code)
+                .collect();
+            if !failed.is_empty() {
+                return Err(format!(
+                    "'{}' is missing characters for {}",
+                    path,
+                    failed.join(", ")
+                ));
+            }
+            Ok(())
+        }
+        Mode::CheckText(source) => {
+            let text = match source {
+                TextSource::Inline(text) => Cow::Borrowed(text.as_str()),
+                TextSource::File(file) => Cow::Owned(
+                    fs::read_to_string(file)
+                        .map_err(|e| format!("Error reading '{}': {}", file, e))?,
+                ),
+            };
+            let coverage = text::TextCoverage::new(face, &text);
+            print_text_coverage(out, &coverage)
+                .map_err(|e| format!("Error writing output: {}", e))?;
+            if !coverage.missing.is_empty() {
+                return Err(format!(
+                    "'{}' is missing {} of {} characters",
+                    path,
+                    coverage.missing.len(),
+                    coverage.checked
+                ));
+            }
+            Ok(())
+        }
+        Mode::Profile(profile, locales) => {
+            let locales: Vec<&str> = if locales.is_empty() {
+                profile::UI_LOCALES.to_vec()
+            } else {
+                locales.iter().map(String::as_str).collect()
+            };
+            let scorecard = profile::Scorecard::new(face, Path::new(path), *profile, &locales);
+            print_scorecard(out, *profile, &scorecard)
+        }
+        Mode::Kerning(dump, pair) => {
+            let pair = match pair {
+                Some((left, right)) => {
+                    let find = |side: &str| {
+                        kerning::find_glyph(face, side)
+                            .ok_or_else(|| format!("'{}' has no glyph for '{}'", path, side))
+                    };
+                    Some((find(left)?, find(right)?))
+                }
+                None => None,
+            };
+            let report = kerning::KerningReport::new(face);
+            print_kerning(out, face, &report, *dump, pair)
+        }
+        Mode::Ligatures => print_ligatures(out, &ligatures::LigatureReport::new(face)),
+        Mode::Substitutions(tag) => {
+            let report = substitutions::SubstitutionReport::new(face, *tag)
+                .ok_or_else(|| format!("'{}' has no GSUB feature '{}'", path, tag))?;
+            print_substitutions(out, &report)
+        }
+        Mode::FeaturesByScript => print_features_by_script(out, face),
+        Mode::Glyph(c) => {
+            let info = glyph::GlyphInfo::new(face, *c)
+                .ok_or_else(|| format!("'{}' has no glyph for U+{:04X}", path, *c as u32))?;
+            print_glyph(out, face, &info)
+        }
+        Mode::GlyphId(id) => {
+            let info = glyph::GlyphInfo::by_id(face, GlyphId(*id)).ok_or_else(|| {
+                format!(
+                    "'{}' has no glyph {}; its IDs run from 0 to {}",
+                    path,
+                    id,
+                    face.number_of_glyphs().saturating_sub(1)
+                )
+            })?;
+            print_glyph(out, face, &info)
+        }
+        Mode::Preview(text, protocol) => {
+            let mut chars = text.chars();
+            if let (Some(c), None) = (chars.next(), chars.next())
+                && face.glyph_index(c).is_none()
+            {
+                return Err(format!("'{}' has no glyph for U+{:04X}", path, c as u32));
+            }
+            // Escape sequences only make sense when printing to a terminal.
+            let protocol = protocol.unwrap_or_else(|| {
+                if io::stdout().is_terminal() {
+                    terminal::Protocol::detect()
+                } else {
+                    terminal::Protocol::Braille
+                }
+            });
+            print_preview(out, face, text, protocol)
+        }
+        Mode::Strict(sarif) => {
+            let report = conformance::ConformanceReport::new(face);
+            if *sarif {
+                let json = serde_json::to_string_pretty(&conformance_sarif(&report, path))
+                    .map_err(|e| format!("Error serializing SARIF: {}", e))?;
+                writeln!(out, "{}", json).map_err(|e| format!("Error writing output: {}", e))?;
+            } else {
+                print_conformance(out, &report)
+                    .map_err(|e| format!("Error writing output: {}", e))?;
+            }
+            return match report.violations() {
+                0 => Ok(()),
+                count => Err(format!(
+                    "'{}' breaks {} structural constraint{} of the OpenType spec",
+                    path,
+                    count,
+                    if count == 1 { "" } else { "s" }
+                )),
+            };
+        }
+        Mode::ExportSvg(c, file) => {
+            let glyph = face
+                .glyph_index(*c)
+                .ok_or_else(|| format!("'{}' has no glyph for U+{:04X}", path, *c as u32))?;
+            fs::write(file, svg::glyph_svg(face, glyph))
+                .map_err(|e| format!("Error writing {}: {}", file, e))?;
+            writeln!(out, "Wrote {} (glyph {})", file, glyph.0)
+        }
+        Mode::ExportSvgAll(dir) => {
+            let count = export_svg_all(face, Path::new(dir))?;
+            writeln!(out, "Wrote {} glyphs to {}", count, dir)
+        }
+        Mode::RenderSpecimen(file, text) => {
+            let (width, height, gray) = render_specimen(face, text);
+            let rgb: Vec<u8> = gray.iter().flat_map(|&value| [value; 3]).collect();
+            fs::write(file, png::encode_rgb(width, height, &rgb))
+                .map_err(|e| format!("Error writing {}: {}", file, e))?;
+            writeln!(out, "Wrote {} ({}×{})", file, width, height)
+        }
+        Mode::Report(options) => print_font_info(out, face, path, config, options),
+    };
+    result.map_err(|e| format!("Error writing output: {}", e))
+}
 
-    let font_path = &args[1];
+/// Runs analysis of a single font, turning a panic caused by malformed
+/// data into an error so one bad file can't abort a batch scan or the
+/// daemon.
+fn contain_panic<T>(analyze: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(analyze)).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown error".to_string());
+        format!("analysis panicked: {}", message)
+    })
+}
 
-    let font_data = match fs::read(font_path) {
+fn read_font_file(font_path: &str) -> Vec<u8> {
+    match fs::read(font_path) {
         Ok(data) => data,
         Err(e) => {
             eprintln!("Error reading font file '{}': {}", font_path, e);
             process::exit(1);
         }
-    };
+    }
+}
 
-    let face = match Face::parse(&font_data, 0) {
+fn parse_face<'a>(font_data: &'a [u8], font_path: &str) -> Face<'a> {
+    match Face::parse(font_data, 0) {
         Ok(face) => face,
         Err(e) => {
             eprintln!("Error parsing font file '{}': {}", font_path, e);
             process::exit(1);
         }
+    }
+}
+
+/// Parses a comma-separated list of bundled language codes, e.g.
+/// `fr,vi,pl`, returning `None` for unknown codes.
+fn parse_locales(list: &str) -> Option<Vec<String>> {
+    list.split(',')
+        .map(|code| {
+            let code = code.trim();
+            languages::is_known(code).then(|| code.to_string())
+        })
+        .collect()
+}
+
+/// Parses a `--kern-pair` argument, two glyphs separated by a comma, e.g.
+/// `A,V` or `T,o.sc`.
+fn parse_kern_pair(pair: &str) -> Option<(String, String)> {
+    let (left, right) = pair.split_once(',')?;
+    let (left, right) = (left.trim(), right.trim());
+    (!left.is_empty() && !right.is_empty()).then(|| (left.to_string(), right.to_string()))
+}
+
+/// Parses a `--glyph` argument: a single character, or a code point such as
+/// `U+1F600`.
+fn parse_char(text: &str) -> Option<char> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => {
+            let hex = text
+                .strip_prefix("U+")
+                .or_else(|| text.strip_prefix("u+"))?;
+            char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+        }
+    }
+}
+
+fn print_glyph(out: &mut dyn Write, face: &Face, info: &glyph::GlyphInfo) -> io::Result<()> {
+    let optional = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+    writeln!(
+        out,
+        "┌─ GLYPH ───────────────────────────────────────────────────────"
+    )?;
+    if let Some(c) = info.c {
+        writeln!(
+            out,
+            "│ Character:        U+{:04X} {}",
+            c as u32,
+            c.escape_debug()
+        )?;
+    }
+    writeln!(out, "│ Glyph ID:         {}", info.id)?;
+    let chars: Vec<String> = info
+        .chars
+        .iter()
+        .map(|c| format!("U+{:04X} {}", *c as u32, c.escape_debug()))
+        .collect();
+    writeln!(
+        out,
+        "│ Code Points:      {}",
+        if chars.is_empty() {
+            "none (reached only through GSUB or as a component)".to_string()
+        } else {
+            chars.join(", ")
+        }
+    )?;
+    writeln!(out, "│ Glyph Name:       {}", optional(info.name.clone()))?;
+    writeln!(
+        out,
+        "│ Advance Width:    {}",
+        optional(info.advance.map(|advance| advance.to_string()))
+    )?;
+    writeln!(
+        out,
+        "│ Left Bearing:     {}",
+        optional(info.left_side_bearing.map(|lsb| lsb.to_string()))
+    )?;
+    writeln!(
+        out,
+        "│ Bounding Box:     {}",
+        optional(info.bbox.map(|[x_min, y_min, x_max, y_max]| format!(
+            "({}, {}) – ({}, {})",
+            x_min, y_min, x_max, y_max
+        )))
+    )?;
+    let kind = match &info.kind {
+        glyph::GlyphKind::Simple { contours } => format!(
+            "simple, {} contour{}",
+            contours,
+            if *contours == 1 { "" } else { "s" }
+        ),
+        glyph::GlyphKind::Composite { components } => {
+            let names: Vec<String> = components
+                .iter()
+                .map(|&id| match face.glyph_name(GlyphId(id)) {
+                    Some(name) => format!("{} ({})", name, id),
+                    None => id.to_string(),
+                })
+                .collect();
+            format!("composite of {}", names.join(", "))
+        }
+        glyph::GlyphKind::Empty => "empty".to_string(),
+    };
+    writeln!(out, "│ Structure:        {}", kind)?;
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )
+}
+
+/// Pixels from the descender to the ascender in a braille `--preview`,
+/// four to a line.
+const PREVIEW_HEIGHT: f32 = 64.0;
+/// The same for previews drawn with a terminal graphics protocol.
+const GRAPHICS_PREVIEW_HEIGHT: f32 = 128.0;
+
+/// Draws `text` in the terminal, as an image when it supports a graphics
+/// protocol and in braille otherwise, scaled so the font's descender to
+/// ascender fits the preview height and widened to show the whole advance
+/// and anything overshooting it. A single character is followed by its
+/// glyph.
+fn print_preview(
+    out: &mut dyn Write,
+    face: &Face,
+    text: &str,
+    protocol: terminal::Protocol,
+) -> io::Result<()> {
+    let ascender = f32::from(face.ascender());
+    let descender = f32::from(face.descender());
+    let height = if protocol == terminal::Protocol::Braille {
+        PREVIEW_HEIGHT
+    } else {
+        GRAPHICS_PREVIEW_HEIGHT
     };
+    let scale = height / (ascender - descender).max(1.0);
+
+    let glyphs: Vec<GlyphId> = text
+        .chars()
+        .map(|c| face.glyph_index(c).unwrap_or_default())
+        .collect();
+    let (mut left, mut right, mut top, mut bottom) = (0.0f32, 0.0f32, ascender, descender);
+    let mut pen = 0.0;
+    for &glyph in &glyphs {
+        if let Some(bbox) = face.glyph_bounding_box(glyph) {
+            left = left.min(pen + f32::from(bbox.x_min));
+            right = right.max(pen + f32::from(bbox.x_max));
+            top = top.max(f32::from(bbox.y_max));
+            bottom = bottom.min(f32::from(bbox.y_min));
+        }
+        pen += f32::from(face.glyph_hor_advance(glyph).unwrap_or(0));
+        right = right.max(pen);
+    }
+    let width = (((right - left) * scale).ceil() as usize).max(2);
+    let height = ((top - bottom) * scale).ceil() as usize;
+    let mut canvas = render::Canvas::new(width, height);
+    canvas.draw_text(face, text, -left * scale, top * scale, scale);
+
+    if protocol == terminal::Protocol::Braille {
+        for line in canvas.braille() {
+            writeln!(out, "{}", line)?;
+        }
+    } else {
+        let gray: Vec<u8> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| ((1.0 - canvas.coverage(x, y)) * 255.0).round() as u8)
+            .collect();
+        writeln!(out, "{}", protocol.encode(width, height, &gray))?;
+    }
+    let mut chars = text.chars();
+    if let (Some(c), None, [glyph]) = (chars.next(), chars.next(), glyphs.as_slice()) {
+        writeln!(
+            out,
+            "U+{:04X} {} → glyph {}{}",
+            c as u32,
+            c.escape_debug(),
+            glyph.0,
+            face.glyph_name(*glyph)
+                .map(|name| format!(" ({})", name))
+                .unwrap_or_default()
+        )?;
+    }
+    Ok(())
+}
 
-    print_font_info(&face, font_path);
+/// Violations listed per rule before the rest are summarized.
+const VIOLATIONS_SHOWN: usize = 10;
+
+/// Prints a pass/fail line per rule, followed by where the font breaks it
+/// and the spec section stating it.
+fn print_conformance(
+    out: &mut dyn Write,
+    report: &conformance::ConformanceReport,
+) -> io::Result<()> {
+    for rule in &report.rules {
+        writeln!(
+            out,
+            "{:<4}  {}",
+            rule.status.name().to_uppercase(),
+            rule.name
+        )?;
+        if rule.violations.is_empty() {
+            continue;
+        }
+        for violation in rule.violations.iter().take(VIOLATIONS_SHOWN) {
+            writeln!(out, "      {}", violation)?;
+        }
+        if rule.violations.len() > VIOLATIONS_SHOWN {
+            writeln!(
+                out,
+                "      ... and {} more",
+                rule.violations.len() - VIOLATIONS_SHOWN
+            )?;
+        }
+        writeln!(out, "      See {}", rule.reference)?;
+    }
+    Ok(())
 }
 
-fn get_name(face: &Face, name_id: u16) -> Option<String> {
-    face.names()
-        .into_iter()
-        .filter(|n| n.name_id == name_id)
-        .find_map(|n| n.to_string())
+/// A SARIF log with one error per violation, under the rule it breaks.
+fn conformance_sarif(report: &conformance::ConformanceReport, path: &str) -> sarif::Log {
+    let mut log = sarif::Log::default();
+    for rule in &report.rules {
+        let index = log.rule(&sarif::rule_id(rule.name), rule.name, Some(&rule.reference));
+        for violation in &rule.violations {
+            log.result(index, sarif::Level::Error, violation, path);
+        }
+    }
+    log
 }
 
-fn print_font_info(face: &Face, path: &str) {
-    println!("╔═══════════════════════════════════════════════════════════════");
-    println!("║ FONT INFORMATION");
-    println!("╠═══════════════════════════════════════════════════════════════");
-    println!("║ File: {}", path);
-    println!("╚═══════════════════════════════════════════════════════════════");
-    println!();
+/// Space around and between the lines of a rendered specimen, in pixels.
+const SPECIMEN_PADDING: usize = 16;
 
-    // Basic font names
-    println!("┌─ FONT NAMES ──────────────────────────────────────────────────");
+/// Renders `text` at each waterfall size of the HTML specimen, in black on
+/// white with the size in pixels per em beside each line, as grayscale
+/// pixels. Returns the width, height and pixels.
+fn render_specimen(face: &Face, text: &str) -> (usize, usize, Vec<u8>) {
+    let ascender = f32::from(face.ascender());
+    let descender = f32::from(face.descender());
+    let units_per_em = f32::from(face.units_per_em());
+    let label_width = 3 * render::LABEL_ADVANCE;
+    let left = SPECIMEN_PADDING + label_width + SPECIMEN_PADDING;
 
-    let mut found_any_name = false;
+    let mut lines = Vec::new();
+    let mut width = 0;
+    let mut height = SPECIMEN_PADDING;
+    for size in specimen::SIZES {
+        let scale = size as f32 / units_per_em;
+        let baseline = height as f32 + (ascender * scale).ceil();
+        lines.push((size, scale, baseline));
+        let text_width = render::text_width(face, text, scale).ceil() as usize;
+        width = width.max(left + text_width + SPECIMEN_PADDING);
+        height += ((ascender - descender) * scale).ceil() as usize + SPECIMEN_PADDING;
+    }
 
-    if let Some(family) = get_name(&face, ttf_parser::name_id::FAMILY) {
-        println!("│ Family Name:      {}", family);
-        found_any_name = true;
+    let mut canvas = render::Canvas::new(width, height);
+    for (size, scale, baseline) in lines {
+        canvas.draw_text(face, text, left as f32, baseline, scale);
+        let label_top = (baseline as usize).saturating_sub(render::LABEL_HEIGHT);
+        canvas.draw_label(&size.to_string(), SPECIMEN_PADDING, label_top);
     }
+    let gray = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| ((1.0 - canvas.coverage(x, y)) * 255.0).round() as u8)
+        .collect();
+    (width, height, gray)
+}
 
-    if let Some(subfamily) = get_name(&face, ttf_parser::name_id::SUBFAMILY) {
-        println!("│ Subfamily:        {}", subfamily);
-        found_any_name = true;
+/// Writes an SVG file per glyph into `dir`, creating it if needed, and
+/// returns how many were written. Files are named after the glyph, or its
+/// ID when the name is missing, unsafe in a file name, or would clash on a
+/// case-insensitive file system.
+fn export_svg_all(face: &Face, dir: &Path) -> Result<u16, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Error creating {}: {}", dir.display(), e))?;
+    let mut used = HashSet::new();
+    for id in 0..face.number_of_glyphs() {
+        let glyph = GlyphId(id);
+        let name = face
+            .glyph_name(glyph)
+            .filter(|name| {
+                !name.starts_with('.')
+                    && name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+            })
+            .filter(|name| used.insert(name.to_ascii_lowercase()))
+            .map_or_else(|| format!("gid{}", id), str::to_string);
+        let file = dir.join(format!("{}.svg", name));
+        fs::write(&file, svg::glyph_svg(face, glyph))
+            .map_err(|e| format!("Error writing {}: {}", file.display(), e))?;
     }
+    Ok(face.number_of_glyphs())
+}
 
-    if let Some(full_name) = get_name(&face, ttf_parser::name_id::FULL_NAME) {
-        println!("│ Full Name:        {}", full_name);
-        found_any_name = true;
+/// Prints a pass/fail line per locale, followed by the characters each
+/// failing one is missing.
+fn print_locale_checks(
+    out: &mut dyn Write,
+    checks: &[languages::LocaleCoverage],
+) -> io::Result<()> {
+    for check in checks {
+        let status = if check.passed() { "PASS" } else { "FAIL" };
+        writeln!(out, "{}  {:<3} {}", status, check.code, check.name)?;
+        for c in &check.missing {
+            writeln!(out, "      U+{:04X} {}", *c as u32, c)?;
+        }
     }
+    Ok(())
+}
 
-    if let Some(postscript) = get_name(&face, ttf_parser::name_id::POST_SCRIPT_NAME) {
-        println!("│ PostScript Name:  {}", postscript);
-        found_any_name = true;
+/// Prints each character missing from the checked text with its code
+/// point and name.
+fn print_text_coverage(out: &mut dyn Write, coverage: &text::TextCoverage) -> io::Result<()> {
+    writeln!(
+        out,
+        "{} of {} characters missing",
+        coverage.missing.len(),
+        coverage.checked
+    )?;
+    for missing in &coverage.missing {
+        writeln!(
+            out,
+            "  U+{:04X} {} {}",
+            missing.c as u32,
+            missing.c,
+            missing.name.as_deref().unwrap_or("<unassigned>")
+        )?;
     }
+    Ok(())
+}
 
-    if let Some(version) = get_name(&face, 5) {
-        println!("│ Version:          {}", version);
-        found_any_name = true;
+/// Prints where the font's kerning comes from and how many pairs each
+/// source kerns, then the requested pair or every pair.
+fn print_kerning(
+    out: &mut dyn Write,
+    face: &Face,
+    report: &kerning::KerningReport,
+    dump: bool,
+    pair: Option<(GlyphId, GlyphId)>,
+) -> io::Result<()> {
+    if report.kern_table {
+        writeln!(
+            out,
+            "kern table: {} subtable{}, {} pairs",
+            report.kern_subtables,
+            if report.kern_subtables == 1 { "" } else { "s" },
+            report.kern_pairs.len()
+        )?;
+    } else {
+        writeln!(out, "kern table: none")?;
     }
+    if report.gpos_lookups > 0 {
+        writeln!(
+            out,
+            "GPOS kern:  {} pair adjustment lookup{}, {} pairs",
+            report.gpos_lookups,
+            if report.gpos_lookups == 1 { "" } else { "s" },
+            report.gpos_pairs.len()
+        )?;
+    } else {
+        writeln!(out, "GPOS kern:  none")?;
+    }
+    writeln!(out, "Total:      {} pairs", report.total())?;
 
-    if !found_any_name {
-        println!("│ No standard name entries found");
-        println!("│");
-        println!("│ Available names:");
-        for name in face.names() {
-            if let Some(name_str) = name.to_string() {
-                println!("│   [ID {}] {}", name.name_id, name_str);
+    let name = |glyph: u16| match face.glyph_name(GlyphId(glyph)) {
+        Some(name) => name.to_string(),
+        None => format!("gid{}", glyph),
+    };
+    if let Some((left, right)) = pair {
+        writeln!(out)?;
+        let pair_name = format!("{} {}", name(left.0), name(right.0));
+        match report.pair(left, right) {
+            (None, None) => writeln!(out, "{}: not kerned", pair_name)?,
+            (kern, gpos) => {
+                let values: Vec<String> = [("kern", kern), ("GPOS", gpos)]
+                    .into_iter()
+                    .filter_map(|(source, value)| Some(format!("{} {}", source, value?)))
+                    .collect();
+                writeln!(out, "{}: {}", pair_name, values.join(", "))?;
             }
         }
     }
-
-    println!("└───────────────────────────────────────────────────────────────");
-    println!();
-
-    // Font metrics
-    println!("┌─ FONT METRICS ────────────────────────────────────────────────");
-    println!("│ Units per EM:     {}", face.units_per_em());
-    println!("│ Ascender:         {}", face.ascender());
-    println!("│ Descender:        {}", face.descender());
-    println!("│ Line Gap:         {}", face.line_gap());
-    println!("│ Glyph Count:      {}", face.number_of_glyphs());
-    println!("│ Is Monospaced:    {}", face.is_monospaced());
-    println!("│ Is Bold:          {}", face.is_bold());
-    println!("│ Is Italic:        {}", face.is_italic());
-    println!("│ Is Oblique:       {}", face.is_oblique());
-    println!("│ Weight:           {}", face.weight().to_number());
-    println!("│ Width:            {:?}", face.width());
-    println!("└───────────────────────────────────────────────────────────────");
-    println!();
-
-    // OpenType features (GSUB - Glyph Substitution)
-    println!("┌─ OPENTYPE FEATURES (GSUB - Glyph Substitution) ───────────────");
-    let mut gsub_features = Vec::new();
-
-    if let Some(gsub) = face.tables().gsub {
-        for script in gsub.scripts {
-            for lang_sys in script.languages {
-                for feature_index in lang_sys.feature_indices {
-                    if let Some(feature) = gsub.features.get(feature_index) {
-                        let tag = feature.tag.to_string();
-                        if !gsub_features.contains(&tag) {
-                            gsub_features.push(tag);
-                        }
-                    }
-                }
+    if dump {
+        for (source, pairs) in [("kern", &report.kern_pairs), ("GPOS", &report.gpos_pairs)] {
+            for pair in pairs {
+                writeln!(
+                    out,
+                    "{}  {} {} {}",
+                    source,
+                    name(pair.left),
+                    name(pair.right),
+                    pair.value
+                )?;
             }
+        }
+    }
+    Ok(())
+}
 
-            if let Some(default_lang) = script.default_language {
-                for feature_index in default_lang.feature_indices {
-                    if let Some(feature) = gsub.features.get(feature_index) {
-                        let tag = feature.tag.to_string();
-                        if !gsub_features.contains(&tag) {
-                            gsub_features.push(tag);
-                        }
-                    }
-                }
+/// Prints each ligature feature's ligatures as their components and the
+/// glyph they form, e.g. `f + i → fi`.
+fn print_ligatures(out: &mut dyn Write, report: &ligatures::LigatureReport) -> io::Result<()> {
+    if report.features.is_empty() {
+        return writeln!(out, "No ligatures");
+    }
+    for (i, feature) in report.features.iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        writeln!(
+            out,
+            "{}: {} ligatures",
+            feature.feature,
+            feature.ligatures.len()
+        )?;
+        for ligature in &feature.ligatures {
+            let components = ligature.components.join(" + ");
+            match ligature.c {
+                Some(c) => writeln!(out, "  {} → {} ({})", components, ligature.glyph, c)?,
+                None => writeln!(out, "  {} → {}", components, ligature.glyph)?,
             }
         }
     }
+    Ok(())
+}
 
-    if gsub_features.is_empty() {
-        println!("│ No GSUB features found");
-    } else {
-        gsub_features.sort();
-        for (i, feature) in gsub_features.iter().enumerate() {
-            let prefix = if i == 0 { "│ Features:" } else { "│          " };
-            println!("{} {} - {}", prefix, feature, describe_opentype_feature(feature));
-        }
-    }
-    println!("└───────────────────────────────────────────────────────────────");
-    println!();
-
-    // OpenType features (GPOS - Glyph Positioning)
-    println!("┌─ OPENTYPE FEATURES (GPOS - Glyph Positioning) ────────────────");
-    let mut gpos_features = Vec::new();
-
-    if let Some(gpos) = face.tables().gpos {
-        for script in gpos.scripts {
-            for lang_sys in script.languages {
-                for feature_index in lang_sys.feature_indices {
-                    if let Some(feature) = gpos.features.get(feature_index) {
-                        let tag = feature.tag.to_string();
-                        if !gpos_features.contains(&tag) {
-                            gpos_features.push(tag);
-                        }
-                    }
-                }
-            }
+fn print_substitutions(
+    out: &mut dyn Write,
+    report: &substitutions::SubstitutionReport,
+) -> io::Result<()> {
+    writeln!(
+        out,
+        "{}: {} substitutions",
+        report.feature,
+        report.substitutions.len()
+    )?;
+    let mut lookup = None;
+    for substitution in &report.substitutions {
+        if lookup != Some(substitution.lookup) {
+            lookup = Some(substitution.lookup);
+            writeln!(
+                out,
+                "  Lookup {} ({})",
+                substitution.lookup,
+                substitution.kind.name()
+            )?;
+        }
+        let separator = match substitution.kind {
+            substitutions::Kind::Alternate => " | ",
+            _ => " + ",
+        };
+        writeln!(
+            out,
+            "    {} → {}",
+            substitution.input.join(" + "),
+            substitution.output.join(separator)
+        )?;
+    }
+    if !report.contextual.is_empty() {
+        let lookups: Vec<String> = report.contextual.iter().map(u16::to_string).collect();
+        writeln!(
+            out,
+            "  Contextual lookup{} {} not expanded",
+            if lookups.len() == 1 { "" } else { "s" },
+            lookups.join(", ")
+        )?;
+    }
+    Ok(())
+}
 
-            if let Some(default_lang) = script.default_language {
-                for feature_index in default_lang.feature_indices {
-                    if let Some(feature) = gpos.features.get(feature_index) {
-                        let tag = feature.tag.to_string();
-                        if !gpos_features.contains(&tag) {
-                            gpos_features.push(tag);
-                        }
-                    }
-                }
+/// Prints a line per language system with its features and their lookup
+/// indices, e.g. `SRB      locl 6,7`, grouped by table and script. The
+/// required feature is marked with `!`.
+fn print_features_by_script(out: &mut dyn Write, face: &Face) -> io::Result<()> {
+    let tables = [("GSUB", face.tables().gsub), ("GPOS", face.tables().gpos)];
+    if tables.iter().all(|(_, table)| table.is_none()) {
+        return writeln!(out, "No GSUB or GPOS table");
+    }
+    for (i, (name, table)) in tables.into_iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        let Some(table) = table else {
+            writeln!(out, "{}: none", name)?;
+            continue;
+        };
+        writeln!(out, "{}", name)?;
+        for script in script_features::list(&table) {
+            writeln!(out, "  {}", script.script)?;
+            for language in &script.languages {
+                let features: Vec<String> = language
+                    .features
+                    .iter()
+                    .map(|feature| {
+                        let lookups: Vec<String> =
+                            feature.lookups.iter().map(u16::to_string).collect();
+                        let required = if feature.required { "!" } else { "" };
+                        format!("{}{} {}", feature.tag, required, lookups.join(","))
+                    })
+                    .collect();
+                writeln!(
+                    out,
+                    "    {:<8} {}",
+                    language.language.as_deref().unwrap_or("default"),
+                    features.join("  ")
+                )?;
             }
         }
     }
+    Ok(())
+}
 
-    if gpos_features.is_empty() {
-        println!("│ No GPOS features found");
-    } else {
-        gpos_features.sort();
-        for (i, feature) in gpos_features.iter().enumerate() {
-            let prefix = if i == 0 { "│ Features:" } else { "│          " };
-            println!("{} {} - {}", prefix, feature, describe_opentype_feature(feature));
+/// Prints the score and a line per check, with what a pass found or why a
+/// check failed.
+fn print_scorecard(
+    out: &mut dyn Write,
+    profile: profile::Profile,
+    scorecard: &profile::Scorecard,
+) -> io::Result<()> {
+    writeln!(
+        out,
+        "{} scorecard: {} of {} checks passed",
+        profile.title(),
+        scorecard.passed(),
+        scorecard.applicable()
+    )?;
+    for check in &scorecard.checks {
+        let status = check.status.name().to_uppercase();
+        if check.detail.is_empty() {
+            writeln!(out, "{:<4}  {}", status, check.name)?;
+        } else {
+            writeln!(out, "{:<4}  {} ({})", status, check.name, check.detail)?;
         }
     }
-    println!("└───────────────────────────────────────────────────────────────");
-    println!();
+    Ok(())
+}
 
-    // Scripts supported
-    println!("┌─ SUPPORTED SCRIPTS ───────────────────────────────────────────");
-    let mut scripts = Vec::new();
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} [options] <font-file>...", program);
+    eprintln!("       {} <command> [args]", program);
+    eprintln!("Example: {} /path/to/font.ttf", program);
+    eprintln!();
+    eprintln!("Options:");
+    eprintln!("  --css            Print CSS rules for each named instance");
+    eprintln!("  --designspace    Export axes, named instances and avar mappings as JSON");
+    eprintln!("  --instance-metrics");
+    eprintln!("                   Print vertical metrics at every named instance, with MVAR");
+    eprintln!("  --glyph-sources  List which glyph data sources cover each glyph");
+    eprintln!("  --format badge   Print shields.io badge JSON for glyphs, languages and license");
+    eprintln!(
+        "  --locales <list> Report missing characters for comma-separated languages, e.g. fr,vi"
+    );
+    eprintln!("  --check-text <text>");
+    eprintln!("                   List the characters of <text> that the font lacks");
+    eprintln!("  --check-file <file>");
+    eprintln!("                   List the characters of a UTF-8 text file that the font lacks");
+    eprintln!("  --profile coding Score the font on monospacing, ligatures, box drawing and more");
+    eprintln!("  --profile ui     Score the font on vertical metrics, figures, x-height and more;");
+    eprintln!("                   --locales picks the languages to check");
+    eprintln!(
+        "  --profile print  Score the font on hinting, oldstyle figures, small caps and more"
+    );
+    eprintln!("  --profile music  Score the font on SMuFL coverage, registration and metadata");
+    eprintln!("  --kerning        Count the pairs the kern table and GPOS kern feature adjust");
+    eprintln!("  --kern-dump      List every kerning pair with its value; implies --kerning");
+    eprintln!("  --kern-pair <left,right>");
+    eprintln!("                   Look up one pair, by characters or glyph names, e.g. A,V");
+    eprintln!(
+        "  --ligatures      List the ligatures of liga, dlig and the other ligature features"
+    );
+    eprintln!("  --feature <tag> --show-substitutions");
+    eprintln!("                   List the input→output glyph mappings of a GSUB feature");
+    eprintln!("  --features-by-script");
+    eprintln!("                   List the features of each script and language system");
+    eprintln!("  --glyph <char>   Show the glyph a character or code point (U+1F600) maps to");
+    eprintln!("  --gid <id>       Show a glyph by ID, with the code points that map to it");
+    eprintln!("  --preview <text> Draw text, or the glyph of a character or code point, in the");
+    eprintln!("                   terminal, as an image where supported and in braille otherwise");
+    eprintln!("  --preview-protocol <name>");
+    eprintln!("                   Draw previews with kitty, iterm, sixel or braille");
+    eprintln!("  --strict         Check table alignment, cmap, loca and name against the spec");
+    eprintln!("  --strict --format sarif");
+    eprintln!("                   Print the --strict results as a SARIF 2.1.0 log");
+    eprintln!("  --export-svg <char> <file>");
+    eprintln!("                   Write the outline of a character's glyph as SVG");
+    eprintln!("  --export-svg-all <dir>");
+    eprintln!("                   Write every glyph's outline as an SVG file in <dir>");
+    eprintln!("  --render-specimen <file>");
+    eprintln!("                   Render a waterfall of text at several sizes to a PNG file");
+    eprintln!("  --text <text>    Text for --render-specimen (default a pangram)");
+    eprintln!("  --json           Print the report as JSON instead of text");
+    eprintln!("  --json --redact  Hash file paths and leave out URLs and license text, for");
+    eprintln!("                   sharing reports publicly");
+    eprintln!("  --names          Add every name record to the report");
+    eprintln!("  --tables         Add the table directory with offsets, lengths and checksums");
+    eprintln!("  --size-report    Add the largest tables, hinting and bitmap sizes, and advice");
+    eprintln!("                   on what could be dropped to shrink the font");
+    eprintln!("  --recursive <dir>");
+    eprintln!("                   Report on every font below <dir>; same as passing <dir>");
+    eprintln!("  --max-file-size <size>");
+    eprintln!("                   Skip fonts larger than <size>, e.g. 50M, instead of reading");
+    eprintln!("                   them for the report");
+    eprintln!("  --lang <code>    Label language: en, de, fr or ja (default: from $LANG)");
+    eprintln!("  --emoji          Summarize how much of the Unicode emoji set the font displays");
+    eprintln!("  --emoji-sequences");
+    eprintln!("                   Check which emoji ZWJ sequences render as single glyphs");
+    eprintln!("  --emoji-test <file>");
+    eprintln!("                   Check the ZWJ sequences in an emoji-test.txt file instead");
+    eprintln!("  --slice <list>   Estimate static vs. subrange slices for comma-separated weights");
+    eprintln!("  --split <groups> Plan per-script subsets with unicode-range CSS, e.g.");
+    eprintln!("                   latin,cyrillic,greek,vietnamese (also latin-ext,");
+    eprintln!("                   cyrillic-ext and greek-ext)");
+    eprintln!("  --variation <axis=value,...>");
+    eprintln!("                   Read the font at a location such as wght=700,wdth=75, and");
+    eprintln!("                   list the glyphs GSUB feature variations (rvrn) swap there");
+    eprintln!("  --config <file>  Read settings such as custom axis descriptions from <file>");
+    eprintln!("  --no-cache       Analyze every font again instead of reusing reports cached");
+    eprintln!("                   in ~/.cache/fontinfo for unchanged files");
+    eprintln!();
+    eprintln!("Commands:");
+    eprintln!("  animate          Render text across a variation axis as a GIF or APNG");
+    eprintln!("  audit-security   Flag structural anomalies associated with malicious fonts");
+    eprintln!("  check            Run lint rules with IDs and severities");
+    eprintln!("  compare-proportions");
+    eprintln!("                   Compare x-height, cap height and widths of two fonts");
+    eprintln!("  daemon           Serve reports from a cache of parsed fonts");
+    eprintln!("  detect           Classify files by magic number without parsing them");
+    eprintln!("  diff             Compare two fonts table by table or as overlaid renderings");
+    eprintln!("  dump             Dump tables as TTX-style XML or raw hex");
+    eprintln!("  extract-table    Write raw table bytes to files");
+    eprintln!("  family           Check naming and metadata consistency across a family");
+    eprintln!("  pdf              Extract and report on the fonts embedded in a PDF");
+    eprintln!("  shape            Shape text and print the glyphs, clusters and positions");
+    eprintln!("  sheet            Render a grid of glyphs to PNG for review");
+    eprintln!("  specimen         Write a self-contained HTML specimen page");
+    process::exit(1);
+}
 
-    if let Some(gsub) = face.tables().gsub {
-        for script in gsub.scripts {
-            let tag = script.tag.to_string();
-            if !scripts.contains(&tag) {
-                scripts.push(tag);
-            }
+fn print_glyph_sources(out: &mut dyn Write, face: &Face) -> io::Result<()> {
+    for id in 0..face.number_of_glyphs() {
+        let glyph_id = ttf_parser::GlyphId(id);
+        let sources = outlines::GlyphSources::new(face, glyph_id);
+        let name = face.glyph_name(glyph_id).unwrap_or("");
+        let warning = if sources.has_color() && !sources.outline {
+            "  (no fallback outline)"
+        } else {
+            ""
+        };
+        writeln!(
+            out,
+            "{:>6}  {:<24} {}{}",
+            id,
+            name,
+            sources.describe(),
+            warning
+        )?;
+    }
+    Ok(())
+}
+
+/// Reads the ZWJ sequences of an `emoji-test.txt` file, or the bundled
+/// list, with a description of where they came from.
+fn load_zwj_sequences(emoji_test: Option<&str>) -> Result<(&str, Vec<emoji::Sequence>), String> {
+    let (source, data) = match emoji_test {
+        Some(file) => {
+            let data =
+                fs::read_to_string(file).map_err(|e| format!("Error reading '{}': {}", file, e))?;
+            (file, Cow::Owned(data))
         }
+        None => ("bundled list", Cow::Borrowed(emoji::BUNDLED_SEQUENCES)),
+    };
+    let sequences = emoji::parse_zwj_sequences(&data);
+    if sequences.is_empty() {
+        return Err(format!("No emoji ZWJ sequences found in {}", source));
     }
+    Ok((source, sequences))
+}
 
-    if let Some(gpos) = face.tables().gpos {
-        for script in gpos.scripts {
-            let tag = script.tag.to_string();
-            if !scripts.contains(&tag) {
-                scripts.push(tag);
-            }
+fn print_emoji_capability(
+    out: &mut dyn Write,
+    source: &str,
+    capability: &emoji::EmojiCapability,
+) -> io::Result<()> {
+    let percent = |part: usize, total: usize| {
+        if total == 0 {
+            0.0
+        } else {
+            part as f64 * 100.0 / total as f64
+        }
+    };
+    writeln!(
+        out,
+        "┌─ EMOJI CAPABILITY ────────────────────────────────────────────"
+    )?;
+    writeln!(
+        out,
+        "│ Characters:       {} of {} ({} in color)",
+        capability.covered_characters, capability.characters, capability.color_characters
+    )?;
+    writeln!(
+        out,
+        "│ Selectors:        VS15 text for {}, VS16 emoji for {} of {} dual-presentation characters",
+        capability.text_selector, capability.emoji_selector, capability.dual_presentation
+    )?;
+    writeln!(
+        out,
+        "│ Keycaps:          {} of {}",
+        capability.formed_keycaps, capability.keycaps
+    )?;
+    writeln!(
+        out,
+        "│ Skin Tones:       {} of {} base and modifier pairs",
+        capability.formed_skin_tones, capability.skin_tones
+    )?;
+    writeln!(
+        out,
+        "│ Flags:            {} of {}",
+        capability.formed_flags, capability.flags
+    )?;
+    writeln!(
+        out,
+        "│ ZWJ Sequences:    {} of {} ({})",
+        capability.formed_zwj_sequences, capability.zwj_sequences, source
+    )?;
+    writeln!(out, "│")?;
+    writeln!(
+        out,
+        "│ Displayable:      {} of {} emoji ({:.1}%)",
+        capability.displayable(),
+        capability.total(),
+        percent(capability.displayable(), capability.total())
+    )?;
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )
+}
+
+fn print_emoji_sequences(
+    out: &mut dyn Write,
+    face: &Face,
+    source: &str,
+    sequences: &[emoji::Sequence],
+) -> io::Result<()> {
+    let mut single = 0;
+    let mut falls_apart = Vec::new();
+    let mut missing = Vec::new();
+    for sequence in sequences {
+        match emoji::SequenceSupport::new(face, &sequence.chars) {
+            emoji::SequenceSupport::SingleGlyph => single += 1,
+            emoji::SequenceSupport::FallsApart(glyphs) => falls_apart.push((sequence, glyphs)),
+            emoji::SequenceSupport::MissingGlyphs => missing.push(sequence),
         }
     }
 
-    if scripts.is_empty() {
-        println!("│ No script information found");
+    writeln!(
+        out,
+        "┌─ EMOJI ZWJ SEQUENCES ─────────────────────────────────────────"
+    )?;
+    writeln!(
+        out,
+        "│ Source:           {} ({} sequences)",
+        source,
+        sequences.len()
+    )?;
+    writeln!(out, "│ Single Glyph:     {}", single)?;
+    writeln!(out, "│ Falls Apart:      {}", falls_apart.len())?;
+    writeln!(out, "│ Missing Glyphs:   {}", missing.len())?;
+    if !falls_apart.is_empty() {
+        writeln!(out, "│")?;
+        writeln!(out, "│ Rendered as separate glyphs:")?;
+        for (sequence, glyphs) in &falls_apart {
+            writeln!(
+                out,
+                "│   {} {} ({} glyphs)",
+                sequence.text(),
+                sequence.name,
+                glyphs
+            )?;
+        }
+    }
+    if !missing.is_empty() {
+        writeln!(out, "│")?;
+        writeln!(out, "│ Missing characters:")?;
+        for sequence in missing.iter().take(10) {
+            writeln!(out, "│   {} {}", sequence.text(), sequence.name)?;
+        }
+        if missing.len() > 10 {
+            writeln!(out, "│   ... and {} more", missing.len() - 10)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )
+}
+
+/// Parses a file size such as `4096`, `512K`, `50M` or `1G`.
+fn parse_file_size(text: &str) -> Option<u64> {
+    let (digits, multiplier) = match text.char_indices().last()? {
+        (i, 'k' | 'K') => (&text[..i], 1 << 10),
+        (i, 'm' | 'M') => (&text[..i], 1 << 20),
+        (i, 'g' | 'G') => (&text[..i], 1 << 30),
+        _ => (text, 1),
+    };
+    digits.parse::<u64>().ok()?.checked_mul(multiplier)
+}
+
+fn parse_weights(list: &str) -> Option<Vec<f32>> {
+    list.split(',').map(|w| w.trim().parse().ok()).collect()
+}
+
+/// Parses `wght=700,wdth=75` into axis tags and values.
+fn parse_variation(list: &str) -> Option<Vec<(ttf_parser::Tag, f32)>> {
+    list.split(',')
+        .map(|setting| {
+            let (tag, value) = setting.trim().split_once('=')?;
+            Some((dump::parse_tag(tag)?, value.parse().ok()?))
+        })
+        .collect()
+}
+
+/// Parses a comma-separated list of `subsets::GROUPS` names.
+fn parse_groups(list: &str) -> Option<Vec<&'static subsets::Group>> {
+    list.split(',')
+        .map(|name| subsets::group(name.trim()))
+        .collect()
+}
+
+/// Prints the plan followed by its CSS.
+fn print_subset_plan(out: &mut dyn Write, plan: &subsets::SubsetPlan, css: &str) -> io::Result<()> {
+    writeln!(
+        out,
+        "┌─ SUBSET PLAN ─────────────────────────────────────────────────"
+    )?;
+    writeln!(
+        out,
+        "│ Full Font:        {}, {} glyphs",
+        slicing::format_size(plan.font_size),
+        plan.glyph_count
+    )?;
+    writeln!(out, "│")?;
+    for subset in &plan.subsets {
+        if subset.code_points.is_empty() {
+            writeln!(out, "│ {:<17} not covered", subset.group.name)?;
+            continue;
+        }
+        writeln!(
+            out,
+            "│ {:<17} {} code points, {} glyphs, ~{}",
+            subset.group.name,
+            subset.code_points.len(),
+            subset.glyphs,
+            slicing::format_size(subset.estimated_size)
+        )?;
+    }
+    writeln!(out, "│")?;
+    writeln!(
+        out,
+        "│ Left Out:         {} code points in no subset",
+        plan.left_out
+    )?;
+    writeln!(
+        out,
+        "│ Note:             Sizes are before WOFF2 compression"
+    )?;
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    if !css.is_empty() {
+        writeln!(out)?;
+        write!(out, "{}", css)?;
+    }
+    Ok(())
+}
+
+fn print_instance_metrics(
+    out: &mut dyn Write,
+    table: &instance_metrics::MetricsTable,
+) -> io::Result<()> {
+    let rows: Vec<&instance_metrics::InstanceMetrics> = std::iter::once(&table.default)
+        .chain(&table.instances)
+        .collect();
+    let name_width = rows
+        .iter()
+        .map(|row| i18n::display_width(&row.name))
+        .max()
+        .unwrap_or(0)
+        .max(8);
+    let optional = |value: Option<i16>| value.map_or("-".to_string(), |v| v.to_string());
+    writeln!(
+        out,
+        "{:<name_width$}  {:>8}  {:>9}  {:>8}  {:>8}  {:>10}  {:>11}",
+        "Instance", "Ascender", "Descender", "Line gap", "x-height", "Cap height", "Line height"
+    )?;
+    for row in rows {
+        let padding = name_width + row.name.len() - i18n::display_width(&row.name);
+        writeln!(
+            out,
+            "{:<padding$}  {:>8}  {:>9}  {:>8}  {:>8}  {:>10}  {:>11}",
+            row.name,
+            row.ascender,
+            row.descender,
+            row.line_gap,
+            optional(row.x_height),
+            optional(row.cap_height),
+            row.line_height()
+        )?;
+    }
+    writeln!(out)?;
+    let inconsistent: Vec<&str> = table
+        .inconsistent()
+        .map(|instance| instance.name.as_str())
+        .collect();
+    if !table.has_mvar {
+        writeln!(
+            out,
+            "No MVAR table: the vertical metrics are the same at every instance"
+        )
+    } else if inconsistent.is_empty() {
+        writeln!(out, "Line height is the same at every instance")
     } else {
-        scripts.sort();
-        for (i, script) in scripts.iter().enumerate() {
-            let prefix = if i == 0 { "│ Scripts:" } else { "│         " };
-            println!("{} {}", prefix, script);
-        }
-    }
-    println!("└───────────────────────────────────────────────────────────────");
-}
-
-fn describe_opentype_feature(tag: &str) -> &'static str {
-    match tag {
-        "aalt" => "Access All Alternates",
-        "abvf" => "Above-base Forms",
-        "abvm" => "Above-base Mark Positioning",
-        "abvs" => "Above-base Substitutions",
-        "afrc" => "Alternative Fractions",
-        "akhn" => "Akhand",
-        "blwf" => "Below-base Forms",
-        "blwm" => "Below-base Mark Positioning",
-        "blws" => "Below-base Substitutions",
-        "calt" => "Contextual Alternates",
-        "case" => "Case-Sensitive Forms",
-        "ccmp" => "Glyph Composition/Decomposition",
-        "cfar" => "Conjunct Form After Ro",
-        "cjct" => "Conjunct Forms",
-        "clig" => "Contextual Ligatures",
-        "cpct" => "Centered CJK Punctuation",
-        "cpsp" => "Capital Spacing",
-        "cswh" => "Contextual Swash",
-        "curs" => "Cursive Positioning",
-        "cv01" => "Character Variant 1",
-        "cv02" => "Character Variant 2",
-        "cv03" => "Character Variant 3",
-        "cv04" => "Character Variant 4",
-        "cv05" => "Character Variant 5",
-        "cv99" => "Character Variant 99",
-        "c2pc" => "Petite Capitals From Capitals",
-        "c2sc" => "Small Capitals From Capitals",
-        "dist" => "Distances",
-        "dlig" => "Discretionary Ligatures",
-        "dnom" => "Denominators",
-        "dtls" => "Dotless Forms",
-        "expt" => "Expert Forms",
-        "falt" => "Final Glyph on Line Alternates",
-        "fin2" => "Terminal Forms #2",
-        "fin3" => "Terminal Forms #3",
-        "fina" => "Terminal Forms",
-        "flac" => "Flattened accent forms",
-        "frac" => "Fractions",
-        "fwid" => "Full Widths",
-        "half" => "Half Forms",
-        "haln" => "Halant Forms",
-        "halt" => "Alternate Half Widths",
-        "hist" => "Historical Forms",
-        "hkna" => "Horizontal Kana Alternates",
-        "hlig" => "Historical Ligatures",
-        "hngl" => "Hangul",
-        "hojo" => "Hojo Kanji Forms",
-        "hwid" => "Half Widths",
-        "init" => "Initial Forms",
-        "isol" => "Isolated Forms",
-        "ital" => "Italics",
-        "jalt" => "Justification Alternates",
-        "jp78" => "JIS78 Forms",
-        "jp83" => "JIS83 Forms",
-        "jp90" => "JIS90 Forms",
-        "jp04" => "JIS2004 Forms",
-        "kern" => "Kerning",
-        "lfbd" => "Left Bounds",
-        "liga" => "Standard Ligatures",
-        "ljmo" => "Leading Jamo Forms",
-        "lnum" => "Lining Figures",
-        "locl" => "Localized Forms",
-        "ltra" => "Left-to-right alternates",
-        "ltrm" => "Left-to-right mirrored forms",
-        "mark" => "Mark Positioning",
-        "med2" => "Medial Forms #2",
-        "medi" => "Medial Forms",
-        "mgrk" => "Mathematical Greek",
-        "mkmk" => "Mark to Mark Positioning",
-        "mset" => "Mark Positioning via Substitution",
-        "nalt" => "Alternate Annotation Forms",
-        "nlck" => "NLC Kanji Forms",
-        "nukt" => "Nukta Forms",
-        "numr" => "Numerators",
-        "onum" => "Oldstyle Figures",
-        "opbd" => "Optical Bounds",
-        "ordn" => "Ordinals",
-        "ornm" => "Ornaments",
-        "palt" => "Proportional Alternate Widths",
-        "pcap" => "Petite Capitals",
-        "pkna" => "Proportional Kana",
-        "pnum" => "Proportional Figures",
-        "pref" => "Pre-Base Forms",
-        "pres" => "Pre-base Substitutions",
-        "pstf" => "Post-base Forms",
-        "psts" => "Post-base Substitutions",
-        "pwid" => "Proportional Widths",
-        "qwid" => "Quarter Widths",
-        "rand" => "Randomize",
-        "rclt" => "Required Contextual Alternates",
-        "rkrf" => "Rakar Forms",
-        "rlig" => "Required Ligatures",
-        "rphf" => "Reph Forms",
-        "rtbd" => "Right Bounds",
-        "rtla" => "Right-to-left alternates",
-        "rtlm" => "Right-to-left mirrored forms",
-        "ruby" => "Ruby Notation Forms",
-        "rvrn" => "Required Variation Alternates",
-        "salt" => "Stylistic Alternates",
-        "sinf" => "Scientific Inferiors",
-        "size" => "Optical size",
-        "smcp" => "Small Capitals",
-        "smpl" => "Simplified Forms",
-        "ss01" => "Stylistic Set 1",
-        "ss02" => "Stylistic Set 2",
-        "ss03" => "Stylistic Set 3",
-        "ss04" => "Stylistic Set 4",
-        "ss05" => "Stylistic Set 5",
-        "ss06" => "Stylistic Set 6",
-        "ss07" => "Stylistic Set 7",
-        "ss08" => "Stylistic Set 8",
-        "ss09" => "Stylistic Set 9",
-        "ss10" => "Stylistic Set 10",
-        "ss11" => "Stylistic Set 11",
-        "ss12" => "Stylistic Set 12",
-        "ss13" => "Stylistic Set 13",
-        "ss14" => "Stylistic Set 14",
-        "ss15" => "Stylistic Set 15",
-        "ss16" => "Stylistic Set 16",
-        "ss17" => "Stylistic Set 17",
-        "ss18" => "Stylistic Set 18",
-        "ss19" => "Stylistic Set 19",
-        "ss20" => "Stylistic Set 20",
-        "ssty" => "Math script style alternates",
-        "stch" => "Stretching Glyph Decomposition",
-        "subs" => "Subscript",
-        "sups" => "Superscript",
-        "swsh" => "Swash",
-        "titl" => "Titling",
-        "tjmo" => "Trailing Jamo Forms",
-        "tnam" => "Traditional Name Forms",
-        "tnum" => "Tabular Figures",
-        "trad" => "Traditional Forms",
-        "twid" => "Third Widths",
-        "unic" => "Unicase",
-        "valt" => "Alternate Vertical Metrics",
-        "vatu" => "Vattu Variants",
-        "vert" => "Vertical Writing",
-        "vhal" => "Alternate Vertical Half Metrics",
-        "vjmo" => "Vowel Jamo Forms",
-        "vkna" => "Vertical Kana Alternates",
-        "vkrn" => "Vertical Kerning",
-        "vpal" => "Proportional Alternate Vertical Metrics",
-        "vrt2" => "Vertical Alternates and Rotation",
-        "vrtr" => "Vertical Alternates for Rotation",
-        "zero" => "Slashed Zero",
-        _ => "Unknown feature",
+        writeln!(
+            out,
+            "Line height differs from the default at {}",
+            inconsistent.join(", ")
+        )
+    }
+}
+
+fn print_slice_plan(out: &mut dyn Write, plan: &slicing::SlicePlan) -> io::Result<()> {
+    let (min, def, max) = plan.axis;
+    writeln!(
+        out,
+        "┌─ SLICING RECOMMENDATION ──────────────────────────────────────"
+    )?;
+    writeln!(out, "│ wght Axis:        {}–{} (default {})", min, max, def)?;
+    writeln!(
+        out,
+        "│ Full Font:        {}",
+        slicing::format_size(plan.font_size)
+    )?;
+    writeln!(out, "│")?;
+    writeln!(out, "│ Static instances:")?;
+    for slice in &plan.statics {
+        let instance = match &slice.instance_name {
+            Some(name) => format!("  (named instance \"{}\")", name),
+            None => String::new(),
+        };
+        writeln!(
+            out,
+            "│   wght {:<10} ~{}{}",
+            slice.weight,
+            slicing::format_size(slice.estimated_size),
+            instance
+        )?;
+    }
+    writeln!(
+        out,
+        "│   Total           ~{}",
+        slicing::format_size(plan.statics_size())
+    )?;
+    writeln!(out, "│")?;
+    let (lo, hi) = plan.subrange;
+    writeln!(out, "│ Axis subrange:")?;
+    writeln!(
+        out,
+        "│   wght {:<10} ~{}",
+        format!("{}–{}", lo, hi),
+        slicing::format_size(plan.subrange_size)
+    )?;
+    writeln!(out, "│")?;
+    if plan.prefers_subrange() {
+        writeln!(
+            out,
+            "│ Recommendation:   Serve one variable font limited to wght {}–{}",
+            lo, hi
+        )?;
+    } else if plan.statics.len() == 1 {
+        writeln!(out, "│ Recommendation:   Serve a single static instance")?;
+    } else {
+        writeln!(
+            out,
+            "│ Recommendation:   Serve {} static instances",
+            plan.statics.len()
+        )?;
+    }
+    if !plan.pinned_axes.is_empty() {
+        writeln!(
+            out,
+            "│ Note:             Other axes ({}) are pinned at their defaults",
+            plan.pinned_axes.join(", ")
+        )?;
+    }
+    writeln!(
+        out,
+        "│ Note:             Sizes are uncompressed estimates; variation data is"
+    )?;
+    writeln!(
+        out,
+        "│                   assumed to be spread evenly along the axis"
+    )?;
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Controls which parts of the default report are computed and printed.
+struct ReportOptions {
+    sections: Vec<Section>,
+    /// Skip per-glyph scans within the selected sections.
+    terse: bool,
+    lang: Lang,
+    /// Print the report as JSON instead of text.
+    json: bool,
+    /// Hash the path and leave out URLs and license text in the JSON.
+    redact: bool,
+}
+
+fn print_font_info(
+    out: &mut dyn Write,
+    face: &Face,
+    path: &str,
+    config: &config::Config,
+    options: &ReportOptions,
+) -> io::Result<()> {
+    let t = &Catalog::new(options.lang);
+    let features = &FeatureRegistry::new(options.lang, t, config);
+    // Only the selected sections are analyzed, so the rest cost nothing.
+    let mut report = FontReport::new(face, config, features, &options.sections, options.terse);
+    report.file = Some(path.to_string());
+    if options.redact {
+        report.redact();
+    }
+    if options.json {
+        let json = serde_json::to_string_pretty(&report).map_err(io::Error::other)?;
+        return writeln!(out, "{}", json);
+    }
+
+    writeln!(
+        out,
+        "╔═══════════════════════════════════════════════════════════════"
+    )?;
+    writeln!(out, "║ {}", t.get("header-font-information"))?;
+    writeln!(
+        out,
+        "╠═══════════════════════════════════════════════════════════════"
+    )?;
+    writeln!(out, "║ {}: {}", t.get("label-file"), path)?;
+    writeln!(
+        out,
+        "╚═══════════════════════════════════════════════════════════════"
+    )?;
+
+    if let Some(names) = &report.names {
+        print_names_section(out, t, names)?;
+    }
+    if let Some(name_table) = &report.name_table {
+        print_name_table_section(out, t, name_table)?;
+    }
+    if let Some(format) = &report.format {
+        print_format_section(out, t, format)?;
+    }
+    if let Some(health) = &report.health {
+        print_health_section(out, t, health)?;
+    }
+    if let Some(footprint) = &report.footprint {
+        print_footprint_section(out, t, footprint)?;
+    }
+    if let Some(tables) = &report.tables {
+        print_tables_section(out, t, tables)?;
+    }
+    if let Some(size) = &report.size {
+        print_size_section(out, t, size)?;
+    }
+    if let Some(head) = &report.head {
+        print_head_section(out, t, head)?;
+    }
+    if let Some(metrics) = &report.metrics {
+        print_metrics_section(out, t, metrics)?;
+    }
+    if let Some(os2) = &report.os2 {
+        print_os2_section(out, t, os2)?;
+    }
+    if let Some(embedding) = &report.embedding {
+        print_embedding_section(out, t, embedding)?;
+    }
+    if let Some(post) = &report.post {
+        print_post_section(out, t, post)?;
+    }
+    if let Some(axes) = &report.axes {
+        print_axes_section(out, t, axes)?;
+    }
+    if let Some(instances) = &report.instances {
+        print_instances_section(out, t, instances)?;
+    }
+    if let Some(feature_variations) = &report.feature_variations {
+        print_feature_variations_section(out, t, feature_variations)?;
+    }
+    if let Some(optical_size) = &report.optical_size {
+        print_optical_size_section(out, t, optical_size)?;
+    }
+    if let Some(gsub) = &report.gsub {
+        print_features_section(out, t, "section-gsub", "gsub-none", gsub)?;
+    }
+    if let Some(gpos) = &report.gpos {
+        print_features_section(out, t, "section-gpos", "gpos-none", gpos)?;
+    }
+    if let Some(scripts) = &report.scripts {
+        print_scripts_section(out, t, scripts)?;
+    }
+    if let Some(math) = &report.math {
+        print_math_section(out, t, math)?;
+    }
+    if let Some(color) = &report.color {
+        print_color_section(out, t, color)?;
+    }
+    if let Some(emoji) = &report.emoji {
+        print_emoji_section(out, t, emoji)?;
+    }
+    if let Some(flags) = &report.flags {
+        print_flags_section(out, t, flags)?;
+    }
+    if let Some(skin_tones) = &report.skin_tones {
+        print_skin_tones_section(out, t, skin_tones)?;
+    }
+    if let Some(unicode) = &report.unicode {
+        print_unicode_section(out, t, unicode)?;
+    }
+    if let Some(planes) = &report.planes {
+        print_planes_section(out, t, planes)?;
     }
+    if let Some(blocks) = &report.blocks {
+        print_blocks_section(out, t, blocks)?;
+    }
+    if let Some(advances) = &report.advances {
+        print_advances_section(out, t, advances)?;
+    }
+    if let Some(tabular) = &report.tabular {
+        print_tabular_section(out, t, tabular)?;
+    }
+    if let Some(disambiguation) = &report.disambiguation {
+        print_disambiguation_section(out, t, disambiguation)?;
+    }
+    if let Some(ink) = &report.ink {
+        print_ink_section(out, t, ink)?;
+    }
+    if let Some(stems) = &report.stems {
+        print_stems_section(out, t, stems, face.units_per_em())?;
+    }
+    if let Some(ignorables) = &report.ignorables {
+        print_ignorables_section(out, t, ignorables)?;
+    }
+    if let Some(mirroring) = &report.mirroring {
+        print_mirroring_section(out, t, mirroring)?;
+    }
+    if let Some(marks) = &report.marks {
+        print_marks_section(out, t, marks)?;
+    }
+    if let Some(normalization) = &report.normalization {
+        print_normalization_section(out, t, normalization)?;
+    }
+    if let Some(case) = &report.case {
+        print_case_section(out, t, case)?;
+    }
+    if let Some(dotted_i) = &report.dotted_i {
+        print_dotted_i_section(out, t, dotted_i)?;
+    }
+    if let Some(special_cases) = &report.special_cases {
+        print_special_cases_section(out, t, special_cases)?;
+    }
+    if let Some(greek) = &report.greek {
+        print_greek_section(out, t, greek)?;
+    }
+    if let Some(cyrillic) = &report.cyrillic {
+        print_cyrillic_section(out, t, cyrillic)?;
+    }
+    if let Some(vietnamese) = &report.vietnamese {
+        print_vietnamese_section(out, t, vietnamese)?;
+    }
+    if let Some(african) = &report.african {
+        print_african_section(out, t, african)?;
+    }
+    Ok(())
+}
+
+fn print_names_section(out: &mut dyn Write, t: &Catalog, names: &report::Names) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-names"))?;
+
+    for (key, value) in [
+        ("label-family-name", &names.family),
+        ("label-subfamily", &names.subfamily),
+        ("label-full-name", &names.full_name),
+        ("label-postscript-name", &names.postscript_name),
+        ("label-version", &names.version),
+    ] {
+        if let Some(value) = value {
+            writeln!(out, "{}{}", t.label(key), value)?;
+        }
+    }
+
+    if names.is_empty() {
+        writeln!(out, "│ {}", t.get("names-none"))?;
+        writeln!(out, "│")?;
+        writeln!(out, "│ {}:", t.get("names-available"))?;
+        for name in &names.available {
+            writeln!(out, "│   [ID {}] {}", name.name_id, name.value)?;
+        }
+    }
+
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Prints every name record: its ID and meaning, platform, encoding and
+/// language, then the decoded string.
+fn print_name_table_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    table: &name_table::NameTableReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-name-table"))?;
+    for (i, record) in table.records.iter().enumerate() {
+        if i > 0 {
+            writeln!(out, "│")?;
+        }
+        let language = match &record.language {
+            Some(language) => format!("{} (0x{:04X})", language, record.language_id),
+            None => format!("0x{:04X}", record.language_id),
+        };
+        writeln!(
+            out,
+            "│ [ID {}] {} · {} {}/{} · {}",
+            record.name_id,
+            record.meaning.unwrap_or("Reserved"),
+            record.platform(),
+            record.platform_id,
+            record.encoding_id,
+            language
+        )?;
+        match &record.value {
+            // Copyright and license strings often span several lines.
+            Some(value) => {
+                for line in value.lines() {
+                    writeln!(out, "│   {}", line)?;
+                }
+            }
+            None => writeln!(out, "│   {}", t.get("name-table-undecodable"))?,
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+fn print_head_section(out: &mut dyn Write, t: &Catalog, head: &head::HeadReport) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-head"))?;
+    writeln!(
+        out,
+        "{}{:.3}",
+        t.label("label-font-revision"),
+        head.font_revision
+    )?;
+    writeln!(out, "{}{}", t.label("label-created"), head.created)?;
+    writeln!(out, "{}{}", t.label("label-modified"), head.modified)?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-units-per-em"),
+        head.units_per_em
+    )?;
+    // Several flags are usually set, each with a long name, so they get a
+    // line each below the value.
+    let label = t.label("label-head-flags");
+    writeln!(out, "{}0x{:04X}", label, head.flags)?;
+    let indent = format!("│{}", " ".repeat(i18n::display_width(&label) - 1));
+    for name in head.flag_names() {
+        writeln!(out, "{}{}", indent, name)?;
+    }
+    let styles = head.mac_style_names();
+    if styles.is_empty() {
+        writeln!(
+            out,
+            "{}0x{:04X}",
+            t.label("label-mac-style"),
+            head.mac_style
+        )?;
+    } else {
+        writeln!(
+            out,
+            "{}0x{:04X} ({})",
+            t.label("label-mac-style"),
+            head.mac_style,
+            styles.join(", ")
+        )?;
+    }
+    writeln!(
+        out,
+        "{}{} px",
+        t.label("label-lowest-rec-ppem"),
+        head.lowest_rec_ppem
+    )?;
+    writeln!(
+        out,
+        "{}{} ({})",
+        t.label("label-loca-format"),
+        head.index_to_loc_format,
+        head.loca_format()
+    )?;
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+fn print_format_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    format: &report::Format,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-format"))?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-sfnt-version"),
+        format.sfnt_version
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-outline-flavor"),
+        format.outline_flavor
+    )?;
+    if !format.other_sources.is_empty() {
+        writeln!(
+            out,
+            "{}{}",
+            t.label("label-other-sources"),
+            format.other_sources.join(", ")
+        )?;
+    }
+    if format.mixed {
+        let label = t.label("label-note");
+        let indent = " ".repeat(i18n::display_width(&label) - 1);
+        writeln!(out, "{}{}", label, t.get("format-mixed"))?;
+        writeln!(out, "│{}{}", indent, t.get("format-mixed-fallback"))?;
+    }
+
+    if let Some(glyphs_by_source) = &format.glyphs_by_source {
+        writeln!(out, "│")?;
+        writeln!(out, "│ {}:", t.get("format-glyphs-by-source"))?;
+        for count in glyphs_by_source {
+            writeln!(out, "│   {:<22} {}", count.sources, count.glyphs)?;
+        }
+        if !format.missing_fallback.is_empty() {
+            writeln!(out, "│")?;
+            let warning = t.get("label-warning");
+            writeln!(
+                out,
+                "│ {}: {} {}",
+                warning,
+                format.missing_fallback.len(),
+                t.get("format-missing-fallback")
+            )?;
+            let indent = " ".repeat(i18n::display_width(warning) + 3);
+            writeln!(
+                out,
+                "│{}{}",
+                indent,
+                t.get("format-missing-fallback-detail")
+            )?;
+            for glyph in format.missing_fallback.iter().take(10) {
+                let name = glyph.name.as_deref().unwrap_or("");
+                writeln!(out, "│            {} {}", glyph.glyph_id, name)?;
+            }
+            if format.missing_fallback.len() > 10 {
+                writeln!(
+                    out,
+                    "│            ... and {} more (see --glyph-sources)",
+                    format.missing_fallback.len() - 10
+                )?;
+            }
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+fn print_health_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &health::HealthReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-health"))?;
+    writeln!(
+        out,
+        "{}{} {}, {} {}, {} {}",
+        t.label("label-table-health"),
+        report.count(health::Health::Ok),
+        t.get("health-decoded"),
+        report.count(health::Health::Partial),
+        t.get("health-partially"),
+        report.count(health::Health::Failed),
+        t.get("health-failed")
+    )?;
+    let label = t.label("label-table-problems");
+    let indent = format!("│{}", " ".repeat(i18n::display_width(&label) - 1));
+    for (i, table) in report.problems().enumerate() {
+        let prefix = if i == 0 { &label } else { &indent };
+        let health = match table.health {
+            health::Health::Failed => t.get("health-failed"),
+            _ => t.get("health-partial"),
+        };
+        writeln!(out, "{}{}  {}: {}", prefix, table.tag, health, table.detail)?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )
+}
+
+fn print_footprint_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    footprint: &footprint::Footprint,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-footprint"))?;
+    writeln!(
+        out,
+        "{}{}, {}",
+        t.label("label-decompressed"),
+        slicing::format_size(footprint.decompressed),
+        footprint.comparison()
+    )?;
+    writeln!(
+        out,
+        "{}{} ({}%) in {}",
+        t.label("label-glyph-data"),
+        slicing::format_size(footprint.glyph_data),
+        footprint.glyph_data * 100 / footprint.decompressed.max(1),
+        footprint.glyph_tables.join(", ")
+    )?;
+    writeln!(
+        out,
+        "{}{} bytes on average over {} glyphs",
+        t.label("label-per-glyph"),
+        footprint.per_glyph(),
+        footprint.glyphs
+    )?;
+    let hinting = match footprint.glyph_instructions {
+        _ if footprint.hinting_tables == 0 && footprint.glyph_instructions.unwrap_or(0) == 0 => {
+            t.get("value-none").to_string()
+        }
+        Some(instructions) => format!(
+            "{} in fpgm, prep and cvt, {} in glyph programs",
+            slicing::format_size(footprint.hinting_tables),
+            slicing::format_size(instructions)
+        ),
+        None => format!(
+            "{} in fpgm, prep and cvt",
+            slicing::format_size(footprint.hinting_tables)
+        ),
+    };
+    writeln!(out, "{}{}", t.label("label-hinting-size"), hinting)?;
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )
+}
+
+/// Prints each table directory record, marking tables whose checksum
+/// doesn't match their bytes.
+fn print_tables_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    directory: &tables::TableDirectory,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-tables"))?;
+    writeln!(
+        out,
+        "│ {:<4}  {:>10}  {:>9}  {:>10}  {:>10}  {:>6}",
+        "Tag", "Offset", "Length", "Checksum", "Computed", "Share"
+    )?;
+    for table in &directory.tables {
+        let computed = match table.computed {
+            Some(computed) => format!("0x{:08X}", computed),
+            None => "past EOF".to_string(),
+        };
+        writeln!(
+            out,
+            "│ {:<4}  0x{:08X}  {:>9}  0x{:08X}  {:>10}  {:>5.1}%{}",
+            table.tag,
+            table.offset,
+            table.length,
+            table.checksum,
+            computed,
+            table.share,
+            if table.checksum_matches() {
+                ""
+            } else {
+                "  ✗"
+            }
+        )?;
+    }
+    let mismatched: Vec<&str> = directory
+        .mismatches()
+        .map(|table| table.tag.trim_end())
+        .collect();
+    let checksums = if mismatched.is_empty() {
+        format!("all {} match", directory.tables.len())
+    } else {
+        format!(
+            "{} of {} don't match ({})",
+            mismatched.len(),
+            directory.tables.len(),
+            mismatched.join(", ")
+        )
+    };
+    writeln!(out, "│")?;
+    writeln!(
+        out,
+        "{}{} bytes",
+        t.label("label-file-size"),
+        directory.file_size
+    )?;
+    writeln!(out, "{}{}", t.label("label-checksums"), checksums)?;
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )
+}
+
+fn print_size_section(out: &mut dyn Write, t: &Catalog, size: &size::SizeReport) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-size"))?;
+    let label = t.label("label-largest-tables");
+    let indent = format!("│{}", " ".repeat(i18n::display_width(&label) - 1));
+    for (i, table) in size.largest.iter().enumerate() {
+        let prefix = if i == 0 { &label } else { &indent };
+        writeln!(
+            out,
+            "{}{}  {} ({:.1}%)",
+            prefix,
+            table.tag,
+            slicing::format_size(table.bytes),
+            table.share
+        )?;
+    }
+    let hinting = &size.hinting;
+    let hinting = if hinting.total() == 0 {
+        t.get("value-none").to_string()
+    } else {
+        format!(
+            "{} in fpgm, prep and cvt, {} in glyph programs, {} in hdmx, LTSH and VDMX",
+            slicing::format_size(hinting.tables),
+            slicing::format_size(hinting.glyph_programs),
+            slicing::format_size(hinting.device_tables)
+        )
+    };
+    writeln!(out, "{}{}", t.label("label-hinting-size"), hinting)?;
+    let bitmaps = if size.bitmap_tables.is_empty() {
+        t.get("value-none").to_string()
+    } else {
+        format!(
+            "{} in {}",
+            slicing::format_size(size.bitmaps),
+            size.bitmap_tables.join(", ")
+        )
+    };
+    writeln!(out, "{}{}", t.label("label-bitmaps"), bitmaps)?;
+    let label = t.label("label-unused-cmap");
+    let indent = format!("│{}", " ".repeat(i18n::display_width(&label) - 1));
+    for (i, subtable) in size.unused_cmap_subtables.iter().enumerate() {
+        let prefix = if i == 0 { &label } else { &indent };
+        writeln!(
+            out,
+            "{}({},{}) format {}, {}: {}",
+            prefix,
+            subtable.platform_id,
+            subtable.encoding_id,
+            subtable.format,
+            slicing::format_size(subtable.bytes),
+            subtable.reason
+        )?;
+    }
+    let label = t.label("label-suggestions");
+    let indent = format!("│{}", " ".repeat(i18n::display_width(&label) - 1));
+    if size.suggestions.is_empty() {
+        writeln!(out, "{}{}", label, t.get("size-no-suggestions"))?;
+    }
+    for (i, suggestion) in size.suggestions.iter().enumerate() {
+        let prefix = if i == 0 { &label } else { &indent };
+        writeln!(out, "{}{}", prefix, suggestion)?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )
+}
+
+fn print_metrics_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    metrics: &report::Metrics,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-metrics"))?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-units-per-em"),
+        metrics.units_per_em
+    )?;
+    writeln!(out, "{}{}", t.label("label-ascender"), metrics.ascender)?;
+    writeln!(out, "{}{}", t.label("label-descender"), metrics.descender)?;
+    writeln!(out, "{}{}", t.label("label-line-gap"), metrics.line_gap)?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-glyph-count"),
+        metrics.glyph_count
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-is-monospaced"),
+        metrics.monospaced
+    )?;
+    writeln!(out, "{}{}", t.label("label-is-bold"), metrics.bold)?;
+    writeln!(out, "{}{}", t.label("label-is-italic"), metrics.italic)?;
+    writeln!(out, "{}{}", t.label("label-is-oblique"), metrics.oblique)?;
+    writeln!(out, "{}{}", t.label("label-weight"), metrics.weight)?;
+    writeln!(out, "{}{}", t.label("label-width"), metrics.width)?;
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+fn print_os2_section(out: &mut dyn Write, t: &Catalog, os2: &os2::Os2Report) -> io::Result<()> {
+    let named = |value: String, name: Option<&str>| match name {
+        Some(name) => format!("{} ({})", value, name),
+        None => value,
+    };
+    let optional = |value: Option<i16>| value.map_or_else(|| "-".to_string(), |v| v.to_string());
+
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-os2"))?;
+    writeln!(out, "{}{}", t.label("label-version"), os2.version)?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-weight-class"),
+        named(os2.weight_class.to_string(), os2.weight_name())
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-width-class"),
+        named(os2.width_class.to_string(), os2.width_name())
+    )?;
+    writeln!(
+        out,
+        "{}0x{:04X} ({})",
+        t.label("label-fs-type"),
+        os2.fs_type,
+        os2.embedding().join(", ")
+    )?;
+    let flags = os2.selection_flags();
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-fs-selection"),
+        named(
+            format!("0x{:04X}", os2.fs_selection),
+            (!flags.is_empty()).then(|| flags.join(", ")).as_deref()
+        )
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-typo-ascender"),
+        os2.typo_ascender
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-typo-descender"),
+        os2.typo_descender
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-typo-line-gap"),
+        os2.typo_line_gap
+    )?;
+    writeln!(out, "{}{}", t.label("label-win-ascent"), os2.win_ascent)?;
+    writeln!(out, "{}{}", t.label("label-win-descent"), os2.win_descent)?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-x-height"),
+        optional(os2.x_height)
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-cap-height"),
+        optional(os2.cap_height)
+    )?;
+    writeln!(out, "{}'{}'", t.label("label-vendor-id"), os2.vendor_id)?;
+
+    let (first, rest) = list_prefixes(t, "label-unicode-ranges");
+    if os2.unicode_ranges.is_empty() {
+        writeln!(out, "{} -", first)?;
+    }
+    for (i, range) in os2.unicode_ranges.iter().enumerate() {
+        let prefix = if i == 0 { &first } else { &rest };
+        writeln!(out, "{} {}", prefix, range)?;
+    }
+    if let Some(code_pages) = &os2.code_pages {
+        let (first, rest) = list_prefixes(t, "label-code-pages");
+        if code_pages.is_empty() {
+            writeln!(out, "{} -", first)?;
+        }
+        for (i, code_page) in code_pages.iter().enumerate() {
+            let prefix = if i == 0 { &first } else { &rest };
+            writeln!(out, "{} {}", prefix, code_page)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Spells out what fsType allows, for deciding whether a font can go into
+/// a PDF or other document.
+fn print_embedding_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &embedding::EmbeddingReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-embedding"))?;
+    writeln!(
+        out,
+        "{}0x{:04X} ({})",
+        t.label("label-fs-type"),
+        report.fs_type,
+        report.summary().join(", ")
+    )?;
+    writeln!(out, "{}{}", t.label("label-pdf-embedding"), report.pdf())?;
+    writeln!(out, "│")?;
+    writeln!(out, "│ {}", report.level.description())?;
+    if report.no_subsetting {
+        writeln!(out, "│ {}", t.get("embedding-full-font"))?;
+    }
+    if report.bitmap_only {
+        writeln!(out, "│ {}", t.get("embedding-bitmap-only"))?;
+    }
+    for warning in &report.warnings {
+        writeln!(out, "│ {}: {}", t.get("label-warning"), warning)?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+fn print_post_section(out: &mut dyn Write, t: &Catalog, post: &post::PostReport) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-post"))?;
+    writeln!(
+        out,
+        "{}{:.1} ({})",
+        t.label("label-post-format"),
+        post.format,
+        post.format_description()
+    )?;
+    writeln!(
+        out,
+        "{}{}°",
+        t.label("label-italic-angle"),
+        post.italic_angle
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-underline-position"),
+        post.underline_position
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-underline-thickness"),
+        post.underline_thickness
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-fixed-pitch"),
+        post.is_fixed_pitch
+    )?;
+    writeln!(
+        out,
+        "{}{} / {}",
+        t.label("label-named-glyphs"),
+        post.named_glyphs,
+        post.glyphs
+    )?;
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+fn print_axes_section(out: &mut dyn Write, t: &Catalog, axes: &[report::Axis]) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-axes"))?;
+    if axes.is_empty() {
+        writeln!(out, "│ {}", t.get("axes-none"))?;
+    }
+    for axis in axes {
+        let hidden = if axis.hidden { " [hidden]" } else { "" };
+        writeln!(
+            out,
+            "│ {}  {:<16} {}–{} (default {}){}",
+            axis.tag, axis.name, axis.min, axis.max, axis.default, hidden
+        )?;
+        match (&axis.description, axis.source) {
+            (Some(description), Some(source)) => {
+                writeln!(out, "│       {} [{}]", description, source)?
+            }
+            _ => writeln!(out, "│       {}", t.get("axis-custom"))?,
+        }
+        for warning in &axis.warnings {
+            writeln!(out, "│       {}: {}", t.get("label-warning"), warning)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Lists each named instance with the axis coordinates it maps to.
+fn print_instances_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    instances: &[report::Instance],
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-instances"))?;
+    if instances.is_empty() {
+        writeln!(out, "│ {}", t.get("instances-none"))?;
+    }
+    for instance in instances {
+        let coordinates: Vec<String> = instance
+            .coordinates
+            .iter()
+            .map(|(tag, value)| format!("{}={}", tag, value))
+            .collect();
+        writeln!(
+            out,
+            "│ {:<24} {}",
+            instance.subfamily,
+            coordinates.join(" ")
+        )?;
+        if let Some(postscript_name) = &instance.postscript_name {
+            writeln!(
+                out,
+                "│   {}: {}",
+                t.get("label-postscript-name"),
+                postscript_name
+            )?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+fn print_feature_variations_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &feature_variations::FeatureVariationReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-feature-variations"))?;
+    let location: Vec<String> = report
+        .location
+        .iter()
+        .map(|(tag, value)| format!("{}={}", tag, value))
+        .collect();
+    writeln!(out, "{}{}", t.label("label-location"), location.join(" "))?;
+
+    let label = t.label("label-rules");
+    let indent = format!("│{}", " ".repeat(i18n::display_width(&label) - 1));
+    for (i, record) in report.records.iter().enumerate() {
+        writeln!(
+            out,
+            "{}{} {}{}",
+            if i == 0 { &label } else { &indent },
+            record.table,
+            record.rule(),
+            if record.active {
+                format!("  ({})", t.get("feature-variations-active"))
+            } else {
+                String::new()
+            }
+        )?;
+    }
+
+    // GPOS variations only move glyphs, so swaps are a GSUB matter.
+    let gsub = report.records.iter().filter(|r| r.table == "GSUB");
+    if report.swaps.is_empty() {
+        if gsub.clone().any(|r| r.active) {
+            writeln!(out, "│ {}", t.get("feature-variations-no-swaps"))?;
+        } else if gsub.count() > 0 {
+            writeln!(out, "│ {}", t.get("feature-variations-inactive"))?;
+        }
+    } else {
+        let label = t.label("label-swapped-glyphs");
+        let indent = format!("│{}", " ".repeat(i18n::display_width(&label) - 1));
+        for (i, swap) in report.swaps.iter().enumerate() {
+            let substitute = if swap.default == swap.glyph {
+                format!("{} → {}", swap.glyph, swap.current)
+            } else {
+                format!("{} → {} → {}", swap.glyph, swap.default, swap.current)
+            };
+            writeln!(
+                out,
+                "{}{} ({})",
+                if i == 0 { &label } else { &indent },
+                substitute,
+                swap.feature
+            )?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+fn print_optical_size_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    optical_size: &report::OpticalSize,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-opsz"))?;
+    if optical_size.is_empty() {
+        writeln!(out, "│ {}", t.get("opsz-none"))?;
+    } else {
+        if let Some(size) = &optical_size.size_feature {
+            let range = match size.range {
+                Some((lo, hi)) => {
+                    format!(", range {}–{}pt", opsz::format_pt(lo), opsz::format_pt(hi))
+                }
+                None => String::new(),
+            };
+            writeln!(
+                out,
+                "{}design size {}pt{}",
+                t.label("label-size-feature"),
+                opsz::format_pt(size.design_size),
+                range
+            )?;
+            if let Some(name) = &size.subfamily {
+                writeln!(out, "│                   subfamily \"{}\"", name)?;
+            }
+        }
+        if let Some((min, def, max)) = optical_size.axis {
+            writeln!(
+                out,
+                "{}{}–{}pt (default {})",
+                t.label("label-opsz-axis"),
+                opsz::format_pt(min),
+                opsz::format_pt(max),
+                opsz::format_pt(def)
+            )?;
+        }
+        if let Some((lo, hi)) = optical_size.os2_range {
+            writeln!(
+                out,
+                "{}{}–{}pt",
+                t.label("label-os2-range"),
+                opsz::format_pt(lo),
+                opsz::format_pt(hi)
+            )?;
+        }
+        for value in &optical_size.stat_values {
+            let range = match value.range {
+                Some((lo, hi)) => format!(" ({}–{})", opsz::format_pt(lo), opsz::format_pt(hi)),
+                None => String::new(),
+            };
+            writeln!(
+                out,
+                "{}{} {}{}",
+                t.label("label-stat-value"),
+                opsz::format_pt(value.value),
+                value.name,
+                range
+            )?;
+        }
+        if let Some((lo, hi)) = optical_size.designed_range {
+            writeln!(out, "│")?;
+            if lo == hi {
+                writeln!(
+                    out,
+                    "│ {} {}pt",
+                    t.get("opsz-designed-for"),
+                    opsz::format_pt(lo)
+                )?;
+            } else {
+                writeln!(
+                    out,
+                    "│ {} {}–{}pt",
+                    t.get("opsz-designed-for"),
+                    opsz::format_pt(lo),
+                    opsz::format_pt(hi)
+                )?;
+            }
+        }
+        for conflict in &optical_size.conflicts {
+            writeln!(out, "│ {}: {}", t.get("label-warning"), conflict)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Prints the GSUB or GPOS feature list, with the catalog keys for the
+/// section header and the message shown when the table has no features.
+fn print_features_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    header: &str,
+    none: &str,
+    features: &[report::Feature],
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header(header))?;
+    if features.is_empty() {
+        writeln!(out, "│ {}", t.get(none))?;
+    }
+    let (first, rest) = list_prefixes(t, "label-features");
+    for (i, feature) in features.iter().enumerate() {
+        let prefix = if i == 0 { &first } else { &rest };
+        writeln!(
+            out,
+            "{} {} - {} ({} lookup{}, {} glyphs)",
+            prefix,
+            feature.tag,
+            feature.ui_name.as_ref().unwrap_or(&feature.description),
+            feature.lookups,
+            if feature.lookups == 1 { "" } else { "s" },
+            feature.glyphs
+        )?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+fn print_scripts_section(out: &mut dyn Write, t: &Catalog, scripts: &[String]) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-scripts"))?;
+    if scripts.is_empty() {
+        writeln!(out, "│ {}", t.get("scripts-none"))?;
+    }
+    let (first, rest) = list_prefixes(t, "label-scripts");
+    for (i, script) in scripts.iter().enumerate() {
+        let prefix = if i == 0 { &first } else { &rest };
+        writeln!(out, "{} {}", prefix, script)?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+fn print_math_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &math::MathReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-math"))?;
+    writeln!(
+        out,
+        "{}{} glyphs, up to {} levels",
+        t.label("label-ssty"),
+        report.ssty_glyphs,
+        report.ssty_levels
+    )?;
+    writeln!(
+        out,
+        "{}{} glyphs",
+        t.label("label-flac"),
+        report.flac_glyphs
+    )?;
+    writeln!(
+        out,
+        "{}{} glyphs, {}",
+        t.label("label-dtls"),
+        report.dtls_glyphs,
+        if report.dotless_i_j {
+            "i and j covered"
+        } else {
+            "i and j not both covered"
+        }
+    )?;
+    let label = t.label("label-size-variants");
+    let indent = format!("│{}", " ".repeat(i18n::display_width(&label) - 1));
+    for (i, operator) in report.operators.iter().enumerate() {
+        let prefix = if i == 0 { &label } else { &indent };
+        let sizes = match (operator.variants, operator.assembly) {
+            _ if !operator.grows() => "single size".to_string(),
+            (0, true) => "assembly".to_string(),
+            (variants, true) => format!("{} sizes + assembly", variants),
+            (variants, false) => format!("{} sizes", variants),
+        };
+        writeln!(out, "{}{}  {}", prefix, operator.c, sizes)?;
+    }
+    if !report.missing_operators.is_empty() {
+        let missing: Vec<String> = report
+            .missing_operators
+            .iter()
+            .map(char::to_string)
+            .collect();
+        writeln!(
+            out,
+            "{}{}",
+            t.label("label-missing-operators"),
+            missing.join(" ")
+        )?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )
+}
+
+/// Number of palette colors shown before the rest are summarized.
+const PALETTE_PREVIEW: usize = 8;
+
+fn print_color_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &color::ColorReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-color"))?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-color-formats"),
+        report.formats()
+    )?;
+    if let Some(colr) = &report.colr {
+        writeln!(
+            out,
+            "{}{} (version {}, {} layers)",
+            t.label("label-colr"),
+            colr.glyphs,
+            colr.version,
+            colr.layers
+        )?;
+    }
+    if let Some(cpal) = &report.cpal {
+        let colors = cpal.palettes.first().map_or(0, Vec::len);
+        writeln!(
+            out,
+            "{}{} × {} colors (version {})",
+            t.label("label-cpal"),
+            cpal.palettes.len(),
+            colors,
+            cpal.version
+        )?;
+        if let Some(palette) = cpal.palettes.first().filter(|p| !p.is_empty()) {
+            let mut preview = palette[..palette.len().min(PALETTE_PREVIEW)].join(" ");
+            if palette.len() > PALETTE_PREVIEW {
+                preview += &format!(" … and {} more", palette.len() - PALETTE_PREVIEW);
+            }
+            writeln!(out, "{}{}", t.label("label-first-palette"), preview)?;
+        }
+    }
+    if let Some(svg) = &report.svg {
+        writeln!(
+            out,
+            "{}{} in {} ranges, {} documents",
+            t.label("label-svg"),
+            svg.glyphs,
+            svg.ranges,
+            svg.documents
+        )?;
+    }
+    for (key, strikes) in [("label-sbix", &report.sbix), ("label-cbdt", &report.cbdt)] {
+        let label = t.label(key);
+        let indent = format!("│{}", " ".repeat(i18n::display_width(&label) - 1));
+        for (i, strike) in strikes.iter().enumerate() {
+            let prefix = if i == 0 { &label } else { &indent };
+            writeln!(
+                out,
+                "{}{:>3} ppem, {} glyphs",
+                prefix, strike.ppem, strike.glyphs
+            )?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )
+}
+
+/// Reports how characters with both a text and an emoji presentation render
+/// with this font.
+fn print_emoji_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &emoji::PresentationReport,
+) -> io::Result<()> {
+    let count = |matches: fn(&emoji::Presentation) -> bool| {
+        report.covered.iter().filter(|p| matches(p)).count()
+    };
+
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-emoji"))?;
+    writeln!(
+        out,
+        "{}{} of {} dual-presentation characters",
+        t.label("label-emoji-covered"),
+        report.covered.len(),
+        report.total
+    )?;
+    writeln!(
+        out,
+        "{}{} emoji, {} text",
+        t.label("label-emoji-default"),
+        count(|p| p.default == emoji::Style::Emoji),
+        count(|p| p.default == emoji::Style::Text)
+    )?;
+    writeln!(
+        out,
+        "{}{} (VS15 selects text, VS16 emoji)",
+        t.label("label-emoji-both"),
+        count(|p| p.has_both())
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-emoji-only"),
+        count(|p| p.only(emoji::Style::Emoji))
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-text-only"),
+        count(|p| p.only(emoji::Style::Text))
+    )?;
+
+    // Characters that need VS16 to render as emoji; chat apps that don't
+    // append it get the text glyph.
+    let text_default: Vec<&emoji::Presentation> = report
+        .covered
+        .iter()
+        .filter(|p| p.default == emoji::Style::Text && p.emoji_selector == emoji::Style::Emoji)
+        .collect();
+    if !text_default.is_empty() {
+        writeln!(out, "│")?;
+        writeln!(
+            out,
+            "{}{} render as text unless followed by VS16:",
+            t.label("label-text-default"),
+            text_default.len()
+        )?;
+        for p in text_default.iter().take(10) {
+            writeln!(out, "│   U+{:04X} {}", p.c as u32, p.c)?;
+        }
+        if text_default.len() > 10 {
+            writeln!(out, "│   ... and {} more", text_default.len() - 10)?;
+        }
+    }
+
+    let inverted =
+        count(|p| p.text_selector == emoji::Style::Emoji && p.emoji_selector == emoji::Style::Text);
+    if inverted > 0 {
+        writeln!(
+            out,
+            "│ {}: {} characters have VS15 and VS16 glyphs swapped",
+            t.get("label-warning"),
+            inverted
+        )?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Reports which flag emoji the font forms from regional indicator pairs
+/// and subdivision tag sequences.
+fn print_flags_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &emoji::FlagReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-flags"))?;
+    writeln!(
+        out,
+        "{}{} of 26",
+        t.label("label-regional-indicators"),
+        report.indicators
+    )?;
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-country-flags"),
+        report.countries.len(),
+        report.total_countries
+    )?;
+    let subdivisions = if report.subdivisions.is_empty() {
+        t.get("flags-none").to_string()
+    } else {
+        report.subdivisions.join(", ")
+    };
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-subdivision-flags"),
+        subdivisions
+    )?;
+
+    // Pairs that shape as two separate letters, e.g. flags added to
+    // Unicode after the font was built.
+    if !report.unformed.is_empty() {
+        writeln!(out, "│")?;
+        writeln!(
+            out,
+            "{}{} render as separate letters:",
+            t.label("label-unformed-flags"),
+            report.unformed.len()
+        )?;
+        for chunk in report.unformed.chunks(12) {
+            writeln!(out, "│   {}", chunk.join(" "))?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Reports which emoji modifier bases combine with the skin-tone modifiers.
+fn print_skin_tones_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &emoji::SkinToneReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-skin-tones"))?;
+    writeln!(
+        out,
+        "{}{} of 5",
+        t.label("label-skin-tone-modifiers"),
+        report.modifiers
+    )?;
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-modifier-bases"),
+        report.covered.len(),
+        report.total_bases
+    )?;
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-skin-tone-combinations"),
+        report.combinations(),
+        report.covered.len() * 5
+    )?;
+
+    // Bases followed by a modifier they don't ligate with render as the
+    // base glyph plus a separate color swatch.
+    let dangling: Vec<&emoji::ModifierBase> = report
+        .covered
+        .iter()
+        .filter(|base| base.dangling > 0)
+        .collect();
+    if !dangling.is_empty() {
+        writeln!(out, "│")?;
+        writeln!(
+            out,
+            "{}{} render with a separate swatch:",
+            t.label("label-dangling-swatch"),
+            dangling.len()
+        )?;
+        for base in dangling.iter().take(10) {
+            writeln!(
+                out,
+                "│   U+{:04X} {} ({} of 5 tones)",
+                base.c as u32, base.c, base.dangling
+            )?;
+        }
+        if dangling.len() > 10 {
+            writeln!(out, "│   ... and {} more", dangling.len() - 10)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Estimates the newest Unicode version the font targets from its coverage
+/// of the characters each version introduced.
+fn print_unicode_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    profile: &unicode::VersionProfile,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-unicode"))?;
+    let newest = match profile.newest() {
+        Some(newest) => format!("Unicode {}", newest.version),
+        None => "Unicode 1.1".to_string(),
+    };
+    let has_strays = profile.strays().next().is_some();
+    match profile.first_missing() {
+        Some(missing) => writeln!(
+            out,
+            "{}{} ({} {}+ additions)",
+            t.label("label-unicode-newest"),
+            newest,
+            if has_strays { "only stray" } else { "no" },
+            missing.version
+        )?,
+        None => writeln!(out, "{}{}", t.label("label-unicode-newest"), newest)?,
+    }
+    if has_strays {
+        let strays: Vec<String> = profile
+            .strays()
+            .map(|v| format!("{} ({})", v.version, v.covered))
+            .collect();
+        writeln!(
+            out,
+            "{}{}",
+            t.label("label-unicode-strays"),
+            strays.join(", ")
+        )?;
+    }
+
+    let (first, rest) = list_prefixes(t, "label-unicode-additions");
+    for (i, version) in profile
+        .versions
+        .iter()
+        .filter(|v| v.covered > 0)
+        .enumerate()
+    {
+        let prefix = if i == 0 { &first } else { &rest };
+        writeln!(
+            out,
+            "{} {:<5} {} of {}",
+            prefix, version.version, version.covered, version.total
+        )?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Counts mapped code points in every Unicode block the font touches.
+fn print_blocks_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &blocks::BlockReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-blocks"))?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-mapped-codepoints"),
+        report.mapped
+    )?;
+    writeln!(out, "│")?;
+    let width = report
+        .blocks
+        .iter()
+        .map(|block| block.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    for block in &report.blocks {
+        writeln!(
+            out,
+            "│ {:<width$}  {}/{}",
+            block.name, block.covered, block.total
+        )?;
+    }
+    if report.outside_blocks > 0 {
+        writeln!(
+            out,
+            "│ {:<width$}  {}",
+            "(newer blocks)", report.outside_blocks
+        )?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Breaks cmap coverage out by Unicode plane, naming the largest blocks in
+/// each.
+fn print_planes_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &planes::PlaneReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-planes"))?;
+    if report.planes.is_empty() {
+        writeln!(out, "│ {}", t.get("planes-none"))?;
+    }
+    let (first, rest) = list_prefixes(t, "label-planes");
+    for (i, plane) in report.planes.iter().enumerate() {
+        let prefix = if i == 0 { &first } else { &rest };
+        writeln!(
+            out,
+            "{} {:<6} {} characters, {} glyphs",
+            prefix,
+            plane.name(),
+            plane.characters,
+            plane.glyphs
+        )?;
+        let blocks: Vec<String> = plane
+            .blocks
+            .iter()
+            .take(3)
+            .map(|(name, count)| format!("{} ({})", name, count))
+            .collect();
+        if !blocks.is_empty() {
+            writeln!(out, "{}        {}", rest, blocks.join(", "))?;
+        }
+    }
+
+    // Format 4 only reaches U+FFFF, so without format 12 older platforms
+    // that skip other subtable formats never see these characters.
+    let supplementary = report.supplementary();
+    if supplementary > 0 && !report.has_format_12 {
+        writeln!(
+            out,
+            "│ {}: {} characters outside the BMP but no format 12 cmap",
+            t.get("label-warning"),
+            supplementary
+        )?;
+        writeln!(
+            out,
+            "│          subtable; many platforms will not render them"
+        )?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Flags glyphs whose advances or side bearings tend to cause layout bugs.
+fn print_advances_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    advances: &report::Advances,
+) -> io::Result<()> {
+    let report = &advances.anomalies;
+
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-advances"))?;
+    if let Some(average) = &advances.average_width {
+        writeln!(
+            out,
+            "{}{} stored, {} computed (OS/2 v{}, delta {:+})",
+            t.label("label-avg-char-width"),
+            average.stored,
+            average.computed,
+            average.os2_version,
+            average.delta()
+        )?;
+        // Layout engines that estimate line widths from xAvgCharWidth
+        // drift once it is off by more than a rounding error.
+        if average.delta().abs() > 1 {
+            writeln!(
+                out,
+                "│ {}: stored xAvgCharWidth is stale",
+                t.get("label-warning")
+            )?;
+        }
+        writeln!(out, "│")?;
+    }
+    if report.anomalies.is_empty() {
+        writeln!(out, "│ {}", t.get("advances-none"))?;
+    }
+    let mut kinds: Vec<&str> = report.anomalies.iter().map(|a| a.anomaly.kind()).collect();
+    kinds.sort();
+    kinds.dedup();
+    for (i, kind) in kinds.iter().enumerate() {
+        let glyphs: Vec<_> = report
+            .anomalies
+            .iter()
+            .filter(|a| a.anomaly.kind() == *kind)
+            .collect();
+        if i > 0 {
+            writeln!(out, "│")?;
+        }
+        writeln!(out, "│ {}: {} glyphs", kind, glyphs.len())?;
+        for glyph in glyphs.iter().take(10) {
+            let name = glyph.name.as_deref().unwrap_or("");
+            writeln!(
+                out,
+                "│   {} {} - {}",
+                glyph.glyph_id,
+                name,
+                glyph.anomaly.details()
+            )?;
+        }
+        if glyphs.len() > 10 {
+            writeln!(out, "│   ... and {} more", glyphs.len() - 10)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Audits how default-ignorable characters such as ZWJ, variation selectors
+/// and bidi controls are mapped.
+fn print_ignorables_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &ignorables::IgnorableReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-ignorables"))?;
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-ignorables-empty"),
+        report.empty,
+        report.total
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-ignorables-unmapped"),
+        report.unmapped
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-ignorables-ink"),
+        report.zero_width_ink.len()
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-ignorables-visible"),
+        report.visible.len()
+    )?;
+    writeln!(out, "{}{}", t.label("label-risk"), report.risk.name())?;
+
+    for (chars, heading) in [
+        (&report.visible, "Mapped to glyphs with an advance:"),
+        (
+            &report.zero_width_ink,
+            "Mapped to zero-width glyphs with ink:",
+        ),
+    ] {
+        if chars.is_empty() {
+            continue;
+        }
+        writeln!(out, "│")?;
+        writeln!(out, "│ {}", heading)?;
+        for ignorable in chars.iter().take(10) {
+            writeln!(out, "│   U+{:04X} {}", ignorable.c as u32, ignorable.group)?;
+        }
+        if chars.len() > 10 {
+            writeln!(out, "│   ... and {} more", chars.len() - 10)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Checks that mirrored bracket and operator pairs are complete and whether
+/// an `rtlm` feature exists.
+fn print_mirroring_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &mirroring::MirroringReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-mirroring"))?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-mirrored-pairs"),
+        report.complete
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-asymmetric-pairs"),
+        report.asymmetric.len()
+    )?;
+    match &report.rtlm {
+        Some(rtlm) => writeln!(
+            out,
+            "{}yes ({} lookup{}, {} glyphs)",
+            t.label("label-rtlm"),
+            rtlm.lookups,
+            if rtlm.lookups == 1 { "" } else { "s" },
+            rtlm.glyphs
+        )?,
+        None => writeln!(out, "{}no", t.label("label-rtlm"))?,
+    }
+
+    // In right-to-left runs the shaper swaps each character for its
+    // counterpart, so a missing half renders as tofu or a fallback glyph.
+    if !report.asymmetric.is_empty() {
+        writeln!(out, "│")?;
+        writeln!(out, "│ Covered without their mirrored counterpart:")?;
+        for (covered, missing) in report.asymmetric.iter().take(10) {
+            writeln!(
+                out,
+                "│   {} U+{:04X} (missing {} U+{:04X})",
+                covered, *covered as u32, missing, *missing as u32
+            )?;
+        }
+        if report.asymmetric.len() > 10 {
+            writeln!(out, "│   ... and {} more", report.asymmetric.len() - 10)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Reports combining diacritic coverage and whether GPOS positions the
+/// marks on bases and on each other.
+fn print_marks_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &marks::MarkReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-marks"))?;
+    let (first, rest) = list_prefixes(t, "label-marks-covered");
+    for (i, (name, count)) in report.blocks.iter().enumerate() {
+        let prefix = if i == 0 { &first } else { &rest };
+        writeln!(out, "{} {} ({})", prefix, name, count)?;
+    }
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-marks-attaching"),
+        report.attaching,
+        report.covered
+    )?;
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-marks-stacking"),
+        report.stacking,
+        report.covered
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-verdict"),
+        report.verdict.description()
+    )?;
+
+    // Decomposed text, e.g. from NFD normalization or languages without
+    // precomposed letters, draws these at their default position.
+    if !report.unattached.is_empty() {
+        writeln!(out, "│")?;
+        writeln!(out, "│ Not positioned on bases:")?;
+        for c in report.unattached.iter().take(10) {
+            writeln!(out, "│   U+{:04X} ◌{}", *c as u32, c)?;
+        }
+        if report.unattached.len() > 10 {
+            writeln!(out, "│   ... and {} more", report.unattached.len() - 10)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Reports whether precomposed (NFC) and decomposed (NFD) text both render.
+fn print_normalization_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &normalization::NormalizationReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-normalization"))?;
+    writeln!(out, "{}{}", t.label("label-round-trip"), report.round_trip)?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-precomposed-only"),
+        report.precomposed_only.len()
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-decomposed-only"),
+        report.decomposed_only.len()
+    )?;
+
+    for (chars, heading) in [
+        (
+            &report.precomposed_only,
+            "Decomposed (NFD) form does not render:",
+        ),
+        (
+            &report.decomposed_only,
+            "Only the decomposed form is covered:",
+        ),
+    ] {
+        if chars.is_empty() {
+            continue;
+        }
+        writeln!(out, "│")?;
+        writeln!(out, "│ {}", heading)?;
+        for c in chars.iter().take(10) {
+            writeln!(out, "│   U+{:04X} {}", *c as u32, c)?;
+        }
+        if chars.len() > 10 {
+            writeln!(out, "│   ... and {} more", chars.len() - 10)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Lists letters covered without their uppercase or lowercase counterpart,
+/// which break case transformations such as `text-transform: uppercase`.
+fn print_case_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &case::CaseReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-case"))?;
+    writeln!(out, "{}{}", t.label("label-case-pairs"), report.complete)?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-missing-upper"),
+        report.missing_upper.len()
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-missing-lower"),
+        report.missing_lower.len()
+    )?;
+
+    for (pairs, heading) in [
+        (&report.missing_upper, "Lowercase without uppercase:"),
+        (&report.missing_lower, "Uppercase without lowercase:"),
+    ] {
+        if pairs.is_empty() {
+            continue;
+        }
+        writeln!(out, "│")?;
+        writeln!(out, "│ {}", heading)?;
+        for (covered, missing) in pairs.iter().take(10) {
+            writeln!(
+                out,
+                "│   {} U+{:04X} (missing {} U+{:04X})",
+                covered, *covered as u32, missing, *missing as u32
+            )?;
+        }
+        if pairs.len() > 10 {
+            writeln!(out, "│   ... and {} more", pairs.len() - 10)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Checks the Turkish and Azerbaijani i/İ/ı/I under `locl`, the most common
+/// localization defect in Latin fonts.
+fn print_dotted_i_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &turkic::TurkicReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-dotted-i"))?;
+    let letters = if report.missing.is_empty() {
+        "i İ ı I".to_string()
+    } else {
+        let missing: Vec<String> = report.missing.iter().map(char::to_string).collect();
+        format!("missing {}", missing.join(" "))
+    };
+    writeln!(out, "{}{}", t.label("label-letters"), letters)?;
+    let (first, rest) = list_prefixes(t, "label-locl");
+    for (i, language) in report.languages.iter().enumerate() {
+        let prefix = if i == 0 { &first } else { &rest };
+        writeln!(
+            out,
+            "{} {} ({}) - {}",
+            prefix,
+            language.tag,
+            language.name,
+            language.handling.description()
+        )?;
+    }
+    let yes_no = |value| if value { "yes" } else { "no" };
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-fi-ligature"),
+        yes_no(report.fi_ligature)
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-small-caps"),
+        yes_no(report.small_caps)
+    )?;
+
+    // Without a localized i, the fi ligature and small caps draw a dotless
+    // form, which Turkish and Azerbaijani readers read as ı.
+    if report.loses_dot() {
+        writeln!(out, "│")?;
+        writeln!(
+            out,
+            "│ {}: i loses its dot in fi ligatures or small caps for",
+            t.get("label-warning")
+        )?;
+        writeln!(out, "│          languages without a locl substitution")?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Prints pass/fail checks for the German sharp s, Dutch IJ and Catalan
+/// l·l.
+fn print_special_cases_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &special_cases::SpecialCaseReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-special-cases"))?;
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-checks-passed"),
+        report.passed(),
+        report.applicable()
+    )?;
+    writeln!(out, "│")?;
+    for check in &report.checks {
+        if check.detail.is_empty() {
+            writeln!(out, "│ {:<4}  {}", check.status.name(), check.name)?;
+        } else {
+            writeln!(
+                out,
+                "│ {:<4}  {} ({})",
+                check.status.name(),
+                check.name,
+                check.detail
+            )?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Reports monotonic and polytonic Greek coverage separately, with final
+/// sigma and the oxia forms.
+fn print_greek_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &greek::GreekReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-greek"))?;
+    for (key, coverage) in [
+        ("label-greek-monotonic", &report.monotonic),
+        ("label-greek-polytonic", &report.polytonic),
+    ] {
+        writeln!(
+            out,
+            "{}{} of {}",
+            t.label(key),
+            coverage.covered,
+            coverage.total
+        )?;
+    }
+    let final_sigma = if report.final_sigma {
+        "yes"
+    } else {
+        "no (ς missing or drawn as σ)"
+    };
+    writeln!(out, "{}{}", t.label("label-greek-final-sigma"), final_sigma)?;
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-greek-oxia"),
+        report.oxia,
+        greek::OXIA_FORMS
+    )?;
+    if report.monotonic_only() {
+        writeln!(out, "│")?;
+        writeln!(out, "│ {}", t.get("greek-monotonic-only"))?;
+    }
+
+    for (heading, missing) in [
+        ("Missing monotonic letters:", &report.monotonic.missing),
+        ("Missing polytonic letters:", &report.polytonic.missing),
+    ] {
+        if missing.is_empty() {
+            continue;
+        }
+        writeln!(out, "│")?;
+        writeln!(out, "│ {}", heading)?;
+        for c in missing.iter().take(10) {
+            writeln!(out, "│   U+{:04X} {}", *c as u32, c)?;
+        }
+        if missing.len() > 10 {
+            writeln!(out, "│   ... and {} more", missing.len() - 10)?;
+        }
+    }
+    if !report.missing_oxia.is_empty() {
+        writeln!(out, "│")?;
+        writeln!(out, "│ Oxia forms missing beside their tonos letter:")?;
+        for (oxia, tonos) in report.missing_oxia.iter().take(10) {
+            writeln!(
+                out,
+                "│   U+{:04X} (tonos form U+{:04X} {})",
+                *oxia as u32, *tonos as u32, tonos
+            )?;
+        }
+        if report.missing_oxia.len() > 10 {
+            writeln!(out, "│   ... and {} more", report.missing_oxia.len() - 10)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Prints coverage of each Cyrillic block and the Bulgarian, Serbian and
+/// Macedonian localized forms.
+fn print_cyrillic_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &cyrillic::CyrillicReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-cyrillic"))?;
+    let (first, rest) = list_prefixes(t, "label-blocks");
+    for (i, block) in report.blocks.iter().enumerate() {
+        let prefix = if i == 0 { &first } else { &rest };
+        writeln!(
+            out,
+            "{} {} ({} of {})",
+            prefix, block.name, block.covered, block.total
+        )?;
+    }
+    let (first, rest) = list_prefixes(t, "label-locl");
+    for (i, language) in report.languages.iter().enumerate() {
+        let prefix = if i == 0 { &first } else { &rest };
+        writeln!(
+            out,
+            "{} {} ({}) - {}",
+            prefix,
+            language.tag,
+            language.name,
+            language.forms.description()
+        )?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Prints whether Vietnamese renders precomposed and decomposed, with a
+/// single readiness verdict.
+fn print_vietnamese_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &vietnamese::VietnameseReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-vietnamese"))?;
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-precomposed"),
+        report.precomposed,
+        report.total
+    )?;
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-decomposed"),
+        report.decomposed,
+        report.total
+    )?;
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-horn-tone"),
+        report.horn,
+        report.horn_total
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-verdict"),
+        report.readiness.description()
+    )?;
+    if !report.missing.is_empty() {
+        writeln!(out, "│")?;
+        writeln!(out, "│ Render in neither form:")?;
+        for c in report.missing.iter().take(10) {
+            writeln!(out, "│   U+{:04X} {}", *c as u32, c)?;
+        }
+        if report.missing.len() > 10 {
+            writeln!(out, "│   ... and {} more", report.missing.len() - 10)?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Prints Latin Extended-B and IPA coverage, the letters shared across
+/// African orthographies and which major African languages are covered.
+fn print_african_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &african::AfricanReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-african"))?;
+    let (first, rest) = list_prefixes(t, "label-blocks");
+    for (i, block) in report.blocks.iter().enumerate() {
+        let prefix = if i == 0 { &first } else { &rest };
+        writeln!(
+            out,
+            "{} {} ({} of {})",
+            prefix, block.name, block.covered, block.total
+        )?;
+    }
+    let key_letters = if report.missing_key_letters.is_empty() {
+        "all covered".to_string()
+    } else {
+        format!("missing {}", join_chars(&report.missing_key_letters))
+    };
+    writeln!(out, "{}{}", t.label("label-key-letters"), key_letters)?;
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-languages"),
+        report.supported(),
+        report.languages.len()
+    )?;
+    writeln!(out, "│")?;
+    for language in &report.languages {
+        if language.missing.is_empty() {
+            writeln!(out, "│   {:<10} complete", language.name)?;
+        } else {
+            writeln!(
+                out,
+                "│   {:<10} missing {}",
+                language.name,
+                join_chars(&language.missing)
+            )?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Joins characters with spaces, e.g. for lists of missing letters.
+fn join_chars(chars: &[char]) -> String {
+    let chars: Vec<String> = chars.iter().map(char::to_string).collect();
+    chars.join(" ")
+}
+
+/// Checks that figures and their companions share one advance, so
+/// columns of amounts line up.
+fn print_tabular_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &tabular::TabularReport,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-tabular"))?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-figures"),
+        report.figures.description()
+    )?;
+    writeln!(out, "{}{}", t.label("label-figure-width"), report.width)?;
+    writeln!(
+        out,
+        "{}{} of {}",
+        t.label("label-tabular"),
+        report.checked - report.offenders.len(),
+        report.checked
+    )?;
+    if !report.offenders.is_empty() {
+        writeln!(out, "│")?;
+        writeln!(out, "│ {}", t.get("tabular-offenders"))?;
+        for offender in &report.offenders {
+            writeln!(
+                out,
+                "│   U+{:04X} {} {} - advance {}",
+                offender.c as u32,
+                offender.c,
+                offender.glyph_name.as_deref().unwrap_or(""),
+                offender.advance
+            )?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Shows how 0 and O, and l, 1 and I, can be told apart.
+fn print_disambiguation_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &disambiguation::DisambiguationReport,
+) -> io::Result<()> {
+    let list = |items: &[String]| {
+        if items.is_empty() {
+            t.get("value-none").to_string()
+        } else {
+            items.join(", ")
+        }
+    };
+
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-disambiguation"))?;
+    let zero = if report.zero_marked {
+        "dotted or slashed"
+    } else {
+        "plain"
+    };
+    writeln!(out, "{}{}", t.label("label-default-zero"), zero)?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-zero-features"),
+        list(&report.zero_features)
+    )?;
+    writeln!(
+        out,
+        "{}{}",
+        t.label("label-zero-alternates"),
+        list(&report.zero_alternates)
+    )?;
+    if !report.zero_distinguishable() {
+        writeln!(out, "│ {}", t.get("disambiguation-zero-plain"))?;
+    }
+    if !report.pairs.is_empty() {
+        writeln!(out, "│")?;
+        let (first, rest) = list_prefixes(t, "label-similarity");
+        for (i, pair) in report.pairs.iter().enumerate() {
+            let prefix = if i == 0 { &first } else { &rest };
+            let verdict = if pair.confusable { " - confusable" } else { "" };
+            writeln!(
+                out,
+                "{} {} / {}  {:.0}%{}",
+                prefix,
+                pair.first,
+                pair.second,
+                pair.similarity * 100.0,
+                verdict
+            )?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+fn print_ink_section(out: &mut dyn Write, t: &Catalog, report: &ink::InkReport) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-ink"))?;
+    writeln!(
+        out,
+        "{}{:.1}%",
+        t.label("label-darkness"),
+        report.darkness * 100.0
+    )?;
+    let (first, rest) = list_prefixes(t, "label-glyph-ink");
+    for (i, glyph) in report.glyphs.iter().enumerate() {
+        let prefix = if i == 0 { &first } else { &rest };
+        writeln!(
+            out,
+            "{} {}  {:>4.1}% of the em, {:>4.1}% of its advance",
+            prefix,
+            glyph.c,
+            glyph.ink * 100.0,
+            glyph.density * 100.0
+        )?;
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+fn print_stems_section(
+    out: &mut dyn Write,
+    t: &Catalog,
+    report: &stems::StemReport,
+    units_per_em: u16,
+) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", t.header("section-stems"))?;
+    for (key, stem) in [
+        ("label-vertical-stem", &report.vertical),
+        ("label-horizontal-stem", &report.horizontal),
+    ] {
+        match stem {
+            Some(stem) => writeln!(
+                out,
+                "{}{:.0} ({:.3} em, {})",
+                t.label(key),
+                stem.width,
+                stem.width / f32::from(units_per_em),
+                stem.source.description()
+            )?,
+            None => writeln!(out, "{}unknown", t.label(key))?,
+        }
+    }
+    if !report.measurements.is_empty() {
+        let (first, rest) = list_prefixes(t, "label-stem-measurements");
+        for (i, measurement) in report.measurements.iter().enumerate() {
+            let prefix = if i == 0 { &first } else { &rest };
+            writeln!(
+                out,
+                "{} {} {:<8} {:.0}",
+                prefix, measurement.c, measurement.stroke, measurement.width
+            )?;
+        }
+    }
+    writeln!(
+        out,
+        "└───────────────────────────────────────────────────────────────"
+    )?;
+    Ok(())
+}
+
+/// Returns the label for the first line of a list and the matching indent
+/// for the lines that follow, e.g. `│ Features:` and `│          `.
+fn list_prefixes(t: &Catalog, key: &str) -> (String, String) {
+    let first = format!("│ {}:", t.get(key));
+    let rest = format!("│{}", " ".repeat(i18n::display_width(&first) - 1));
+    (first, rest)
 }
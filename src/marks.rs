@@ -0,0 +1,113 @@
+use crate::layout::MarkAttachment;
+use serde::Serialize;
+use ttf_parser::Face;
+
+/// Blocks of general-purpose combining diacritics, as inclusive ranges.
+const MARK_BLOCKS: [(u32, u32, &str); 5] = [
+    (0x0300, 0x036F, "Combining Diacritical Marks"),
+    (0x1AB0, 0x1AFF, "Combining Diacritical Marks Extended"),
+    (0x1DC0, 0x1DFF, "Combining Diacritical Marks Supplement"),
+    (0x20D0, 0x20FF, "Combining Diacritical Marks for Symbols"),
+    (0xFE20, 0xFE2F, "Combining Half Marks"),
+];
+
+/// How well the font renders a base letter followed by arbitrary marks.
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    /// No covered mark is positioned; only precomposed characters such as
+    /// é render correctly, and decomposed text shows misplaced accents.
+    PrecomposedOnly,
+    /// Some covered marks attach to bases, others don't.
+    Partial,
+    /// Every covered mark attaches to bases, but not all attach to other
+    /// marks, so stacked marks collide.
+    SingleMarks,
+    /// Every covered mark attaches to bases and to other marks.
+    Arbitrary,
+}
+
+impl Verdict {
+    pub fn description(self) -> &'static str {
+        match self {
+            Verdict::PrecomposedOnly => "precomposed characters only",
+            Verdict::Partial => "some marks attach to bases",
+            Verdict::SingleMarks => "one mark per base; stacked marks collide",
+            Verdict::Arbitrary => "arbitrary base+mark stacking",
+        }
+    }
+}
+
+/// Combining diacritic coverage and GPOS mark attachment.
+#[derive(Serialize)]
+pub struct MarkReport {
+    /// Covered marks per block, for blocks with any.
+    pub blocks: Vec<(&'static str, usize)>,
+    pub covered: usize,
+    /// Covered marks in a mark-to-base or mark-to-ligature lookup.
+    pub attaching: usize,
+    /// Covered marks in a mark-to-mark lookup.
+    pub stacking: usize,
+    /// Covered marks that no lookup positions on a base.
+    pub unattached: Vec<char>,
+    pub verdict: Verdict,
+}
+
+impl MarkReport {
+    /// Returns `None` for fonts that cover none of the combining marks.
+    pub fn new(face: &Face) -> Option<Self> {
+        let attachment = face
+            .tables()
+            .gpos
+            .map(|gpos| MarkAttachment::new(&gpos))
+            .unwrap_or_default();
+
+        let mut blocks = Vec::new();
+        let mut attaching = 0;
+        let mut stacking = 0;
+        let mut unattached = Vec::new();
+        for &(start, end, name) in &MARK_BLOCKS {
+            let mut covered = 0;
+            for c in (start..=end).filter_map(char::from_u32) {
+                let Some(glyph_id) = face.glyph_index(c) else {
+                    continue;
+                };
+                covered += 1;
+                if attachment.to_base.contains(&glyph_id) {
+                    attaching += 1;
+                } else {
+                    unattached.push(c);
+                }
+                if attachment.to_mark.contains(&glyph_id) {
+                    stacking += 1;
+                }
+            }
+            if covered > 0 {
+                blocks.push((name, covered));
+            }
+        }
+
+        let covered: usize = blocks.iter().map(|&(_, count)| count).sum();
+        if covered == 0 {
+            return None;
+        }
+        let verdict = if attaching == 0 {
+            Verdict::PrecomposedOnly
+        } else if attaching < covered {
+            Verdict::Partial
+        } else if stacking < covered {
+            Verdict::SingleMarks
+        } else {
+            Verdict::Arbitrary
+        };
+
+        Some(MarkReport {
+            blocks,
+            covered,
+            attaching,
+            stacking,
+            unattached,
+            verdict,
+        })
+    }
+}
@@ -0,0 +1,117 @@
+use crate::layout;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use ttf_parser::gsub::SubstitutionSubtable;
+use ttf_parser::{Face, Tag};
+
+/// Operators and delimiters that grow with what they enclose, so a math
+/// font needs them at several sizes: n-ary operators, the radical and the
+/// brackets.
+const OPERATORS: &str = "∑∏∫∮√()[]{}⟨⟩|‖";
+
+/// The size variants a math font offers for one operator.
+#[derive(Serialize)]
+pub struct OperatorSizes {
+    pub c: char,
+    /// Pre-drawn sizes in the MATH vertical construction, the base glyph
+    /// included.
+    pub variants: usize,
+    /// Whether a glyph assembly can build it at any size.
+    pub assembly: bool,
+}
+
+impl OperatorSizes {
+    /// More than one size, through variants or an assembly.
+    pub fn grows(&self) -> bool {
+        self.variants > 1 || self.assembly
+    }
+}
+
+/// The GSUB features and MATH size variants math typesetting relies on.
+#[derive(Serialize)]
+pub struct MathReport {
+    /// Glyphs with script-style alternates for sub- and superscripts, and
+    /// the most alternates any of them has (one per script level).
+    pub ssty_glyphs: usize,
+    pub ssty_levels: usize,
+    /// Glyphs with flattened accent forms for use over tall bases.
+    pub flac_glyphs: usize,
+    /// Glyphs with dotless forms for accented letters.
+    pub dtls_glyphs: usize,
+    /// Whether `dtls` covers both i and j.
+    pub dotless_i_j: bool,
+    pub operators: Vec<OperatorSizes>,
+    /// Operators from `OPERATORS` the cmap lacks.
+    pub missing_operators: Vec<char>,
+}
+
+impl MathReport {
+    /// Returns `None` for fonts without a MATH table.
+    pub fn new(face: &Face) -> Option<Self> {
+        let math = face.tables().math?;
+        let gsub = face.tables().gsub;
+        let impacts = gsub.map(|gsub| layout::gsub_impacts(&gsub));
+        let glyphs = |tag: &[u8; 4]| {
+            impacts
+                .as_ref()
+                .and_then(|impacts| impacts.get(&Tag::from_bytes(tag)))
+                .map_or(0, |impact| impact.glyphs)
+        };
+
+        let mut ssty = BTreeSet::new();
+        let mut ssty_levels = 0;
+        let mut dotless_i_j = false;
+        if let Some(gsub) = gsub {
+            for index in layout::feature_lookups(&gsub, Tag::from_bytes(b"ssty")) {
+                let Some(lookup) = gsub.lookups.get(index) else {
+                    continue;
+                };
+                for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+                    layout::add_coverage(subtable.coverage(), &mut ssty);
+                    ssty_levels = ssty_levels.max(match subtable {
+                        SubstitutionSubtable::Alternate(alternate) => alternate
+                            .alternate_sets
+                            .into_iter()
+                            .map(|set| usize::from(set.alternates.len()))
+                            .max()
+                            .unwrap_or(0),
+                        _ => 1,
+                    });
+                }
+            }
+            let dtls = layout::feature_lookups(&gsub, Tag::from_bytes(b"dtls"));
+            dotless_i_j = ['i', 'j'].iter().all(|&c| {
+                face.glyph_index(c)
+                    .is_some_and(|glyph| layout::gsub_covers(&gsub, &dtls, glyph))
+            });
+        }
+
+        let mut operators = Vec::new();
+        let mut missing_operators = Vec::new();
+        for c in OPERATORS.chars() {
+            let Some(glyph) = face.glyph_index(c) else {
+                missing_operators.push(c);
+                continue;
+            };
+            let construction = math
+                .variants
+                .and_then(|variants| variants.vertical_constructions.get(glyph));
+            operators.push(OperatorSizes {
+                c,
+                variants: construction
+                    .map_or(0, |construction| usize::from(construction.variants.len())),
+                assembly: construction.is_some_and(|construction| construction.assembly.is_some()),
+            });
+        }
+
+        Some(MathReport {
+            ssty_glyphs: ssty.len(),
+            ssty_levels,
+            flac_glyphs: glyphs(b"flac"),
+            dtls_glyphs: glyphs(b"dtls"),
+            dotless_i_j,
+            operators,
+            missing_operators,
+        })
+    }
+}
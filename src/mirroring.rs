@@ -0,0 +1,152 @@
+use crate::layout;
+use serde::Serialize;
+use ttf_parser::{Face, Tag};
+
+/// Right-to-left scripts' blocks, as inclusive ranges: Hebrew through N'Ko
+/// and the Hebrew and Arabic presentation forms.
+const RTL_RANGES: [(u32, u32); 3] = [(0x0590, 0x07FF), (0xFB1D, 0xFDFF), (0xFE70, 0xFEFF)];
+
+/// Character pairs that swap in right-to-left text, from Unicode's
+/// `BidiMirroring.txt`. Each pair is listed once.
+const MIRROR_PAIRS: &[(char, char)] = &[
+    ('(', ')'),
+    ('<', '>'),
+    ('[', ']'),
+    ('{', '}'),
+    ('«', '»'),
+    ('‹', '›'),
+    ('⁅', '⁆'),
+    ('⁽', '⁾'),
+    ('₍', '₎'),
+    ('∈', '∋'),
+    ('∉', '∌'),
+    ('∊', '∍'),
+    ('∕', '⧵'),
+    ('∼', '∽'),
+    ('≃', '⋍'),
+    ('≒', '≓'),
+    ('≔', '≕'),
+    ('≤', '≥'),
+    ('≦', '≧'),
+    ('≨', '≩'),
+    ('≪', '≫'),
+    ('≮', '≯'),
+    ('≰', '≱'),
+    ('≲', '≳'),
+    ('≴', '≵'),
+    ('≶', '≷'),
+    ('≸', '≹'),
+    ('≺', '≻'),
+    ('≼', '≽'),
+    ('≾', '≿'),
+    ('⊀', '⊁'),
+    ('⊂', '⊃'),
+    ('⊄', '⊅'),
+    ('⊆', '⊇'),
+    ('⊈', '⊉'),
+    ('⊊', '⊋'),
+    ('⊏', '⊐'),
+    ('⊑', '⊒'),
+    ('⊢', '⊣'),
+    ('⊰', '⊱'),
+    ('⊲', '⊳'),
+    ('⊴', '⊵'),
+    ('⊶', '⊷'),
+    ('⋉', '⋊'),
+    ('⋋', '⋌'),
+    ('⋐', '⋑'),
+    ('⋖', '⋗'),
+    ('⋘', '⋙'),
+    ('⋚', '⋛'),
+    ('⋜', '⋝'),
+    ('⋞', '⋟'),
+    ('⋠', '⋡'),
+    ('⋢', '⋣'),
+    ('⋤', '⋥'),
+    ('⋦', '⋧'),
+    ('⋨', '⋩'),
+    ('⋪', '⋫'),
+    ('⋬', '⋭'),
+    ('⋰', '⋱'),
+    ('⌈', '⌉'),
+    ('⌊', '⌋'),
+    ('\u{2329}', '\u{232A}'),
+    ('❨', '❩'),
+    ('❪', '❫'),
+    ('❬', '❭'),
+    ('❮', '❯'),
+    ('❰', '❱'),
+    ('❲', '❳'),
+    ('❴', '❵'),
+    ('⟦', '⟧'),
+    ('⟨', '⟩'),
+    ('⟪', '⟫'),
+    ('⦃', '⦄'),
+    ('⦅', '⦆'),
+    ('〈', '〉'),
+    ('《', '》'),
+    ('「', '」'),
+    ('『', '』'),
+    ('【', '】'),
+    ('〔', '〕'),
+    ('〖', '〗'),
+    ('〘', '〙'),
+    ('〚', '〛'),
+    ('﹙', '﹚'),
+    ('﹛', '﹜'),
+    ('﹝', '﹞'),
+    ('﹤', '﹥'),
+    ('（', '）'),
+    ('＜', '＞'),
+    ('［', '］'),
+    ('｛', '｝'),
+    ('｟', '｠'),
+    ('｢', '｣'),
+];
+
+/// Mirrored-pair coverage of a font that supports a right-to-left script.
+#[derive(Serialize)]
+pub struct MirroringReport {
+    /// Pairs with both characters covered.
+    pub complete: usize,
+    /// Pairs with only one character covered, as (covered, missing).
+    pub asymmetric: Vec<(char, char)>,
+    /// Lookups and glyphs of the `rtlm` feature, if the font has one.
+    pub rtlm: Option<layout::FeatureImpact>,
+}
+
+impl MirroringReport {
+    /// Returns `None` for fonts that don't cover a right-to-left script.
+    pub fn new(face: &Face) -> Option<Self> {
+        let covers_rtl = RTL_RANGES
+            .iter()
+            .flat_map(|&(start, end)| start..=end)
+            .filter_map(char::from_u32)
+            .any(|c| face.glyph_index(c).is_some());
+        if !covers_rtl {
+            return None;
+        }
+
+        let mut complete = 0;
+        let mut asymmetric = Vec::new();
+        for &(a, b) in MIRROR_PAIRS {
+            match (face.glyph_index(a).is_some(), face.glyph_index(b).is_some()) {
+                (true, true) => complete += 1,
+                (true, false) => asymmetric.push((a, b)),
+                (false, true) => asymmetric.push((b, a)),
+                (false, false) => {}
+            }
+        }
+
+        let rtlm = face
+            .tables()
+            .gsub
+            .and_then(|gsub| layout::gsub_impacts(&gsub).remove(&Tag::from_bytes(b"rtlm")));
+
+        Some(MirroringReport {
+            complete,
+            asymmetric,
+            rtlm,
+        })
+    }
+}
@@ -0,0 +1,215 @@
+use crate::reader::Reader;
+use serde::Serialize;
+use ttf_parser::name::{Name, PlatformId};
+use ttf_parser::{Face, Tag};
+
+/// Meanings of the name IDs the OpenType spec predefines, indexed by ID.
+const NAME_IDS: [&str; 26] = [
+    "Copyright notice",
+    "Font family",
+    "Font subfamily",
+    "Unique identifier",
+    "Full name",
+    "Version",
+    "PostScript name",
+    "Trademark",
+    "Manufacturer",
+    "Designer",
+    "Description",
+    "Vendor URL",
+    "Designer URL",
+    "License description",
+    "License info URL",
+    "Reserved",
+    "Typographic family",
+    "Typographic subfamily",
+    "Compatible full name",
+    "Sample text",
+    "PostScript CID findfont name",
+    "WWS family",
+    "WWS subfamily",
+    "Light background palette",
+    "Dark background palette",
+    "Variations PostScript name prefix",
+];
+
+/// Macintosh language IDs, indexed by ID, up to the last one fonts
+/// commonly use.
+const MAC_LANGUAGES: [&str; 24] = [
+    "English",
+    "French",
+    "German",
+    "Italian",
+    "Dutch",
+    "Swedish",
+    "Spanish",
+    "Danish",
+    "Portuguese",
+    "Norwegian",
+    "Hebrew",
+    "Japanese",
+    "Arabic",
+    "Finnish",
+    "Greek",
+    "Icelandic",
+    "Maltese",
+    "Turkish",
+    "Croatian",
+    "Chinese (Traditional)",
+    "Urdu",
+    "Hindi",
+    "Thai",
+    "Korean",
+];
+
+/// Bytes 0x80–0xFF of the Mac Roman encoding, which Macintosh name records
+/// with encoding ID 0 use.
+const MAC_ROMAN: &str = "ÄÅÇÉÑÖÜáàâäãåçéèêëíìîïñóòôöõúùûü\
+    †°¢£§•¶ß®©™´¨≠ÆØ∞±≤≥¥µ∂∑∏π∫ªºΩæø\
+    ¿¡¬√ƒ≈∆«»…\u{A0}ÀÃÕŒœ–—“”‘’÷◊ÿŸ⁄€‹›ﬁﬂ\
+    ‡·‚„‰ÂÊÁËÈÍÎÏÌÓÔ\u{F8FF}ÒÚÛÙıˆ˜¯˘˙˚¸˝˛ˇ";
+
+/// Every record of the `name` table, in table order.
+#[derive(Serialize)]
+pub struct NameTableReport {
+    pub records: Vec<NameTableRecord>,
+}
+
+#[derive(Serialize)]
+pub struct NameTableRecord {
+    pub name_id: u16,
+    /// What the name ID is for, or `None` for reserved IDs.
+    pub meaning: Option<&'static str>,
+    pub platform_id: u16,
+    pub encoding_id: u16,
+    pub language_id: u16,
+    /// The language's name or BCP 47 tag, when the ID is one we know.
+    pub language: Option<String>,
+    /// The decoded string, or `None` for encodings we can't decode.
+    pub value: Option<String>,
+}
+
+impl NameTableReport {
+    /// Returns `None` for fonts without name records.
+    pub fn new(face: &Face) -> Option<Self> {
+        let lang_tags = face
+            .raw_face()
+            .table(Tag::from_bytes(b"name"))
+            .and_then(lang_tags)
+            .unwrap_or_default();
+        let records: Vec<_> = face
+            .names()
+            .into_iter()
+            .map(|name| NameTableRecord {
+                name_id: name.name_id,
+                meaning: meaning(name.name_id),
+                platform_id: platform_number(name.platform_id),
+                encoding_id: name.encoding_id,
+                language_id: name.language_id,
+                language: language(&name, &lang_tags),
+                value: decode(&name),
+            })
+            .collect();
+        (!records.is_empty()).then_some(NameTableReport { records })
+    }
+}
+
+impl NameTableRecord {
+    pub fn platform(&self) -> &'static str {
+        match self.platform_id {
+            0 => "Unicode",
+            1 => "Macintosh",
+            2 => "ISO",
+            3 => "Windows",
+            _ => "Custom",
+        }
+    }
+}
+
+fn meaning(name_id: u16) -> Option<&'static str> {
+    match name_id {
+        15 => None,
+        0..=25 => Some(NAME_IDS[name_id as usize]),
+        256..=32767 => Some("Font-specific"),
+        _ => None,
+    }
+}
+
+fn platform_number(platform_id: PlatformId) -> u16 {
+    match platform_id {
+        PlatformId::Unicode => 0,
+        PlatformId::Macintosh => 1,
+        PlatformId::Iso => 2,
+        PlatformId::Windows => 3,
+        PlatformId::Custom => 4,
+    }
+}
+
+fn language(name: &Name, lang_tags: &[String]) -> Option<String> {
+    match name.platform_id {
+        // IDs from 0x8000 index the language-tag records of a format 1
+        // table, on any platform.
+        _ if name.language_id >= 0x8000 => {
+            lang_tags.get(name.language_id as usize - 0x8000).cloned()
+        }
+        PlatformId::Windows => {
+            let language = name.language();
+            (language != ttf_parser::Language::Unknown).then(|| language.to_string())
+        }
+        PlatformId::Macintosh => MAC_LANGUAGES
+            .get(name.language_id as usize)
+            .map(|name| name.to_string()),
+        _ => None,
+    }
+}
+
+/// Decodes UTF-16BE names on the Unicode and Windows platforms and Mac
+/// Roman names on the Macintosh platform.
+fn decode(name: &Name) -> Option<String> {
+    match (name.platform_id, name.encoding_id) {
+        (PlatformId::Macintosh, 0) => Some(
+            name.name
+                .iter()
+                .map(|&b| match b {
+                    0..0x80 => b as char,
+                    _ => MAC_ROMAN
+                        .chars()
+                        .nth(b as usize - 0x80)
+                        .unwrap_or('\u{FFFD}'),
+                })
+                .collect(),
+        ),
+        (PlatformId::Windows, 10) => utf16_be(name.name),
+        _ => name.to_string(),
+    }
+}
+
+fn utf16_be(data: &[u8]) -> Option<String> {
+    let units: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+        .collect();
+    String::from_utf16(&units).ok()
+}
+
+/// Reads the language tags of a format 1 `name` table, which ttf-parser
+/// skips over.
+fn lang_tags(data: &[u8]) -> Option<Vec<String>> {
+    let mut r = Reader::new(data);
+    let version = r.read_u16()?;
+    let count = r.read_u16()? as usize;
+    let storage_offset = r.read_u16()? as usize;
+    if version < 1 {
+        return None;
+    }
+    r.skip(count * 12); // nameRecord[count]
+    let tag_count = r.read_u16()?;
+    (0..tag_count)
+        .map(|_| {
+            let length = r.read_u16()? as usize;
+            let offset = r.read_u16()? as usize;
+            let start = storage_offset + offset;
+            utf16_be(data.get(start..start + length)?)
+        })
+        .collect()
+}
@@ -0,0 +1,98 @@
+use crate::layout::{self, MarkAttachment};
+use serde::Serialize;
+use ttf_parser::{Face, GlyphId, Tag};
+
+/// Precomposed characters with their canonical decompositions into a base
+/// and nonspacing marks.
+const DECOMPOSITIONS: &str = include_str!("../data/decompositions.txt");
+
+/// Whether text in either normalization form renders with the font.
+/// NFC text uses precomposed characters such as é, NFD text the base
+/// letter followed by combining marks.
+#[derive(Serialize)]
+pub struct NormalizationReport {
+    /// Characters that render both precomposed and decomposed.
+    pub round_trip: usize,
+    /// Covered precomposed characters whose decomposition doesn't render:
+    /// a piece is missing, or the marks are neither positioned by GPOS nor
+    /// composed by `ccmp`.
+    pub precomposed_only: Vec<char>,
+    /// Characters whose decomposition renders but which aren't covered
+    /// precomposed.
+    pub decomposed_only: Vec<char>,
+}
+
+impl NormalizationReport {
+    /// Returns `None` for fonts that render none of the bundled characters
+    /// in either form.
+    pub fn new(face: &Face) -> Option<Self> {
+        let attachment = face
+            .tables()
+            .gpos
+            .map(|gpos| MarkAttachment::new(&gpos))
+            .unwrap_or_default();
+
+        let mut report = NormalizationReport {
+            round_trip: 0,
+            precomposed_only: Vec::new(),
+            decomposed_only: Vec::new(),
+        };
+        for (c, decomposed) in decompositions() {
+            let precomposed = face.glyph_index(c).is_some();
+            match (precomposed, renders(face, &attachment, &decomposed)) {
+                (true, true) => report.round_trip += 1,
+                (true, false) => report.precomposed_only.push(c),
+                (false, true) => report.decomposed_only.push(c),
+                (false, false) => {}
+            }
+        }
+
+        let empty = report.round_trip == 0
+            && report.precomposed_only.is_empty()
+            && report.decomposed_only.is_empty();
+        (!empty).then_some(report)
+    }
+}
+
+/// Parses the bundled decompositions.
+pub fn decompositions() -> impl Iterator<Item = (char, Vec<char>)> {
+    DECOMPOSITIONS.lines().filter_map(|line| {
+        let (fields, _) = line.split_once('#').unwrap_or((line, ""));
+        let (precomposed, decomposed) = fields.split_once(';')?;
+        let parse = |cp: &str| {
+            u32::from_str_radix(cp.trim(), 16)
+                .ok()
+                .and_then(char::from_u32)
+        };
+        let decomposed = decomposed
+            .split_whitespace()
+            .map(parse)
+            .collect::<Option<_>>()?;
+        Some((parse(precomposed)?, decomposed))
+    })
+}
+
+/// True when the base and marks are all covered and either `ccmp` composes
+/// them into one glyph or GPOS attaches the first mark to the base and each
+/// further mark to the one before.
+pub fn renders(face: &Face, attachment: &MarkAttachment, chars: &[char]) -> bool {
+    let Some(glyphs) = chars
+        .iter()
+        .map(|&c| face.glyph_index(c))
+        .collect::<Option<Vec<GlyphId>>>()
+    else {
+        return false;
+    };
+    let composed = face
+        .tables()
+        .gsub
+        .is_some_and(|gsub| layout::has_ligature(&gsub, Tag::from_bytes(b"ccmp"), &glyphs));
+    if composed {
+        return true;
+    }
+    let (first, stacked) = match &glyphs[1..] {
+        [first, stacked @ ..] => (first, stacked),
+        [] => return true,
+    };
+    attachment.to_base.contains(first) && stacked.iter().all(|g| attachment.to_mark.contains(g))
+}
@@ -0,0 +1,191 @@
+use crate::layout::raw_features;
+use crate::reader::Reader;
+use ttf_parser::stat::AxisValueSubtable;
+use ttf_parser::{Face, Tag};
+
+/// Parameters of the GPOS `size` feature, in points.
+pub struct SizeFeature {
+    pub design_size: f32,
+    /// The recommended usage range, absent when the font only states a
+    /// design size.
+    pub range: Option<(f32, f32)>,
+    pub subfamily_name_id: u16,
+}
+
+/// A STAT axis value on the `opsz` axis.
+pub struct StatValue {
+    pub name_id: u16,
+    /// Either a single value or a nominal value with its range.
+    pub value: f32,
+    pub range: Option<(f32, f32)>,
+}
+
+/// Optical size data gathered from every place a font can record it.
+pub struct OpticalSize {
+    pub size_feature: Option<SizeFeature>,
+    /// `opsz` axis range as (min, default, max).
+    pub axis: Option<(f32, f32, f32)>,
+    pub stat_values: Vec<StatValue>,
+    /// OS/2 version 5 usLower/UpperOpticalPointSize, in points.
+    pub os2_range: Option<(f32, f32)>,
+}
+
+impl OpticalSize {
+    pub fn new(face: &Face) -> Self {
+        let opsz = Tag::from_bytes(b"opsz");
+
+        let axis = face
+            .variation_axes()
+            .into_iter()
+            .find(|a| a.tag == opsz)
+            .map(|a| (a.min_value, a.def_value, a.max_value));
+
+        OpticalSize {
+            size_feature: size_feature(face),
+            axis,
+            stat_values: stat_values(face, opsz),
+            os2_range: os2_range(face),
+        }
+    }
+
+    /// Reconciles all sources into one point-size range, preferring the
+    /// variable axis, then the `size` feature, OS/2 and finally STAT.
+    pub fn designed_range(&self) -> Option<(f32, f32)> {
+        if let Some((min, _, max)) = self.axis {
+            return Some((min, max));
+        }
+        if let Some(size) = &self.size_feature {
+            return Some(size.range.unwrap_or((size.design_size, size.design_size)));
+        }
+        if let Some(range) = self.os2_range {
+            return Some(range);
+        }
+        let mut values = self.stat_values.iter().flat_map(|v| match v.range {
+            Some((min, max)) => vec![min, max],
+            None => vec![v.value],
+        });
+        let first = values.next()?;
+        Some(values.fold((first, first), |(lo, hi), v| (lo.min(v), hi.max(v))))
+    }
+
+    /// Notes disagreements between sources, e.g. a `size` design size that
+    /// falls outside the `opsz` axis.
+    pub fn conflicts(&self) -> Vec<String> {
+        let mut conflicts = Vec::new();
+        if let (Some(size), Some((min, _, max))) = (&self.size_feature, self.axis)
+            && (size.design_size < min || size.design_size > max)
+        {
+            conflicts.push(format!(
+                "size feature design size {}pt is outside the opsz axis {}–{}",
+                format_pt(size.design_size),
+                format_pt(min),
+                format_pt(max)
+            ));
+        }
+        if let (Some((lo, hi)), Some((min, _, max))) = (self.os2_range, self.axis)
+            && ((lo - min).abs() > 0.05 || (hi - max).abs() > 0.05)
+        {
+            conflicts.push(format!(
+                "OS/2 optical range {}–{}pt differs from the opsz axis {}–{}",
+                format_pt(lo),
+                format_pt(hi),
+                format_pt(min),
+                format_pt(max)
+            ));
+        }
+        if let Some((min, _, max)) = self.axis {
+            for value in &self.stat_values {
+                if value.value < min || value.value > max {
+                    conflicts.push(format!(
+                        "STAT opsz value {} is outside the opsz axis {}–{}",
+                        format_pt(value.value),
+                        format_pt(min),
+                        format_pt(max)
+                    ));
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+/// Formats a point size without a trailing `.0`.
+pub fn format_pt(value: f32) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i32)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+fn size_feature(face: &Face) -> Option<SizeFeature> {
+    let gpos = face.raw_face().table(Tag::from_bytes(b"GPOS"))?;
+    let feature = raw_features(gpos)
+        .into_iter()
+        .find(|f| f.tag == Tag::from_bytes(b"size"))?;
+    let params = feature.params(|data| {
+        let mut r = Reader::new(data);
+        let design = r.read_u16().unwrap_or(0);
+        r.skip(4);
+        let start = r.read_u16().unwrap_or(0);
+        let end = r.read_u16().unwrap_or(0);
+        design != 0 && (start == 0 && end == 0 || start <= design && design <= end)
+    })?;
+
+    let mut r = Reader::new(params);
+    let design_size = r.read_u16()?;
+    r.skip(2); // subfamily identifier
+    let subfamily_name_id = r.read_u16()?;
+    let range_start = r.read_u16()?;
+    let range_end = r.read_u16()?;
+
+    Some(SizeFeature {
+        design_size: design_size as f32 / 10.0,
+        range: (range_end != 0).then(|| (range_start as f32 / 10.0, range_end as f32 / 10.0)),
+        subfamily_name_id,
+    })
+}
+
+fn stat_values(face: &Face, opsz: Tag) -> Vec<StatValue> {
+    let Some(stat) = face.tables().stat else {
+        return Vec::new();
+    };
+    let Some(axis_index) = stat.axes.into_iter().position(|a| a.tag == opsz) else {
+        return Vec::new();
+    };
+    let axis_index = axis_index as u16;
+
+    stat.subtables()
+        .filter_map(|subtable| match subtable {
+            AxisValueSubtable::Format1(v) if v.axis_index == axis_index => Some(StatValue {
+                name_id: v.value_name_id,
+                value: v.value.0,
+                range: None,
+            }),
+            AxisValueSubtable::Format2(v) if v.axis_index == axis_index => Some(StatValue {
+                name_id: v.value_name_id,
+                value: v.nominal_value.0,
+                range: Some((v.range_min_value.0, v.range_max_value.0)),
+            }),
+            AxisValueSubtable::Format3(v) if v.axis_index == axis_index => Some(StatValue {
+                name_id: v.value_name_id,
+                value: v.value.0,
+                range: None,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+fn os2_range(face: &Face) -> Option<(f32, f32)> {
+    let os2 = face.raw_face().table(Tag::from_bytes(b"OS/2"))?;
+    let mut r = Reader::new(os2);
+    if r.read_u16()? < 5 {
+        return None;
+    }
+    let mut r = Reader::at(os2, 96);
+    let lower = r.read_u16()?;
+    let upper = r.read_u16()?;
+    // Stored in TWIPs (twentieths of a point).
+    Some((lower as f32 / 20.0, upper as f32 / 20.0))
+}
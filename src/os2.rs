@@ -0,0 +1,325 @@
+use crate::embedding::EmbeddingReport;
+use crate::reader::Reader;
+use serde::Serialize;
+use ttf_parser::{Face, Tag};
+
+/// Names of the ulUnicodeRange bits, by bit number, after the first block
+/// each bit stands for. Bits 123–127 are reserved.
+const UNICODE_RANGES: [&str; 123] = [
+    "Basic Latin",
+    "Latin-1 Supplement",
+    "Latin Extended-A",
+    "Latin Extended-B",
+    "IPA Extensions",
+    "Spacing Modifier Letters",
+    "Combining Diacritical Marks",
+    "Greek and Coptic",
+    "Coptic",
+    "Cyrillic",
+    "Armenian",
+    "Hebrew",
+    "Vai",
+    "Arabic",
+    "NKo",
+    "Devanagari",
+    "Bengali",
+    "Gurmukhi",
+    "Gujarati",
+    "Oriya",
+    "Tamil",
+    "Telugu",
+    "Kannada",
+    "Malayalam",
+    "Thai",
+    "Lao",
+    "Georgian",
+    "Balinese",
+    "Hangul Jamo",
+    "Latin Extended Additional",
+    "Greek Extended",
+    "General Punctuation",
+    "Superscripts and Subscripts",
+    "Currency Symbols",
+    "Combining Diacritical Marks for Symbols",
+    "Letterlike Symbols",
+    "Number Forms",
+    "Arrows",
+    "Mathematical Operators",
+    "Miscellaneous Technical",
+    "Control Pictures",
+    "Optical Character Recognition",
+    "Enclosed Alphanumerics",
+    "Box Drawing",
+    "Block Elements",
+    "Geometric Shapes",
+    "Miscellaneous Symbols",
+    "Dingbats",
+    "CJK Symbols and Punctuation",
+    "Hiragana",
+    "Katakana",
+    "Bopomofo",
+    "Hangul Compatibility Jamo",
+    "Phags-pa",
+    "Enclosed CJK Letters and Months",
+    "CJK Compatibility",
+    "Hangul Syllables",
+    "Non-Plane 0",
+    "Phoenician",
+    "CJK Unified Ideographs",
+    "Private Use Area (plane 0)",
+    "CJK Strokes",
+    "Alphabetic Presentation Forms",
+    "Arabic Presentation Forms-A",
+    "Combining Half Marks",
+    "Vertical Forms",
+    "Small Form Variants",
+    "Arabic Presentation Forms-B",
+    "Halfwidth and Fullwidth Forms",
+    "Specials",
+    "Tibetan",
+    "Syriac",
+    "Thaana",
+    "Sinhala",
+    "Myanmar",
+    "Ethiopic",
+    "Cherokee",
+    "Unified Canadian Aboriginal Syllabics",
+    "Ogham",
+    "Runic",
+    "Khmer",
+    "Mongolian",
+    "Braille Patterns",
+    "Yi Syllables",
+    "Tagalog",
+    "Old Italic",
+    "Gothic",
+    "Deseret",
+    "Byzantine Musical Symbols",
+    "Mathematical Alphanumeric Symbols",
+    "Private Use (planes 15 and 16)",
+    "Variation Selectors",
+    "Tags",
+    "Limbu",
+    "Tai Le",
+    "New Tai Lue",
+    "Buginese",
+    "Glagolitic",
+    "Tifinagh",
+    "Yijing Hexagram Symbols",
+    "Syloti Nagri",
+    "Linear B Syllabary",
+    "Ancient Greek Numbers",
+    "Ugaritic",
+    "Old Persian",
+    "Shavian",
+    "Osmanya",
+    "Cypriot Syllabary",
+    "Kharoshthi",
+    "Tai Xuan Jing Symbols",
+    "Cuneiform",
+    "Counting Rod Numerals",
+    "Sundanese",
+    "Lepcha",
+    "Ol Chiki",
+    "Saurashtra",
+    "Kayah Li",
+    "Rejang",
+    "Cham",
+    "Ancient Symbols",
+    "Phaistos Disc",
+    "Carian",
+    "Domino Tiles",
+];
+
+/// The defined ulCodePageRange bits, numbered across both fields.
+const CODE_PAGES: [(u32, &str); 34] = [
+    (0, "1252 Latin 1"),
+    (1, "1250 Latin 2: Eastern Europe"),
+    (2, "1251 Cyrillic"),
+    (3, "1253 Greek"),
+    (4, "1254 Turkish"),
+    (5, "1255 Hebrew"),
+    (6, "1256 Arabic"),
+    (7, "1257 Windows Baltic"),
+    (8, "1258 Vietnamese"),
+    (16, "874 Thai"),
+    (17, "932 JIS/Japan"),
+    (18, "936 Chinese: Simplified"),
+    (19, "949 Korean Wansung"),
+    (20, "950 Chinese: Traditional"),
+    (21, "1361 Korean Johab"),
+    (29, "Macintosh Character Set (US Roman)"),
+    (30, "OEM Character Set"),
+    (31, "Symbol Character Set"),
+    (48, "869 IBM Greek"),
+    (49, "866 MS-DOS Russian"),
+    (50, "865 MS-DOS Nordic"),
+    (51, "864 Arabic"),
+    (52, "863 MS-DOS Canadian French"),
+    (53, "862 Hebrew"),
+    (54, "861 MS-DOS Icelandic"),
+    (55, "860 MS-DOS Portuguese"),
+    (56, "857 IBM Turkish"),
+    (57, "855 IBM Cyrillic"),
+    (58, "852 Latin 2"),
+    (59, "775 MS-DOS Baltic"),
+    (60, "737 Greek"),
+    (61, "708 Arabic; ASMO 708"),
+    (62, "850 WE/Latin 1"),
+    (63, "437 US"),
+];
+
+/// fsSelection bits by number.
+const SELECTION_FLAGS: [&str; 10] = [
+    "ITALIC",
+    "UNDERSCORE",
+    "NEGATIVE",
+    "OUTLINED",
+    "STRIKEOUT",
+    "BOLD",
+    "REGULAR",
+    "USE_TYPO_METRICS",
+    "WWS",
+    "OBLIQUE",
+];
+
+const WIDTH_CLASSES: [&str; 9] = [
+    "Ultra-condensed",
+    "Extra-condensed",
+    "Condensed",
+    "Semi-condensed",
+    "Medium (normal)",
+    "Semi-expanded",
+    "Expanded",
+    "Extra-expanded",
+    "Ultra-expanded",
+];
+
+/// The OS/2 table, with its bit fields decoded.
+#[derive(Serialize)]
+pub struct Os2Report {
+    pub version: u16,
+    pub weight_class: u16,
+    pub width_class: u16,
+    pub fs_type: u16,
+    pub fs_selection: u16,
+    pub typo_ascender: i16,
+    pub typo_descender: i16,
+    pub typo_line_gap: i16,
+    pub win_ascent: u16,
+    pub win_descent: u16,
+    /// Added in version 2.
+    pub x_height: Option<i16>,
+    pub cap_height: Option<i16>,
+    pub vendor_id: String,
+    /// Names of the set ulUnicodeRange bits.
+    pub unicode_ranges: Vec<String>,
+    /// Names of the set ulCodePageRange bits, which version 0 lacks.
+    pub code_pages: Option<Vec<String>>,
+}
+
+impl Os2Report {
+    /// Returns `None` without an OS/2 table or when it is truncated.
+    pub fn new(face: &Face) -> Option<Self> {
+        let os2 = face.raw_face().table(Tag::from_bytes(b"OS/2"))?;
+        let read_i16 = |offset| Reader::at(os2, offset).read_i16();
+        let read_u16 = |offset| Reader::at(os2, offset).read_u16();
+        let read_u32 = |offset| Reader::at(os2, offset).read_u32();
+
+        let version = read_u16(0)?;
+        // ulUnicodeRange1 holds bits 0-31, ulUnicodeRange4 bits 96-127.
+        let mut unicode_bits = 0u128;
+        for i in 0..4 {
+            unicode_bits |= u128::from(read_u32(42 + 4 * i)?) << (32 * i);
+        }
+        let unicode_ranges = set_bits(unicode_bits, 128)
+            .map(|bit| match UNICODE_RANGES.get(bit as usize) {
+                Some(name) => name.to_string(),
+                None => format!("Reserved bit {}", bit),
+            })
+            .collect();
+        let code_pages = (version >= 1)
+            .then(|| {
+                let bits = u128::from(read_u32(82)?) << 32 | u128::from(read_u32(78)?);
+                Some(
+                    set_bits(bits, 64)
+                        .map(|bit| match CODE_PAGES.iter().find(|(b, _)| *b == bit) {
+                            Some((_, name)) => name.to_string(),
+                            None => format!("Reserved bit {}", bit),
+                        })
+                        .collect(),
+                )
+            })
+            .flatten();
+        let (x_height, cap_height) = if version >= 2 {
+            (read_i16(86), read_i16(88))
+        } else {
+            (None, None)
+        };
+        let vendor_id = Reader::at(os2, 58)
+            .read_bytes(4)
+            .map(|bytes| String::from_utf8_lossy(bytes).into_owned())?;
+
+        Some(Os2Report {
+            version,
+            weight_class: read_u16(4)?,
+            width_class: read_u16(6)?,
+            fs_type: read_u16(8)?,
+            fs_selection: read_u16(62)?,
+            typo_ascender: read_i16(68)?,
+            typo_descender: read_i16(70)?,
+            typo_line_gap: read_i16(72)?,
+            win_ascent: read_u16(74)?,
+            win_descent: read_u16(76)?,
+            x_height,
+            cap_height,
+            vendor_id,
+            unicode_ranges,
+            code_pages,
+        })
+    }
+
+    /// The usWeightClass name, e.g. "Regular" for 400.
+    pub fn weight_name(&self) -> Option<&'static str> {
+        let name = match self.weight_class {
+            100 => "Thin",
+            200 => "Extra-light",
+            300 => "Light",
+            400 => "Regular",
+            500 => "Medium",
+            600 => "Semi-bold",
+            700 => "Bold",
+            800 => "Extra-bold",
+            900 => "Black",
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    pub fn width_name(&self) -> Option<&'static str> {
+        let index = usize::from(self.width_class).checked_sub(1)?;
+        WIDTH_CLASSES.get(index).copied()
+    }
+
+    /// The embedding permissions fsType grants.
+    pub fn embedding(&self) -> Vec<&'static str> {
+        EmbeddingReport::from_fs_type(self.fs_type).summary()
+    }
+
+    /// Names of the set fsSelection bits.
+    pub fn selection_flags(&self) -> Vec<&'static str> {
+        set_bits(u128::from(self.fs_selection), 16)
+            .map(|bit| {
+                SELECTION_FLAGS
+                    .get(bit as usize)
+                    .copied()
+                    .unwrap_or("reserved")
+            })
+            .collect()
+    }
+}
+
+/// The numbers of the set bits among the low `width` bits of `bits`.
+fn set_bits(bits: u128, width: u32) -> impl Iterator<Item = u32> {
+    (0..width).filter(move |bit| bits >> bit & 1 == 1)
+}
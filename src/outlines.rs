@@ -0,0 +1,175 @@
+use crate::reader::Reader;
+use std::collections::BTreeMap;
+use ttf_parser::{Face, GlyphId, OutlineBuilder, Tag};
+
+/// The glyph data sources present in a font.
+pub struct OutlineReport {
+    pub sfnt_version: u32,
+    /// Vector outline tables: `glyf`, `CFF `, `CFF2`.
+    pub outlines: Vec<&'static str>,
+    /// Color and bitmap glyph tables layered on top of (or instead of) outlines.
+    pub other_sources: Vec<&'static str>,
+}
+
+const OUTLINE_TABLES: [(&[u8; 4], &str); 3] = [
+    (b"glyf", "TrueType (glyf)"),
+    (b"CFF ", "CFF"),
+    (b"CFF2", "CFF2"),
+];
+
+const OTHER_SOURCES: [(&[u8; 4], &str); 6] = [
+    (b"COLR", "COLR"),
+    (b"SVG ", "SVG"),
+    (b"CBDT", "CBDT"),
+    (b"sbix", "sbix"),
+    (b"EBDT", "EBDT"),
+    (b"bdat", "bdat"),
+];
+
+impl OutlineReport {
+    pub fn new(face: &Face) -> Self {
+        let raw = face.raw_face();
+        let present = |tag: &[u8; 4]| raw.table(Tag::from_bytes(tag)).is_some();
+
+        OutlineReport {
+            sfnt_version: sfnt_version(raw.data),
+            outlines: OUTLINE_TABLES
+                .iter()
+                .filter(|(tag, _)| present(tag))
+                .map(|&(_, name)| name)
+                .collect(),
+            other_sources: OTHER_SOURCES
+                .iter()
+                .filter(|(tag, _)| present(tag))
+                .map(|&(_, name)| name)
+                .collect(),
+        }
+    }
+
+    pub fn sfnt_version_name(&self) -> String {
+        match &self.sfnt_version.to_be_bytes() {
+            [0x00, 0x01, 0x00, 0x00] => "0x00010000 (TrueType)".to_string(),
+            b"OTTO" => "'OTTO' (OpenType CFF)".to_string(),
+            b"true" => "'true' (Apple TrueType)".to_string(),
+            b"typ1" => "'typ1' (Apple Type 1)".to_string(),
+            _ => format!("{:#010x} (unknown)", self.sfnt_version),
+        }
+    }
+
+    /// Describes the primary outline flavor.
+    pub fn flavor(&self) -> String {
+        match self.outlines.as_slice() {
+            [] if self.other_sources.is_empty() => "None (no glyph data)".to_string(),
+            [] => format!("Bitmap/color only ({})", self.other_sources.join(", ")),
+            [single] => single.to_string(),
+            several => format!("Conflicting: {}", several.join(" + ")),
+        }
+    }
+
+    /// True when the font mixes outlines with color or bitmap glyph sources.
+    pub fn is_mixed(&self) -> bool {
+        !self.outlines.is_empty() && !self.other_sources.is_empty()
+    }
+
+    /// True when more than one table can supply glyph images.
+    pub fn has_multiple_sources(&self) -> bool {
+        self.outlines.len() + self.other_sources.len() > 1
+    }
+}
+
+/// Reads the sfnt version of the first face, following the TTC header
+/// for font collections.
+fn sfnt_version(data: &[u8]) -> u32 {
+    let mut r = Reader::new(data);
+    let magic = r.read_u32().unwrap_or(0);
+    if magic != u32::from_be_bytes(*b"ttcf") {
+        return magic;
+    }
+    r.skip(8); // version, numFonts
+    r.read_u32()
+        .and_then(|offset| Reader::at(data, offset as usize).read_u32())
+        .unwrap_or(0)
+}
+
+/// Which glyph data sources provide an image for a single glyph.
+#[derive(Clone, Copy)]
+pub struct GlyphSources {
+    pub outline: bool,
+    pub colr: bool,
+    pub svg: bool,
+    pub bitmap: bool,
+}
+
+impl GlyphSources {
+    pub fn new(face: &Face, glyph_id: GlyphId) -> Self {
+        GlyphSources {
+            outline: face.outline_glyph(glyph_id, &mut NullBuilder).is_some(),
+            colr: face.is_color_glyph(glyph_id),
+            svg: face.glyph_svg_image(glyph_id).is_some(),
+            bitmap: face.glyph_raster_image(glyph_id, u16::MAX).is_some(),
+        }
+    }
+
+    pub fn has_color(&self) -> bool {
+        self.colr || self.svg || self.bitmap
+    }
+
+    /// Lists the sources, e.g. `outline + COLR`, or `none` for empty glyphs.
+    pub fn describe(&self) -> String {
+        let names: Vec<&str> = [
+            (self.outline, "outline"),
+            (self.colr, "COLR"),
+            (self.svg, "SVG"),
+            (self.bitmap, "bitmap"),
+        ]
+        .iter()
+        .filter(|(present, _)| *present)
+        .map(|&(_, name)| name)
+        .collect();
+        if names.is_empty() {
+            "none".to_string()
+        } else {
+            names.join(" + ")
+        }
+    }
+}
+
+/// Per-glyph source coverage for fonts with more than one glyph source.
+pub struct SourceCoverage {
+    /// Glyph counts keyed by source combination, e.g. `outline + COLR`.
+    pub combinations: BTreeMap<String, usize>,
+    /// Color glyphs without a fallback outline; these render blank in
+    /// renderers that don't support the color table.
+    pub missing_fallback: Vec<GlyphId>,
+}
+
+impl SourceCoverage {
+    pub fn new(face: &Face) -> Self {
+        let mut combinations = BTreeMap::new();
+        let mut missing_fallback = Vec::new();
+
+        for id in 0..face.number_of_glyphs() {
+            let glyph_id = GlyphId(id);
+            let sources = GlyphSources::new(face, glyph_id);
+            *combinations.entry(sources.describe()).or_insert(0) += 1;
+            if sources.has_color() && !sources.outline {
+                missing_fallback.push(glyph_id);
+            }
+        }
+
+        SourceCoverage {
+            combinations,
+            missing_fallback,
+        }
+    }
+}
+
+struct NullBuilder;
+
+impl OutlineBuilder for NullBuilder {
+    fn move_to(&mut self, _x: f32, _y: f32) {}
+    fn line_to(&mut self, _x: f32, _y: f32) {}
+    fn quad_to(&mut self, _x1: f32, _y1: f32, _x: f32, _y: f32) {}
+    fn curve_to(&mut self, _x1: f32, _y1: f32, _x2: f32, _y2: f32, _x: f32, _y: f32) {}
+    fn close(&mut self) {}
+}
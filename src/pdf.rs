@@ -0,0 +1,602 @@
+use crate::inflate::zlib_decompress;
+use std::collections::HashMap;
+
+/// Decoded streams larger than this are treated as corrupt, which bounds
+/// what a hostile PDF can make us allocate.
+const MAX_STREAM_SIZE: usize = 256 * 1024 * 1024;
+/// How deep arrays and dictionaries may nest, and how many references
+/// resolving one value may follow.
+const MAX_DEPTH: usize = 32;
+
+/// The font program formats a font descriptor can embed.
+#[derive(Clone, Copy, PartialEq)]
+pub enum FontProgram {
+    /// `FontFile`: a Type 1 font.
+    Type1,
+    /// `FontFile2`: a TrueType font.
+    TrueType,
+    /// `FontFile3` with subtype `Type1C`: a bare CFF font.
+    Cff,
+    /// `FontFile3` with subtype `CIDFontType0C`: a bare CID-keyed CFF font.
+    CidCff,
+    /// `FontFile3` with subtype `OpenType`.
+    OpenType,
+}
+
+impl FontProgram {
+    pub fn name(self) -> &'static str {
+        match self {
+            FontProgram::Type1 => "Type 1 (FontFile)",
+            FontProgram::TrueType => "TrueType (FontFile2)",
+            FontProgram::Cff => "CFF (FontFile3/Type1C)",
+            FontProgram::CidCff => "CID-keyed CFF (FontFile3/CIDFontType0C)",
+            FontProgram::OpenType => "OpenType (FontFile3/OpenType)",
+        }
+    }
+
+    /// The usual file extension for the program.
+    pub fn extension(self) -> &'static str {
+        match self {
+            FontProgram::Type1 => "t1",
+            FontProgram::TrueType => "ttf",
+            FontProgram::Cff | FontProgram::CidCff => "cff",
+            FontProgram::OpenType => "otf",
+        }
+    }
+
+    /// Whether the program is an sfnt that the report can analyze.
+    pub fn is_sfnt(self) -> bool {
+        matches!(self, FontProgram::TrueType | FontProgram::OpenType)
+    }
+}
+
+/// A font program embedded in a PDF, with the descriptor that refers to it.
+pub struct EmbeddedFont {
+    /// The descriptor's FontName, e.g. `ABCDEF+Helvetica`.
+    pub name: String,
+    /// The six uppercase letters before `+` that mark a subset, e.g.
+    /// `ABCDEF`.
+    pub subset_prefix: Option<String>,
+    pub program: FontProgram,
+    /// Object number of the stream holding the program.
+    pub object: u32,
+    /// The decoded program, or why it couldn't be decoded.
+    pub data: Result<Vec<u8>, String>,
+}
+
+/// Finds the font programs embedded in a PDF, ordered by object number.
+/// Objects are found by scanning for `obj` keywords rather than through
+/// the cross-reference table, so damaged and incrementally updated files
+/// work too; the last definition of an object wins.
+pub fn embedded_fonts(pdf: &[u8]) -> Result<Vec<EmbeddedFont>, String> {
+    if !pdf[..pdf.len().min(1024)]
+        .windows(5)
+        .any(|window| window == b"%PDF-")
+    {
+        return Err("not a PDF file".to_string());
+    }
+    let document = Document::new(pdf);
+    if document.encrypted {
+        return Err("encrypted PDFs aren't supported".to_string());
+    }
+
+    let mut fonts: Vec<EmbeddedFont> = Vec::new();
+    for number in document.object_numbers() {
+        let Some(Object::Dict(descriptor)) = document.object(number) else {
+            continue;
+        };
+        for (key, program) in [
+            ("FontFile", Some(FontProgram::Type1)),
+            ("FontFile2", Some(FontProgram::TrueType)),
+            ("FontFile3", None),
+        ] {
+            let Some(&Object::Ref(object)) = descriptor.get(key) else {
+                continue;
+            };
+            if fonts.iter().any(|font| font.object == object) {
+                continue;
+            }
+            let name = match document.resolve(descriptor.get("FontName")) {
+                Some(Object::Name(name)) => name,
+                _ => format!("font{}", number),
+            };
+            let stream = document.stream(object);
+            let program = program.or_else(|| {
+                let (dict, _) = stream.as_ref()?;
+                match document.resolve(dict.get("Subtype")) {
+                    Some(Object::Name(subtype)) => match subtype.as_str() {
+                        "Type1C" => Some(FontProgram::Cff),
+                        "CIDFontType0C" => Some(FontProgram::CidCff),
+                        "OpenType" => Some(FontProgram::OpenType),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            });
+            let Some(program) = program else {
+                continue;
+            };
+            let data = match stream {
+                Some((dict, raw)) => document.decode(&dict, raw),
+                None => Err(format!("object {} is missing or not a stream", object)),
+            };
+            fonts.push(EmbeddedFont {
+                subset_prefix: subset_prefix(&name),
+                name,
+                program,
+                object,
+                data,
+            });
+        }
+    }
+    fonts.sort_by_key(|font| font.object);
+    Ok(fonts)
+}
+
+/// The tag of a subset font name: exactly six uppercase letters and `+`.
+fn subset_prefix(name: &str) -> Option<String> {
+    let (prefix, _) = name.split_once('+')?;
+    (prefix.len() == 6 && prefix.bytes().all(|b| b.is_ascii_uppercase()))
+        .then(|| prefix.to_string())
+}
+
+#[derive(Clone)]
+enum Object {
+    Null,
+    Bool,
+    Number(f64),
+    Name(String),
+    String,
+    Array(Vec<Object>),
+    Dict(HashMap<String, Object>),
+    Ref(u32),
+}
+
+/// Where each object's definition starts, and the objects stored inside
+/// object streams.
+struct Document<'a> {
+    data: &'a [u8],
+    offsets: HashMap<u32, usize>,
+    compressed: HashMap<u32, Object>,
+    encrypted: bool,
+}
+
+impl<'a> Document<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        let mut document = Document {
+            data,
+            offsets: HashMap::new(),
+            compressed: HashMap::new(),
+            encrypted: false,
+        };
+        let mut pos = 0;
+        while let Some(found) = find(data, pos, b"obj") {
+            pos = found + 3;
+            if data.get(pos).is_some_and(|&b| !is_delimiter(b)) {
+                continue;
+            }
+            if let Some(number) = object_header(data, found) {
+                document.offsets.insert(number, pos);
+            }
+        }
+        // Trailers and cross-reference stream dictionaries name the
+        // encryption dictionary.
+        document.encrypted = find(data, 0, b"/Encrypt").is_some();
+
+        let mut compressed = HashMap::new();
+        for number in document.object_numbers() {
+            let Some((dict, raw)) = document.stream(number) else {
+                continue;
+            };
+            if !matches!(dict.get("Type"), Some(Object::Name(kind)) if kind == "ObjStm") {
+                continue;
+            }
+            if let Ok(decoded) = document.decode(&dict, raw) {
+                object_stream(&dict, &decoded, &mut compressed);
+            }
+        }
+        document.compressed = compressed;
+        document
+    }
+
+    fn object_numbers(&self) -> Vec<u32> {
+        let mut numbers: Vec<u32> = self
+            .offsets
+            .keys()
+            .chain(self.compressed.keys())
+            .copied()
+            .collect();
+        numbers.sort_unstable();
+        numbers.dedup();
+        numbers
+    }
+
+    fn object(&self, number: u32) -> Option<Object> {
+        match self.offsets.get(&number) {
+            Some(&offset) => Parser::at(self.data, offset).object(0),
+            None => self.compressed.get(&number).cloned(),
+        }
+    }
+
+    /// Follows references until a direct value.
+    fn resolve(&self, value: Option<&Object>) -> Option<Object> {
+        let mut value = value?.clone();
+        for _ in 0..MAX_DEPTH {
+            match value {
+                Object::Ref(number) => value = self.object(number)?,
+                value => return Some(value),
+            }
+        }
+        None
+    }
+
+    /// The dictionary and raw data of a stream object.
+    fn stream(&self, number: u32) -> Option<(HashMap<String, Object>, &'a [u8])> {
+        let mut parser = Parser::at(self.data, *self.offsets.get(&number)?);
+        let Object::Dict(dict) = parser.object(0)? else {
+            return None;
+        };
+        parser.skip_whitespace();
+        if !parser.data[parser.pos..].starts_with(b"stream") {
+            return None;
+        }
+        let mut start = parser.pos + 6;
+        if self.data.get(start) == Some(&b'\r') {
+            start += 1;
+        }
+        if self.data.get(start) == Some(&b'\n') {
+            start += 1;
+        }
+        // Lengths are often wrong in damaged files; fall back to the
+        // endstream keyword.
+        let declared = match self.resolve(dict.get("Length")) {
+            Some(Object::Number(length)) if length >= 0.0 => start.checked_add(length as usize),
+            _ => None,
+        };
+        let end = declared
+            .filter(|&end| {
+                self.data
+                    .get(end..)
+                    .is_some_and(|rest| trim_start(rest).starts_with(b"endstream"))
+            })
+            .or_else(|| find(self.data, start, b"endstream"))?;
+        Some((dict, self.data.get(start..end)?))
+    }
+
+    /// Applies a stream's filters.
+    fn decode(&self, dict: &HashMap<String, Object>, raw: &[u8]) -> Result<Vec<u8>, String> {
+        let filters = match self.resolve(dict.get("Filter")) {
+            None | Some(Object::Null) => Vec::new(),
+            Some(Object::Name(name)) => vec![name],
+            Some(Object::Array(names)) => names
+                .iter()
+                .filter_map(|name| match self.resolve(Some(name)) {
+                    Some(Object::Name(name)) => Some(name),
+                    _ => None,
+                })
+                .collect(),
+            Some(_) => return Err("malformed /Filter".to_string()),
+        };
+        if let Some(Object::Dict(params)) = self.resolve(dict.get("DecodeParms"))
+            && matches!(params.get("Predictor"), Some(Object::Number(p)) if *p > 1.0)
+        {
+            return Err("predictors aren't supported".to_string());
+        }
+        let mut data = raw.to_vec();
+        for filter in filters {
+            data = match filter.as_str() {
+                "FlateDecode" => zlib_decompress(&data, MAX_STREAM_SIZE)
+                    .ok_or_else(|| "corrupt FlateDecode data".to_string())?,
+                "ASCIIHexDecode" => ascii_hex(&data),
+                "ASCII85Decode" => {
+                    ascii85(&data).ok_or_else(|| "corrupt ASCII85Decode data".to_string())?
+                }
+                _ => return Err(format!("the {} filter isn't supported", filter)),
+            };
+        }
+        Ok(data)
+    }
+}
+
+/// The object number of the `N G obj` header whose `obj` keyword is at
+/// `pos`.
+fn object_header(data: &[u8], pos: usize) -> Option<u32> {
+    let mut i = pos;
+    let skip_back = |i: &mut usize, predicate: fn(u8) -> bool| {
+        let end = *i;
+        while *i > 0 && predicate(data[*i - 1]) {
+            *i -= 1;
+        }
+        *i < end
+    };
+    if !skip_back(&mut i, is_whitespace) || !skip_back(&mut i, |b| b.is_ascii_digit()) {
+        return None;
+    }
+    if !skip_back(&mut i, is_whitespace) {
+        return None;
+    }
+    let end = i;
+    if !skip_back(&mut i, |b| b.is_ascii_digit()) {
+        return None;
+    }
+    if i > 0 && !is_delimiter(data[i - 1]) {
+        return None;
+    }
+    std::str::from_utf8(&data[i..end]).ok()?.parse().ok()
+}
+
+/// Adds the objects of a decoded object stream: pairs of object number and
+/// offset, then the objects from `/First` on.
+fn object_stream(
+    dict: &HashMap<String, Object>,
+    decoded: &[u8],
+    objects: &mut HashMap<u32, Object>,
+) {
+    let (Some(Object::Number(count)), Some(Object::Number(first))) =
+        (dict.get("N"), dict.get("First"))
+    else {
+        return;
+    };
+    let mut header = Parser::at(decoded, 0);
+    for _ in 0..*count as usize {
+        let (Some(Object::Number(number)), Some(Object::Number(offset))) =
+            (header.object(0), header.object(0))
+        else {
+            return;
+        };
+        // Both come from the file, so their sum may overflow.
+        let Some(start) = (*first as usize).checked_add(offset as usize) else {
+            continue;
+        };
+        if let Some(object) = Parser::at(decoded, start).object(0) {
+            objects.entry(number as u32).or_insert(object);
+        }
+    }
+}
+
+/// Reads PDF values. Only what font descriptors and stream dictionaries
+/// need is kept: strings and booleans are parsed but not stored.
+struct Parser<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn at(data: &'a [u8], pos: usize) -> Self {
+        Parser { data, pos }
+    }
+
+    fn object(&mut self, depth: usize) -> Option<Object> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        self.skip_whitespace();
+        match *self.data.get(self.pos)? {
+            b'/' => {
+                self.pos += 1;
+                Some(Object::Name(self.name()))
+            }
+            b'<' if self.data.get(self.pos + 1) == Some(&b'<') => {
+                self.pos += 2;
+                let mut dict = HashMap::new();
+                loop {
+                    self.skip_whitespace();
+                    if self.data[self.pos..].starts_with(b">>") {
+                        self.pos += 2;
+                        return Some(Object::Dict(dict));
+                    }
+                    let Object::Name(key) = self.object(depth + 1)? else {
+                        return None;
+                    };
+                    let value = self.object(depth + 1)?;
+                    dict.insert(key, value);
+                }
+            }
+            b'<' => {
+                self.pos = find(self.data, self.pos, b">")? + 1;
+                Some(Object::String)
+            }
+            b'[' => {
+                self.pos += 1;
+                let mut array = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    if self.data.get(self.pos) == Some(&b']') {
+                        self.pos += 1;
+                        return Some(Object::Array(array));
+                    }
+                    array.push(self.object(depth + 1)?);
+                }
+            }
+            b'(' => {
+                self.literal_string()?;
+                Some(Object::String)
+            }
+            b'0'..=b'9' | b'+' | b'-' | b'.' => {
+                let number = self.number()?;
+                // `N G R` is a reference; look ahead without consuming.
+                let save = self.pos;
+                if number.fract() == 0.0 && number >= 0.0 {
+                    self.skip_whitespace();
+                    if let Some(_generation) = self.integer() {
+                        self.skip_whitespace();
+                        if self.data.get(self.pos) == Some(&b'R')
+                            && self.data.get(self.pos + 1).is_none_or(|&b| is_delimiter(b))
+                        {
+                            self.pos += 1;
+                            return Some(Object::Ref(number as u32));
+                        }
+                    }
+                }
+                self.pos = save;
+                Some(Object::Number(number))
+            }
+            _ => {
+                let word = self.name();
+                match word.as_str() {
+                    "true" | "false" => Some(Object::Bool),
+                    "null" => Some(Object::Null),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// A name or keyword, with `#xx` escapes decoded.
+    fn name(&mut self) -> String {
+        let mut bytes = Vec::new();
+        while let Some(&b) = self.data.get(self.pos) {
+            if is_delimiter(b) {
+                break;
+            }
+            self.pos += 1;
+            if b == b'#'
+                && let Some(byte) = self
+                    .data
+                    .get(self.pos..self.pos + 2)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                bytes.push(byte);
+                self.pos += 2;
+            } else {
+                bytes.push(b);
+            }
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        let start = self.pos;
+        while self
+            .data
+            .get(self.pos)
+            .is_some_and(|&b| b.is_ascii_digit() || matches!(b, b'+' | b'-' | b'.'))
+        {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.data[start..self.pos])
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    fn integer(&mut self) -> Option<u32> {
+        let start = self.pos;
+        while self.data.get(self.pos).is_some_and(u8::is_ascii_digit) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.data[start..self.pos])
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    /// Skips a `(...)` string, with its nested parentheses and escapes.
+    fn literal_string(&mut self) -> Option<()> {
+        let mut depth = 0;
+        loop {
+            match *self.data.get(self.pos)? {
+                b'\\' => self.pos += 1,
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.pos += 1;
+                        return Some(());
+                    }
+                }
+                _ => {}
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(&b) = self.data.get(self.pos) {
+            if is_whitespace(b) {
+                self.pos += 1;
+            } else if b == b'%' {
+                // Comments run to the end of the line.
+                while self
+                    .data
+                    .get(self.pos)
+                    .is_some_and(|&b| b != b'\n' && b != b'\r')
+                {
+                    self.pos += 1;
+                }
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+fn is_whitespace(b: u8) -> bool {
+    matches!(b, b' ' | b'\t' | b'\n' | b'\r' | b'\x0C' | b'\0')
+}
+
+fn is_delimiter(b: u8) -> bool {
+    is_whitespace(b) || b"()<>[]{}/%".contains(&b)
+}
+
+fn trim_start(data: &[u8]) -> &[u8] {
+    let start = data
+        .iter()
+        .position(|&b| !is_whitespace(b))
+        .unwrap_or(data.len());
+    &data[start..]
+}
+
+fn find(data: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    data.get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|i| from + i)
+}
+
+fn ascii_hex(data: &[u8]) -> Vec<u8> {
+    let digits: Vec<u8> = data
+        .iter()
+        .take_while(|&&b| b != b'>')
+        .filter_map(|&b| (b as char).to_digit(16).map(|d| d as u8))
+        .collect();
+    // An odd final digit is followed by an implied 0.
+    digits
+        .chunks(2)
+        .map(|pair| pair[0] << 4 | pair.get(1).copied().unwrap_or(0))
+        .collect()
+}
+
+fn ascii85(data: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut group = Vec::with_capacity(5);
+    for &b in data {
+        match b {
+            b'~' => break,
+            b'z' if group.is_empty() => out.extend_from_slice(&[0; 4]),
+            b'!'..=b'u' => {
+                group.push(u32::from(b - b'!'));
+                if group.len() == 5 {
+                    let value = group.iter().try_fold(0u32, |value, &digit| {
+                        value.checked_mul(85)?.checked_add(digit)
+                    })?;
+                    out.extend_from_slice(&value.to_be_bytes());
+                    group.clear();
+                }
+            }
+            _ if is_whitespace(b) => {}
+            _ => return None,
+        }
+    }
+    // A final partial group is padded with `u` and truncated.
+    if !group.is_empty() {
+        let len = group.len();
+        group.resize(5, 84);
+        let value = group.iter().try_fold(0u32, |value, &digit| {
+            value.checked_mul(85)?.checked_add(digit)
+        })?;
+        out.extend_from_slice(&value.to_be_bytes()[..len - 1]);
+    }
+    Some(out)
+}
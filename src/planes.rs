@@ -0,0 +1,203 @@
+use crate::unicode::subtable_codepoints;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet};
+use ttf_parser::cmap::Format;
+use ttf_parser::{Face, GlyphId};
+
+/// Unicode planes with a conventional short name.
+const PLANE_NAMES: [(u32, &str); 7] = [
+    (0, "BMP"),
+    (1, "SMP"),
+    (2, "SIP"),
+    (3, "TIP"),
+    (14, "SSP"),
+    (15, "SPUA-A"),
+    (16, "SPUA-B"),
+];
+
+/// Blocks worth naming in the summary, as inclusive ranges. Characters
+/// outside these still count towards their plane.
+const BLOCKS: &[(u32, u32, &str)] = &[
+    (0x0000, 0x007F, "Basic Latin"),
+    (0x0080, 0x00FF, "Latin-1 Supplement"),
+    (0x0100, 0x017F, "Latin Extended-A"),
+    (0x0180, 0x024F, "Latin Extended-B"),
+    (0x0370, 0x03FF, "Greek and Coptic"),
+    (0x0400, 0x04FF, "Cyrillic"),
+    (0x0590, 0x05FF, "Hebrew"),
+    (0x0600, 0x06FF, "Arabic"),
+    (0x0900, 0x097F, "Devanagari"),
+    (0x0E00, 0x0E7F, "Thai"),
+    (0x1E00, 0x1EFF, "Latin Extended Additional"),
+    (0x2000, 0x206F, "General Punctuation"),
+    (0x2190, 0x21FF, "Arrows"),
+    (0x2200, 0x22FF, "Mathematical Operators"),
+    (0x2500, 0x257F, "Box Drawing"),
+    (0x2600, 0x26FF, "Miscellaneous Symbols"),
+    (0x2700, 0x27BF, "Dingbats"),
+    (0x3040, 0x309F, "Hiragana"),
+    (0x30A0, 0x30FF, "Katakana"),
+    (0x4E00, 0x9FFF, "CJK Unified Ideographs"),
+    (0xAC00, 0xD7AF, "Hangul Syllables"),
+    (0xE000, 0xF8FF, "Private Use Area"),
+    (0x10000, 0x1007F, "Linear B Syllabary"),
+    (0x10100, 0x1013F, "Aegean Numbers"),
+    (0x10140, 0x1018F, "Ancient Greek Numbers"),
+    (0x10190, 0x101CF, "Ancient Symbols"),
+    (0x10300, 0x1032F, "Old Italic"),
+    (0x10330, 0x1034F, "Gothic"),
+    (0x10380, 0x1039F, "Ugaritic"),
+    (0x10400, 0x1044F, "Deseret"),
+    (0x10450, 0x1047F, "Shavian"),
+    (0x104B0, 0x104FF, "Osage"),
+    (0x10900, 0x1091F, "Phoenician"),
+    (0x10C00, 0x10C4F, "Old Turkic"),
+    (0x11000, 0x1107F, "Brahmi"),
+    (0x11100, 0x1114F, "Chakma"),
+    (0x11400, 0x1147F, "Newa"),
+    (0x12000, 0x123FF, "Cuneiform"),
+    (0x13000, 0x1342F, "Egyptian Hieroglyphs"),
+    (0x16F00, 0x16F9F, "Miao"),
+    (0x17000, 0x187FF, "Tangut"),
+    (0x1B000, 0x1B0FF, "Kana Supplement"),
+    (0x1B170, 0x1B2FF, "Nushu"),
+    (0x1D000, 0x1D0FF, "Byzantine Musical Symbols"),
+    (0x1D100, 0x1D1FF, "Musical Symbols"),
+    (0x1D400, 0x1D7FF, "Mathematical Alphanumeric Symbols"),
+    (0x1D800, 0x1DAAF, "Sutton SignWriting"),
+    (0x1E900, 0x1E95F, "Adlam"),
+    (0x1EE00, 0x1EEFF, "Arabic Mathematical Alphabetic Symbols"),
+    (0x1F000, 0x1F02F, "Mahjong Tiles"),
+    (0x1F030, 0x1F09F, "Domino Tiles"),
+    (0x1F0A0, 0x1F0FF, "Playing Cards"),
+    (0x1F100, 0x1F1FF, "Enclosed Alphanumeric Supplement"),
+    (0x1F200, 0x1F2FF, "Enclosed Ideographic Supplement"),
+    (0x1F300, 0x1F5FF, "Miscellaneous Symbols and Pictographs"),
+    (0x1F600, 0x1F64F, "Emoticons"),
+    (0x1F680, 0x1F6FF, "Transport and Map Symbols"),
+    (0x1F700, 0x1F77F, "Alchemical Symbols"),
+    (0x1F780, 0x1F7FF, "Geometric Shapes Extended"),
+    (0x1F800, 0x1F8FF, "Supplemental Arrows-C"),
+    (0x1F900, 0x1F9FF, "Supplemental Symbols and Pictographs"),
+    (0x1FA00, 0x1FA6F, "Chess Symbols"),
+    (0x1FA70, 0x1FAFF, "Symbols and Pictographs Extended-A"),
+    (0x1FB00, 0x1FBFF, "Symbols for Legacy Computing"),
+    (0x20000, 0x2A6DF, "CJK Unified Ideographs Extension B"),
+    (0x2A700, 0x2B73F, "CJK Unified Ideographs Extension C"),
+    (0x2B740, 0x2B81F, "CJK Unified Ideographs Extension D"),
+    (0x2B820, 0x2CEAF, "CJK Unified Ideographs Extension E"),
+    (0x2CEB0, 0x2EBEF, "CJK Unified Ideographs Extension F"),
+    (0x2EBF0, 0x2EE5F, "CJK Unified Ideographs Extension I"),
+    (0x2F800, 0x2FA1F, "CJK Compatibility Ideographs Supplement"),
+    (0x30000, 0x3134F, "CJK Unified Ideographs Extension G"),
+    (0x31350, 0x323AF, "CJK Unified Ideographs Extension H"),
+    (0xE0000, 0xE007F, "Tags"),
+    (0xE0100, 0xE01EF, "Variation Selectors Supplement"),
+];
+
+/// Characters and glyphs the cmap covers in one Unicode plane.
+#[derive(Serialize)]
+pub struct PlaneCoverage {
+    pub plane: u32,
+    pub characters: usize,
+    /// Distinct glyphs those characters map to.
+    pub glyphs: usize,
+    /// Named blocks with covered characters, largest first.
+    pub blocks: Vec<(&'static str, usize)>,
+}
+
+impl PlaneCoverage {
+    /// The plane's short name, e.g. `SMP`, or its number for unnamed planes.
+    pub fn name(&self) -> String {
+        PLANE_NAMES
+            .iter()
+            .find(|&&(plane, _)| plane == self.plane)
+            .map_or_else(
+                || format!("Plane {}", self.plane),
+                |&(_, name)| name.to_string(),
+            )
+    }
+}
+
+/// Unicode cmap coverage broken out by plane.
+#[derive(Serialize)]
+pub struct PlaneReport {
+    pub planes: Vec<PlaneCoverage>,
+    /// True when a Unicode cmap subtable uses format 12, the only common
+    /// format that can map characters outside the BMP.
+    pub has_format_12: bool,
+}
+
+impl PlaneReport {
+    pub fn new(face: &Face) -> Self {
+        let mut mapped: BTreeMap<u32, GlyphId> = BTreeMap::new();
+        let mut has_format_12 = false;
+        if let Some(cmap) = face.tables().cmap {
+            for (index, subtable) in cmap.subtables.into_iter().enumerate() {
+                if !subtable.is_unicode() {
+                    continue;
+                }
+                match subtable.format {
+                    Format::UnicodeVariationSequences(_) => continue,
+                    Format::SegmentedCoverage(_) => has_format_12 = true,
+                    _ => {}
+                }
+                // Values past U+10FFFF aren't characters of any plane.
+                subtable_codepoints(face, index as u16, &subtable, |code_point| {
+                    if let Some(glyph_id) = subtable.glyph_index(code_point) {
+                        mapped.entry(code_point).or_insert(glyph_id);
+                    }
+                });
+            }
+        }
+
+        let mut planes: Vec<PlaneCoverage> = Vec::new();
+        let mut glyphs = HashSet::new();
+        for (&code_point, &glyph_id) in &mapped {
+            let plane = code_point >> 16;
+            if planes.last().is_none_or(|p| p.plane != plane) {
+                glyphs.clear();
+                planes.push(PlaneCoverage {
+                    plane,
+                    characters: 0,
+                    glyphs: 0,
+                    blocks: Vec::new(),
+                });
+            }
+            let Some(coverage) = planes.last_mut() else {
+                continue;
+            };
+            coverage.characters += 1;
+            if glyphs.insert(glyph_id) {
+                coverage.glyphs += 1;
+            }
+            let block = BLOCKS
+                .iter()
+                .find(|&&(start, end, _)| (start..=end).contains(&code_point));
+            if let Some(&(_, _, name)) = block {
+                match coverage.blocks.iter_mut().find(|(n, _)| *n == name) {
+                    Some((_, count)) => *count += 1,
+                    None => coverage.blocks.push((name, 1)),
+                }
+            }
+        }
+        for coverage in &mut planes {
+            coverage.blocks.sort_by_key(|&(_, count)| Reverse(count));
+        }
+
+        PlaneReport {
+            planes,
+            has_format_12,
+        }
+    }
+
+    /// Number of covered characters outside the BMP.
+    pub fn supplementary(&self) -> usize {
+        self.planes
+            .iter()
+            .filter(|p| p.plane > 0)
+            .map(|p| p.characters)
+            .sum()
+    }
+}
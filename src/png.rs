@@ -0,0 +1,214 @@
+/// Lengths of deflate length codes 257–285 and their extra bits.
+const LENGTH_BASES: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA_BITS: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+
+/// Encodes 8-bit RGB pixels, row by row, as a PNG.
+pub fn encode_rgb(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    let mut png = start(width, height);
+    write_chunk(&mut png, b"IDAT", &zlib(&filter(width, height, pixels)));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// Encodes frames of 8-bit RGB pixels as an animated PNG that loops
+/// forever, showing each frame for `delay_ms` milliseconds. Viewers
+/// without APNG support show the first frame.
+pub fn encode_rgb_animation(
+    width: usize,
+    height: usize,
+    frames: &[Vec<u8>],
+    delay_ms: u16,
+) -> Vec<u8> {
+    let mut png = start(width, height);
+    let mut control = Vec::with_capacity(8);
+    control.extend_from_slice(&(frames.len() as u32).to_be_bytes());
+    control.extend_from_slice(&0u32.to_be_bytes()); // loop forever
+    write_chunk(&mut png, b"acTL", &control);
+
+    // Frame control and frame data chunks share one sequence.
+    let mut sequence = 0u32;
+    for (i, frame) in frames.iter().enumerate() {
+        let mut control = Vec::with_capacity(26);
+        control.extend_from_slice(&sequence.to_be_bytes());
+        control.extend_from_slice(&(width as u32).to_be_bytes());
+        control.extend_from_slice(&(height as u32).to_be_bytes());
+        control.extend_from_slice(&[0; 8]); // x and y offsets
+        control.extend_from_slice(&delay_ms.to_be_bytes());
+        control.extend_from_slice(&1000u16.to_be_bytes());
+        // Leave the frame in place, and replace rather than blend.
+        control.extend_from_slice(&[0, 0]);
+        write_chunk(&mut png, b"fcTL", &control);
+        sequence += 1;
+
+        let data = zlib(&filter(width, height, frame));
+        if i == 0 {
+            write_chunk(&mut png, b"IDAT", &data);
+        } else {
+            let mut frame_data = sequence.to_be_bytes().to_vec();
+            frame_data.extend(data);
+            write_chunk(&mut png, b"fdAT", &frame_data);
+            sequence += 1;
+        }
+    }
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// The signature and header of an RGB image.
+fn start(width: usize, height: usize) -> Vec<u8> {
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // 8 bits per channel, truecolor, deflate, no interlacing.
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    png
+}
+
+/// Prefixes each row with its filter type. The Sub filter stores each byte
+/// as the difference from the pixel to its left, turning flat backgrounds
+/// into runs of zeros that compress well with nothing more than run-length
+/// matches.
+fn filter(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    let stride = width * 3;
+    let mut filtered = Vec::with_capacity(height * (stride + 1));
+    for row in pixels.chunks(stride).take(height) {
+        filtered.push(1);
+        for (i, &byte) in row.iter().enumerate() {
+            let left = if i >= 3 { row[i - 3] } else { 0 };
+            filtered.push(byte.wrapping_sub(left));
+        }
+    }
+    filtered
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Wraps `data` in a zlib stream of one deflate block with the fixed
+/// Huffman codes. The only matches are repeats of the previous byte.
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut bits = BitWriter::default();
+    bits.write(0b1, 1); // final block
+    bits.write(0b01, 2); // fixed Huffman codes
+    let mut i = 0;
+    while i < data.len() {
+        let run = if i == 0 {
+            0
+        } else {
+            data[i..]
+                .iter()
+                .take(258)
+                .take_while(|&&byte| byte == data[i - 1])
+                .count()
+        };
+        if run >= 3 {
+            let code = LENGTH_BASES
+                .iter()
+                .rposition(|&base| base as usize <= run)
+                .unwrap();
+            bits.write_symbol(257 + code as u16);
+            bits.write(
+                (run - LENGTH_BASES[code] as usize) as u32,
+                LENGTH_EXTRA_BITS[code],
+            );
+            // Distance 1 is distance code 0, five bits with no extra bits.
+            bits.write_code(0, 5);
+            i += run;
+        } else {
+            bits.write_symbol(u16::from(data[i]));
+            i += 1;
+        }
+    }
+    bits.write_symbol(256);
+
+    let mut stream = vec![0x78, 0x01];
+    stream.extend(bits.finish());
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    b << 16 | a
+}
+
+/// Packs bits least significant first, as deflate requires.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    count: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, count: u8) {
+        for bit in 0..count {
+            self.buffer |= (value >> bit & 1) << self.count;
+            self.count += 1;
+            if self.count == 8 {
+                self.bytes.push(self.buffer as u8);
+                self.buffer = 0;
+                self.count = 0;
+            }
+        }
+    }
+
+    /// Writes a Huffman code, which deflate packs most significant bit
+    /// first.
+    fn write_code(&mut self, code: u32, length: u8) {
+        for bit in (0..length).rev() {
+            self.write(code >> bit & 1, 1);
+        }
+    }
+
+    /// Writes a literal/length symbol with the fixed Huffman code.
+    fn write_symbol(&mut self, symbol: u16) {
+        let symbol = u32::from(symbol);
+        match symbol {
+            0..=143 => self.write_code(0x30 + symbol, 8),
+            144..=255 => self.write_code(0x190 + symbol - 144, 9),
+            256..=279 => self.write_code(symbol - 256, 7),
+            _ => self.write_code(0xC0 + symbol - 280, 8),
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
@@ -0,0 +1,57 @@
+use crate::reader::Reader;
+use serde::Serialize;
+use ttf_parser::{Face, GlyphId, Tag};
+
+/// The `post` table's italic angle, underline metrics and glyph names.
+#[derive(Serialize)]
+pub struct PostReport {
+    /// The table version, e.g. 2.0; version 2.5 is stored as 0x00025000.
+    pub format: f32,
+    /// Degrees counter-clockwise from vertical, negative for a rightward
+    /// slant.
+    pub italic_angle: f32,
+    pub underline_position: i16,
+    pub underline_thickness: i16,
+    pub is_fixed_pitch: bool,
+    /// Glyphs with a name, from `post` or, in CFF fonts, the charset.
+    pub named_glyphs: u16,
+    pub glyphs: u16,
+}
+
+impl PostReport {
+    /// Returns `None` without a `post` table or when it's truncated.
+    pub fn new(face: &Face) -> Option<Self> {
+        let post = face.raw_face().table(Tag::from_bytes(b"post"))?;
+        let mut r = Reader::new(post);
+        let version = r.read_u32()?;
+        let italic_angle = r.read_fixed()?;
+        let underline_position = r.read_i16()?;
+        let underline_thickness = r.read_i16()?;
+        let is_fixed_pitch = r.read_u32()? != 0;
+        let glyphs = face.number_of_glyphs();
+        let named_glyphs = (0..glyphs)
+            .filter(|&id| face.glyph_name(GlyphId(id)).is_some())
+            .count() as u16;
+        Some(PostReport {
+            format: (version >> 16) as f32 + (version >> 12 & 0xF) as f32 / 10.0,
+            italic_angle,
+            underline_position,
+            underline_thickness,
+            is_fixed_pitch,
+            named_glyphs,
+            glyphs,
+        })
+    }
+
+    /// What the format means for glyph names.
+    pub fn format_description(&self) -> &'static str {
+        match self.format {
+            1.0 => "standard Macintosh glyph names",
+            2.0 => "glyph names stored",
+            2.5 => "reordered standard names, deprecated",
+            3.0 => "no glyph names",
+            4.0 => "character codes, Apple composite fonts",
+            _ => "unknown format",
+        }
+    }
+}
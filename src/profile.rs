@@ -0,0 +1,887 @@
+use crate::cff::PrivateHints;
+use crate::detect::{self, FileKind};
+use crate::disambiguation::DisambiguationReport;
+use crate::embedding::{EmbeddingReport, Level};
+use crate::languages::LocaleCoverage;
+use crate::opsz::{OpticalSize, format_pt};
+use crate::os2::Os2Report;
+use crate::special_cases::{Check, Status};
+use crate::tabular::TabularReport;
+use crate::unicode::covered_chars;
+use crate::{get_name, layout};
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use ttf_parser::{Face, GlyphId, Tag, name_id};
+
+/// Operator sequences coding fonts commonly draw as ligatures.
+const PROGRAMMING_LIGATURES: [&str; 24] = [
+    "->", "=>", "<-", "<=", ">=", "==", "!=", "===", "!==", "::", "&&", "||", "//", "/*", "*/",
+    "++", "--", "|>", "<|", "<>", "..", "...", "<=>", "**",
+];
+
+/// The Powerline glyphs in the Private Use Area that shell prompts and
+/// editor status lines draw with: branch, line number and padlock, and the
+/// solid and hollow separators.
+const POWERLINE: [char; 7] = [
+    '\u{E0A0}', '\u{E0A1}', '\u{E0A2}', '\u{E0B0}', '\u{E0B1}', '\u{E0B2}', '\u{E0B3}',
+];
+
+/// Languages the `ui` profile checks unless `--locales` names others.
+pub const UI_LOCALES: [&str; 8] = ["en", "de", "fr", "es", "it", "pt", "nl", "pl"];
+
+/// x-height, as a share of the em, below which small UI text gets hard to
+/// read.
+const MIN_UI_X_HEIGHT: f32 = 0.5;
+
+/// The f-ligatures of Latin book typography; the `print` profile requires
+/// the first two.
+const F_LIGATURES: [&str; 5] = ["fi", "fl", "ff", "ffi", "ffl"];
+
+/// fsSelection USE_TYPO_METRICS.
+const USE_TYPO_METRICS: u16 = 0x0080;
+
+/// The Private Use Area block SMuFL assigns its recommended characters in.
+const SMUFL_RECOMMENDED: (u32, u32) = (0xE000, 0xF3FF);
+/// Where SMuFL fonts put their optional glyphs: alternates, ligatures and
+/// sets of their own.
+const SMUFL_OPTIONAL: (u32, u32) = (0xF400, 0xF8FF);
+/// The Musical Symbols block of Unicode proper.
+const MUSICAL_SYMBOLS: (u32, u32) = (0x1D100, 0x1D1FF);
+
+/// The SMuFL categories every notation font needs, with their ranges.
+const SMUFL_CATEGORIES: [(&str, u32, u32); 18] = [
+    ("Staff brackets and dividers", 0xE000, 0xE00F),
+    ("Staves", 0xE010, 0xE02F),
+    ("Barlines", 0xE030, 0xE03F),
+    ("Repeats", 0xE040, 0xE04F),
+    ("Clefs", 0xE050, 0xE07F),
+    ("Time signatures", 0xE080, 0xE09F),
+    ("Noteheads", 0xE0A0, 0xE0FF),
+    ("Individual notes", 0xE1D0, 0xE1EF),
+    ("Beamed groups of notes", 0xE1F0, 0xE20F),
+    ("Stems", 0xE210, 0xE21F),
+    ("Tremolos", 0xE220, 0xE23F),
+    ("Flags", 0xE240, 0xE25F),
+    ("Standard accidentals", 0xE260, 0xE26F),
+    ("Articulation", 0xE4A0, 0xE4BF),
+    ("Holds and pauses", 0xE4C0, 0xE4DF),
+    ("Rests", 0xE4E0, 0xE4FF),
+    ("Octaves", 0xE510, 0xE51F),
+    ("Dynamics", 0xE520, 0xE54F),
+];
+
+/// SMuFL names of a few core glyphs, which music software looks glyphs up
+/// by in the font's metadata and glyph names.
+const SMUFL_NAMES: [(char, &str); 6] = [
+    ('\u{E014}', "staff5Lines"),
+    ('\u{E050}', "gClef"),
+    ('\u{E0A4}', "noteheadBlack"),
+    ('\u{E260}', "accidentalFlat"),
+    ('\u{E262}', "accidentalSharp"),
+    ('\u{E4E5}', "restQuarter"),
+];
+
+/// How far, as a share of the em, registration may stray from SMuFL's
+/// positions, leaving room for staff line thickness.
+const REGISTRATION_TOLERANCE: f32 = 0.05;
+
+/// A purpose-built battery of checks, selected with `--profile`.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Profile {
+    Coding,
+    Ui,
+    Print,
+    Music,
+}
+
+impl Profile {
+    pub fn from_name(name: &str) -> Option<Profile> {
+        match name {
+            "coding" => Some(Profile::Coding),
+            "ui" => Some(Profile::Ui),
+            "print" => Some(Profile::Print),
+            "music" => Some(Profile::Music),
+            _ => None,
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Profile::Coding => "Coding font",
+            Profile::Ui => "UI font",
+            Profile::Print => "Print font",
+            Profile::Music => "Music font",
+        }
+    }
+}
+
+/// The results of a profile's checks.
+#[derive(Serialize)]
+pub struct Scorecard {
+    pub checks: Vec<Check>,
+}
+
+impl Scorecard {
+    /// Runs the profile's checks. `path` locates sibling files, such as a
+    /// separate italic; `locales` are the language codes the `ui` profile
+    /// checks coverage for.
+    pub fn new(face: &Face, path: &Path, profile: Profile, locales: &[&str]) -> Self {
+        let checks = match profile {
+            Profile::Coding => coding(face, path),
+            Profile::Ui => ui(face, locales),
+            Profile::Print => print(face),
+            Profile::Music => music(face, path),
+        };
+        Scorecard { checks }
+    }
+
+    pub fn passed(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|c| c.status == Status::Pass)
+            .count()
+    }
+
+    /// Checks that apply to the font, passed or not.
+    pub fn applicable(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|c| c.status != Status::NotApplicable)
+            .count()
+    }
+}
+
+fn coding(face: &Face, path: &Path) -> Vec<Check> {
+    let disambiguation = DisambiguationReport::new(face);
+    vec![
+        monospace(face),
+        check(
+            "Declared monospaced (post isFixedPitch)",
+            if face.is_monospaced() {
+                Status::Pass
+            } else {
+                Status::Fail
+            },
+            String::new(),
+        ),
+        ligatures(face),
+        coverage("Box drawing (U+2500–257F)", face, 0x2500..=0x257F),
+        coverage("Block elements (U+2580–259F)", face, 0x2580..=0x259F),
+        coverage(
+            "Powerline symbols",
+            face,
+            POWERLINE.iter().map(|&c| c as u32),
+        ),
+        match &disambiguation {
+            Some(report) if report.zero_distinguishable() => {
+                check("Zero distinct from O", Status::Pass, String::new())
+            }
+            Some(_) => check(
+                "Zero distinct from O",
+                Status::Fail,
+                "plain zero and no zero feature".to_string(),
+            ),
+            None => check("Zero distinct from O", Status::Fail, "no zero".to_string()),
+        },
+        confusables(disambiguation.as_ref()),
+        italic(face, path),
+    ]
+}
+
+fn ui(face: &Face, locales: &[&str]) -> Vec<Check> {
+    let os2 = Os2Report::new(face);
+    let gsub = face.tables().gsub;
+    let has_case = gsub.is_some_and(|gsub| {
+        gsub.features
+            .into_iter()
+            .any(|feature| feature.tag == Tag::from_bytes(b"case"))
+    });
+    vec![
+        line_height(face, os2.as_ref()),
+        clipping(face, os2.as_ref()),
+        tabular_figures(face),
+        check(
+            "Case-sensitive forms (case)",
+            if has_case { Status::Pass } else { Status::Fail },
+            String::new(),
+        ),
+        x_height(face, os2.as_ref()),
+        languages(face, locales),
+        weight_range(face),
+    ]
+}
+
+/// Passes when macOS, which uses hhea, and Windows, which uses the typo
+/// metrics under USE_TYPO_METRICS and the win metrics otherwise, space
+/// lines the same.
+fn line_height(face: &Face, os2: Option<&Os2Report>) -> Check {
+    let name = "Line height consistent across platforms";
+    let Some(os2) = os2 else {
+        return check(name, Status::Fail, "no OS/2 table".to_string());
+    };
+    let hhea = face.tables().hhea;
+    let mac = i32::from(hhea.ascender) - i32::from(hhea.descender) + i32::from(hhea.line_gap);
+    let (windows, source) = if os2.fs_selection & USE_TYPO_METRICS != 0 {
+        let typo = i32::from(os2.typo_ascender) - i32::from(os2.typo_descender)
+            + i32::from(os2.typo_line_gap);
+        (typo, "typo")
+    } else {
+        let win = i32::from(os2.win_ascent) + i32::from(os2.win_descent);
+        (win, "win")
+    };
+    if mac == windows {
+        check(name, Status::Pass, format!("{} units", mac))
+    } else {
+        check(
+            name,
+            Status::Fail,
+            format!("hhea {} vs. {} {}", mac, source, windows),
+        )
+    }
+}
+
+/// Passes when usWinAscent and usWinDescent reach the font's bounding box;
+/// Windows clips whatever lies beyond them.
+fn clipping(face: &Face, os2: Option<&Os2Report>) -> Check {
+    let name = "Win metrics cover every glyph";
+    let Some(os2) = os2 else {
+        return check(name, Status::Fail, "no OS/2 table".to_string());
+    };
+    let bbox = face.global_bounding_box();
+    let mut clipped = Vec::new();
+    if i32::from(os2.win_ascent) < i32::from(bbox.y_max) {
+        clipped.push(format!(
+            "usWinAscent {} < yMax {}",
+            os2.win_ascent, bbox.y_max
+        ));
+    }
+    if i32::from(os2.win_descent) < -i32::from(bbox.y_min) {
+        clipped.push(format!(
+            "usWinDescent {} < -yMin {}",
+            os2.win_descent, -bbox.y_min
+        ));
+    }
+    if clipped.is_empty() {
+        check(name, Status::Pass, String::new())
+    } else {
+        check(name, Status::Fail, clipped.join(", "))
+    }
+}
+
+/// Passes when figures and their companions share one advance, by default
+/// or under `tnum`.
+fn tabular_figures(face: &Face) -> Check {
+    let name = "Tabular figures";
+    match TabularReport::new(face) {
+        Some(report) if report.offenders.is_empty() => {
+            check(name, Status::Pass, report.figures.description().to_string())
+        }
+        Some(report) => {
+            let offenders: Vec<String> = report
+                .offenders
+                .iter()
+                .map(|offender| offender.c.to_string())
+                .collect();
+            check(
+                name,
+                Status::Fail,
+                format!("{} differ from the figure width", offenders.join(" ")),
+            )
+        }
+        None => check(
+            name,
+            Status::Fail,
+            "proportional digits and no tnum".to_string(),
+        ),
+    }
+}
+
+/// Passes when the x-height is at least `MIN_UI_X_HEIGHT` of the em. Uses
+/// OS/2 sxHeight, or the height of x for fonts without it.
+fn x_height(face: &Face, os2: Option<&Os2Report>) -> Check {
+    let name = "Large x-height";
+    let x_height = os2
+        .and_then(|os2| os2.x_height)
+        .filter(|&height| height > 0)
+        .or_else(|| {
+            let x = face.glyph_index('x')?;
+            Some(face.glyph_bounding_box(x)?.y_max)
+        });
+    let Some(x_height) = x_height else {
+        return check(name, Status::Fail, "no x-height".to_string());
+    };
+    let ratio = f32::from(x_height) / f32::from(face.units_per_em());
+    let detail = format!("{:.2} of the em", ratio);
+    if ratio >= MIN_UI_X_HEIGHT {
+        check(name, Status::Pass, detail)
+    } else {
+        check(name, Status::Fail, detail)
+    }
+}
+
+/// Passes when every language in `locales` is fully covered.
+fn languages(face: &Face, locales: &[&str]) -> Check {
+    let name = "Language coverage";
+    let coverage: Vec<LocaleCoverage> = locales
+        .iter()
+        .filter_map(|code| LocaleCoverage::new(face, code))
+        .collect();
+    let failed: Vec<String> = coverage
+        .iter()
+        .filter(|locale| !locale.passed())
+        .map(|locale| format!("{} missing {}", locale.code, locale.missing.len()))
+        .collect();
+    if failed.is_empty() {
+        let codes: Vec<&str> = coverage.iter().map(|locale| locale.code).collect();
+        check(name, Status::Pass, codes.join(", "))
+    } else {
+        check(name, Status::Fail, failed.join(", "))
+    }
+}
+
+/// Passes when a `wght` axis spans at least regular to bold; doesn't apply
+/// to static fonts.
+fn weight_range(face: &Face) -> Check {
+    let name = "Variable weight range";
+    let Some(axis) = face
+        .variation_axes()
+        .into_iter()
+        .find(|axis| axis.tag == Tag::from_bytes(b"wght"))
+    else {
+        return check(name, Status::NotApplicable, "no wght axis".to_string());
+    };
+    let detail = format!("{}–{}", axis.min_value, axis.max_value);
+    if axis.min_value <= 400.0 && axis.max_value >= 700.0 {
+        check(name, Status::Pass, detail)
+    } else {
+        check(name, Status::Fail, detail)
+    }
+}
+
+fn print(face: &Face) -> Vec<Check> {
+    vec![
+        hints(face),
+        gsub_feature("Oldstyle figures (onum)", face, b"onum", '1'),
+        gsub_feature("Small caps (smcp)", face, b"smcp", 'a'),
+        gsub_feature("Capitals to small caps (c2sc)", face, b"c2sc", 'A'),
+        f_ligatures(face),
+        optical_sizes(face),
+        pdf_embedding(face),
+    ]
+}
+
+/// Passes when a CFF Private DICT sets alignment zones, which keep
+/// baselines, x-heights and cap heights even at small sizes and low
+/// resolutions; doesn't apply to TrueType outlines.
+fn hints(face: &Face) -> Check {
+    let name = "PostScript hints";
+    let Some(hints) = PrivateHints::new(face) else {
+        return check(name, Status::NotApplicable, "TrueType outlines".to_string());
+    };
+    if hints.alignment_zones == 0 {
+        return check(name, Status::Fail, "no alignment zones".to_string());
+    }
+    let mut detail = format!("{} alignment zones", hints.alignment_zones);
+    if hints.std_hw.is_none() || hints.std_vw.is_none() {
+        detail.push_str(", no standard stems");
+    }
+    check(name, Status::Pass, detail)
+}
+
+/// Passes when `feature` substitutes the glyph of `sample`.
+fn gsub_feature(name: &'static str, face: &Face, feature: &[u8; 4], sample: char) -> Check {
+    let covered = face
+        .tables()
+        .gsub
+        .zip(face.glyph_index(sample))
+        .is_some_and(|(gsub, glyph)| {
+            let lookups = layout::feature_lookups(&gsub, Tag::from_bytes(feature));
+            layout::gsub_covers(&gsub, &lookups, glyph)
+        });
+    if covered {
+        check(name, Status::Pass, String::new())
+    } else {
+        check(name, Status::Fail, String::new())
+    }
+}
+
+/// Passes when fi and fl ligate; lists whichever of `F_LIGATURES` do.
+fn f_ligatures(face: &Face) -> Check {
+    let name = "f-ligatures";
+    let found: Vec<&str> = F_LIGATURES
+        .iter()
+        .copied()
+        .filter(|sequence| {
+            sequence
+                .chars()
+                .map(|c| face.glyph_index(c))
+                .collect::<Option<Vec<GlyphId>>>()
+                .is_some_and(|glyphs| layout::apply_ligatures(face, glyphs).len() == 1)
+        })
+        .collect();
+    let detail = if found.is_empty() {
+        "none".to_string()
+    } else {
+        found.join(" ")
+    };
+    if found.contains(&"fi") && found.contains(&"fl") {
+        check(name, Status::Pass, detail)
+    } else {
+        check(name, Status::Fail, detail)
+    }
+}
+
+/// Lists the designed point-size range; doesn't apply to fonts that state
+/// none.
+fn optical_sizes(face: &Face) -> Check {
+    let name = "Optical sizes";
+    match OpticalSize::new(face).designed_range() {
+        Some((min, max)) if min == max => {
+            check(name, Status::Pass, format!("{}pt", format_pt(min)))
+        }
+        Some((min, max)) => check(
+            name,
+            Status::Pass,
+            format!("{}–{}pt", format_pt(min), format_pt(max)),
+        ),
+        None => check(name, Status::NotApplicable, "none stated".to_string()),
+    }
+}
+
+/// Passes when fsType lets the outlines be embedded, which PDF/X requires
+/// of every font a document uses.
+fn pdf_embedding(face: &Face) -> Check {
+    let name = "Embeddable for PDF/X";
+    let Some(embedding) = EmbeddingReport::new(face) else {
+        return check(name, Status::Fail, "no OS/2 table".to_string());
+    };
+    if embedding.level == Level::Restricted || embedding.bitmap_only {
+        check(name, Status::Fail, embedding.summary().join(", "))
+    } else {
+        check(name, Status::Pass, embedding.pdf().to_string())
+    }
+}
+
+fn music(face: &Face, path: &Path) -> Vec<Check> {
+    let chars = covered_chars(face);
+    let count = |(from, to): (u32, u32)| {
+        chars
+            .iter()
+            .filter(|&&c| (from..=to).contains(&(c as u32)))
+            .count()
+    };
+    let categories: Vec<Check> = SMUFL_CATEGORIES
+        .iter()
+        .map(|&(name, from, to)| match count((from, to)) {
+            0 => check(name, Status::Fail, "none".to_string()),
+            glyphs => check(name, Status::Pass, format!("{} glyphs", glyphs)),
+        })
+        .collect();
+    let covered = categories
+        .iter()
+        .filter(|c| c.status == Status::Pass)
+        .count();
+    // Other fonts put icons and ligatures in the Private Use Area too, so
+    // the range only counts as SMuFL when it holds some core category.
+    let name = "SMuFL characters (U+E000–F3FF)";
+    let mut checks = vec![match count(SMUFL_RECOMMENDED) {
+        0 => check(name, Status::Fail, "none".to_string()),
+        mapped if covered == 0 => check(
+            name,
+            Status::Fail,
+            format!("{} mapped, none in a core category", mapped),
+        ),
+        mapped => check(
+            name,
+            Status::Pass,
+            format!(
+                "{} mapped, {} of {} core categories",
+                mapped,
+                covered,
+                SMUFL_CATEGORIES.len()
+            ),
+        ),
+    }];
+    checks.extend(categories);
+    checks.push(match count(SMUFL_OPTIONAL) {
+        _ if covered == 0 => check(
+            "Optional glyphs (U+F400–F8FF)",
+            Status::NotApplicable,
+            "not a SMuFL font".to_string(),
+        ),
+        0 => check(
+            "Optional glyphs (U+F400–F8FF)",
+            Status::NotApplicable,
+            "none".to_string(),
+        ),
+        glyphs => check(
+            "Optional glyphs (U+F400–F8FF)",
+            Status::Pass,
+            format!("{} glyphs", glyphs),
+        ),
+    });
+    checks.push(match count(MUSICAL_SYMBOLS) {
+        0 => check(
+            "Unicode Musical Symbols",
+            Status::NotApplicable,
+            "none".to_string(),
+        ),
+        glyphs => check(
+            "Unicode Musical Symbols",
+            Status::Pass,
+            format!("{} glyphs", glyphs),
+        ),
+    });
+    checks.push(staff_space(face));
+    checks.push(notehead_registration(face));
+    checks.push(smufl_names(face));
+    checks.push(smufl_metadata(path));
+    checks
+}
+
+/// Passes when the five-line staff spans the em from the baseline up, so a
+/// staff space is a quarter em as SMuFL requires.
+fn staff_space(face: &Face) -> Check {
+    let name = "Staff space a quarter em";
+    let Some(bbox) = face
+        .glyph_index('\u{E014}')
+        .and_then(|glyph| face.glyph_bounding_box(glyph))
+    else {
+        return check(name, Status::NotApplicable, "no staff5Lines".to_string());
+    };
+    let em = f32::from(face.units_per_em());
+    let bottom = f32::from(bbox.y_min) / em;
+    let top = f32::from(bbox.y_max) / em;
+    let detail = format!("staff5Lines spans {:.2}–{:.2} em", bottom, top);
+    if bottom.abs() <= REGISTRATION_TOLERANCE && (top - 1.0).abs() <= REGISTRATION_TOLERANCE {
+        check(name, Status::Pass, detail)
+    } else {
+        check(name, Status::Fail, detail)
+    }
+}
+
+/// Passes when the black notehead is a staff space tall and centered on
+/// the baseline, which SMuFL takes as the middle of its staff position.
+fn notehead_registration(face: &Face) -> Check {
+    let name = "Noteheads centered on the baseline";
+    let Some(bbox) = face
+        .glyph_index('\u{E0A4}')
+        .and_then(|glyph| face.glyph_bounding_box(glyph))
+    else {
+        return check(name, Status::NotApplicable, "no noteheadBlack".to_string());
+    };
+    let em = f32::from(face.units_per_em());
+    let center = (f32::from(bbox.y_min) + f32::from(bbox.y_max)) / 2.0 / em;
+    let height = f32::from(bbox.height()) / em;
+    let detail = format!(
+        "noteheadBlack {:.2} em tall, centered at {:.2} em",
+        height, center
+    );
+    if center.abs() <= REGISTRATION_TOLERANCE && (height - 0.25).abs() <= REGISTRATION_TOLERANCE {
+        check(name, Status::Pass, detail)
+    } else {
+        check(name, Status::Fail, detail)
+    }
+}
+
+/// Passes when the core glyphs the font maps carry their SMuFL names;
+/// doesn't apply to fonts without glyph names.
+fn smufl_names(face: &Face) -> Check {
+    let name = "SMuFL glyph names";
+    let names: Vec<(&str, &str)> = SMUFL_NAMES
+        .iter()
+        .filter_map(|&(c, expected)| {
+            let glyph = face.glyph_index(c)?;
+            Some((expected, face.glyph_name(glyph)?))
+        })
+        .collect();
+    if names.is_empty() {
+        return check(
+            name,
+            Status::NotApplicable,
+            "no named core glyphs".to_string(),
+        );
+    }
+    let wrong: Vec<String> = names
+        .iter()
+        .filter(|(expected, actual)| expected != actual)
+        .map(|(expected, actual)| format!("{} named {}", expected, actual))
+        .collect();
+    if wrong.is_empty() {
+        check(name, Status::Pass, format!("{} checked", names.len()))
+    } else {
+        check(name, Status::Fail, wrong.join(", "))
+    }
+}
+
+/// Passes when a SMuFL metadata file sits next to the font, named after it
+/// (`bravura.json`, `bravura_metadata.json`) or `metadata.json`, with the
+/// engraving defaults and glyph bounding boxes engravers position by.
+fn smufl_metadata(path: &Path) -> Check {
+    let name = "SMuFL metadata";
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let candidates = [
+        format!("{}.json", stem),
+        format!("{}_metadata.json", stem),
+        "metadata.json".to_string(),
+    ];
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let Some((file_name, data)) = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_lowercase();
+            candidates.contains(&file_name)
+        })
+        .find_map(|entry| {
+            let data = fs::read(entry.path()).ok()?;
+            Some((entry.file_name().to_string_lossy().into_owned(), data))
+        })
+    else {
+        return check(
+            name,
+            Status::Fail,
+            "no metadata JSON next to the font".to_string(),
+        );
+    };
+    let Ok(serde_json::Value::Object(metadata)) = serde_json::from_slice(&data) else {
+        return check(
+            name,
+            Status::Fail,
+            format!("{} is not a JSON object", file_name),
+        );
+    };
+    let missing: Vec<&str> = ["engravingDefaults", "glyphBBoxes"]
+        .into_iter()
+        .filter(|key| !metadata.contains_key(*key))
+        .collect();
+    let anchors = if metadata.contains_key("glyphsWithAnchors") {
+        ", with anchors"
+    } else {
+        ""
+    };
+    if missing.is_empty() {
+        check(name, Status::Pass, format!("{}{}", file_name, anchors))
+    } else {
+        check(
+            name,
+            Status::Fail,
+            format!("{} lacks {}", file_name, missing.join(" and ")),
+        )
+    }
+}
+
+fn check(name: &'static str, status: Status, detail: String) -> Check {
+    Check {
+        name,
+        status,
+        detail,
+    }
+}
+
+/// Passes when every printable ASCII character has the same advance.
+fn monospace(face: &Face) -> Check {
+    let advances: Vec<(char, u16)> = (' '..='~')
+        .filter_map(|c| {
+            let glyph = face.glyph_index(c)?;
+            Some((c, face.glyph_hor_advance(glyph)?))
+        })
+        .collect();
+    let Some(&(_, space)) = advances.first().filter(|(c, _)| *c == ' ') else {
+        return check("Monospaced ASCII", Status::Fail, "no space".to_string());
+    };
+    let differing = advances
+        .iter()
+        .filter(|&&(_, advance)| advance != space)
+        .count();
+    if differing == 0 {
+        check("Monospaced ASCII", Status::Pass, String::new())
+    } else {
+        check(
+            "Monospaced ASCII",
+            Status::Fail,
+            format!(
+                "{} of {} differ from the space's advance",
+                differing,
+                advances.len()
+            ),
+        )
+    }
+}
+
+/// Lists the operator sequences that ligate. Passes when any do; fonts
+/// without ligatures aren't penalized, since many programmers turn them off
+/// anyway.
+fn ligatures(face: &Face) -> Check {
+    let found: Vec<&str> = PROGRAMMING_LIGATURES
+        .iter()
+        .copied()
+        .filter(|sequence| forms_ligature(face, sequence))
+        .collect();
+    if found.is_empty() {
+        check(
+            "Programming ligatures",
+            Status::NotApplicable,
+            "none".to_string(),
+        )
+    } else {
+        check(
+            "Programming ligatures",
+            Status::Pass,
+            format!(
+                "{} of {}: {}",
+                found.len(),
+                PROGRAMMING_LIGATURES.len(),
+                found.join(" ")
+            ),
+        )
+    }
+}
+
+/// True when a GSUB ligature lookup joins `sequence` into one glyph, or a
+/// glyph is named after its components joined by underscores, as in
+/// `hyphen_greater.liga`. Fonts like Fira Code form those with contextual
+/// `calt` lookups that only the name reveals.
+fn forms_ligature(face: &Face, sequence: &str) -> bool {
+    let Some(glyphs) = sequence
+        .chars()
+        .map(|c| face.glyph_index(c))
+        .collect::<Option<Vec<GlyphId>>>()
+    else {
+        return false;
+    };
+    if layout::apply_ligatures(face, glyphs.clone()).len() == 1 {
+        return true;
+    }
+    let Some(names) = glyphs
+        .iter()
+        .map(|&glyph| face.glyph_name(glyph))
+        .collect::<Option<Vec<&str>>>()
+    else {
+        return false;
+    };
+    let joined = names.join("_");
+    (0..face.number_of_glyphs())
+        .filter_map(|id| face.glyph_name(GlyphId(id)))
+        .any(|name| name.split('.').next() == Some(joined.as_str()))
+}
+
+/// Passes when every code point of `range` is in the cmap.
+fn coverage(name: &'static str, face: &Face, range: impl IntoIterator<Item = u32>) -> Check {
+    let chars: Vec<char> = range.into_iter().filter_map(char::from_u32).collect();
+    let missing = chars
+        .iter()
+        .filter(|&&c| face.glyph_index(c).is_none())
+        .count();
+    match missing {
+        0 => check(name, Status::Pass, String::new()),
+        _ => check(
+            name,
+            Status::Fail,
+            format!("missing {} of {}", missing, chars.len()),
+        ),
+    }
+}
+
+/// Passes when no two of l, 1 and I look alike.
+fn confusables(report: Option<&DisambiguationReport>) -> Check {
+    let name = "l, 1 and I distinct";
+    let confusable: Vec<String> = report
+        .map(|report| {
+            report
+                .pairs
+                .iter()
+                .filter(|pair| pair.first != '0' && pair.confusable)
+                .map(|pair| format!("{}/{}", pair.first, pair.second))
+                .collect()
+        })
+        .unwrap_or_default();
+    if confusable.is_empty() {
+        check(name, Status::Pass, String::new())
+    } else {
+        check(
+            name,
+            Status::Fail,
+            format!("{} look alike", confusable.join(", ")),
+        )
+    }
+}
+
+/// Passes when the font is italic itself, has an `ital` or `slnt` axis, or
+/// sits next to an italic of the same family.
+fn italic(face: &Face, path: &Path) -> Check {
+    let name = "Italic available";
+    if face.is_italic() || face.is_oblique() {
+        return check(name, Status::Pass, "this font".to_string());
+    }
+    if let Some(axis) = face
+        .variation_axes()
+        .into_iter()
+        .find(|axis| axis.tag == Tag::from_bytes(b"ital") || axis.tag == Tag::from_bytes(b"slnt"))
+    {
+        return check(name, Status::Pass, format!("{} axis", axis.tag));
+    }
+    match italic_sibling(face, path) {
+        Some(file_name) => check(name, Status::Pass, file_name),
+        None => check(
+            name,
+            Status::Fail,
+            "no italic axis or italic font next to this one".to_string(),
+        ),
+    }
+}
+
+/// Looks for an italic font of the same typographic family in the
+/// directory of `path`, preferring one of the same weight, and returns its
+/// file name.
+fn italic_sibling(face: &Face, path: &Path) -> Option<String> {
+    let family = |face: &Face| {
+        get_name(face, name_id::TYPOGRAPHIC_FAMILY).or_else(|| get_name(face, name_id::FAMILY))
+    };
+    let own_family = family(face)?;
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let mut siblings: Vec<_> = fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|sibling| sibling.is_file() && sibling.file_name() != path.file_name())
+        .collect();
+    siblings.sort();
+    let italics: Vec<(String, u16)> = siblings
+        .into_iter()
+        .filter_map(|sibling| {
+            // Classify from the header first so non-font files are never read
+            // in full.
+            let (header, file_len) = detect::read_header(&sibling).ok()?;
+            if !matches!(
+                detect::classify(&header, file_len),
+                FileKind::TrueType { .. } | FileKind::OpenTypeCff | FileKind::Collection { .. }
+            ) {
+                return None;
+            }
+            let data = fs::read(&sibling).ok()?;
+            let other = Face::parse(&data, 0).ok()?;
+            if !other.is_italic() || family(&other).as_ref() != Some(&own_family) {
+                return None;
+            }
+            let file_name = sibling.file_name()?.to_string_lossy().into_owned();
+            Some((file_name, other.weight().to_number()))
+        })
+        .collect();
+    let weight = face.weight().to_number();
+    italics
+        .iter()
+        .find(|(_, w)| *w == weight)
+        .or(italics.first())
+        .map(|(file_name, _)| file_name.clone())
+}
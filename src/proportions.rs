@@ -0,0 +1,57 @@
+use crate::os2::Os2Report;
+use serde::Serialize;
+use ttf_parser::Face;
+
+/// Vertical and horizontal proportions, each as a share of the em, for
+/// matching fonts set side by side.
+#[derive(Serialize)]
+pub struct Proportions {
+    /// OS/2 sxHeight, or the top of x; the CSS `font-size-adjust` value.
+    pub x_height: f32,
+    /// OS/2 sCapHeight, or the top of H.
+    pub cap_height: f32,
+    /// The top of d, or the hhea ascender without one.
+    pub ascender: f32,
+    /// How far p reaches below the baseline, or the hhea descender, as a
+    /// positive share.
+    pub descender: f32,
+    /// The average advance of the lowercase letters a–z the font has.
+    pub lowercase_width: Option<f32>,
+}
+
+impl Proportions {
+    pub fn new(face: &Face) -> Self {
+        let em = f32::from(face.units_per_em());
+        let os2 = Os2Report::new(face);
+        let top = |c: char| Some(face.glyph_bounding_box(face.glyph_index(c)?)?.y_max);
+        let x_height = os2
+            .as_ref()
+            .and_then(|os2| os2.x_height)
+            .filter(|&height| height > 0)
+            .or_else(|| top('x'))
+            .unwrap_or(0);
+        let cap_height = os2
+            .as_ref()
+            .and_then(|os2| os2.cap_height)
+            .filter(|&height| height > 0)
+            .or_else(|| top('H'))
+            .unwrap_or(0);
+        let ascender = top('d').unwrap_or(face.ascender());
+        let descender = face
+            .glyph_index('p')
+            .and_then(|p| face.glyph_bounding_box(p))
+            .map_or(face.descender(), |bbox| bbox.y_min);
+        let widths: Vec<f32> = ('a'..='z')
+            .filter_map(|c| face.glyph_hor_advance(face.glyph_index(c)?))
+            .map(f32::from)
+            .collect();
+        Proportions {
+            x_height: f32::from(x_height) / em,
+            cap_height: f32::from(cap_height) / em,
+            ascender: f32::from(ascender) / em,
+            descender: -f32::from(descender) / em,
+            lowercase_width: (!widths.is_empty())
+                .then(|| widths.iter().sum::<f32>() / widths.len() as f32 / em),
+        }
+    }
+}
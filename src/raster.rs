@@ -0,0 +1,194 @@
+use ttf_parser::{Face, GlyphId, OutlineBuilder};
+
+/// Line segments each curve is flattened into.
+const CURVE_STEPS: usize = 8;
+
+/// A glyph's outline flattened into closed polygons.
+#[derive(Default)]
+pub struct Polygons {
+    pub contours: Vec<Vec<(f32, f32)>>,
+}
+
+impl Polygons {
+    fn last(&mut self) -> &mut Vec<(f32, f32)> {
+        if self.contours.is_empty() {
+            self.contours.push(Vec::new());
+        }
+        self.contours.last_mut().unwrap()
+    }
+
+    fn current(&mut self) -> (f32, f32) {
+        self.last().last().copied().unwrap_or_default()
+    }
+
+    /// The filled intervals along a scanline under the nonzero rule: a row
+    /// at height `at` when `horizontal`, otherwise a column at `at`.
+    pub fn spans(&self, at: f32, horizontal: bool) -> Vec<(f32, f32)> {
+        let mut crossings = Vec::new();
+        for contour in &self.contours {
+            for (i, &(x0, y0)) in contour.iter().enumerate() {
+                let (x1, y1) = contour[(i + 1) % contour.len()];
+                // Measure along the scanline, across it in the other axis.
+                let ((along0, across0), (along1, across1)) = if horizontal {
+                    ((x0, y0), (x1, y1))
+                } else {
+                    ((y0, x0), (y1, x1))
+                };
+                if (across0 <= at) != (across1 <= at) {
+                    let crossing =
+                        along0 + (at - across0) / (across1 - across0) * (along1 - along0);
+                    crossings.push((crossing, if across1 > across0 { 1 } else { -1 }));
+                }
+            }
+        }
+        crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut spans = Vec::new();
+        let mut winding = 0;
+        let mut start = 0.0;
+        for (position, direction) in crossings {
+            let was_filled = winding != 0;
+            winding += direction;
+            match (was_filled, winding != 0) {
+                (false, true) => start = position,
+                (true, false) => spans.push((start, position)),
+                _ => {}
+            }
+        }
+        spans
+    }
+}
+
+impl OutlineBuilder for Polygons {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.contours.push(vec![(x, y)]);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.last().push((x, y));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x0, y0) = self.current();
+        for step in 1..=CURVE_STEPS {
+            let t = step as f32 / CURVE_STEPS as f32;
+            let u = 1.0 - t;
+            self.last().push((
+                u * u * x0 + 2.0 * u * t * x1 + t * t * x,
+                u * u * y0 + 2.0 * u * t * y1 + t * t * y,
+            ));
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x0, y0) = self.current();
+        for step in 1..=CURVE_STEPS {
+            let t = step as f32 / CURVE_STEPS as f32;
+            let u = 1.0 - t;
+            self.last().push((
+                u * u * u * x0 + 3.0 * u * u * t * x1 + 3.0 * u * t * t * x2 + t * t * t * x,
+                u * u * u * y0 + 3.0 * u * u * t * y1 + 3.0 * u * t * t * y2 + t * t * t * y,
+            ));
+        }
+    }
+
+    fn close(&mut self) {}
+}
+
+/// A flattened outline, shifted so its bounding box is centered on x = 0
+/// while keeping its height above the baseline.
+pub struct Raster {
+    polygons: Polygons,
+    x_min: f32,
+    x_max: f32,
+    y_min: f32,
+    y_max: f32,
+}
+
+impl Raster {
+    pub fn new(face: &Face, glyph: GlyphId) -> Option<Self> {
+        let mut polygons = Polygons::default();
+        let bbox = face.outline_glyph(glyph, &mut polygons)?;
+        let center = (f32::from(bbox.x_min) + f32::from(bbox.x_max)) / 2.0;
+        for point in polygons.contours.iter_mut().flatten() {
+            point.0 -= center;
+        }
+        let half_width = f32::from(bbox.width()) / 2.0;
+        Some(Raster {
+            polygons,
+            x_min: -half_width,
+            x_max: half_width,
+            y_min: f32::from(bbox.y_min),
+            y_max: f32::from(bbox.y_max),
+        })
+    }
+
+    /// Intersection over union of the cells whose centers both outlines
+    /// fill, on a grid of `cell`-unit squares.
+    pub fn similarity(&self, other: &Raster, cell: f32) -> f32 {
+        let x_min = self.x_min.min(other.x_min);
+        let y_min = self.y_min.min(other.y_min);
+        let columns = ((self.x_max.max(other.x_max) - x_min) / cell).ceil() as usize;
+        let rows = ((self.y_max.max(other.y_max) - y_min) / cell).ceil() as usize;
+
+        let (mut both, mut either) = (0, 0);
+        for row in 0..rows {
+            let y = y_min + (row as f32 + 0.5) * cell;
+            for column in 0..columns {
+                let x = x_min + (column as f32 + 0.5) * cell;
+                let (a, b) = (self.contains(x, y), other.contains(x, y));
+                both += usize::from(a && b);
+                either += usize::from(a || b);
+            }
+        }
+        if either == 0 {
+            return 0.0;
+        }
+        both as f32 / either as f32
+    }
+
+    /// Filled area in square font units, counted in `cell`-unit squares
+    /// whose centers the outline fills.
+    pub fn area(&self, cell: f32) -> f32 {
+        let columns = ((self.x_max - self.x_min) / cell).ceil() as usize;
+        let rows = ((self.y_max - self.y_min) / cell).ceil() as usize;
+        let mut filled = 0;
+        for row in 0..rows {
+            let y = self.y_min + (row as f32 + 0.5) * cell;
+            for column in 0..columns {
+                let x = self.x_min + (column as f32 + 0.5) * cell;
+                filled += usize::from(self.contains(x, y));
+            }
+        }
+        filled as f32 * cell * cell
+    }
+
+    /// The filled intervals along a scanline: a row at height `at` when
+    /// `horizontal`, otherwise a column at `at`, where x = 0 is the center
+    /// of the bounding box.
+    pub fn spans(&self, at: f32, horizontal: bool) -> Vec<(f32, f32)> {
+        self.polygons.spans(at, horizontal)
+    }
+
+    /// The height `fraction` of the way up the bounding box.
+    pub fn height_at(&self, fraction: f32) -> f32 {
+        self.y_min + (self.y_max - self.y_min) * fraction
+    }
+
+    /// Nonzero winding test, the fill rule of both TrueType and CFF.
+    fn contains(&self, x: f32, y: f32) -> bool {
+        let mut winding = 0;
+        for contour in &self.polygons.contours {
+            for (i, &(x0, y0)) in contour.iter().enumerate() {
+                let (x1, y1) = contour[(i + 1) % contour.len()];
+                if (y0 <= y) != (y1 <= y) {
+                    let crossing = x0 + (y - y0) / (y1 - y0) * (x1 - x0);
+                    if crossing > x {
+                        winding += if y1 > y0 { 1 } else { -1 };
+                    }
+                }
+            }
+        }
+        winding != 0
+    }
+}
@@ -0,0 +1,54 @@
+/// A minimal big-endian cursor for tables that ttf-parser doesn't expose.
+pub struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Reader { data, pos: 0 }
+    }
+
+    pub fn at(data: &'a [u8], offset: usize) -> Self {
+        Reader { data, pos: offset }
+    }
+
+    pub fn skip(&mut self, len: usize) {
+        self.pos = self.pos.saturating_add(len);
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.pos.checked_add(len)?;
+        let bytes = self.data.get(self.pos..end)?;
+        self.pos = end;
+        Some(bytes)
+    }
+
+    pub fn read_u8(&mut self) -> Option<u8> {
+        self.read_bytes(1).map(|b| b[0])
+    }
+
+    pub fn read_u16(&mut self) -> Option<u16> {
+        self.read_bytes(2).map(|b| u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    pub fn read_i16(&mut self) -> Option<i16> {
+        self.read_u16().map(|v| v as i16)
+    }
+
+    pub fn read_u32(&mut self) -> Option<u32> {
+        self.read_bytes(4)
+            .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    pub fn read_i64(&mut self) -> Option<i64> {
+        let hi = self.read_u32()? as u64;
+        let lo = self.read_u32()? as u64;
+        Some((hi << 32 | lo) as i64)
+    }
+
+    /// Reads a 16.16 fixed-point number.
+    pub fn read_fixed(&mut self) -> Option<f32> {
+        self.read_u32().map(|v| v as i32 as f32 / 65536.0)
+    }
+}
@@ -0,0 +1,174 @@
+use crate::raster::Polygons;
+use ttf_parser::{Face, GlyphId};
+
+/// Scanlines sampled per pixel row for anti-aliasing.
+const SUBSAMPLES: usize = 4;
+
+/// A 5×7 pixel font for labels, so that they don't depend on the font
+/// being rendered having digits. Each row is five bits, leftmost first.
+const LABEL_FONT: [(char, [u8; 7]); 20] = [
+    ('0', [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E]),
+    ('1', [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E]),
+    ('2', [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F]),
+    ('3', [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E]),
+    ('4', [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02]),
+    ('5', [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E]),
+    ('6', [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E]),
+    ('7', [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08]),
+    ('8', [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E]),
+    ('9', [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C]),
+    ('A', [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11]),
+    ('B', [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E]),
+    ('C', [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E]),
+    ('D', [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C]),
+    ('E', [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F]),
+    ('F', [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10]),
+    ('U', [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E]),
+    ('+', [0x00, 0x04, 0x04, 0x1F, 0x04, 0x04, 0x00]),
+    ('-', [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00]),
+    ('.', [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C]),
+];
+
+/// Height of a label drawn with `Canvas::draw_label`, in pixels.
+pub const LABEL_HEIGHT: usize = 7;
+/// Horizontal distance between label characters, in pixels.
+pub const LABEL_ADVANCE: usize = 6;
+
+/// How far `text` advances at `scale` pixels per font unit, laid out as
+/// `Canvas::draw_text` does.
+pub fn text_width(face: &Face, text: &str, scale: f32) -> f32 {
+    text.chars()
+        .map(|c| advance(face, face.glyph_index(c).unwrap_or_default()) * scale)
+        .sum()
+}
+
+fn advance(face: &Face, glyph: GlyphId) -> f32 {
+    f32::from(face.glyph_hor_advance(glyph).unwrap_or(0))
+}
+
+/// A grayscale coverage buffer that glyph outlines are filled into, with
+/// y growing downwards.
+pub struct Canvas {
+    pub width: usize,
+    pub height: usize,
+    coverage: Vec<f32>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize) -> Self {
+        Canvas {
+            width,
+            height,
+            coverage: vec![0.0; width * height],
+        }
+    }
+
+    /// How much of the pixel is covered, from 0 to 1.
+    pub fn coverage(&self, x: usize, y: usize) -> f32 {
+        self.coverage[y * self.width + x]
+    }
+
+    /// Lays `text` out from `x` along `baseline`, in pixels, at `scale`
+    /// pixels per font unit, and fills each glyph. There is no shaping:
+    /// each character gets its cmap glyph and advance, without kerning or
+    /// substitutions. Returns where the text ends.
+    pub fn draw_text(&mut self, face: &Face, text: &str, x: f32, baseline: f32, scale: f32) -> f32 {
+        let mut pen = x;
+        for c in text.chars() {
+            let glyph = face.glyph_index(c).unwrap_or_default();
+            self.draw_glyph(face, glyph, pen, baseline, scale);
+            pen += advance(face, glyph) * scale;
+        }
+        pen
+    }
+
+    /// Fills one glyph's outline with its origin at `x` on `baseline`.
+    pub fn draw_glyph(&mut self, face: &Face, glyph: GlyphId, x: f32, baseline: f32, scale: f32) {
+        let mut polygons = Polygons::default();
+        face.outline_glyph(glyph, &mut polygons);
+        for point in polygons.contours.iter_mut().flatten() {
+            *point = (x + point.0 * scale, baseline - point.1 * scale);
+        }
+        self.fill(&polygons);
+    }
+
+    /// Renders the canvas as lines of braille characters, each covering a
+    /// block of 2×4 pixels, with a dot for every pixel at least half
+    /// covered. Partial blocks at the right and bottom edges are padded.
+    pub fn braille(&self) -> Vec<String> {
+        // Dot bits by position within the block, column by column.
+        const DOTS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+        (0..self.height.div_ceil(4))
+            .map(|row| {
+                (0..self.width.div_ceil(2))
+                    .map(|column| {
+                        let mut bits = 0;
+                        for (dx, dots) in DOTS.iter().enumerate() {
+                            for (dy, dot) in dots.iter().enumerate() {
+                                let (x, y) = (column * 2 + dx, row * 4 + dy);
+                                if x < self.width && y < self.height && self.coverage(x, y) >= 0.5 {
+                                    bits |= dot;
+                                }
+                            }
+                        }
+                        char::from_u32(0x2800 + bits).unwrap_or(' ')
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Draws `text` in the built-in label font with its top left corner at
+    /// `x`, `y`. Characters the label font lacks are left blank.
+    pub fn draw_label(&mut self, text: &str, x: usize, y: usize) {
+        for (i, c) in text.chars().enumerate() {
+            let Some((_, rows)) = LABEL_FONT.iter().find(|(label, _)| *label == c) else {
+                continue;
+            };
+            for (dy, row) in rows.iter().enumerate() {
+                for dx in (0..5).filter(|dx| row >> (4 - dx) & 1 == 1) {
+                    let (px, py) = (x + i * LABEL_ADVANCE + dx, y + dy);
+                    if px < self.width && py < self.height {
+                        self.coverage[py * self.width + px] = 1.0;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Adds the area of each pixel the polygons cover, found along
+    /// `SUBSAMPLES` scanlines per row.
+    fn fill(&mut self, polygons: &Polygons) {
+        let points = || polygons.contours.iter().flatten();
+        let Some(top) = points().map(|point| point.1).reduce(f32::min) else {
+            return;
+        };
+        let bottom = points().map(|point| point.1).fold(top, f32::max);
+        let first_row = top.floor().max(0.0) as usize;
+        let last_row = (bottom.ceil().max(0.0) as usize).min(self.height);
+        let weight = 1.0 / SUBSAMPLES as f32;
+        for row in first_row..last_row {
+            let line = &mut self.coverage[row * self.width..(row + 1) * self.width];
+            for sample in 0..SUBSAMPLES {
+                let y = row as f32 + (sample as f32 + 0.5) * weight;
+                for (start, end) in polygons.spans(y, true) {
+                    let start = start.max(0.0);
+                    let end = end.min(self.width as f32);
+                    if start >= end {
+                        continue;
+                    }
+                    for (column, pixel) in line
+                        .iter_mut()
+                        .enumerate()
+                        .take(end.ceil() as usize)
+                        .skip(start.floor() as usize)
+                    {
+                        let left = start.max(column as f32);
+                        let right = end.min(column as f32 + 1.0);
+                        *pixel = (*pixel + (right - left) * weight).min(1.0);
+                    }
+                }
+            }
+        }
+    }
+}
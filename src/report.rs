@@ -0,0 +1,810 @@
+use crate::advances::{AnomalyReport, AverageWidth};
+use crate::african::AfricanReport;
+use crate::blocks::BlockReport;
+use crate::case::CaseReport;
+use crate::color::ColorReport;
+use crate::config::Config;
+use crate::cyrillic::CyrillicReport;
+use crate::disambiguation::DisambiguationReport;
+use crate::embedding::EmbeddingReport;
+use crate::emoji::{FlagReport, PresentationReport, SkinToneReport};
+use crate::feature_variations::FeatureVariationReport;
+use crate::features::FeatureRegistry;
+use crate::footprint::Footprint;
+use crate::greek::GreekReport;
+use crate::head::HeadReport;
+use crate::health::HealthReport;
+use crate::ignorables::IgnorableReport;
+use crate::ink::InkReport;
+use crate::marks::MarkReport;
+use crate::math::MathReport;
+use crate::mirroring::MirroringReport;
+use crate::name_table::NameTableReport;
+use crate::normalization::NormalizationReport;
+use crate::os2::Os2Report;
+use crate::planes::PlaneReport;
+use crate::post::PostReport;
+use crate::sha256::{self, Sha256};
+use crate::size::SizeReport;
+use crate::special_cases::SpecialCaseReport;
+use crate::stems::StemReport;
+use crate::tables::TableDirectory;
+use crate::tabular::TabularReport;
+use crate::turkic::TurkicReport;
+use crate::unicode::VersionProfile;
+use crate::vietnamese::VietnameseReport;
+use crate::{axes, get_name, layout, opsz, outlines, variations};
+use serde::Serialize;
+use std::collections::HashMap;
+use ttf_parser::{Face, GlyphId, Tag};
+
+/// Name IDs `--redact` leaves out: vendor and designer URLs, the license
+/// description and the license URL, which may point at internal sites or
+/// quote contract terms.
+const REDACTED_NAME_IDS: [u16; 4] = [11, 12, 13, 14];
+
+/// A section of the report, in the order sections are printed.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Section {
+    Names,
+    /// Every `name` record; too long for the default report, so only
+    /// printed on request.
+    NameTable,
+    Format,
+    Health,
+    Footprint,
+    /// The table directory with checksums; only printed on request, like
+    /// the name table.
+    Tables,
+    /// Where the bytes go and what could be dropped; on request too.
+    Size,
+    Head,
+    Metrics,
+    Os2,
+    Embedding,
+    Post,
+    Axes,
+    Instances,
+    FeatureVariations,
+    OpticalSize,
+    Gsub,
+    Gpos,
+    Scripts,
+    Math,
+    Color,
+    Emoji,
+    Flags,
+    SkinTones,
+    Unicode,
+    Planes,
+    Blocks,
+    Advances,
+    Tabular,
+    Disambiguation,
+    Ink,
+    Stems,
+    Ignorables,
+    Mirroring,
+    Marks,
+    Normalization,
+    Case,
+    DottedI,
+    SpecialCases,
+    Greek,
+    Cyrillic,
+    Vietnamese,
+    African,
+}
+
+impl Section {
+    /// Sections of the default report.
+    pub const ALL: [Section; 40] = [
+        Section::Names,
+        Section::Format,
+        Section::Health,
+        Section::Footprint,
+        Section::Head,
+        Section::Metrics,
+        Section::Os2,
+        Section::Embedding,
+        Section::Post,
+        Section::Axes,
+        Section::Instances,
+        Section::FeatureVariations,
+        Section::OpticalSize,
+        Section::Gsub,
+        Section::Gpos,
+        Section::Scripts,
+        Section::Math,
+        Section::Color,
+        Section::Emoji,
+        Section::Flags,
+        Section::SkinTones,
+        Section::Unicode,
+        Section::Planes,
+        Section::Blocks,
+        Section::Advances,
+        Section::Tabular,
+        Section::Disambiguation,
+        Section::Ink,
+        Section::Stems,
+        Section::Ignorables,
+        Section::Mirroring,
+        Section::Marks,
+        Section::Normalization,
+        Section::Case,
+        Section::DottedI,
+        Section::SpecialCases,
+        Section::Greek,
+        Section::Cyrillic,
+        Section::Vietnamese,
+        Section::African,
+    ];
+
+    /// Sections shown by `--terse`: the ones that only read table headers.
+    pub const TERSE: [Section; 3] = [Section::Names, Section::Format, Section::Metrics];
+
+    pub fn from_name(name: &str) -> Option<Section> {
+        match name {
+            "names" => Some(Section::Names),
+            "name-table" => Some(Section::NameTable),
+            "format" => Some(Section::Format),
+            "health" => Some(Section::Health),
+            "footprint" => Some(Section::Footprint),
+            "tables" => Some(Section::Tables),
+            "size" => Some(Section::Size),
+            "head" => Some(Section::Head),
+            "metrics" => Some(Section::Metrics),
+            "os2" => Some(Section::Os2),
+            "embedding" => Some(Section::Embedding),
+            "post" => Some(Section::Post),
+            "axes" => Some(Section::Axes),
+            "instances" => Some(Section::Instances),
+            "feature-variations" => Some(Section::FeatureVariations),
+            "opsz" => Some(Section::OpticalSize),
+            "gsub" => Some(Section::Gsub),
+            "gpos" => Some(Section::Gpos),
+            "scripts" => Some(Section::Scripts),
+            "math" => Some(Section::Math),
+            "color" => Some(Section::Color),
+            "emoji" => Some(Section::Emoji),
+            "flags" => Some(Section::Flags),
+            "skin-tones" => Some(Section::SkinTones),
+            "unicode" => Some(Section::Unicode),
+            "planes" => Some(Section::Planes),
+            "blocks" => Some(Section::Blocks),
+            "advances" => Some(Section::Advances),
+            "tabular" => Some(Section::Tabular),
+            "disambiguation" => Some(Section::Disambiguation),
+            "ink" => Some(Section::Ink),
+            "stems" => Some(Section::Stems),
+            "ignorables" => Some(Section::Ignorables),
+            "mirroring" => Some(Section::Mirroring),
+            "marks" => Some(Section::Marks),
+            "normalization" => Some(Section::Normalization),
+            "case" => Some(Section::Case),
+            "dotted-i" => Some(Section::DottedI),
+            "special-cases" => Some(Section::SpecialCases),
+            "greek" => Some(Section::Greek),
+            "cyrillic" => Some(Section::Cyrillic),
+            "vietnamese" => Some(Section::Vietnamese),
+            "african" => Some(Section::African),
+            _ => None,
+        }
+    }
+}
+
+/// Everything the default report shows, gathered before any output is
+/// written so it can be rendered as text or serialized as JSON. Sections
+/// that weren't selected, or don't apply to the font, are `None`.
+#[derive(Serialize)]
+pub struct FontReport {
+    /// Path of the analyzed file, when it was read from one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub names: Option<Names>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_table: Option<NameTableReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<Format>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health: Option<HealthReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footprint: Option<Footprint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tables: Option<TableDirectory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<SizeReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head: Option<HeadReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<Metrics>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os2: Option<Os2Report>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedding: Option<EmbeddingReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post: Option<PostReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Empty for static fonts.
+    pub axes: Option<Vec<Axis>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instances: Option<Vec<Instance>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature_variations: Option<FeatureVariationReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optical_size: Option<OpticalSize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gsub: Option<Vec<Feature>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpos: Option<Vec<Feature>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scripts: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub math: Option<MathReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<ColorReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<PresentationReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<FlagReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skin_tones: Option<SkinToneReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unicode: Option<VersionProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub planes: Option<PlaneReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<BlockReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advances: Option<Advances>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tabular: Option<TabularReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disambiguation: Option<DisambiguationReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ink: Option<InkReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stems: Option<StemReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignorables: Option<IgnorableReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirroring: Option<MirroringReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marks: Option<MarkReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalization: Option<NormalizationReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub case: Option<CaseReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dotted_i: Option<TurkicReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub special_cases: Option<SpecialCaseReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub greek: Option<GreekReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cyrillic: Option<CyrillicReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vietnamese: Option<VietnameseReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub african: Option<AfricanReport>,
+}
+
+impl FontReport {
+    /// Runs the analysis for each selected section. `terse` skips per-glyph
+    /// scans within them.
+    pub fn new(
+        face: &Face,
+        config: &Config,
+        features: &FeatureRegistry,
+        sections: &[Section],
+        terse: bool,
+    ) -> Self {
+        let selected = |section| sections.contains(&section);
+        FontReport {
+            file: None,
+            names: selected(Section::Names).then(|| Names::new(face)),
+            name_table: selected(Section::NameTable)
+                .then(|| NameTableReport::new(face))
+                .flatten(),
+            format: selected(Section::Format).then(|| Format::new(face, terse)),
+            health: selected(Section::Health).then(|| HealthReport::new(face)),
+            footprint: selected(Section::Footprint).then(|| Footprint::new(face, terse)),
+            tables: selected(Section::Tables).then(|| TableDirectory::new(face)),
+            size: selected(Section::Size).then(|| SizeReport::new(face)),
+            head: selected(Section::Head)
+                .then(|| HeadReport::new(face))
+                .flatten(),
+            metrics: selected(Section::Metrics).then(|| Metrics::new(face)),
+            os2: selected(Section::Os2)
+                .then(|| Os2Report::new(face))
+                .flatten(),
+            embedding: selected(Section::Embedding)
+                .then(|| EmbeddingReport::new(face))
+                .flatten(),
+            post: selected(Section::Post)
+                .then(|| PostReport::new(face))
+                .flatten(),
+            axes: selected(Section::Axes).then(|| Axis::all(face, config)),
+            instances: selected(Section::Instances)
+                .then(|| face.is_variable().then(|| Instance::all(face)))
+                .flatten(),
+            feature_variations: selected(Section::FeatureVariations)
+                .then(|| FeatureVariationReport::new(face))
+                .flatten(),
+            optical_size: selected(Section::OpticalSize).then(|| OpticalSize::new(face)),
+            gsub: selected(Section::Gsub).then(|| {
+                let table = face.tables().gsub;
+                let impacts = table.map(|t| layout::gsub_impacts(&t));
+                let ui_names = layout::ui_names(face);
+                Feature::list(table, impacts.unwrap_or_default(), ui_names, features)
+            }),
+            gpos: selected(Section::Gpos).then(|| {
+                let table = face.tables().gpos;
+                let impacts = table.map(|t| layout::gpos_impacts(&t));
+                Feature::list(table, impacts.unwrap_or_default(), HashMap::new(), features)
+            }),
+            scripts: selected(Section::Scripts).then(|| scripts(face)),
+            math: selected(Section::Math)
+                .then(|| MathReport::new(face))
+                .flatten(),
+            color: selected(Section::Color)
+                .then(|| ColorReport::new(face))
+                .flatten(),
+            emoji: selected(Section::Emoji)
+                .then(|| PresentationReport::new(face))
+                .filter(PresentationReport::is_relevant),
+            flags: selected(Section::Flags)
+                .then(|| FlagReport::new(face))
+                .filter(|report| report.indicators > 0),
+            skin_tones: selected(Section::SkinTones)
+                .then(|| SkinToneReport::new(face))
+                .filter(|report| report.modifiers > 0),
+            unicode: selected(Section::Unicode).then(|| VersionProfile::new(face)),
+            planes: selected(Section::Planes).then(|| PlaneReport::new(face)),
+            blocks: selected(Section::Blocks)
+                .then(|| BlockReport::new(face))
+                .flatten(),
+            advances: selected(Section::Advances).then(|| Advances {
+                average_width: AverageWidth::new(face),
+                anomalies: AnomalyReport::new(face),
+            }),
+            tabular: selected(Section::Tabular)
+                .then(|| TabularReport::new(face))
+                .flatten(),
+            disambiguation: selected(Section::Disambiguation)
+                .then(|| DisambiguationReport::new(face))
+                .flatten(),
+            ink: selected(Section::Ink)
+                .then(|| InkReport::new(face))
+                .flatten(),
+            stems: selected(Section::Stems)
+                .then(|| StemReport::new(face))
+                .flatten(),
+            ignorables: selected(Section::Ignorables).then(|| IgnorableReport::new(face)),
+            mirroring: selected(Section::Mirroring)
+                .then(|| MirroringReport::new(face))
+                .flatten(),
+            marks: selected(Section::Marks)
+                .then(|| MarkReport::new(face))
+                .flatten(),
+            normalization: selected(Section::Normalization)
+                .then(|| NormalizationReport::new(face))
+                .flatten(),
+            case: selected(Section::Case)
+                .then(|| CaseReport::new(face))
+                .flatten(),
+            dotted_i: selected(Section::DottedI)
+                .then(|| TurkicReport::new(face))
+                .flatten(),
+            special_cases: selected(Section::SpecialCases)
+                .then(|| SpecialCaseReport::new(face))
+                .flatten(),
+            greek: selected(Section::Greek)
+                .then(|| GreekReport::new(face))
+                .flatten(),
+            cyrillic: selected(Section::Cyrillic)
+                .then(|| CyrillicReport::new(face))
+                .flatten(),
+            vietnamese: selected(Section::Vietnamese)
+                .then(|| VietnameseReport::new(face))
+                .flatten(),
+            african: selected(Section::African)
+                .then(|| AfricanReport::new(face))
+                .flatten(),
+        }
+    }
+
+    /// Prepares the report for sharing outside the organization: the file
+    /// path becomes a hash, so reports on the same file can still be
+    /// matched up, and the name records of `REDACTED_NAME_IDS` are left
+    /// out.
+    pub fn redact(&mut self) {
+        if let Some(file) = &mut self.file {
+            let mut hasher = Sha256::default();
+            hasher.update(file.as_bytes());
+            *file = format!("sha256:{}", sha256::hex(&hasher.finish()));
+        }
+        if let Some(names) = &mut self.names {
+            names
+                .available
+                .retain(|record| !REDACTED_NAME_IDS.contains(&record.name_id));
+        }
+        if let Some(name_table) = &mut self.name_table {
+            name_table
+                .records
+                .retain(|record| !REDACTED_NAME_IDS.contains(&record.name_id));
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct Names {
+    pub family: Option<String>,
+    pub subfamily: Option<String>,
+    pub full_name: Option<String>,
+    pub postscript_name: Option<String>,
+    pub version: Option<String>,
+    /// Every decodable name record, listed only when none of the names
+    /// above could be found.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub available: Vec<NameRecord>,
+}
+
+#[derive(Serialize)]
+pub struct NameRecord {
+    pub name_id: u16,
+    pub value: String,
+}
+
+impl Names {
+    fn new(face: &Face) -> Self {
+        let mut names = Names {
+            family: get_name(face, ttf_parser::name_id::FAMILY),
+            subfamily: get_name(face, ttf_parser::name_id::SUBFAMILY),
+            full_name: get_name(face, ttf_parser::name_id::FULL_NAME),
+            postscript_name: get_name(face, ttf_parser::name_id::POST_SCRIPT_NAME),
+            version: get_name(face, ttf_parser::name_id::VERSION),
+            available: Vec::new(),
+        };
+        if names.is_empty() {
+            names.available = face
+                .names()
+                .into_iter()
+                .filter_map(|name| {
+                    Some(NameRecord {
+                        name_id: name.name_id,
+                        value: name.to_string()?,
+                    })
+                })
+                .collect();
+        }
+        names
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.family.is_none()
+            && self.subfamily.is_none()
+            && self.full_name.is_none()
+            && self.postscript_name.is_none()
+            && self.version.is_none()
+    }
+}
+
+#[derive(Serialize)]
+pub struct Format {
+    pub sfnt_version: String,
+    pub outline_flavor: String,
+    pub other_sources: Vec<&'static str>,
+    /// Outlines mixed with color or bitmap glyphs.
+    pub mixed: bool,
+    /// Glyph counts per source combination, for fonts with several glyph
+    /// sources. Not computed by terse runs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glyphs_by_source: Option<Vec<SourceCount>>,
+    /// Color glyphs with no fallback outline.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub missing_fallback: Vec<GlyphRef>,
+}
+
+#[derive(Serialize)]
+pub struct SourceCount {
+    pub sources: String,
+    pub glyphs: usize,
+}
+
+#[derive(Serialize)]
+pub struct GlyphRef {
+    pub glyph_id: u16,
+    pub name: Option<String>,
+}
+
+impl GlyphRef {
+    fn new(face: &Face, glyph_id: GlyphId) -> Self {
+        GlyphRef {
+            glyph_id: glyph_id.0,
+            name: face.glyph_name(glyph_id).map(str::to_string),
+        }
+    }
+}
+
+impl Format {
+    fn new(face: &Face, terse: bool) -> Self {
+        let outline_report = outlines::OutlineReport::new(face);
+        let mut format = Format {
+            sfnt_version: outline_report.sfnt_version_name(),
+            outline_flavor: outline_report.flavor(),
+            other_sources: outline_report.other_sources.clone(),
+            mixed: outline_report.is_mixed(),
+            glyphs_by_source: None,
+            missing_fallback: Vec::new(),
+        };
+
+        // The per-glyph scan touches every outline, so terse runs skip it.
+        if outline_report.has_multiple_sources() && !terse {
+            let coverage = outlines::SourceCoverage::new(face);
+            format.glyphs_by_source = Some(
+                coverage
+                    .combinations
+                    .into_iter()
+                    .map(|(sources, glyphs)| SourceCount { sources, glyphs })
+                    .collect(),
+            );
+            format.missing_fallback = coverage
+                .missing_fallback
+                .into_iter()
+                .map(|glyph_id| GlyphRef::new(face, glyph_id))
+                .collect();
+        }
+        format
+    }
+}
+
+#[derive(Serialize)]
+pub struct Metrics {
+    pub units_per_em: u16,
+    pub ascender: i16,
+    pub descender: i16,
+    pub line_gap: i16,
+    pub glyph_count: u16,
+    pub monospaced: bool,
+    pub bold: bool,
+    pub italic: bool,
+    pub oblique: bool,
+    pub weight: u16,
+    pub width: String,
+}
+
+impl Metrics {
+    fn new(face: &Face) -> Self {
+        Metrics {
+            units_per_em: face.units_per_em(),
+            ascender: face.ascender(),
+            descender: face.descender(),
+            line_gap: face.line_gap(),
+            glyph_count: face.number_of_glyphs(),
+            monospaced: face.is_monospaced(),
+            bold: face.is_bold(),
+            italic: face.is_italic(),
+            oblique: face.is_oblique(),
+            weight: face.weight().to_number(),
+            width: format!("{:?}", face.width()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct Axis {
+    pub tag: String,
+    pub name: String,
+    pub min: f32,
+    pub default: f32,
+    pub max: f32,
+    /// The `fvar` flag asking applications not to show the axis in their
+    /// UI.
+    pub hidden: bool,
+    /// Description from the config or the built-in registry; `None` for
+    /// custom axes.
+    pub description: Option<String>,
+    /// Where the description came from, e.g. `registered`.
+    pub source: Option<&'static str>,
+    pub warnings: Vec<String>,
+}
+
+impl Axis {
+    fn all(face: &Face, config: &Config) -> Vec<Axis> {
+        let hidden = variations::hidden_axes(face);
+        face.variation_axes()
+            .into_iter()
+            .enumerate()
+            .map(|(i, axis)| {
+                let tag = axis.tag.to_string();
+                let known = axes::lookup(&tag, config);
+                Axis {
+                    name: get_name(face, axis.name_id).unwrap_or_default(),
+                    min: axis.min_value,
+                    default: axis.def_value,
+                    max: axis.max_value,
+                    hidden: hidden.get(i).copied().unwrap_or(false),
+                    description: known.as_ref().map(|k| k.description.to_string()),
+                    source: known.as_ref().map(|k| k.source.label()),
+                    warnings: axes::check_axis(&axis, config),
+                    tag,
+                }
+            })
+            .collect()
+    }
+}
+
+/// A named instance from `fvar` with its name IDs resolved.
+#[derive(Serialize)]
+pub struct Instance {
+    pub subfamily: String,
+    pub postscript_name: Option<String>,
+    /// Axis tag and user-space value, in `fvar` axis order.
+    pub coordinates: Vec<(String, f32)>,
+}
+
+impl Instance {
+    fn all(face: &Face) -> Vec<Instance> {
+        let axes = face.variation_axes();
+        variations::named_instances(face)
+            .into_iter()
+            .enumerate()
+            .map(|(i, instance)| Instance {
+                subfamily: get_name(face, instance.subfamily_name_id)
+                    .unwrap_or_else(|| format!("Instance {}", i + 1)),
+                postscript_name: instance
+                    .postscript_name_id
+                    .and_then(|id| get_name(face, id)),
+                coordinates: axes
+                    .into_iter()
+                    .zip(instance.coordinates)
+                    .map(|(axis, value)| (axis.tag.to_string(), value))
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// Optical size data with name IDs resolved to strings.
+#[derive(Serialize)]
+pub struct OpticalSize {
+    pub size_feature: Option<SizeFeature>,
+    /// `opsz` axis range as (min, default, max).
+    pub axis: Option<(f32, f32, f32)>,
+    pub os2_range: Option<(f32, f32)>,
+    pub stat_values: Vec<StatValue>,
+    /// All sources reconciled into one point-size range.
+    pub designed_range: Option<(f32, f32)>,
+    pub conflicts: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct SizeFeature {
+    pub design_size: f32,
+    pub range: Option<(f32, f32)>,
+    pub subfamily: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct StatValue {
+    pub value: f32,
+    pub name: String,
+    pub range: Option<(f32, f32)>,
+}
+
+impl OpticalSize {
+    fn new(face: &Face) -> Self {
+        let optical_size = opsz::OpticalSize::new(face);
+        OpticalSize {
+            size_feature: optical_size.size_feature.as_ref().map(|size| SizeFeature {
+                design_size: size.design_size,
+                range: size.range,
+                subfamily: get_name(face, size.subfamily_name_id)
+                    .filter(|_| size.subfamily_name_id != 0),
+            }),
+            axis: optical_size.axis,
+            os2_range: optical_size.os2_range,
+            stat_values: optical_size
+                .stat_values
+                .iter()
+                .map(|value| StatValue {
+                    value: value.value,
+                    name: get_name(face, value.name_id).unwrap_or_default(),
+                    range: value.range,
+                })
+                .collect(),
+            designed_range: optical_size.designed_range(),
+            conflicts: optical_size.conflicts(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size_feature.is_none()
+            && self.axis.is_none()
+            && self.stat_values.is_empty()
+            && self.os2_range.is_none()
+    }
+}
+
+/// A GSUB or GPOS feature registered for at least one script.
+#[derive(Serialize)]
+pub struct Feature {
+    pub tag: String,
+    pub description: String,
+    /// The designer's name for a stylistic set or character variant, e.g.
+    /// `Single-story a`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui_name: Option<String>,
+    pub lookups: usize,
+    pub glyphs: usize,
+}
+
+impl Feature {
+    /// Lists the features reachable from the script list, sorted by tag.
+    fn list(
+        table: Option<ttf_parser::opentype_layout::LayoutTable>,
+        impacts: HashMap<Tag, layout::FeatureImpact>,
+        ui_names: HashMap<Tag, String>,
+        features: &FeatureRegistry,
+    ) -> Vec<Feature> {
+        let mut tags = Vec::new();
+        if let Some(table) = table {
+            for script in table.scripts {
+                let lang_systems = script.languages.into_iter().chain(script.default_language);
+                for lang_sys in lang_systems {
+                    for feature_index in lang_sys.feature_indices {
+                        if let Some(feature) = table.features.get(feature_index)
+                            && !tags.contains(&feature.tag)
+                        {
+                            tags.push(feature.tag);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut list: Vec<Feature> = tags
+            .into_iter()
+            .map(|tag| {
+                let name = tag.to_string();
+                let impact = impacts.get(&tag);
+                Feature {
+                    description: features.describe(&name).to_string(),
+                    ui_name: ui_names.get(&tag).cloned(),
+                    lookups: impact.map_or(0, |i| i.lookups),
+                    glyphs: impact.map_or(0, |i| i.glyphs),
+                    tag: name,
+                }
+            })
+            .collect();
+        list.sort_by(|a, b| a.tag.cmp(&b.tag));
+        list
+    }
+}
+
+/// Script tags from GSUB and GPOS, sorted.
+fn scripts(face: &Face) -> Vec<String> {
+    let mut scripts = Vec::new();
+    for table in [face.tables().gsub, face.tables().gpos]
+        .into_iter()
+        .flatten()
+    {
+        for script in table.scripts {
+            let tag = script.tag.to_string();
+            if !scripts.contains(&tag) {
+                scripts.push(tag);
+            }
+        }
+    }
+    scripts.sort();
+    scripts
+}
+
+#[derive(Serialize)]
+pub struct Advances {
+    pub average_width: Option<AverageWidth>,
+    pub anomalies: AnomalyReport,
+}
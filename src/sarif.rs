@@ -0,0 +1,156 @@
+use serde::Serialize;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// How serious a finding is, as SARIF levels it.
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Error,
+    Warning,
+}
+
+/// A SARIF 2.1.0 log of one fontinfo run, for code review tools and
+/// dashboards that ingest findings from other linters. Declare each rule
+/// checked, passing or not, then record its findings as results.
+#[derive(Serialize)]
+pub struct Log {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run; 1],
+}
+
+#[derive(Serialize)]
+struct Run {
+    tool: Tool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: String,
+    name: String,
+    short_description: Message,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: Level,
+    message: Message,
+    locations: [Location; 1],
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+impl Default for Log {
+    fn default() -> Self {
+        Log {
+            schema: SCHEMA,
+            version: "2.1.0",
+            runs: [Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: "fontinfo",
+                        version: env!("CARGO_PKG_VERSION"),
+                        rules: Vec::new(),
+                    },
+                },
+                results: Vec::new(),
+            }],
+        }
+    }
+}
+
+impl Log {
+    /// Declares a rule, returning its index. Results refer to it by `id`,
+    /// which should stay stable so tools can suppress its findings.
+    pub fn rule(&mut self, id: &str, description: &str, help_uri: Option<&str>) -> usize {
+        let rules = &mut self.runs[0].tool.driver.rules;
+        if let Some(index) = rules.iter().position(|rule| rule.id == id) {
+            return index;
+        }
+        rules.push(Rule {
+            id: id.to_string(),
+            name: description.to_string(),
+            short_description: Message {
+                text: description.to_string(),
+            },
+            help_uri: help_uri.map(str::to_string),
+        });
+        rules.len() - 1
+    }
+
+    /// Records a finding of rule `index` in the font at `path`.
+    pub fn result(&mut self, index: usize, level: Level, message: &str, path: &str) {
+        let run = &mut self.runs[0];
+        run.results.push(SarifResult {
+            rule_id: run.tool.driver.rules[index].id.clone(),
+            rule_index: index,
+            level,
+            message: Message {
+                text: message.to_string(),
+            },
+            locations: [Location {
+                physical_location: PhysicalLocation {
+                    artifact_location: ArtifactLocation {
+                        uri: path.replace('\\', "/"),
+                    },
+                },
+            }],
+        });
+    }
+}
+
+/// An ID for a rule known only by its display name: the name in kebab
+/// case, e.g. `cmap-encoding-records-sorted`.
+pub fn rule_id(name: &str) -> String {
+    let mut id = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            id.push(c.to_ascii_lowercase());
+        } else if !id.is_empty() && !id.ends_with('-') {
+            id.push('-');
+        }
+    }
+    id.trim_end_matches('-').to_string()
+}
@@ -0,0 +1,81 @@
+use serde::Serialize;
+use ttf_parser::opentype_layout::{LanguageSystem, LayoutTable};
+
+/// A feature as one language system sees it. Language systems can point
+/// at different feature records with the same tag, so the lookups are
+/// what tell them apart.
+#[derive(Serialize)]
+pub struct FeatureRecord {
+    pub tag: String,
+    pub lookups: Vec<u16>,
+    /// The language system's required feature, applied whether or not
+    /// it's enabled.
+    pub required: bool,
+}
+
+#[derive(Serialize)]
+pub struct LanguageFeatures {
+    /// The language system tag, or `None` for the script's default.
+    pub language: Option<String>,
+    /// Sorted by tag.
+    pub features: Vec<FeatureRecord>,
+}
+
+/// The features each language system of a script registers.
+#[derive(Serialize)]
+pub struct ScriptFeatures {
+    pub script: String,
+    pub languages: Vec<LanguageFeatures>,
+}
+
+/// Lists the features of every script and language system in a GSUB or
+/// GPOS table, in script list order with the default language system
+/// first.
+pub fn list(table: &LayoutTable) -> Vec<ScriptFeatures> {
+    table
+        .scripts
+        .into_iter()
+        .map(|script| {
+            let default = script
+                .default_language
+                .map(|lang_sys| language_features(table, None, lang_sys));
+            let languages = script.languages.into_iter().map(|lang_sys| {
+                let tag = lang_sys.tag.to_string().trim_end().to_string();
+                language_features(table, Some(tag), lang_sys)
+            });
+            ScriptFeatures {
+                script: script.tag.to_string(),
+                languages: default.into_iter().chain(languages).collect(),
+            }
+        })
+        .collect()
+}
+
+fn language_features(
+    table: &LayoutTable,
+    language: Option<String>,
+    lang_sys: LanguageSystem,
+) -> LanguageFeatures {
+    let indices = lang_sys
+        .required_feature
+        .into_iter()
+        .map(|index| (index, true))
+        .chain(
+            lang_sys
+                .feature_indices
+                .into_iter()
+                .map(|index| (index, false)),
+        );
+    let mut features: Vec<FeatureRecord> = indices
+        .filter_map(|(index, required)| {
+            let feature = table.features.get(index)?;
+            Some(FeatureRecord {
+                tag: feature.tag.to_string(),
+                lookups: feature.lookup_indices.into_iter().collect(),
+                required,
+            })
+        })
+        .collect();
+    features.sort_by(|a, b| a.tag.cmp(&b.tag));
+    LanguageFeatures { language, features }
+}
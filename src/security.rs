@@ -0,0 +1,626 @@
+use crate::glyph;
+use crate::reader::Reader;
+use crate::special_cases::Status;
+use serde::Serialize;
+use ttf_parser::{RawFace, Tag};
+
+/// More tables than any legitimate font carries; the spec registers
+/// about fifty.
+const MAX_TABLES: usize = 100;
+/// How many times its fixed size a fixed-size table may be before its
+/// length counts as absurd.
+const FIXED_SIZE_SLACK: usize = 4;
+/// `maxp` limits from here on are far beyond anything a hinting compiler
+/// emits and only serve to make an interpreter allocate.
+const ABSURD_MAXP_LIMIT: u16 = 0x8000;
+
+/// Structure sizes of tables whose length the spec fixes, at their latest
+/// version.
+const FIXED_SIZES: [(&[u8; 4], usize); 5] = [
+    (b"head", 54),
+    (b"hhea", 36),
+    (b"vhea", 36),
+    (b"maxp", 32),
+    (b"OS/2", 100),
+];
+
+/// TrueType opcodes below the PUSH range that the spec leaves undefined,
+/// besides 0x8F–0xAF less GETVARIATION and GETDATA. Fonts can give them
+/// meaning with IDEF, which is rare enough to flag on its own.
+const UNDEFINED_OPCODES: [u8; 4] = [0x28, 0x7B, 0x83, 0x84];
+const GETVARIATION: u8 = 0x91;
+const GETDATA: u8 = 0x92;
+
+const NPUSHB: u8 = 0x40;
+const NPUSHW: u8 = 0x41;
+const FDEF: u8 = 0x2C;
+const ENDF: u8 = 0x2D;
+const IF: u8 = 0x58;
+const EIF: u8 = 0x59;
+const IDEF: u8 = 0x89;
+
+/// One family of anomalies and every instance found.
+#[derive(Serialize)]
+pub struct Scan {
+    pub name: &'static str,
+    pub status: Status,
+    pub findings: Vec<String>,
+}
+
+/// The results of `fontinfo audit-security`: patterns seen in fonts built
+/// to exploit parsers rather than to render text, such as tables that
+/// overlap or lie about their size, offsets pointing outside their table
+/// and malformed hinting bytecode. Works from the raw bytes, so fonts too
+/// broken for the parser still get a report.
+#[derive(Serialize)]
+pub struct SecurityReport {
+    pub scans: Vec<Scan>,
+}
+
+impl SecurityReport {
+    pub fn new(data: &[u8]) -> Self {
+        let raw = match RawFace::parse(data, 0) {
+            Ok(raw) => raw,
+            Err(e) => {
+                return SecurityReport {
+                    scans: vec![scan(
+                        "Table directory",
+                        Some(vec![format!("unreadable: {}", e)]),
+                    )],
+                };
+            }
+        };
+        let font = Font::new(data, raw);
+        SecurityReport {
+            scans: vec![
+                scan("Table directory", Some(directory(&font))),
+                scan("Table sizes", Some(table_sizes(&font))),
+                scan("Offsets within bounds", Some(offsets(&font))),
+                scan("Overlapping tables", Some(overlaps(&font))),
+                scan("Hinting programs", hinting(&font)),
+            ],
+        }
+    }
+
+    pub fn findings(&self) -> usize {
+        self.scans.iter().map(|scan| scan.findings.len()).sum()
+    }
+}
+
+/// `None` findings means there was nothing to scan.
+fn scan(name: &'static str, findings: Option<Vec<String>>) -> Scan {
+    let status = match &findings {
+        None => Status::NotApplicable,
+        Some(findings) if findings.is_empty() => Status::Pass,
+        Some(_) => Status::Fail,
+    };
+    Scan {
+        name,
+        status,
+        findings: findings.unwrap_or_default(),
+    }
+}
+
+/// A table record, with the end computed without wrapping.
+struct Record {
+    tag: Tag,
+    offset: u64,
+    end: u64,
+}
+
+struct Font<'a> {
+    data: &'a [u8],
+    raw: RawFace<'a>,
+    records: Vec<Record>,
+}
+
+impl<'a> Font<'a> {
+    fn new(data: &'a [u8], raw: RawFace<'a>) -> Self {
+        let records = raw
+            .table_records
+            .into_iter()
+            .map(|record| Record {
+                tag: record.tag,
+                offset: u64::from(record.offset),
+                end: u64::from(record.offset) + u64::from(record.length),
+            })
+            .collect();
+        Font { data, raw, records }
+    }
+
+    fn table(&self, tag: &[u8; 4]) -> Option<&'a [u8]> {
+        self.raw.table(Tag::from_bytes(tag))
+    }
+
+    /// `numGlyphs` from `maxp`.
+    fn glyph_count(&self) -> Option<u16> {
+        Reader::at(self.table(b"maxp")?, 4).read_u16()
+    }
+}
+
+fn directory(font: &Font) -> Vec<String> {
+    let mut findings = Vec::new();
+    if font.records.len() > MAX_TABLES {
+        findings.push(format!(
+            "declares {} tables, more than any real font",
+            font.records.len()
+        ));
+    }
+    let mut seen = Vec::new();
+    for record in &font.records {
+        if seen.contains(&record.tag) {
+            findings.push(format!(
+                "'{}' appears more than once, so parsers may disagree on which copy to use",
+                record.tag
+            ));
+        } else {
+            seen.push(record.tag);
+        }
+    }
+    let directory_end = 12 + 16 * font.records.len() as u64;
+    for record in &font.records {
+        if record.offset < directory_end && record.end > record.offset {
+            findings.push(format!(
+                "'{}' starts at {}, inside the table directory",
+                record.tag, record.offset
+            ));
+        }
+    }
+    findings
+}
+
+fn table_sizes(font: &Font) -> Vec<String> {
+    let file_len = font.data.len() as u64;
+    let mut findings = Vec::new();
+    for record in &font.records {
+        let length = record.end - record.offset;
+        if length > file_len {
+            findings.push(format!(
+                "'{}' claims {} bytes, more than the {}-byte file",
+                record.tag, length, file_len
+            ));
+        } else if let Some((_, size)) = FIXED_SIZES
+            .iter()
+            .find(|(tag, _)| Tag::from_bytes(tag) == record.tag)
+            && length > (size * FIXED_SIZE_SLACK) as u64
+        {
+            findings.push(format!(
+                "'{}' is {} bytes, though its structure takes at most {}",
+                record.tag, length, size
+            ));
+        }
+    }
+    if let Some(maxp) = font.table(b"maxp")
+        && Reader::at(maxp, 0).read_u32() == Some(0x0001_0000)
+    {
+        let mut r = Reader::at(maxp, 14);
+        let names = [
+            "maxZones",
+            "maxTwilightPoints",
+            "maxStorage",
+            "maxFunctionDefs",
+            "maxInstructionDefs",
+            "maxStackElements",
+            "maxSizeOfInstructions",
+        ];
+        for name in names {
+            match r.read_u16() {
+                Some(value) if value >= ABSURD_MAXP_LIMIT => {
+                    findings.push(format!("maxp {} is {}", name, value));
+                }
+                Some(_) => {}
+                None => break,
+            }
+        }
+    }
+    if let (Some(hhea), Some(glyphs)) = (font.table(b"hhea"), font.glyph_count())
+        && let Some(metrics) = Reader::at(hhea, 34).read_u16()
+        && metrics > glyphs
+    {
+        findings.push(format!(
+            "hhea numberOfHMetrics is {}, more than the {} glyphs",
+            metrics, glyphs
+        ));
+    }
+    findings
+}
+
+fn offsets(font: &Font) -> Vec<String> {
+    let file_len = font.data.len() as u64;
+    let mut findings: Vec<String> = font
+        .records
+        .iter()
+        .filter(|record| record.end > file_len)
+        .map(|record| {
+            if record.end > u64::from(u32::MAX) {
+                format!(
+                    "'{}' offset {} plus length {} overflows 32 bits",
+                    record.tag,
+                    record.offset,
+                    record.end - record.offset
+                )
+            } else {
+                format!(
+                    "'{}' spans {}–{}, past the end of the {}-byte file",
+                    record.tag, record.offset, record.end, file_len
+                )
+            }
+        })
+        .collect();
+    if let Some(cmap) = font.table(b"cmap") {
+        findings.extend(cmap_offsets(cmap));
+    }
+    if let Some(name) = font.table(b"name") {
+        findings.extend(name_offsets(name));
+    }
+    for tag in [b"GDEF", b"GSUB", b"GPOS"] {
+        if let Some(table) = font.table(tag) {
+            findings.extend(layout_offsets(tag, table));
+        }
+    }
+    if let (Some(hhea), Some(hmtx), Some(glyphs)) =
+        (font.table(b"hhea"), font.table(b"hmtx"), font.glyph_count())
+        && let Some(metrics) = Reader::at(hhea, 34).read_u16()
+    {
+        let needed = 4 * usize::from(metrics) + 2 * usize::from(glyphs.saturating_sub(metrics));
+        if hmtx.len() < needed {
+            findings.push(format!(
+                "hmtx is {} bytes, but hhea and maxp say it holds {}",
+                hmtx.len(),
+                needed
+            ));
+        }
+    }
+    if let Some(locations) = glyph_locations(font)
+        && let Some(glyf) = font.table(b"glyf")
+    {
+        findings.extend(
+            locations
+                .iter()
+                .enumerate()
+                .filter(|&(_, &(start, end))| end < start || end > glyf.len())
+                .map(|(glyph, &(start, end))| {
+                    format!(
+                        "loca puts glyph {} at {}–{}, outside the {}-byte glyf",
+                        glyph,
+                        start,
+                        end,
+                        glyf.len()
+                    )
+                }),
+        );
+    }
+    findings
+}
+
+/// Pairs of tables sharing bytes, which lets one table's parser read
+/// another's data. Identical records are called out separately since some
+/// tools deduplicate tables that way.
+fn overlaps(font: &Font) -> Vec<String> {
+    let mut records: Vec<&Record> = font
+        .records
+        .iter()
+        .filter(|record| record.end > record.offset)
+        .collect();
+    records.sort_by_key(|record| (record.offset, record.end));
+    let mut findings = Vec::new();
+    for (i, a) in records.iter().enumerate() {
+        for b in records[i + 1..].iter().take_while(|b| b.offset < a.end) {
+            if (a.offset, a.end) == (b.offset, b.end) {
+                findings.push(format!(
+                    "'{}' and '{}' point at the same {} bytes at {}",
+                    a.tag,
+                    b.tag,
+                    a.end - a.offset,
+                    a.offset
+                ));
+            } else {
+                findings.push(format!(
+                    "'{}' ({}–{}) overlaps '{}' ({}–{})",
+                    a.tag, a.offset, a.end, b.tag, b.offset, b.end
+                ));
+            }
+        }
+    }
+    findings
+}
+
+/// Each encoding record's subtable starts, and by its own length ends,
+/// inside `cmap`.
+fn cmap_offsets(cmap: &[u8]) -> Vec<String> {
+    let mut r = Reader::at(cmap, 2);
+    let count = r.read_u16().unwrap_or(0);
+    let mut findings = Vec::new();
+    for _ in 0..count {
+        let (Some(platform), Some(encoding), Some(offset)) =
+            (r.read_u16(), r.read_u16(), r.read_u32())
+        else {
+            findings.push(format!(
+                "{} encoding records run past the end of cmap",
+                count
+            ));
+            break;
+        };
+        let offset = offset as usize;
+        let mut subtable = Reader::at(cmap, offset);
+        let length = match subtable.read_u16() {
+            Some(0 | 2 | 4 | 6) => subtable.read_u16().map(usize::from),
+            Some(8 | 10 | 12 | 13) => {
+                subtable.skip(2);
+                subtable.read_u32().map(|length| length as usize)
+            }
+            Some(14) => subtable.read_u32().map(|length| length as usize),
+            _ => None,
+        };
+        match length {
+            None if offset >= cmap.len() => findings.push(format!(
+                "platform {} encoding {} subtable at {} is past the end of the {}-byte cmap",
+                platform,
+                encoding,
+                offset,
+                cmap.len()
+            )),
+            Some(length) if offset.saturating_add(length) > cmap.len() => {
+                findings.push(format!(
+                    "platform {} encoding {} subtable claims {} bytes at {}, past the end of the {}-byte cmap",
+                    platform,
+                    encoding,
+                    length,
+                    offset,
+                    cmap.len()
+                ));
+            }
+            _ => {}
+        }
+    }
+    findings
+}
+
+/// Each name string lies inside the table.
+fn name_offsets(name: &[u8]) -> Vec<String> {
+    let mut r = Reader::at(name, 2);
+    let (Some(count), Some(storage)) = (r.read_u16(), r.read_u16()) else {
+        return vec!["name header is truncated".to_string()];
+    };
+    let storage = usize::from(storage);
+    if storage > name.len() {
+        return vec![format!(
+            "name string storage starts at {}, past the end of the {}-byte table",
+            storage,
+            name.len()
+        )];
+    }
+    let out_of_bounds = (0..count)
+        .map_while(|_| {
+            r.skip(8); // platformID, encodingID, languageID, nameID
+            Some((r.read_u16()?, r.read_u16()?))
+        })
+        .filter(|&(length, offset)| {
+            storage + usize::from(offset) + usize::from(length) > name.len()
+        })
+        .count();
+    if out_of_bounds > 0 {
+        vec![format!(
+            "{} of {} name strings run past the end of the {}-byte table",
+            out_of_bounds,
+            count,
+            name.len()
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+/// The header's subtable offsets, and for GSUB and GPOS each lookup's,
+/// lie inside the table.
+fn layout_offsets(tag: &[u8; 4], table: &[u8]) -> Vec<String> {
+    let tag = Tag::from_bytes(tag);
+    let mut r = Reader::new(table);
+    let (Some(_major), Some(minor)) = (r.read_u16(), r.read_u16()) else {
+        return vec![format!("'{}' header is truncated", tag)];
+    };
+    let fields: &[&str] = if tag == Tag::from_bytes(b"GDEF") {
+        &[
+            "glyphClassDef",
+            "attachList",
+            "ligCaretList",
+            "markAttachClassDef",
+        ]
+    } else {
+        &["scriptList", "featureList", "lookupList"]
+    };
+    let mut findings = Vec::new();
+    let mut lookup_list = None;
+    for &field in fields {
+        let Some(offset) = r.read_u16().map(usize::from) else {
+            findings.push(format!("'{}' header is truncated", tag));
+            return findings;
+        };
+        if offset >= table.len() {
+            findings.push(format!(
+                "'{}' {} offset {} is past the end of the {}-byte table",
+                tag,
+                field,
+                offset,
+                table.len()
+            ));
+        } else if field == "lookupList" && offset != 0 {
+            lookup_list = Some(offset);
+        }
+    }
+    // GSUB and GPOS 1.1 add a 32-bit featureVariations offset.
+    if tag != Tag::from_bytes(b"GDEF")
+        && minor >= 1
+        && let Some(offset) = r.read_u32()
+        && offset as usize >= table.len()
+    {
+        findings.push(format!(
+            "'{}' featureVariations offset {} is past the end of the {}-byte table",
+            tag,
+            offset,
+            table.len()
+        ));
+    }
+    if let Some(list) = lookup_list {
+        let mut r = Reader::at(table, list);
+        let count = r.read_u16().unwrap_or(0);
+        let outside = (0..count)
+            .map_while(|_| r.read_u16())
+            .filter(|&offset| list + usize::from(offset) >= table.len())
+            .count();
+        if outside > 0 {
+            findings.push(format!(
+                "'{}' has {} of {} lookups past the end of the table",
+                tag, outside, count
+            ));
+        }
+    }
+    findings
+}
+
+/// Each glyph's byte range in `glyf` as `loca` gives it, for the glyphs
+/// `maxp` declares. `None` without `loca` or its `head` format.
+fn glyph_locations(font: &Font) -> Option<Vec<(usize, usize)>> {
+    let loca = font.table(b"loca")?;
+    let long = Reader::at(font.table(b"head")?, 50).read_u16()? == 1;
+    let count = usize::from(font.glyph_count()?) + 1;
+    let mut r = Reader::new(loca);
+    let offsets: Vec<usize> = (0..count)
+        .map_while(|_| {
+            if long {
+                r.read_u32().map(|offset| offset as usize)
+            } else {
+                // Short offsets are stored divided by two.
+                r.read_u16().map(|offset| usize::from(offset) * 2)
+            }
+        })
+        .collect();
+    Some(offsets.windows(2).map(|pair| (pair[0], pair[1])).collect())
+}
+
+/// `fpgm`, `prep` and every glyph's instructions decode as TrueType
+/// bytecode, with definitions only where the spec allows them. `None`
+/// for fonts without TrueType hinting.
+fn hinting(font: &Font) -> Option<Vec<String>> {
+    let fpgm = font.table(b"fpgm");
+    let prep = font.table(b"prep");
+    let glyphs = glyph_instructions(font);
+    if fpgm.is_none() && prep.is_none() && glyphs.is_empty() {
+        return None;
+    }
+    let mut findings = Vec::new();
+    for (name, program) in [("fpgm", fpgm), ("prep", prep)] {
+        if let Some(program) = program {
+            findings.extend(
+                bytecode(program, true)
+                    .into_iter()
+                    .map(|finding| format!("{}: {}", name, finding)),
+            );
+        }
+    }
+    let max_size = font
+        .table(b"maxp")
+        .and_then(|maxp| Reader::at(maxp, 26).read_u16());
+    for (glyph, instructions) in glyphs {
+        let Some(program) = instructions else {
+            findings.push(format!(
+                "glyph {}: instructions run past the end of the glyph",
+                glyph
+            ));
+            continue;
+        };
+        if let Some(max_size) = max_size
+            && program.len() > usize::from(max_size)
+        {
+            findings.push(format!(
+                "glyph {}: {} bytes of instructions, more than maxp maxSizeOfInstructions {}",
+                glyph,
+                program.len(),
+                max_size
+            ));
+        }
+        findings.extend(
+            bytecode(program, false)
+                .into_iter()
+                .map(|finding| format!("glyph {}: {}", glyph, finding)),
+        );
+    }
+    Some(findings)
+}
+
+/// The instructions of every glyph that has any, or `None` for a glyph
+/// whose instruction length runs past its end.
+fn glyph_instructions<'a>(font: &Font<'a>) -> Vec<(usize, Option<&'a [u8]>)> {
+    let (Some(glyf), Some(locations)) = (font.table(b"glyf"), glyph_locations(font)) else {
+        return Vec::new();
+    };
+    locations
+        .into_iter()
+        .enumerate()
+        .filter_map(|(glyph, (start, end))| {
+            let data = glyf.get(start..end).filter(|data| !data.is_empty())?;
+            match glyph::glyph_instructions(data) {
+                Some([]) => None,
+                program => Some((glyph, program)),
+            }
+        })
+        .collect()
+}
+
+/// Walks a program one instruction at a time. FDEF and IDEF belong in
+/// `fpgm` and `prep` (`definitions`), never nested; FDEF/ENDF and IF/EIF
+/// should balance. IDEF is reported wherever it appears.
+fn bytecode(program: &[u8], definitions: bool) -> Vec<String> {
+    let mut findings = Vec::new();
+    let mut in_definition = false;
+    let mut ifs = 0usize;
+    let mut pos = 0;
+    while let Some(&op) = program.get(pos) {
+        let operands = match op {
+            NPUSHB => program.get(pos + 1).map(|&n| 1 + usize::from(n)),
+            NPUSHW => program.get(pos + 1).map(|&n| 1 + 2 * usize::from(n)),
+            0xB0..=0xB7 => Some(usize::from(op - 0xAF)),
+            0xB8..=0xBF => Some(2 * usize::from(op - 0xB7)),
+            _ => Some(0),
+        };
+        match operands {
+            Some(operands) if pos + 1 + operands <= program.len() => pos += 1 + operands,
+            _ => {
+                findings.push(format!("PUSH at {} runs past the end", pos));
+                break;
+            }
+        }
+        match op {
+            FDEF | IDEF if !definitions => findings.push(format!(
+                "{} at {} outside fpgm and prep",
+                if op == FDEF { "FDEF" } else { "IDEF" },
+                pos - 1
+            )),
+            FDEF | IDEF if in_definition => {
+                findings.push(format!("definition nested in another at {}", pos - 1))
+            }
+            FDEF => in_definition = true,
+            IDEF => {
+                findings.push(format!("IDEF at {} redefines an opcode", pos - 1));
+                in_definition = true;
+            }
+            ENDF if !in_definition => findings.push(format!("ENDF without FDEF at {}", pos - 1)),
+            ENDF => in_definition = false,
+            IF => ifs += 1,
+            EIF if ifs == 0 => findings.push(format!("EIF without IF at {}", pos - 1)),
+            EIF => ifs -= 1,
+            GETVARIATION | GETDATA => {}
+            _ if UNDEFINED_OPCODES.contains(&op) || (0x8F..=0xAF).contains(&op) => {
+                findings.push(format!("undefined opcode {:#04X} at {}", op, pos - 1))
+            }
+            _ => {}
+        }
+    }
+    if in_definition {
+        findings.push("definition without ENDF".to_string());
+    }
+    if ifs > 0 {
+        findings.push(format!("{} IF without EIF", ifs));
+    }
+    findings
+}
@@ -0,0 +1,135 @@
+use crate::{parse_face, read_font_file};
+use rustybuzz::{Direction, Feature, Language, Script, UnicodeBuffer};
+use std::process;
+use std::str::FromStr;
+use ttf_parser::GlyphId;
+
+/// Runs `fontinfo shape <font-file> --text <text> [--features <list>]
+/// [--language <tag>] [--script <tag>] [--direction <dir>]`.
+pub fn run(program: &str, args: &[String]) {
+    let mut paths = Vec::new();
+    let mut text = None;
+    let mut features = Vec::new();
+    let mut language = None;
+    let mut script = None;
+    let mut direction = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage(program));
+        match arg.as_str() {
+            "--text" => text = Some(value().clone()),
+            "--features" => {
+                for feature in value().split(',').filter(|feature| !feature.is_empty()) {
+                    match Feature::from_str(feature) {
+                        Ok(parsed) => features.push((feature.to_string(), parsed)),
+                        Err(_) => usage(program),
+                    }
+                }
+            }
+            "--language" => match Language::from_str(value()) {
+                Ok(parsed) => language = Some(parsed),
+                Err(_) => usage(program),
+            },
+            "--script" => match Script::from_str(value()) {
+                Ok(parsed) => script = Some(parsed),
+                Err(_) => usage(program),
+            },
+            "--direction" => match Direction::from_str(value()) {
+                Ok(parsed) => direction = Some(parsed),
+                Err(_) => usage(program),
+            },
+            _ if !arg.starts_with('-') => paths.push(arg),
+            _ => usage(program),
+        }
+    }
+    let ([font_path], Some(text)) = (paths.as_slice(), text) else {
+        usage(program);
+    };
+
+    let font_data = read_font_file(font_path);
+    let face = rustybuzz::Face::from_face(parse_face(&font_data, font_path));
+    let mut buffer = UnicodeBuffer::new();
+    buffer.push_str(&text);
+    if let Some(language) = language {
+        buffer.set_language(language);
+    }
+    if let Some(script) = script {
+        buffer.set_script(script);
+    }
+    if let Some(direction) = direction {
+        buffer.set_direction(direction);
+    }
+    buffer.guess_segment_properties();
+    let settings: Vec<Feature> = features.iter().map(|(_, feature)| *feature).collect();
+    let shaped = rustybuzz::shape(&face, &settings, buffer);
+
+    let names: Vec<String> = shaped
+        .glyph_infos()
+        .iter()
+        .map(|info| {
+            face.glyph_name(GlyphId(info.glyph_id as u16))
+                .unwrap_or("")
+                .to_string()
+        })
+        .collect();
+    let name_width = names
+        .iter()
+        .map(|name| name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    let advance: i32 = shaped
+        .glyph_positions()
+        .iter()
+        .map(|p| p.x_advance + p.y_advance)
+        .sum();
+    let requested: Vec<&str> = features.iter().map(|(name, _)| name.as_str()).collect();
+    println!(
+        "Shaped \"{}\" with {}: {} glyphs, advance {}",
+        text.escape_debug(),
+        if requested.is_empty() {
+            "default features".to_string()
+        } else {
+            requested.join(", ")
+        },
+        shaped.len(),
+        advance
+    );
+    println!(
+        "{:>4}  {:>5}  {:<name_width$}  {:>7}  {:>7}  Offset",
+        "#", "Glyph", "Name", "Cluster", "Advance"
+    );
+    for (i, ((info, position), name)) in shaped
+        .glyph_infos()
+        .iter()
+        .zip(shaped.glyph_positions())
+        .zip(&names)
+        .enumerate()
+    {
+        let advance = if position.y_advance != 0 {
+            format!("{},{}", position.x_advance, position.y_advance)
+        } else {
+            position.x_advance.to_string()
+        };
+        println!(
+            "{:>4}  {:>5}  {:<name_width$}  {:>7}  {:>7}  {},{}",
+            i, info.glyph_id, name, info.cluster, advance, position.x_offset, position.y_offset
+        );
+    }
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} shape <font-file> --text <text> [options]",
+        program
+    );
+    eprintln!("  --features <list>   Comma-separated features in hb-shape syntax,");
+    eprintln!("                      e.g. liga,smcp or -kern or salt=2");
+    eprintln!("  --language <tag>    BCP 47 language, e.g. tr (default guessed)");
+    eprintln!("  --script <tag>      ISO 15924 script, e.g. Latn (default guessed)");
+    eprintln!("  --direction <dir>   ltr, rtl, ttb or btt (default from the script)");
+    eprintln!("Clusters are byte offsets into the text; advances and offsets are in");
+    eprintln!("font units.");
+    process::exit(1);
+}
@@ -0,0 +1,241 @@
+use crate::{parse_face, read_font_file};
+use fontinfo::png;
+use fontinfo::render::{Canvas, LABEL_ADVANCE, LABEL_HEIGHT};
+use fontinfo::unicode::covered_chars;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::process;
+use ttf_parser::{Face, GlyphId};
+
+/// Default cell size in pixels, wide enough for a `U+1F600` label.
+const DEFAULT_CELL: usize = 72;
+const DEFAULT_COLUMNS: usize = 16;
+const DEFAULT_ROWS: usize = 16;
+/// Space between a cell's edge and its labels or glyph, in pixels.
+const MARGIN: usize = 3;
+const GRID: u8 = 208;
+const LABEL: u8 = 128;
+
+/// Which glyphs go on the sheet.
+enum Selection {
+    /// Every glyph, or those with IDs in the inclusive range.
+    Glyphs(Option<(u32, u32)>),
+    /// The glyphs the cmap maps code points in the inclusive range to.
+    CodePoints(u32, u32),
+}
+
+/// Runs `fontinfo sheet <font-file> [-o <file>] [--range <from>-<to>]
+/// [--glyphs <from>-<to>] [--columns <n>] [--rows <n>] [--cell <px>]`.
+pub fn run(program: &str, args: &[String]) {
+    let mut paths = Vec::new();
+    let mut output = "sheet.png".to_string();
+    let mut selection = Selection::Glyphs(None);
+    let mut columns = DEFAULT_COLUMNS;
+    let mut rows = DEFAULT_ROWS;
+    let mut cell = DEFAULT_CELL;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage(program));
+        let count = |value: &String| match value.parse() {
+            Ok(count) if count > 0 => count,
+            _ => usage(program),
+        };
+        match arg.as_str() {
+            "-o" | "--output" => output = value().clone(),
+            "--range" => match parse_range(value(), 16) {
+                Some((from, to)) => selection = Selection::CodePoints(from, to),
+                None => usage(program),
+            },
+            "--glyphs" => match parse_range(value(), 10) {
+                Some(range) => selection = Selection::Glyphs(Some(range)),
+                None => usage(program),
+            },
+            "--columns" => columns = count(value()),
+            "--rows" => rows = count(value()),
+            "--cell" => match count(value()) {
+                // Room for both labels and some glyph between them.
+                size if size >= 4 * (LABEL_HEIGHT + MARGIN) => cell = size,
+                _ => usage(program),
+            },
+            _ if !arg.starts_with('-') => paths.push(arg),
+            _ => usage(program),
+        }
+    }
+    let [font_path] = paths.as_slice() else {
+        usage(program);
+    };
+
+    let font_data = read_font_file(font_path);
+    let face = parse_face(&font_data, font_path);
+    let glyphs = select(&face, &selection);
+    if glyphs.is_empty() {
+        eprintln!("No glyphs in '{}' match the selection", font_path);
+        process::exit(1);
+    }
+
+    let per_page = columns * rows;
+    let pages = glyphs.len().div_ceil(per_page);
+    for (page, glyphs) in glyphs.chunks(per_page).enumerate() {
+        let path = if pages == 1 {
+            output.clone()
+        } else {
+            page_path(&output, page + 1)
+        };
+        // The last page only has as many rows as it needs.
+        let rows = glyphs.len().div_ceil(columns);
+        let png = render_page(&face, glyphs, columns, rows, cell);
+        if let Err(e) = fs::write(&path, png) {
+            eprintln!("Error writing {}: {}", path, e);
+            process::exit(1);
+        }
+        println!(
+            "Wrote {} ({} glyphs, {}×{})",
+            path,
+            glyphs.len(),
+            columns * cell + 1,
+            rows * cell + 1
+        );
+    }
+}
+
+/// The glyphs to draw, in order, each with the code point to label it with.
+fn select(face: &Face, selection: &Selection) -> Vec<(GlyphId, Option<char>)> {
+    let chars = covered_chars(face);
+    match *selection {
+        Selection::CodePoints(from, to) => chars
+            .iter()
+            .filter(|&&c| (from..=to).contains(&(c as u32)))
+            .filter_map(|&c| Some((face.glyph_index(c)?, Some(c))))
+            .collect(),
+        Selection::Glyphs(range) => {
+            // The lowest code point of each glyph.
+            let mut lowest: HashMap<GlyphId, char> = HashMap::new();
+            for &c in &chars {
+                if let Some(glyph) = face.glyph_index(c) {
+                    lowest.entry(glyph).or_insert(c);
+                }
+            }
+            let (from, to) = range.unwrap_or((0, u32::MAX));
+            (0..face.number_of_glyphs())
+                .filter(|&id| (from..=to).contains(&u32::from(id)))
+                .map(|id| (GlyphId(id), lowest.get(&GlyphId(id)).copied()))
+                .collect()
+        }
+    }
+}
+
+/// Draws a grid of cells, each with the glyph ID at the top, the glyph
+/// scaled so the font's ascender to descender fits, and the code point at
+/// the bottom.
+fn render_page(
+    face: &Face,
+    glyphs: &[(GlyphId, Option<char>)],
+    columns: usize,
+    rows: usize,
+    cell: usize,
+) -> Vec<u8> {
+    let width = columns * cell + 1;
+    let height = rows * cell + 1;
+    let mut glyph_canvas = Canvas::new(width, height);
+    let mut label_canvas = Canvas::new(width, height);
+
+    let label_strip = LABEL_HEIGHT + 2 * MARGIN;
+    let area = (cell - 2 * label_strip) as f32;
+    let ascender = f32::from(face.ascender());
+    let extent = (ascender - f32::from(face.descender())).max(1.0);
+    let scale = area / extent;
+
+    for (i, &(glyph, c)) in glyphs.iter().enumerate() {
+        let x = i % columns * cell;
+        let y = i / columns * cell;
+        label_canvas.draw_label(&glyph.0.to_string(), x + MARGIN, y + MARGIN);
+        if let Some(c) = c {
+            let label = format!("U+{:04X}", c as u32);
+            let label_x = x + cell.saturating_sub(label.len() * LABEL_ADVANCE) / 2;
+            label_canvas.draw_label(&label, label_x, y + cell - MARGIN - LABEL_HEIGHT);
+        }
+
+        // Centered on its advance, shrunk if wider than the cell.
+        let advance = f32::from(face.glyph_hor_advance(glyph).unwrap_or(0));
+        let room = (cell - 2 * MARGIN) as f32;
+        let scale = if advance * scale > room {
+            room / advance
+        } else {
+            scale
+        };
+        let left = x as f32 + (cell as f32 - advance * scale) / 2.0;
+        let baseline = (y + label_strip) as f32 + ascender * scale;
+        glyph_canvas.draw_glyph(face, glyph, left, baseline, scale);
+    }
+
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        for x in 0..width {
+            let value = if x % cell == 0 || y % cell == 0 {
+                GRID
+            } else if label_canvas.coverage(x, y) > 0.0 {
+                LABEL
+            } else {
+                ((1.0 - glyph_canvas.coverage(x, y)) * 255.0).round() as u8
+            };
+            pixels.extend_from_slice(&[value; 3]);
+        }
+    }
+    png::encode_rgb(width, height, &pixels)
+}
+
+/// Parses `<from>-<to>` or a single value in the given radix. Hexadecimal
+/// values may start with `U+`.
+fn parse_range(text: &str, radix: u32) -> Option<(u32, u32)> {
+    let parse = |value: &str| {
+        let value = if radix == 16 {
+            value
+                .strip_prefix("U+")
+                .or_else(|| value.strip_prefix("u+"))
+                .unwrap_or(value)
+        } else {
+            value
+        };
+        u32::from_str_radix(value, radix).ok()
+    };
+    let (from, to) = text.split_once('-').unwrap_or((text, text));
+    let (from, to) = (parse(from)?, parse(to)?);
+    (from <= to).then_some((from, to))
+}
+
+/// Numbers a page's file, e.g. `sheet.png` becomes `sheet-2.png`.
+fn page_path(output: &str, page: usize) -> String {
+    let path = Path::new(output);
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("sheet");
+    let name = match path.extension().and_then(|e| e.to_str()) {
+        Some(extension) => format!("{}-{}.{}", stem, page, extension),
+        None => format!("{}-{}", stem, page),
+    };
+    path.with_file_name(name).to_string_lossy().into_owned()
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} sheet <font-file> [options]", program);
+    eprintln!("  -o <file>             Output file (default sheet.png; numbered when");
+    eprintln!("                        the glyphs need several pages)");
+    eprintln!(
+        "  --range <from>-<to>   Only glyphs for code points in the range, e.g. U+0400-U+04FF"
+    );
+    eprintln!("  --glyphs <from>-<to>  Only glyph IDs in the range, e.g. 100-199");
+    eprintln!(
+        "  --columns <n>         Cells per row (default {})",
+        DEFAULT_COLUMNS
+    );
+    eprintln!(
+        "  --rows <n>            Rows per page (default {})",
+        DEFAULT_ROWS
+    );
+    eprintln!(
+        "  --cell <px>           Cell size in pixels (default {})",
+        DEFAULT_CELL
+    );
+    eprintln!("Example: {} sheet font.ttf -o sheet.png", program);
+    process::exit(1);
+}
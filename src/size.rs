@@ -0,0 +1,266 @@
+use crate::footprint::instruction_bytes;
+use crate::reader::Reader;
+use crate::slicing::format_size;
+use crate::tables::TableDirectory;
+use serde::Serialize;
+use std::cmp::Reverse;
+use ttf_parser::{Face, Tag};
+
+/// Tables shown under the largest.
+const LARGEST_SHOWN: usize = 5;
+/// Savings below this aren't worth a suggestion, except unused `cmap`
+/// subtables, which are dead weight at any size.
+const MIN_SAVING: usize = 1024;
+/// TrueType hinting programs and the control values they read.
+const HINTING_TABLES: [&[u8; 4]; 3] = [b"fpgm", b"prep", b"cvt "];
+/// Tables caching hinted advances and extents, only useful with hinting.
+const DEVICE_TABLES: [&[u8; 4]; 3] = [b"hdmx", b"LTSH", b"VDMX"];
+/// Embedded bitmap strikes, monochrome and color.
+const BITMAP_TABLES: [&[u8; 4]; 7] = [
+    b"EBDT", b"EBLC", b"EBSC", b"CBDT", b"CBLC", b"sbix", b"bdat",
+];
+
+/// A table and its share of the file.
+#[derive(Serialize)]
+pub struct TableSize {
+    pub tag: String,
+    pub bytes: usize,
+    /// In percent.
+    pub share: f64,
+}
+
+/// Bytes spent on TrueType hinting.
+#[derive(Serialize)]
+pub struct Hinting {
+    /// `fpgm`, `prep` and `cvt`.
+    pub tables: usize,
+    /// Instructions inside `glyf`.
+    pub glyph_programs: usize,
+    /// `hdmx`, `LTSH` and `VDMX`.
+    pub device_tables: usize,
+}
+
+impl Hinting {
+    pub fn total(&self) -> usize {
+        self.tables + self.glyph_programs + self.device_tables
+    }
+}
+
+/// A `cmap` subtable no current renderer reads, with what its removal
+/// saves.
+#[derive(Serialize)]
+pub struct CmapSubtable {
+    pub platform_id: u16,
+    pub encoding_id: u16,
+    pub format: u16,
+    pub bytes: usize,
+    pub reason: String,
+}
+
+/// Where a font's bytes go, and what could be dropped to shrink it.
+#[derive(Serialize)]
+pub struct SizeReport {
+    pub file_size: usize,
+    /// Largest first.
+    pub largest: Vec<TableSize>,
+    pub hinting: Hinting,
+    pub bitmaps: usize,
+    pub bitmap_tables: Vec<String>,
+    pub unused_cmap_subtables: Vec<CmapSubtable>,
+    /// Advice, largest saving first, e.g. `Removing hinting would save
+    /// ~38.0 KB`.
+    pub suggestions: Vec<String>,
+}
+
+impl SizeReport {
+    pub fn new(face: &Face) -> Self {
+        let directory = TableDirectory::new(face);
+        let length = |tags: &[&[u8; 4]]| -> usize {
+            directory
+                .tables
+                .iter()
+                .filter(|table| tags.iter().any(|t| table.tag.as_bytes() == &t[..]))
+                .map(|table| table.length as usize)
+                .sum()
+        };
+        let has = |tag: &[u8; 4]| face.raw_face().table(Tag::from_bytes(tag)).is_some();
+
+        let mut largest: Vec<TableSize> = directory
+            .tables
+            .iter()
+            .map(|table| TableSize {
+                tag: table.tag.clone(),
+                bytes: table.length as usize,
+                share: table.share,
+            })
+            .collect();
+        largest.sort_by_key(|table| Reverse(table.bytes));
+        largest.truncate(LARGEST_SHOWN);
+
+        let hinting = Hinting {
+            tables: length(&HINTING_TABLES),
+            glyph_programs: instruction_bytes(face),
+            device_tables: length(&DEVICE_TABLES),
+        };
+        let bitmap_tables = directory
+            .tables
+            .iter()
+            .filter(|table| BITMAP_TABLES.iter().any(|t| table.tag.as_bytes() == &t[..]))
+            .map(|table| table.tag.trim_end().to_string())
+            .collect();
+        let unused_cmap_subtables = unused_cmap_subtables(face).unwrap_or_default();
+
+        let mut suggestions: Vec<(usize, String)> = Vec::new();
+        if hinting.total() > 0 {
+            suggestions.push((
+                hinting.total(),
+                format!(
+                    "Removing hinting would save ~{}; high-resolution screens and macOS ignore it",
+                    format_size(hinting.total())
+                ),
+            ));
+        }
+        let bitmaps = length(&BITMAP_TABLES);
+        // Color fonts made only of bitmaps have nothing to fall back to.
+        if bitmaps > 0 && (has(b"glyf") || has(b"CFF ") || has(b"CFF2")) {
+            suggestions.push((
+                bitmaps,
+                format!(
+                    "Dropping the embedded bitmaps would save ~{}; the outlines render without them",
+                    format_size(bitmaps)
+                ),
+            ));
+        }
+        if let Some(post) = face.raw_face().table(Tag::from_bytes(b"post"))
+            && Reader::new(post).read_u32() == Some(0x00020000)
+            && post.len() > 32
+        {
+            suggestions.push((
+                post.len() - 32,
+                format!(
+                    "Switching post to format 3 would save ~{} of glyph names, which PDF text extraction may use",
+                    format_size(post.len() - 32)
+                ),
+            ));
+        }
+        let gpos_kerns = face.tables().gpos.is_some_and(|gpos| {
+            gpos.features
+                .into_iter()
+                .any(|f| f.tag == Tag::from_bytes(b"kern"))
+        });
+        if gpos_kerns && has(b"kern") {
+            let kern = length(&[b"kern"]);
+            suggestions.push((
+                kern,
+                format!(
+                    "The kern table repeats GPOS kerning; dropping it would save ~{}, though apps without GPOS support lose kerning",
+                    format_size(kern)
+                ),
+            ));
+        }
+        suggestions.retain(|&(saving, _)| saving >= MIN_SAVING);
+        if !unused_cmap_subtables.is_empty() {
+            let bytes = unused_cmap_subtables.iter().map(|s| s.bytes).sum();
+            let count = unused_cmap_subtables.len();
+            suggestions.push((
+                bytes,
+                format!(
+                    "This font ships {} unused cmap subtable{}; dropping {} would save ~{}",
+                    count,
+                    if count == 1 { "" } else { "s" },
+                    if count == 1 { "it" } else { "them" },
+                    format_size(bytes)
+                ),
+            ));
+        }
+        suggestions.sort_by_key(|&(saving, _)| Reverse(saving));
+
+        SizeReport {
+            file_size: directory.file_size,
+            largest,
+            hinting,
+            bitmaps,
+            bitmap_tables,
+            unused_cmap_subtables,
+            suggestions: suggestions.into_iter().map(|(_, advice)| advice).collect(),
+        }
+    }
+}
+
+/// Subtables of the deprecated ISO platform, Macintosh subtables in a font
+/// that has Unicode ones, and byte-for-byte copies of an earlier subtable
+/// stored separately. Records sharing a subtable with one that stays cost
+/// nothing and aren't listed.
+fn unused_cmap_subtables(face: &Face) -> Option<Vec<CmapSubtable>> {
+    let cmap = face.raw_face().table(Tag::from_bytes(b"cmap"))?;
+    let mut r = Reader::new(cmap);
+    r.skip(2); // version
+    let count = r.read_u16()?;
+    let mut records = Vec::new();
+    for _ in 0..count {
+        let platform_id = r.read_u16()?;
+        let encoding_id = r.read_u16()?;
+        let offset = r.read_u32()? as usize;
+        let mut s = Reader::at(cmap, offset);
+        let format = s.read_u16()?;
+        let length = match format {
+            0 | 2 | 4 | 6 => usize::from(s.read_u16()?),
+            14 => s.read_u32()? as usize,
+            _ => {
+                s.skip(2); // reserved
+                s.read_u32()? as usize
+            }
+        };
+        let bytes = cmap.get(offset..offset.checked_add(length)?)?;
+        records.push((platform_id, encoding_id, format, offset, bytes));
+    }
+
+    let unicode = records.iter().any(|&(platform, encoding, ..)| {
+        platform == 0 || (platform == 3 && matches!(encoding, 1 | 10))
+    });
+    let mut unused = Vec::new();
+    let mut kept: Vec<(u16, u16, usize, &[u8])> = Vec::new();
+    for &(platform_id, encoding_id, format, offset, bytes) in &records {
+        let reason = if platform_id == 2 {
+            Some("deprecated ISO platform".to_string())
+        } else if platform_id == 1 && unicode {
+            Some("Macintosh encoding, which renderers skip for the Unicode subtables".to_string())
+        } else {
+            kept.iter()
+                .find(|&&(.., other_offset, other)| other_offset != offset && other == bytes)
+                .map(|&(platform, encoding, ..)| {
+                    format!("copy of the ({},{}) subtable", platform, encoding)
+                })
+        };
+        match reason {
+            Some(reason) => unused.push((offset, reason, platform_id, encoding_id, format, bytes)),
+            None => kept.push((platform_id, encoding_id, offset, bytes)),
+        }
+    }
+    // Each freed subtable is listed once, under its first record.
+    let mut freed = Vec::new();
+    Some(
+        unused
+            .into_iter()
+            .filter(|&(offset, ..)| {
+                let shared = kept
+                    .iter()
+                    .any(|&(.., kept_offset, _)| kept_offset == offset);
+                if shared || freed.contains(&offset) {
+                    return false;
+                }
+                freed.push(offset);
+                true
+            })
+            .map(
+                |(_, reason, platform_id, encoding_id, format, bytes)| CmapSubtable {
+                    platform_id,
+                    encoding_id,
+                    format,
+                    bytes: bytes.len(),
+                    reason,
+                },
+            )
+            .collect(),
+    )
+}
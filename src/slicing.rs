@@ -0,0 +1,143 @@
+use crate::get_name;
+use crate::variations::named_instances;
+use ttf_parser::{Face, Tag};
+
+/// Tables that only exist to make a font variable and are dropped when
+/// instancing a static font.
+const VARIATION_TABLES: [&[u8; 4]; 7] = [
+    b"fvar", b"gvar", b"avar", b"cvar", b"HVAR", b"VVAR", b"MVAR",
+];
+
+/// A static instance pinned at one target weight.
+pub struct StaticSlice {
+    pub weight: f32,
+    /// Subfamily name of the named instance at this weight, if any.
+    pub instance_name: Option<String>,
+    pub estimated_size: usize,
+}
+
+/// Size estimates for serving a set of target weights from a variable font.
+pub struct SlicePlan {
+    /// The `wght` axis as (min, default, max).
+    pub axis: (f32, f32, f32),
+    pub font_size: usize,
+    pub statics: Vec<StaticSlice>,
+    /// The smallest `wght` subrange covering every target, with its size.
+    pub subrange: (f32, f32),
+    pub subrange_size: usize,
+    /// Tags of axes other than `wght`, which both approaches pin at their
+    /// defaults.
+    pub pinned_axes: Vec<String>,
+}
+
+impl SlicePlan {
+    /// Plans slices for the given target weights. Fails for fonts without a
+    /// `wght` axis or targets outside of it.
+    pub fn new(face: &Face, targets: &[f32]) -> Result<SlicePlan, String> {
+        let axes = face.variation_axes();
+        let Some((index, wght)) = axes
+            .into_iter()
+            .enumerate()
+            .find(|(_, a)| a.tag == Tag::from_bytes(b"wght"))
+        else {
+            return Err("font has no wght axis".to_string());
+        };
+        let (min, def, max) = (wght.min_value, wght.def_value, wght.max_value);
+
+        if let Some(target) = targets.iter().find(|&&t| t < min || t > max) {
+            return Err(format!(
+                "target weight {} is outside the wght axis {}–{}",
+                target, min, max
+            ));
+        }
+
+        let (font_size, variation_size) = table_sizes(face);
+        let static_size = font_size - variation_size;
+
+        let instances = named_instances(face);
+        let statics = targets
+            .iter()
+            .map(|&weight| StaticSlice {
+                weight,
+                instance_name: instances
+                    .iter()
+                    .find(|i| i.coordinates.get(index) == Some(&weight))
+                    .and_then(|i| get_name(face, i.subfamily_name_id)),
+                estimated_size: static_size,
+            })
+            .collect();
+
+        let lo = targets.iter().copied().fold(f32::MAX, f32::min);
+        let hi = targets.iter().copied().fold(f32::MIN, f32::max);
+        // Variation data is assumed to be spread evenly over the normalized
+        // axis, so a subrange keeps a proportional share of it.
+        let full_span = normalize(max, min, def, max) - normalize(min, min, def, max);
+        let span = normalize(hi, min, def, max) - normalize(lo, min, def, max);
+        let share = if full_span > 0.0 {
+            span / full_span
+        } else {
+            0.0
+        };
+        let subrange_size = static_size + (variation_size as f32 * share).round() as usize;
+
+        Ok(SlicePlan {
+            axis: (min, def, max),
+            font_size,
+            statics,
+            subrange: (lo, hi),
+            subrange_size,
+            pinned_axes: axes
+                .into_iter()
+                .filter(|a| a.tag != wght.tag)
+                .map(|a| a.tag.to_string())
+                .collect(),
+        })
+    }
+
+    pub fn statics_size(&self) -> usize {
+        self.statics.iter().map(|s| s.estimated_size).sum()
+    }
+
+    /// True when a single subrange font is smaller than the static set.
+    pub fn prefers_subrange(&self) -> bool {
+        self.statics.len() > 1 && self.subrange_size < self.statics_size()
+    }
+}
+
+/// Returns the size of the font and of its variation tables, including the
+/// table directory entries.
+fn table_sizes(face: &Face) -> (usize, usize) {
+    let raw = face.raw_face();
+    let mut total = 12;
+    let mut variation = 0;
+    for record in raw.table_records {
+        let size = 16 + record.length as usize;
+        total += size;
+        if VARIATION_TABLES
+            .iter()
+            .any(|tag| record.tag == Tag::from_bytes(tag))
+        {
+            variation += size;
+        }
+    }
+    (total, variation)
+}
+
+fn normalize(value: f32, min: f32, default: f32, max: f32) -> f32 {
+    if value < default {
+        if default > min {
+            (value - default) / (default - min)
+        } else {
+            0.0
+        }
+    } else if max > default {
+        (value - default) / (max - default)
+    } else {
+        0.0
+    }
+}
+
+/// Formats a byte count as KB with one decimal.
+pub fn format_size(bytes: usize) -> String {
+    format!("{:.1} KB", bytes as f32 / 1024.0)
+}
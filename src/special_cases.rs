@@ -0,0 +1,185 @@
+use crate::layout;
+use serde::Serialize;
+use ttf_parser::opentype_layout::LayoutTable;
+use ttf_parser::{Face, Tag};
+
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Pass,
+    Fail,
+    /// The check depends on a feature the font doesn't have.
+    NotApplicable,
+}
+
+impl Status {
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::NotApplicable => "n/a",
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    /// Why the check failed or doesn't apply, or what a pass found;
+    /// usually empty for passes.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub detail: String,
+}
+
+/// Pass/fail checks for letters with language-specific casing or shaping:
+/// the German sharp s, Dutch IJ and Catalan l·l.
+#[derive(Serialize)]
+pub struct SpecialCaseReport {
+    pub checks: Vec<Check>,
+}
+
+impl SpecialCaseReport {
+    /// Returns `None` for fonts without basic Latin letters.
+    pub fn new(face: &Face) -> Option<Self> {
+        face.glyph_index('a')?;
+        let gsub = face.tables().gsub;
+        let gsub = gsub.as_ref();
+        let checks = vec![
+            coverage("German capital sharp s (ẞ)", face, &['ẞ']),
+            // Uppercasing ß gives SS, so small caps must substitute ß
+            // itself rather than rely on the case mapping.
+            in_feature("German ß in small caps (smcp)", face, gsub, b"smcp", 'ß'),
+            in_feature(
+                "German ẞ in caps to small caps (c2sc)",
+                face,
+                gsub,
+                b"c2sc",
+                'ẞ',
+            ),
+            coverage("Dutch IJ digraph (Ĳ ĳ)", face, &['Ĳ', 'ĳ']),
+            in_locl(
+                "Dutch IJ under locl (NLD)",
+                face,
+                gsub,
+                b"NLD ",
+                &['i', 'j', 'í'],
+            ),
+            coverage("Catalan l·l (Ŀ ŀ ·)", face, &['Ŀ', 'ŀ', '·']),
+            in_locl(
+                "Catalan l·l under locl (CAT)",
+                face,
+                gsub,
+                b"CAT ",
+                &['l', 'L', '·'],
+            ),
+        ];
+        Some(SpecialCaseReport { checks })
+    }
+
+    pub fn passed(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|c| c.status == Status::Pass)
+            .count()
+    }
+
+    /// Checks that apply to the font, passed or not.
+    pub fn applicable(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|c| c.status != Status::NotApplicable)
+            .count()
+    }
+}
+
+fn check(name: &'static str, status: Status, detail: String) -> Check {
+    Check {
+        name,
+        status,
+        detail,
+    }
+}
+
+/// Passes when every one of `chars` is in the cmap.
+fn coverage(name: &'static str, face: &Face, chars: &[char]) -> Check {
+    let missing: Vec<String> = chars
+        .iter()
+        .filter(|&&c| face.glyph_index(c).is_none())
+        .map(|c| format!("U+{:04X}", *c as u32))
+        .collect();
+    if missing.is_empty() {
+        check(name, Status::Pass, String::new())
+    } else {
+        check(
+            name,
+            Status::Fail,
+            format!("missing {}", missing.join(", ")),
+        )
+    }
+}
+
+/// Passes when the GSUB `feature` substitutes `c`; doesn't apply to fonts
+/// without the feature.
+fn in_feature(
+    name: &'static str,
+    face: &Face,
+    gsub: Option<&LayoutTable>,
+    feature: &[u8; 4],
+    c: char,
+) -> Check {
+    let lookups = gsub.map(|gsub| layout::feature_lookups(gsub, Tag::from_bytes(feature)));
+    let (Some(gsub), Some(lookups)) = (gsub, lookups.filter(|l| !l.is_empty())) else {
+        let tag = Tag::from_bytes(feature);
+        return check(name, Status::NotApplicable, format!("no {} feature", tag));
+    };
+    match face.glyph_index(c) {
+        Some(glyph) if layout::gsub_covers(gsub, &lookups, glyph) => {
+            check(name, Status::Pass, String::new())
+        }
+        Some(_) => check(
+            name,
+            Status::Fail,
+            format!("{} leaves {} unchanged", Tag::from_bytes(feature), c),
+        ),
+        None => check(name, Status::Fail, format!("{} not covered", c)),
+    }
+}
+
+/// Passes when the `latn` language system `lang_sys` has a `locl` that
+/// substitutes any of `chars`.
+fn in_locl(
+    name: &'static str,
+    face: &Face,
+    gsub: Option<&LayoutTable>,
+    lang_sys: &[u8; 4],
+    chars: &[char],
+) -> Check {
+    let lookups = gsub.and_then(|gsub| {
+        layout::lang_sys_lookups(
+            gsub,
+            Tag::from_bytes(b"latn"),
+            Tag::from_bytes(lang_sys),
+            Tag::from_bytes(b"locl"),
+        )
+    });
+    let (Some(gsub), Some(lookups)) = (gsub, lookups) else {
+        return check(name, Status::Fail, "no language system".to_string());
+    };
+    if lookups.is_empty() {
+        return check(name, Status::Fail, "no locl feature".to_string());
+    }
+    let substituted = chars
+        .iter()
+        .filter_map(|&c| face.glyph_index(c))
+        .any(|glyph| layout::gsub_covers(gsub, &lookups, glyph));
+    if substituted {
+        check(name, Status::Pass, String::new())
+    } else {
+        check(
+            name,
+            Status::Fail,
+            "locl leaves the letters unchanged".to_string(),
+        )
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{ReportOptions, parse_face, print_font_info, read_font_file};
+use fontinfo::config::Config;
+use fontinfo::features::FeatureRegistry;
+use fontinfo::i18n::{Catalog, Lang};
+use fontinfo::layout::{feature_lookups, gsub_covers};
+use fontinfo::ligatures::LigatureReport;
+use fontinfo::report::Section;
+use fontinfo::unicode::covered_chars;
+use fontinfo::{base64, get_name};
+use std::fmt::Write;
+use std::fs;
+use std::process;
+use ttf_parser::{Face, Tag, name_id};
+
+pub const DEFAULT_TEXT: &str = "The quick brown fox jumps over the lazy dog";
+/// Waterfall sizes in CSS pixels, or pixels per em.
+pub const SIZES: [u32; 7] = [12, 16, 24, 36, 48, 72, 96];
+/// Sample characters or ligatures shown per feature.
+const FEATURE_SAMPLES: usize = 16;
+/// The `font-family` the embedded font is declared under, distinct from
+/// its real family so an installed copy can't stand in for it.
+const FAMILY: &str = "fontinfo-specimen";
+
+const STYLE: &str = "\
+body { font-family: system-ui, sans-serif; margin: 2em; color: #222; }
+h1 { margin-bottom: 0.2em; }
+h2 { border-bottom: 1px solid #ccc; margin-top: 2em; }
+.sample { font-family: \"fontinfo-specimen\", \"Adobe NotDef\"; }
+.waterfall div { white-space: nowrap; overflow: hidden; margin: 0.2em 0; }
+.size { display: inline-block; width: 4em; color: #888; font-size: 12px; }
+.grid { display: flex; flex-wrap: wrap; }
+.cell { width: 4.5em; border: 1px solid #ddd; margin: -1px 0 0 -1px; text-align: center; }
+.cell .sample { font-size: 32px; line-height: 1.5; display: block; }
+.cell .cp { font-size: 10px; color: #888; }
+.feature { margin: 1em 0; }
+.feature .sample { font-size: 28px; }
+.label { display: inline-block; width: 3em; color: #888; font-size: 12px; }
+pre { font-size: 12px; line-height: 1.2; }
+";
+
+/// Runs `fontinfo specimen <font-file> [-o <file>] [--text <text>]`.
+pub fn run(program: &str, args: &[String]) {
+    let mut paths = Vec::new();
+    let mut output = "specimen.html".to_string();
+    let mut text = DEFAULT_TEXT.to_string();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage(program));
+        match arg.as_str() {
+            "-o" | "--output" => output = value().clone(),
+            "--text" => text = value().clone(),
+            _ if !arg.starts_with('-') => paths.push(arg),
+            _ => usage(program),
+        }
+    }
+    let [font_path] = paths.as_slice() else {
+        usage(program);
+    };
+
+    let font_data = read_font_file(font_path);
+    let face = parse_face(&font_data, font_path);
+    let config = match Config::load(None) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    let lang = Lang::from_env();
+
+    let mut report = Vec::new();
+    let options = ReportOptions {
+        sections: Section::ALL.to_vec(),
+        terse: false,
+        lang,
+        json: false,
+        redact: false,
+    };
+    if let Err(e) = print_font_info(&mut report, &face, font_path, &config, &options) {
+        eprintln!("Error generating the report for '{}': {}", font_path, e);
+        process::exit(1);
+    }
+
+    let catalog = Catalog::new(lang);
+    let features = FeatureRegistry::new(lang, &catalog, &config);
+    let chars: Vec<char> = covered_chars(&face)
+        .into_iter()
+        .filter(|c| !c.is_control() && !c.is_whitespace())
+        .collect();
+    let demos = feature_demos(&face);
+
+    let mut html = String::new();
+    let title = title(&face);
+    let _ = writeln!(
+        html,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">"
+    );
+    let _ = writeln!(html, "<title>{}</title>", escape(&title));
+    let _ = writeln!(html, "<style>\n@font-face {{");
+    let _ = writeln!(html, "  font-family: \"{}\";", FAMILY);
+    let (mime, format) = if font_data.starts_with(b"OTTO") {
+        ("font/otf", "opentype")
+    } else {
+        ("font/ttf", "truetype")
+    };
+    let _ = writeln!(
+        html,
+        "  src: url(\"data:{};base64,{}\") format(\"{}\");\n}}",
+        mime,
+        base64::encode(&font_data),
+        format
+    );
+    let _ = writeln!(html, "{}</style>\n</head>\n<body>", STYLE);
+    let _ = writeln!(html, "<h1>{}</h1>", escape(&title));
+
+    html.push_str("<h2>Waterfall</h2>\n<div class=\"waterfall\">\n");
+    for size in SIZES {
+        let _ = writeln!(
+            html,
+            "<div><span class=\"size\">{}px</span><span class=\"sample\" style=\"font-size: {}px\">{}</span></div>",
+            size,
+            size,
+            escape(&text)
+        );
+    }
+    html.push_str("</div>\n");
+
+    let _ = writeln!(html, "<h2>Character Set ({})</h2>", chars.len());
+    html.push_str("<div class=\"grid\">\n");
+    for c in &chars {
+        let _ = writeln!(
+            html,
+            "<div class=\"cell\"><span class=\"sample\">&#x{:X};</span><span class=\"cp\">U+{:04X}</span></div>",
+            u32::from(*c),
+            u32::from(*c)
+        );
+    }
+    html.push_str("</div>\n");
+
+    if !demos.is_empty() {
+        html.push_str("<h2>Features</h2>\n");
+    }
+    for (tag, sample) in &demos {
+        let _ = writeln!(
+            html,
+            "<div class=\"feature\">\n<h3>{} — {}</h3>",
+            tag,
+            escape(features.describe(tag))
+        );
+        for (label, on) in [("off", 0), ("on", 1)] {
+            let _ = writeln!(
+                html,
+                "<div><span class=\"label\">{}</span><span class=\"sample\" style=\"font-feature-settings: '{}' {}\">{}</span></div>",
+                label,
+                tag,
+                on,
+                escape(sample)
+            );
+        }
+        html.push_str("</div>\n");
+    }
+
+    html.push_str("<h2>Report</h2>\n<pre>");
+    html.push_str(&escape(&String::from_utf8_lossy(&report)));
+    html.push_str("</pre>\n</body>\n</html>\n");
+
+    if let Err(e) = fs::write(&output, html) {
+        eprintln!("Error writing '{}': {}", output, e);
+        process::exit(1);
+    }
+    println!(
+        "Wrote {} ({} characters, {} feature demos)",
+        output,
+        chars.len(),
+        demos.len()
+    );
+}
+
+fn title(face: &Face) -> String {
+    let family = get_name(face, name_id::TYPOGRAPHIC_FAMILY)
+        .or_else(|| get_name(face, name_id::FAMILY))
+        .unwrap_or_else(|| "Untitled".to_string());
+    match get_name(face, name_id::TYPOGRAPHIC_SUBFAMILY)
+        .or_else(|| get_name(face, name_id::SUBFAMILY))
+    {
+        Some(subfamily) => format!("{} {}", family, subfamily),
+        None => family,
+    }
+}
+
+/// A sample for each GSUB feature, in feature list order: the ligatures
+/// it forms for ligature features, otherwise the characters whose glyphs
+/// it substitutes. Features that touch no encoded character are left out,
+/// since there is nothing to type for them.
+fn feature_demos(face: &Face) -> Vec<(String, String)> {
+    let Some(gsub) = face.tables().gsub else {
+        return Vec::new();
+    };
+    let ligatures = LigatureReport::new(face);
+    let chars = covered_chars(face);
+
+    let mut tags: Vec<Tag> = Vec::new();
+    for feature in gsub.features {
+        if !tags.contains(&feature.tag) {
+            tags.push(feature.tag);
+        }
+    }
+    tags.into_iter()
+        .filter_map(|tag| {
+            let name = tag.to_string();
+            let ligature_samples: Vec<String> = ligatures
+                .features
+                .iter()
+                .filter(|feature| feature.feature == name)
+                .flat_map(|feature| &feature.ligatures)
+                .filter(|ligature| {
+                    ligature
+                        .components
+                        .iter()
+                        .all(|component| component.chars().count() == 1)
+                })
+                .map(|ligature| ligature.components.concat())
+                .take(FEATURE_SAMPLES)
+                .collect();
+            let sample = if ligature_samples.is_empty() {
+                let lookups = feature_lookups(&gsub, tag);
+                chars
+                    .iter()
+                    .filter(|c| !c.is_control() && !c.is_whitespace())
+                    .filter(|&&c| {
+                        face.glyph_index(c)
+                            .is_some_and(|glyph| gsub_covers(&gsub, &lookups, glyph))
+                    })
+                    .take(FEATURE_SAMPLES)
+                    .collect::<String>()
+            } else {
+                ligature_samples.join(" ")
+            };
+            (!sample.is_empty()).then_some((name, sample))
+        })
+        .collect()
+}
+
+/// Escapes text for HTML content and attribute values.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} specimen <font-file> [options]", program);
+    eprintln!("  -o <file>      Output file (default specimen.html)");
+    eprintln!(
+        "  --text <text>  Waterfall text (default \"{}\")",
+        DEFAULT_TEXT
+    );
+    process::exit(1);
+}
@@ -0,0 +1,129 @@
+use crate::cff::PrivateHints;
+use crate::raster::Raster;
+use serde::Serialize;
+use ttf_parser::Face;
+
+/// Where a stem width comes from.
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StemSource {
+    /// The StdVW or StdHW value of a CFF Private DICT.
+    Cff,
+    /// Measured across the outlines of H, n and O.
+    Outlines,
+}
+
+impl StemSource {
+    pub fn description(self) -> &'static str {
+        match self {
+            StemSource::Cff => "CFF Private DICT",
+            StemSource::Outlines => "measured from outlines",
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct Stem {
+    /// Thickness in font units.
+    pub width: f32,
+    pub source: StemSource,
+}
+
+/// One stroke thickness measured across a glyph's outline.
+#[derive(Serialize)]
+pub struct Measurement {
+    pub c: char,
+    /// The stroke it crosses, e.g. `stem` or `crossbar`.
+    pub stroke: &'static str,
+    pub width: f32,
+}
+
+/// Typical vertical and horizontal stroke thicknesses, for judging how
+/// fonts pair.
+#[derive(Serialize)]
+pub struct StemReport {
+    pub vertical: Option<Stem>,
+    pub horizontal: Option<Stem>,
+    pub measurements: Vec<Measurement>,
+}
+
+impl StemReport {
+    /// Returns `None` when the font declares no stem widths and has none of
+    /// H, n and O to measure.
+    pub fn new(face: &Face) -> Option<Self> {
+        let measurements = measure(face);
+        let average = |strokes: &[&str]| {
+            let widths: Vec<f32> = measurements
+                .iter()
+                .filter(|m| strokes.contains(&m.stroke))
+                .map(|m| m.width)
+                .collect();
+            (!widths.is_empty()).then(|| Stem {
+                width: widths.iter().sum::<f32>() / widths.len() as f32,
+                source: StemSource::Outlines,
+            })
+        };
+        let declared = |width: Option<f32>| {
+            width.map(|width| Stem {
+                width,
+                source: StemSource::Cff,
+            })
+        };
+        let hints = PrivateHints::new(face);
+        let vertical =
+            declared(hints.as_ref().and_then(|hints| hints.std_vw)).or_else(|| average(&["stem"]));
+        let horizontal = declared(hints.as_ref().and_then(|hints| hints.std_hw))
+            .or_else(|| average(&["crossbar", "top", "bottom"]));
+        if vertical.is_none() && horizontal.is_none() {
+            return None;
+        }
+        Some(StemReport {
+            vertical,
+            horizontal,
+            measurements,
+        })
+    }
+}
+
+/// Measures the straight stems of H and n a quarter and a third of the way
+/// up, clear of the crossbar and the arch, H's crossbar and the top and
+/// bottom of O through their centers, and O's sides at half height.
+fn measure(face: &Face) -> Vec<Measurement> {
+    let mut measurements = Vec::new();
+    let mut add = |c: char, stroke: &'static str, spans: &[(f32, f32)]| {
+        measurements.extend(spans.iter().map(|&(start, end)| Measurement {
+            c,
+            stroke,
+            width: end - start,
+        }));
+    };
+    let raster = |c: char| Raster::new(face, face.glyph_index(c)?);
+
+    if let Some(h) = raster('H') {
+        let stems = h.spans(h.height_at(0.25), true);
+        if stems.len() == 2 {
+            add('H', "stem", &stems);
+        }
+        let crossbar = h.spans(0.0, false);
+        if crossbar.len() == 1 {
+            add('H', "crossbar", &crossbar);
+        }
+    }
+    if let Some(n) = raster('n') {
+        let stems = n.spans(n.height_at(0.33), true);
+        if stems.len() == 2 {
+            add('n', "stem", &stems);
+        }
+    }
+    if let Some(o) = raster('O') {
+        let sides = o.spans(o.height_at(0.5), true);
+        if sides.len() == 2 {
+            add('O', "side", &sides);
+        }
+        if let [bottom, top] = o.spans(0.0, false)[..] {
+            add('O', "bottom", &[bottom]);
+            add('O', "top", &[top]);
+        }
+    }
+    measurements
+}
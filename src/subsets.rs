@@ -0,0 +1,309 @@
+use crate::glyph::{components, glyf_range};
+use crate::layout::gsub_closure;
+use crate::unicode::covered_chars;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use ttf_parser::{Face, GlyphId, Tag};
+
+/// A script group and its `unicode-range`, as Google Fonts splits fonts.
+pub struct Group {
+    pub name: &'static str,
+    pub ranges: &'static [(u32, u32)],
+}
+
+pub const GROUPS: [Group; 7] = [
+    Group {
+        name: "latin",
+        ranges: &[
+            (0x0000, 0x00FF),
+            (0x0131, 0x0131),
+            (0x0152, 0x0153),
+            (0x02BB, 0x02BC),
+            (0x02C6, 0x02C6),
+            (0x02DA, 0x02DA),
+            (0x02DC, 0x02DC),
+            (0x0304, 0x0304),
+            (0x0308, 0x0308),
+            (0x0329, 0x0329),
+            (0x2000, 0x206F),
+            (0x20AC, 0x20AC),
+            (0x2122, 0x2122),
+            (0x2191, 0x2191),
+            (0x2193, 0x2193),
+            (0x2212, 0x2212),
+            (0x2215, 0x2215),
+            (0xFEFF, 0xFEFF),
+            (0xFFFD, 0xFFFD),
+        ],
+    },
+    Group {
+        name: "latin-ext",
+        ranges: &[
+            (0x0100, 0x02BA),
+            (0x02BD, 0x02C5),
+            (0x02C7, 0x02CC),
+            (0x02CE, 0x02D7),
+            (0x02DD, 0x02FF),
+            (0x0304, 0x0304),
+            (0x0308, 0x0308),
+            (0x0329, 0x0329),
+            (0x1D00, 0x1DBF),
+            (0x1E00, 0x1E9F),
+            (0x1EF2, 0x1EFF),
+            (0x2020, 0x2020),
+            (0x20A0, 0x20AB),
+            (0x20AD, 0x20C0),
+            (0x2113, 0x2113),
+            (0x2C60, 0x2C7F),
+            (0xA720, 0xA7FF),
+        ],
+    },
+    Group {
+        name: "cyrillic",
+        ranges: &[
+            (0x0301, 0x0301),
+            (0x0400, 0x045F),
+            (0x0490, 0x0491),
+            (0x04B0, 0x04B1),
+            (0x2116, 0x2116),
+        ],
+    },
+    Group {
+        name: "cyrillic-ext",
+        ranges: &[
+            (0x0460, 0x052F),
+            (0x1C80, 0x1C8A),
+            (0x20B4, 0x20B4),
+            (0x2DE0, 0x2DFF),
+            (0xA640, 0xA69F),
+            (0xFE2E, 0xFE2F),
+        ],
+    },
+    Group {
+        name: "greek",
+        ranges: &[
+            (0x0370, 0x0377),
+            (0x037A, 0x037F),
+            (0x0384, 0x038A),
+            (0x038C, 0x038C),
+            (0x038E, 0x03A1),
+            (0x03A3, 0x03FF),
+        ],
+    },
+    Group {
+        name: "greek-ext",
+        ranges: &[(0x1F00, 0x1FFF)],
+    },
+    Group {
+        name: "vietnamese",
+        ranges: &[
+            (0x0102, 0x0103),
+            (0x0110, 0x0111),
+            (0x0128, 0x0129),
+            (0x0168, 0x0169),
+            (0x01A0, 0x01A1),
+            (0x01AF, 0x01B0),
+            (0x0300, 0x0301),
+            (0x0303, 0x0304),
+            (0x0308, 0x0309),
+            (0x0323, 0x0323),
+            (0x0329, 0x0329),
+            (0x1EA0, 0x1EF9),
+            (0x20AB, 0x20AB),
+        ],
+    },
+];
+
+/// Tables a subset keeps whole, since they don't grow with the glyph
+/// count. The rest shrink in proportion to the glyphs kept, except `glyf`,
+/// which is measured glyph by glyph.
+const SHARED_TABLES: [&[u8; 4]; 13] = [
+    b"head", b"hhea", b"vhea", b"maxp", b"OS/2", b"name", b"fpgm", b"prep", b"cvt ", b"gasp",
+    b"fvar", b"avar", b"STAT",
+];
+
+pub fn group(name: &str) -> Option<&'static Group> {
+    GROUPS.iter().find(|group| group.name == name)
+}
+
+/// One subset of the font for a script group.
+pub struct Subset {
+    pub group: &'static Group,
+    /// The font's code points in the group.
+    pub code_points: Vec<char>,
+    /// Glyphs the subset needs: `.notdef`, the code points' glyphs and
+    /// everything GSUB and composites reach from them.
+    pub glyphs: usize,
+    /// Before compression.
+    pub estimated_size: usize,
+}
+
+/// How the font splits into per-script subsets for `unicode-range`
+/// delivery.
+pub struct SubsetPlan {
+    pub font_size: usize,
+    pub glyph_count: u16,
+    pub subsets: Vec<Subset>,
+    /// Code points the font maps that no subset includes.
+    pub left_out: usize,
+}
+
+impl SubsetPlan {
+    pub fn new(face: &Face, groups: &[&'static Group]) -> Self {
+        let chars = covered_chars(face);
+        let sizes = TableSizes::new(face);
+        let subsets = groups
+            .iter()
+            .map(|&group| {
+                let code_points: Vec<char> = chars
+                    .iter()
+                    .copied()
+                    .filter(|&c| in_group(group, c))
+                    .collect();
+                let glyphs = closure(face, &code_points);
+                Subset {
+                    group,
+                    estimated_size: sizes.estimate(face, &glyphs),
+                    glyphs: glyphs.len(),
+                    code_points,
+                }
+            })
+            .collect();
+        SubsetPlan {
+            font_size: sizes.total,
+            glyph_count: face.number_of_glyphs(),
+            subsets,
+            left_out: chars
+                .iter()
+                .filter(|&&c| !groups.iter().any(|group| in_group(group, c)))
+                .count(),
+        }
+    }
+
+    /// One `@font-face` rule per subset, with the font's covered code
+    /// points as the `unicode-range` and `<stem>-<group>.woff2` as the
+    /// file.
+    pub fn css(&self, face: &Face, family: &str, stem: &str) -> String {
+        let style = if face.is_italic() { "italic" } else { "normal" };
+        let mut css = String::new();
+        for subset in &self.subsets {
+            if subset.code_points.is_empty() {
+                continue;
+            }
+            if !css.is_empty() {
+                css.push('\n');
+            }
+            let _ = writeln!(css, "/* {} */", subset.group.name);
+            css.push_str("@font-face {\n");
+            let _ = writeln!(css, "  font-family: \"{}\";", family);
+            let _ = writeln!(css, "  font-style: {};", style);
+            let _ = writeln!(css, "  font-weight: {};", face.weight().to_number());
+            css.push_str("  font-display: swap;\n");
+            let _ = writeln!(
+                css,
+                "  src: url(\"{}-{}.woff2\") format(\"woff2\");",
+                stem, subset.group.name
+            );
+            let _ = writeln!(
+                css,
+                "  unicode-range: {};",
+                unicode_range(&subset.code_points)
+            );
+            css.push_str("}\n");
+        }
+        css
+    }
+}
+
+fn in_group(group: &Group, c: char) -> bool {
+    let c = u32::from(c);
+    group
+        .ranges
+        .iter()
+        .any(|&(start, end)| (start..=end).contains(&c))
+}
+
+fn closure(face: &Face, code_points: &[char]) -> BTreeSet<GlyphId> {
+    let mut glyphs: BTreeSet<GlyphId> = code_points
+        .iter()
+        .filter_map(|&c| face.glyph_index(c))
+        .collect();
+    glyphs.insert(GlyphId(0));
+    if let Some(gsub) = face.tables().gsub {
+        gsub_closure(&gsub, &mut glyphs);
+    }
+    let mut pending: Vec<GlyphId> = glyphs.iter().copied().collect();
+    while let Some(glyph) = pending.pop() {
+        for component in components(face, glyph).into_iter().map(GlyphId) {
+            if glyphs.insert(component) {
+                pending.push(component);
+            }
+        }
+    }
+    glyphs
+}
+
+/// Code points as `U+XXXX` or `U+XXXX-YYYY` runs, comma-separated.
+fn unicode_range(code_points: &[char]) -> String {
+    let mut runs: Vec<(u32, u32)> = Vec::new();
+    for c in code_points.iter().map(|&c| u32::from(c)) {
+        match runs.last_mut() {
+            Some((_, end)) if *end + 1 == c => *end = c,
+            _ => runs.push((c, c)),
+        }
+    }
+    runs.iter()
+        .map(|&(start, end)| {
+            if start == end {
+                format!("U+{:04X}", start)
+            } else {
+                format!("U+{:04X}-{:04X}", start, end)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Table sizes, with their directory entries, split the way `estimate`
+/// needs them.
+struct TableSizes {
+    total: usize,
+    shared: usize,
+    /// Everything but the shared tables and `glyf`.
+    per_glyph: usize,
+}
+
+impl TableSizes {
+    fn new(face: &Face) -> Self {
+        let mut sizes = TableSizes {
+            total: 12,
+            shared: 12,
+            per_glyph: 0,
+        };
+        for record in face.raw_face().table_records {
+            let size = 16 + record.length as usize;
+            sizes.total += size;
+            if SHARED_TABLES
+                .iter()
+                .any(|tag| record.tag == Tag::from_bytes(tag))
+            {
+                sizes.shared += size;
+            } else if record.tag == Tag::from_bytes(b"glyf") {
+                sizes.shared += 16;
+            } else {
+                sizes.per_glyph += size;
+            }
+        }
+        sizes
+    }
+
+    fn estimate(&self, face: &Face, glyphs: &BTreeSet<GlyphId>) -> usize {
+        let share = glyphs.len() as f32 / f32::from(face.number_of_glyphs().max(1));
+        let glyf: usize = glyphs
+            .iter()
+            .filter_map(|&glyph| glyf_range(face, glyph))
+            .map(|(start, end)| end.saturating_sub(start))
+            .sum();
+        self.shared + glyf + (self.per_glyph as f32 * share).round() as usize
+    }
+}
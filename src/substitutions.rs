@@ -0,0 +1,173 @@
+use crate::layout::{add_coverage, feature_lookups};
+use crate::unicode::covered_chars;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use ttf_parser::gsub::{SingleSubstitution, SubstitutionSubtable};
+use ttf_parser::{Face, GlyphId, Tag};
+
+/// How a substitution rewrites glyphs.
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Single,
+    /// One glyph into several, as in decomposition.
+    Multiple,
+    /// One glyph into a choice of alternates; `output` lists them all.
+    Alternate,
+    Ligature,
+    /// A single substitution applied from the end of the run backwards.
+    Reverse,
+}
+
+impl Kind {
+    pub fn name(self) -> &'static str {
+        match self {
+            Kind::Single => "single",
+            Kind::Multiple => "multiple",
+            Kind::Alternate => "alternate",
+            Kind::Ligature => "ligature",
+            Kind::Reverse => "reverse",
+        }
+    }
+}
+
+/// One input→output mapping. Glyphs read as the character the cmap maps
+/// to them where there is one, or else as their name or ID.
+#[derive(Serialize)]
+pub struct Substitution {
+    pub lookup: u16,
+    pub kind: Kind,
+    pub input: Vec<String>,
+    pub output: Vec<String>,
+}
+
+/// What the GSUB lookups of one feature do, in lookup order.
+#[derive(Serialize)]
+pub struct SubstitutionReport {
+    pub feature: String,
+    pub substitutions: Vec<Substitution>,
+    /// Contextual lookups, whose rules only pick where other lookups apply
+    /// and aren't expanded.
+    pub contextual: Vec<u16>,
+}
+
+impl SubstitutionReport {
+    /// Returns `None` when the font has no GSUB feature with this tag.
+    pub fn new(face: &Face, feature: Tag) -> Option<Self> {
+        let gsub = face.tables().gsub?;
+        let lookups = feature_lookups(&gsub, feature);
+        if !gsub.features.into_iter().any(|f| f.tag == feature) {
+            return None;
+        }
+
+        // The lowest code point of each glyph, as in the ligature report.
+        let mut chars: HashMap<GlyphId, char> = HashMap::new();
+        for c in covered_chars(face) {
+            if let Some(glyph) = face.glyph_index(c) {
+                chars.entry(glyph).or_insert(c);
+            }
+        }
+        let label = |glyph: GlyphId| match (chars.get(&glyph), face.glyph_name(glyph)) {
+            (Some(c), _) => c.to_string(),
+            (None, Some(name)) => name.to_string(),
+            (None, None) => format!("gid{}", glyph.0),
+        };
+
+        let mut substitutions = Vec::new();
+        let mut contextual = Vec::new();
+        for index in lookups {
+            let Some(lookup) = gsub.lookups.get(index) else {
+                continue;
+            };
+            let mut add = |kind, input: Vec<GlyphId>, output: Vec<GlyphId>| {
+                substitutions.push(Substitution {
+                    lookup: index,
+                    kind,
+                    input: input.into_iter().map(label).collect(),
+                    output: output.into_iter().map(label).collect(),
+                });
+            };
+            for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+                let mut covered = BTreeSet::new();
+                add_coverage(subtable.coverage(), &mut covered);
+                match subtable {
+                    SubstitutionSubtable::Single(single) => {
+                        for glyph in covered {
+                            let substitute = match single {
+                                SingleSubstitution::Format1 { delta, .. } => {
+                                    Some(GlyphId((glyph.0 as i32 + delta as i32) as u16))
+                                }
+                                SingleSubstitution::Format2 {
+                                    coverage,
+                                    substitutes,
+                                } => coverage.get(glyph).and_then(|i| substitutes.get(i)),
+                            };
+                            if let Some(substitute) = substitute {
+                                add(Kind::Single, vec![glyph], vec![substitute]);
+                            }
+                        }
+                    }
+                    SubstitutionSubtable::Multiple(t) => {
+                        for glyph in covered {
+                            if let Some(sequence) =
+                                t.coverage.get(glyph).and_then(|i| t.sequences.get(i))
+                            {
+                                add(
+                                    Kind::Multiple,
+                                    vec![glyph],
+                                    sequence.substitutes.into_iter().collect(),
+                                );
+                            }
+                        }
+                    }
+                    SubstitutionSubtable::Alternate(t) => {
+                        for glyph in covered {
+                            if let Some(set) =
+                                t.coverage.get(glyph).and_then(|i| t.alternate_sets.get(i))
+                            {
+                                add(
+                                    Kind::Alternate,
+                                    vec![glyph],
+                                    set.alternates.into_iter().collect(),
+                                );
+                            }
+                        }
+                    }
+                    SubstitutionSubtable::Ligature(t) => {
+                        for first in covered {
+                            let Some(set) =
+                                t.coverage.get(first).and_then(|i| t.ligature_sets.get(i))
+                            else {
+                                continue;
+                            };
+                            for ligature in set {
+                                let input =
+                                    std::iter::once(first).chain(ligature.components).collect();
+                                add(Kind::Ligature, input, vec![ligature.glyph]);
+                            }
+                        }
+                    }
+                    SubstitutionSubtable::ReverseChainSingle(t) => {
+                        for glyph in covered {
+                            if let Some(substitute) =
+                                t.coverage.get(glyph).and_then(|i| t.substitutes.get(i))
+                            {
+                                add(Kind::Reverse, vec![glyph], vec![substitute]);
+                            }
+                        }
+                    }
+                    SubstitutionSubtable::Context(_) | SubstitutionSubtable::ChainContext(_) => {
+                        if !contextual.contains(&index) {
+                            contextual.push(index);
+                        }
+                    }
+                }
+            }
+        }
+        Some(SubstitutionReport {
+            feature: feature.to_string(),
+            substitutions,
+            contextual,
+        })
+    }
+}
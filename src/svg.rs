@@ -0,0 +1,74 @@
+use std::fmt::Write;
+use ttf_parser::{Face, GlyphId, OutlineBuilder};
+
+/// Writes a glyph's outline as a standalone SVG document in font units.
+/// The viewBox spans the advance horizontally and an em from the
+/// descender up, widened to the glyph's bounding box where it overshoots,
+/// so glyphs exported from one font line up when placed side by side.
+pub fn glyph_svg(face: &Face, glyph: GlyphId) -> String {
+    let mut path = PathData(String::new());
+    let bbox = face.outline_glyph(glyph, &mut path);
+
+    let advance = i32::from(face.glyph_hor_advance(glyph).unwrap_or(0));
+    let bottom = i32::from(face.descender());
+    let top = bottom + i32::from(face.units_per_em());
+    let (mut x_min, mut x_max, mut y_min, mut y_max) = (0, advance, bottom, top);
+    if let Some(bbox) = bbox {
+        x_min = x_min.min(i32::from(bbox.x_min));
+        x_max = x_max.max(i32::from(bbox.x_max));
+        y_min = y_min.min(i32::from(bbox.y_min));
+        y_max = y_max.max(i32::from(bbox.y_max));
+    }
+    let (width, height) = ((x_max - x_min).max(1), y_max - y_min);
+
+    // SVG's y axis points down, so the path flips y and the viewBox starts
+    // at the top.
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" width=\"{}\" height=\"{}\">\n",
+        x_min, -y_max, width, height, width, height
+    );
+    if !path.0.is_empty() {
+        let _ = writeln!(svg, "  <path d=\"{}\"/>", path.0.trim_end());
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Collects outline commands as SVG path data with y negated.
+struct PathData(String);
+
+impl OutlineBuilder for PathData {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let _ = write!(self.0, "M{} {} ", x, flip(y));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let _ = write!(self.0, "L{} {} ", x, flip(y));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let _ = write!(self.0, "Q{} {} {} {} ", x1, flip(y1), x, flip(y));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let _ = write!(
+            self.0,
+            "C{} {} {} {} {} {} ",
+            x1,
+            flip(y1),
+            x2,
+            flip(y2),
+            x,
+            flip(y)
+        );
+    }
+
+    fn close(&mut self) {
+        self.0.push_str("Z ");
+    }
+}
+
+/// Negates y, turning 0 into 0 rather than -0.
+fn flip(y: f32) -> f32 {
+    0.0 - y
+}
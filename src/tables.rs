@@ -0,0 +1,85 @@
+use serde::Serialize;
+use ttf_parser::{Face, Tag};
+
+/// One record of the sfnt table directory.
+#[derive(Serialize)]
+pub struct TableEntry {
+    pub tag: String,
+    pub offset: u32,
+    pub length: u32,
+    /// The checksum the directory declares.
+    pub checksum: u32,
+    /// The checksum of the table's bytes; `None` when the table runs past
+    /// the end of the file.
+    pub computed: Option<u32>,
+    /// Share of the file size, in percent.
+    pub share: f64,
+}
+
+impl TableEntry {
+    pub fn checksum_matches(&self) -> bool {
+        self.computed == Some(self.checksum)
+    }
+}
+
+/// Every table of the font as the table directory lists it, in directory
+/// order, with declared and computed checksums.
+#[derive(Serialize)]
+pub struct TableDirectory {
+    pub file_size: usize,
+    pub tables: Vec<TableEntry>,
+}
+
+impl TableDirectory {
+    pub fn new(face: &Face) -> Self {
+        let raw = face.raw_face();
+        // Offsets count from the start of the file, collections included.
+        let data = raw.data;
+        let tables = raw
+            .table_records
+            .into_iter()
+            .map(|record| {
+                let start = record.offset as usize;
+                let bytes = start
+                    .checked_add(record.length as usize)
+                    .and_then(|end| data.get(start..end));
+                TableEntry {
+                    tag: record.tag.to_string(),
+                    offset: record.offset,
+                    length: record.length,
+                    checksum: record.check_sum,
+                    computed: bytes.map(|bytes| table_checksum(record.tag, bytes)),
+                    share: record.length as f64 * 100.0 / data.len().max(1) as f64,
+                }
+            })
+            .collect();
+        TableDirectory {
+            file_size: data.len(),
+            tables,
+        }
+    }
+
+    /// Tables whose computed checksum differs from the declared one.
+    pub fn mismatches(&self) -> impl Iterator<Item = &TableEntry> {
+        self.tables.iter().filter(|table| !table.checksum_matches())
+    }
+}
+
+/// The sum of a table's big-endian 32-bit words, zero-padded to a multiple
+/// of four bytes. `head` is summed with checkSumAdjustment as zero, since
+/// that field is set after the checksums are.
+pub fn table_checksum(tag: Tag, bytes: &[u8]) -> u32 {
+    let is_head = tag == Tag::from_bytes(b"head");
+    bytes
+        .chunks(4)
+        .enumerate()
+        .map(|(i, chunk)| {
+            if is_head && i == 2 {
+                return 0;
+            }
+            let mut word = [0; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u32::from_be_bytes(word)
+        })
+        .fold(0u32, u32::wrapping_add)
+}
@@ -0,0 +1,102 @@
+use crate::layout;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use ttf_parser::{Face, Tag};
+
+const DIGITS: &str = "0123456789";
+
+/// Characters set alongside figures in tables of amounts, which must keep
+/// the figure width for columns to line up.
+const COMPANIONS: &str = "$€£¥%.,";
+
+/// Where the tabular widths come from.
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Figures {
+    /// The glyphs `tnum` substitutes, or the default ones it leaves alone.
+    Tnum,
+    /// The font has no `tnum`, but its default digits share one advance.
+    DefaultTabular,
+}
+
+impl Figures {
+    pub fn description(self) -> &'static str {
+        match self {
+            Figures::Tnum => "tnum feature",
+            Figures::DefaultTabular => "default digits (no tnum)",
+        }
+    }
+}
+
+/// A character whose tabular glyph doesn't have the figure width.
+#[derive(Serialize)]
+pub struct Offender {
+    pub c: char,
+    pub glyph_name: Option<String>,
+    pub advance: u16,
+}
+
+/// Whether digits, currency signs, percent, period and comma share one
+/// advance under `tnum`, as columns of figures need.
+#[derive(Serialize)]
+pub struct TabularReport {
+    pub figures: Figures,
+    /// The most common advance among the tabular digits.
+    pub width: u16,
+    /// Characters the cmap covers and that were checked.
+    pub checked: usize,
+    pub offenders: Vec<Offender>,
+}
+
+impl TabularReport {
+    /// Returns `None` for fonts without digits, and for fonts whose
+    /// default digits are proportional and that have no `tnum`.
+    pub fn new(face: &Face) -> Option<Self> {
+        let gsub = face.tables().gsub;
+        let lookups = gsub
+            .map(|gsub| layout::feature_lookups(&gsub, Tag::from_bytes(b"tnum")))
+            .filter(|lookups| !lookups.is_empty());
+        let tabular = |c: char| {
+            let glyph = face.glyph_index(c)?;
+            let glyph = match (gsub, &lookups) {
+                (Some(gsub), Some(lookups)) => layout::single_substitute(&gsub, lookups, glyph),
+                _ => glyph,
+            };
+            Some((c, glyph, face.glyph_hor_advance(glyph)?))
+        };
+
+        let digits: Vec<_> = DIGITS.chars().filter_map(tabular).collect();
+        let mut counts: BTreeMap<u16, usize> = BTreeMap::new();
+        for &(_, _, advance) in &digits {
+            *counts.entry(advance).or_default() += 1;
+        }
+        let (&width, _) = counts.iter().max_by_key(|&(_, &count)| count)?;
+        let figures = if lookups.is_some() {
+            Figures::Tnum
+        } else if counts.len() == 1 {
+            Figures::DefaultTabular
+        } else {
+            return None;
+        };
+
+        let checked: Vec<_> = digits
+            .into_iter()
+            .chain(COMPANIONS.chars().filter_map(tabular))
+            .collect();
+        let offenders = checked
+            .iter()
+            .filter(|&&(_, _, advance)| advance != width)
+            .map(|&(c, glyph, advance)| Offender {
+                c,
+                glyph_name: face.glyph_name(glyph).map(str::to_string),
+                advance,
+            })
+            .collect();
+        Some(TabularReport {
+            figures,
+            width,
+            checked: checked.len(),
+            offenders,
+        })
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{base64, png};
+use std::env;
+use std::fmt::Write;
+
+/// Largest base64 payload Kitty accepts per escape sequence.
+const KITTY_CHUNK: usize = 4096;
+/// Gray levels in the Sixel palette.
+const SIXEL_LEVELS: usize = 16;
+
+/// How an image reaches the terminal.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Protocol {
+    /// Braille characters, which any terminal with a Unicode font shows.
+    Braille,
+    /// The Kitty graphics protocol, also spoken by WezTerm and Ghostty.
+    Kitty,
+    /// iTerm2 inline images, also shown by WezTerm and mintty.
+    Iterm,
+    /// DEC Sixel graphics, as in xterm -ti vt340, mlterm, foot and
+    /// Windows Terminal.
+    Sixel,
+}
+
+impl Protocol {
+    pub fn from_name(name: &str) -> Option<Protocol> {
+        match name {
+            "braille" => Some(Protocol::Braille),
+            "kitty" => Some(Protocol::Kitty),
+            "iterm" => Some(Protocol::Iterm),
+            "sixel" => Some(Protocol::Sixel),
+            _ => None,
+        }
+    }
+
+    /// Guesses the terminal's graphics support from the variables terminals
+    /// set, since querying it needs raw terminal input. Falls back to
+    /// braille.
+    pub fn detect() -> Protocol {
+        let var = |name: &str| env::var(name).unwrap_or_default();
+        let term = var("TERM");
+        let program = var("TERM_PROGRAM");
+        if env::var_os("KITTY_WINDOW_ID").is_some()
+            || term == "xterm-kitty"
+            || term == "xterm-ghostty"
+            || program == "ghostty"
+        {
+            Protocol::Kitty
+        } else if matches!(program.as_str(), "iTerm.app" | "WezTerm" | "mintty") {
+            Protocol::Iterm
+        } else if term.contains("sixel")
+            || term.starts_with("mlterm")
+            || term.starts_with("foot")
+            || env::var_os("WT_SESSION").is_some()
+        {
+            Protocol::Sixel
+        } else {
+            Protocol::Braille
+        }
+    }
+
+    /// Encodes 8-bit grayscale pixels, row by row, as the escape sequence
+    /// that displays them. Not meant for braille, which is text.
+    pub fn encode(self, width: usize, height: usize, gray: &[u8]) -> String {
+        match self {
+            Protocol::Kitty => {
+                let data = base64::encode(&png_data(width, height, gray));
+                let chunks: Vec<&[u8]> = data.as_bytes().chunks(KITTY_CHUNK).collect();
+                let mut out = String::new();
+                for (i, chunk) in chunks.iter().enumerate() {
+                    let more = u8::from(i + 1 < chunks.len());
+                    let control = if i == 0 {
+                        format!("a=T,f=100,m={}", more)
+                    } else {
+                        format!("m={}", more)
+                    };
+                    let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+                    let _ = write!(out, "\x1b_G{};{}\x1b\\", control, chunk);
+                }
+                out
+            }
+            Protocol::Iterm => {
+                let png = png_data(width, height, gray);
+                format!(
+                    "\x1b]1337;File=inline=1;size={};preserveAspectRatio=1:{}\x07",
+                    png.len(),
+                    base64::encode(&png)
+                )
+            }
+            Protocol::Sixel => sixel(width, height, gray),
+            Protocol::Braille => String::new(),
+        }
+    }
+}
+
+fn png_data(width: usize, height: usize, gray: &[u8]) -> Vec<u8> {
+    let rgb: Vec<u8> = gray.iter().flat_map(|&value| [value; 3]).collect();
+    png::encode_rgb(width, height, &rgb)
+}
+
+/// Encodes pixels as Sixel: bands six pixels tall, each drawn once per gray
+/// level it uses, with runs of four or more repeated columns compressed.
+fn sixel(width: usize, height: usize, gray: &[u8]) -> String {
+    let mut out = format!("\x1bPq\"1;1;{};{}", width, height);
+    for level in 0..SIXEL_LEVELS {
+        let percent = level * 100 / (SIXEL_LEVELS - 1);
+        let _ = write!(out, "#{};2;{};{};{}", level, percent, percent, percent);
+    }
+    let level = |x: usize, y: usize| usize::from(gray[y * width + x]) * (SIXEL_LEVELS - 1) / 255;
+    for top in (0..height).step_by(6) {
+        let rows = top..(top + 6).min(height);
+        let mut used = [false; SIXEL_LEVELS];
+        for y in rows.clone() {
+            for x in 0..width {
+                used[level(x, y)] = true;
+            }
+        }
+        for color in (0..SIXEL_LEVELS).filter(|&color| used[color]) {
+            let _ = write!(out, "#{}", color);
+            let columns: Vec<u8> = (0..width)
+                .map(|x| {
+                    let bits = rows
+                        .clone()
+                        .filter(|&y| level(x, y) == color)
+                        .fold(0, |bits, y| bits | 1 << (y - top));
+                    b'?' + bits
+                })
+                .collect();
+            for run in columns.chunk_by(|a, b| a == b) {
+                let c = char::from(run[0]);
+                if run.len() >= 4 {
+                    let _ = write!(out, "!{}{}", run.len(), c);
+                } else {
+                    out.extend(std::iter::repeat_n(c, run.len()));
+                }
+            }
+            // Back to the start of the band for the next color.
+            out.push('$');
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    out
+}
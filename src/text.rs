@@ -0,0 +1,45 @@
+use crate::charnames;
+use crate::ignorables;
+use std::collections::BTreeSet;
+use ttf_parser::Face;
+
+pub struct MissingChar {
+    pub c: char,
+    /// The Unicode name, or `None` for unassigned code points.
+    pub name: Option<String>,
+}
+
+/// Which characters of a text the font's cmap lacks.
+pub struct TextCoverage {
+    /// Distinct characters checked. Controls such as newlines and
+    /// default-ignorable characters aren't checked: renderers don't draw
+    /// them.
+    pub checked: usize,
+    /// Missing characters in code point order.
+    pub missing: Vec<MissingChar>,
+}
+
+impl TextCoverage {
+    pub fn new(face: &Face, text: &str) -> Self {
+        let chars: BTreeSet<char> = text
+            .chars()
+            .filter(|&c| !c.is_control() && !ignorables::is_ignorable(c))
+            .collect();
+        let missing: BTreeSet<char> = chars
+            .iter()
+            .copied()
+            .filter(|&c| face.glyph_index(c).is_none())
+            .collect();
+        let mut names = charnames::names(&missing);
+        TextCoverage {
+            checked: chars.len(),
+            missing: missing
+                .into_iter()
+                .map(|c| MissingChar {
+                    c,
+                    name: names.remove(&c),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,356 @@
+use crate::reader::Reader;
+use crate::timestamp::DateTime;
+use std::fmt::Write;
+use ttf_parser::Tag;
+
+/// Tables that can be written in TTX form.
+pub const SUPPORTED_TABLES: [&str; 6] = ["head", "hhea", "maxp", "name", "OS/2", "post"];
+
+#[derive(Clone, Copy)]
+enum Field {
+    U8,
+    U16,
+    I16,
+    U32,
+    Hex32,
+    PaddedHex32,
+    Fixed,
+    Bin16,
+    Bin32,
+    Date,
+    Tag,
+}
+
+const HEAD: &[(&str, Field)] = &[
+    ("tableVersion", Field::Fixed),
+    ("fontRevision", Field::Fixed),
+    ("checkSumAdjustment", Field::Hex32),
+    ("magicNumber", Field::Hex32),
+    ("flags", Field::Bin16),
+    ("unitsPerEm", Field::U16),
+    ("created", Field::Date),
+    ("modified", Field::Date),
+    ("xMin", Field::I16),
+    ("yMin", Field::I16),
+    ("xMax", Field::I16),
+    ("yMax", Field::I16),
+    ("macStyle", Field::Bin16),
+    ("lowestRecPPEM", Field::U16),
+    ("fontDirectionHint", Field::I16),
+    ("indexToLocFormat", Field::I16),
+    ("glyphDataFormat", Field::I16),
+];
+
+const HHEA: &[(&str, Field)] = &[
+    ("tableVersion", Field::PaddedHex32),
+    ("ascent", Field::I16),
+    ("descent", Field::I16),
+    ("lineGap", Field::I16),
+    ("advanceWidthMax", Field::U16),
+    ("minLeftSideBearing", Field::I16),
+    ("minRightSideBearing", Field::I16),
+    ("xMaxExtent", Field::I16),
+    ("caretSlopeRise", Field::I16),
+    ("caretSlopeRun", Field::I16),
+    ("caretOffset", Field::I16),
+    ("reserved0", Field::I16),
+    ("reserved1", Field::I16),
+    ("reserved2", Field::I16),
+    ("reserved3", Field::I16),
+    ("metricDataFormat", Field::I16),
+    ("numberOfHMetrics", Field::U16),
+];
+
+const MAXP_V05: &[(&str, Field)] = &[("tableVersion", Field::Hex32), ("numGlyphs", Field::U16)];
+
+const MAXP_V1: &[(&str, Field)] = &[
+    ("maxPoints", Field::U16),
+    ("maxContours", Field::U16),
+    ("maxCompositePoints", Field::U16),
+    ("maxCompositeContours", Field::U16),
+    ("maxZones", Field::U16),
+    ("maxTwilightPoints", Field::U16),
+    ("maxStorage", Field::U16),
+    ("maxFunctionDefs", Field::U16),
+    ("maxInstructionDefs", Field::U16),
+    ("maxStackElements", Field::U16),
+    ("maxSizeOfInstructions", Field::U16),
+    ("maxComponentElements", Field::U16),
+    ("maxComponentDepth", Field::U16),
+];
+
+const POST: &[(&str, Field)] = &[
+    ("formatType", Field::Fixed),
+    ("italicAngle", Field::Fixed),
+    ("underlinePosition", Field::I16),
+    ("underlineThickness", Field::I16),
+    ("isFixedPitch", Field::U32),
+    ("minMemType42", Field::U32),
+    ("maxMemType42", Field::U32),
+    ("minMemType1", Field::U32),
+    ("maxMemType1", Field::U32),
+];
+
+const OS2_HEAD: &[(&str, Field)] = &[
+    ("version", Field::U16),
+    ("xAvgCharWidth", Field::I16),
+    ("usWeightClass", Field::U16),
+    ("usWidthClass", Field::U16),
+    ("fsType", Field::Bin16),
+    ("ySubscriptXSize", Field::I16),
+    ("ySubscriptYSize", Field::I16),
+    ("ySubscriptXOffset", Field::I16),
+    ("ySubscriptYOffset", Field::I16),
+    ("ySuperscriptXSize", Field::I16),
+    ("ySuperscriptYSize", Field::I16),
+    ("ySuperscriptXOffset", Field::I16),
+    ("ySuperscriptYOffset", Field::I16),
+    ("yStrikeoutSize", Field::I16),
+    ("yStrikeoutPosition", Field::I16),
+    ("sFamilyClass", Field::I16),
+];
+
+const OS2_PANOSE: &[(&str, Field)] = &[
+    ("bFamilyType", Field::U8),
+    ("bSerifStyle", Field::U8),
+    ("bWeight", Field::U8),
+    ("bProportion", Field::U8),
+    ("bContrast", Field::U8),
+    ("bStrokeVariation", Field::U8),
+    ("bArmStyle", Field::U8),
+    ("bLetterForm", Field::U8),
+    ("bMidline", Field::U8),
+    ("bXHeight", Field::U8),
+];
+
+const OS2_V0: &[(&str, Field)] = &[
+    ("ulUnicodeRange1", Field::Bin32),
+    ("ulUnicodeRange2", Field::Bin32),
+    ("ulUnicodeRange3", Field::Bin32),
+    ("ulUnicodeRange4", Field::Bin32),
+    ("achVendID", Field::Tag),
+    ("fsSelection", Field::Bin16),
+    ("usFirstCharIndex", Field::U16),
+    ("usLastCharIndex", Field::U16),
+    ("sTypoAscender", Field::I16),
+    ("sTypoDescender", Field::I16),
+    ("sTypoLineGap", Field::I16),
+    ("usWinAscent", Field::U16),
+    ("usWinDescent", Field::U16),
+];
+
+const OS2_V1: &[(&str, Field)] = &[
+    ("ulCodePageRange1", Field::Bin32),
+    ("ulCodePageRange2", Field::Bin32),
+];
+
+const OS2_V2: &[(&str, Field)] = &[
+    ("sxHeight", Field::I16),
+    ("sCapHeight", Field::I16),
+    ("usDefaultChar", Field::U16),
+    ("usBreakChar", Field::U16),
+    ("usMaxContext", Field::U16),
+];
+
+const OS2_V5: &[(&str, Field)] = &[
+    ("usLowerOpticalPointSize", Field::U16),
+    ("usUpperOpticalPointSize", Field::U16),
+];
+
+/// Returns the TTX element name for a table tag, e.g. `OS/2` -> `OS_2`.
+pub fn element_name(tag: Tag) -> String {
+    tag.to_string().trim_end().replace('/', "_")
+}
+
+/// Writes the opening `<ttFont>` element for a font whose data starts
+/// with the given sfnt version.
+pub fn write_header(out: &mut String, sfnt_version: u32) {
+    let version = if sfnt_version == 0x4F54_544F {
+        "OTTO".to_string()
+    } else {
+        sfnt_version
+            .to_be_bytes()
+            .iter()
+            .map(|b| format!("\\x{:02x}", b))
+            .collect()
+    };
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(out, "<ttFont sfntVersion=\"{}\">", version);
+}
+
+pub fn write_footer(out: &mut String) {
+    out.push_str("\n</ttFont>\n");
+}
+
+/// Writes one table as TTX XML. Returns `false` if the table isn't
+/// supported or can't be decoded.
+pub fn write_table(out: &mut String, tag: Tag, data: &[u8]) -> bool {
+    let mut body = String::new();
+    let ok = match &tag.to_bytes() {
+        b"head" => write_fields(&mut body, &mut Reader::new(data), HEAD, 4),
+        b"hhea" => write_fields(&mut body, &mut Reader::new(data), HHEA, 4),
+        b"maxp" => write_maxp(&mut body, data),
+        b"name" => write_name(&mut body, data),
+        b"OS/2" => write_os2(&mut body, data),
+        b"post" => write_fields(&mut body, &mut Reader::new(data), POST, 4),
+        _ => None,
+    }
+    .is_some();
+
+    if ok {
+        let element = element_name(tag);
+        let _ = writeln!(out, "\n  <{}>", element);
+        out.push_str(&body);
+        let _ = writeln!(out, "  </{}>", element);
+    }
+    ok
+}
+
+fn write_fields(
+    out: &mut String,
+    r: &mut Reader,
+    fields: &[(&str, Field)],
+    indent: usize,
+) -> Option<()> {
+    for &(name, field) in fields {
+        let value = match field {
+            Field::U8 => r.read_u8()?.to_string(),
+            Field::U16 => r.read_u16()?.to_string(),
+            Field::I16 => r.read_i16()?.to_string(),
+            Field::U32 => r.read_u32()?.to_string(),
+            Field::Hex32 => format!("{:#x}", r.read_u32()?),
+            Field::PaddedHex32 => format!("{:#010x}", r.read_u32()?),
+            Field::Fixed => fixed_to_str(r.read_u32()? as i32),
+            Field::Bin16 => binary(r.read_u16()? as u32, 16),
+            Field::Bin32 => binary(r.read_u32()?, 32),
+            Field::Date => DateTime::from_longdatetime(r.read_i64()?).to_asctime(),
+            Field::Tag => escape(&String::from_utf8_lossy(r.read_bytes(4)?)).replace('"', "&quot;"),
+        };
+        let _ = writeln!(out, "{:indent$}<{} value=\"{}\"/>", "", name, value);
+    }
+    Some(())
+}
+
+fn write_maxp(out: &mut String, data: &[u8]) -> Option<()> {
+    let mut r = Reader::new(data);
+    write_fields(out, &mut r, MAXP_V05, 4)?;
+    if Reader::new(data).read_u32()? >= 0x10000 {
+        write_fields(out, &mut r, MAXP_V1, 4)?;
+    }
+    Some(())
+}
+
+fn write_os2(out: &mut String, data: &[u8]) -> Option<()> {
+    let version = Reader::new(data).read_u16()?;
+    let mut r = Reader::new(data);
+    write_fields(out, &mut r, OS2_HEAD, 4)?;
+    out.push_str("    <panose>\n");
+    write_fields(out, &mut r, OS2_PANOSE, 6)?;
+    out.push_str("    </panose>\n");
+    write_fields(out, &mut r, OS2_V0, 4)?;
+    if version >= 1 {
+        write_fields(out, &mut r, OS2_V1, 4)?;
+    }
+    if version >= 2 {
+        write_fields(out, &mut r, OS2_V2, 4)?;
+    }
+    if version >= 5 {
+        write_fields(out, &mut r, OS2_V5, 4)?;
+    }
+    Some(())
+}
+
+fn write_name(out: &mut String, data: &[u8]) -> Option<()> {
+    let mut r = Reader::new(data);
+    r.skip(2); // version
+    let count = r.read_u16()?;
+    let storage_offset = r.read_u16()? as usize;
+
+    for _ in 0..count {
+        let platform_id = r.read_u16()?;
+        let encoding_id = r.read_u16()?;
+        let language_id = r.read_u16()?;
+        let name_id = r.read_u16()?;
+        let length = r.read_u16()? as usize;
+        let offset = r.read_u16()? as usize;
+
+        let start = storage_offset + offset;
+        let Some(bytes) = data.get(start..start + length) else {
+            continue;
+        };
+        let Some(text) = decode_name(platform_id, encoding_id, bytes) else {
+            continue;
+        };
+
+        let _ = writeln!(
+            out,
+            "    <namerecord nameID=\"{}\" platformID=\"{}\" platEncID=\"{}\" langID=\"{:#x}\">",
+            name_id, platform_id, encoding_id, language_id
+        );
+        let _ = writeln!(out, "      {}", escape(&text));
+        out.push_str("    </namerecord>\n");
+    }
+    Some(())
+}
+
+/// Decodes a name record string the way TTX does for the encodings it
+/// writes as text.
+pub fn decode_name(platform_id: u16, encoding_id: u16, bytes: &[u8]) -> Option<String> {
+    match (platform_id, encoding_id) {
+        (0, _) | (3, 0) | (3, 1) | (3, 10) => {
+            let units: Vec<u16> = bytes
+                .chunks_exact(2)
+                .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                .collect();
+            Some(String::from_utf16_lossy(&units))
+        }
+        (1, 0) => Some(bytes.iter().map(|&b| mac_roman_char(b)).collect()),
+        _ => None,
+    }
+}
+
+fn mac_roman_char(byte: u8) -> char {
+    const HIGH: &str = "ÄÅÇÉÑÖÜáàâäãåçéèêëíìîïñóòôöõúùûü†°¢£§•¶ß®©™´¨≠ÆØ∞±≤≥¥µ∂∑∏π∫ªºΩæø\
+                        ¿¡¬√ƒ≈∆«»…\u{A0}ÀÃÕŒœ–—“”‘’÷◊ÿŸ⁄€‹›ﬁﬂ‡·‚„‰ÂÊÁËÈÍÎÏÌÓÔ\u{F8FF}ÒÚÛÙıˆ˜¯˘˙˚¸˝˛ˇ";
+    if byte < 0x80 {
+        byte as char
+    } else {
+        HIGH.chars().nth(byte as usize - 0x80).unwrap_or('\u{FFFD}')
+    }
+}
+
+/// Formats a 16.16 fixed value with the fewest decimals that round-trip.
+fn fixed_to_str(raw: i32) -> String {
+    let value = raw as f64 / 65536.0;
+    for precision in 1..=6 {
+        let s = format!("{:.*}", precision, value);
+        if (s.parse::<f64>().unwrap_or(0.0) * 65536.0).round() as i32 == raw {
+            return s;
+        }
+    }
+    format!("{:.6}", value)
+}
+
+fn binary(value: u32, bits: u32) -> String {
+    (0..bits / 8)
+        .rev()
+        .map(|byte| format!("{:08b}", (value >> (byte * 8)) & 0xFF))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c if (c as u32) < 0x20 && c != '\n' && c != '\t' => {
+                let _ = write!(escaped, "&#{};", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
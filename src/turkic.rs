@@ -0,0 +1,125 @@
+use crate::layout;
+use serde::Serialize;
+use ttf_parser::{Face, Tag};
+
+/// The four letters of the Turkish and Azerbaijani i: dotted and dotless,
+/// each in both cases.
+const LETTERS: [char; 4] = ['i', 'İ', 'ı', 'I'];
+
+/// OpenType language systems whose orthography pairs i with İ.
+const LANGUAGES: [(&[u8; 4], &str); 2] = [(b"TRK ", "Turkish"), (b"AZE ", "Azerbaijani")];
+
+/// What a language system's `locl` does with the dotted i.
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Handling {
+    /// The `latn` script has no such language system.
+    NoLanguageSystem,
+    /// The language system has no `locl` feature.
+    NoLocl,
+    /// `locl` exists but doesn't substitute the i.
+    IgnoresI,
+    /// `locl` substitutes the i, typically with a glyph that keeps its dot
+    /// under `liga` and `smcp`.
+    SubstitutesI,
+}
+
+impl Handling {
+    pub fn description(self) -> &'static str {
+        match self {
+            Handling::NoLanguageSystem => "no language system",
+            Handling::NoLocl => "no locl feature",
+            Handling::IgnoresI => "locl leaves i unchanged",
+            Handling::SubstitutesI => "locl substitutes i",
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LanguageHandling {
+    pub tag: String,
+    pub name: &'static str,
+    pub handling: Handling,
+}
+
+/// How the font treats the dotted and dotless i of Turkish and
+/// Azerbaijani.
+#[derive(Serialize)]
+pub struct TurkicReport {
+    /// Which of i, İ, ı and I the cmap lacks.
+    pub missing: Vec<char>,
+    pub languages: Vec<LanguageHandling>,
+    /// `liga` forms an fi ligature, which drops the dot of a Turkish i.
+    pub fi_ligature: bool,
+    /// `smcp` substitutes i, and a small-cap I without a dot is wrong for
+    /// Turkish i.
+    pub small_caps: bool,
+}
+
+impl TurkicReport {
+    /// Returns `None` for fonts without a Latin i.
+    pub fn new(face: &Face) -> Option<Self> {
+        let i = face.glyph_index('i')?;
+        let missing = LETTERS
+            .into_iter()
+            .filter(|&c| face.glyph_index(c).is_none())
+            .collect();
+
+        let gsub = face.tables().gsub;
+        let locl = Tag::from_bytes(b"locl");
+        let languages = LANGUAGES
+            .iter()
+            .map(|&(tag, name)| {
+                let lookups = gsub.as_ref().and_then(|gsub| {
+                    let lookups = layout::lang_sys_lookups(
+                        gsub,
+                        Tag::from_bytes(b"latn"),
+                        Tag::from_bytes(tag),
+                        locl,
+                    )?;
+                    Some((gsub, lookups))
+                });
+                let handling = match lookups {
+                    None => Handling::NoLanguageSystem,
+                    Some((_, lookups)) if lookups.is_empty() => Handling::NoLocl,
+                    Some((gsub, lookups)) if layout::gsub_covers(gsub, &lookups, i) => {
+                        Handling::SubstitutesI
+                    }
+                    Some(_) => Handling::IgnoresI,
+                };
+                LanguageHandling {
+                    tag: Tag::from_bytes(tag).to_string().trim_end().to_string(),
+                    name,
+                    handling,
+                }
+            })
+            .collect();
+
+        let fi_ligature = match (gsub, face.glyph_index('f')) {
+            (Some(gsub), Some(f)) => layout::has_ligature(&gsub, Tag::from_bytes(b"liga"), &[f, i]),
+            _ => false,
+        };
+        let small_caps = gsub.is_some_and(|gsub| {
+            let lookups = layout::feature_lookups(&gsub, Tag::from_bytes(b"smcp"));
+            layout::gsub_covers(&gsub, &lookups, i)
+        });
+
+        Some(TurkicReport {
+            missing,
+            languages,
+            fi_ligature,
+            small_caps,
+        })
+    }
+
+    /// True when Turkish or Azerbaijani text would lose the dot of i: the
+    /// font forms an fi ligature or small-cap i without a `locl` that
+    /// substitutes the i for one of the languages.
+    pub fn loses_dot(&self) -> bool {
+        (self.fi_ligature || self.small_caps)
+            && self
+                .languages
+                .iter()
+                .any(|language| language.handling != Handling::SubstitutesI)
+    }
+}
@@ -0,0 +1,548 @@
+use crate::reader::Reader;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use ttf_parser::cmap::{Format, Subtable};
+use ttf_parser::{Face, Tag};
+
+/// The last Unicode code point; cmap values past it aren't characters.
+pub const MAX_CODE_POINT: u32 = 0x10FFFF;
+
+/// Characters introduced in each Unicode version, as inclusive ranges. Each
+/// entry samples the scripts, symbols and emoji a version added rather than
+/// listing every character from `DerivedAge.txt`, which is enough to tell
+/// which versions a font was built against.
+const ADDITIONS: &[(&str, &[(u32, u32)])] = &[
+    ("2.1", &[(0x20AC, 0x20AC), (0xFFFC, 0xFFFC)]),
+    (
+        "3.0",
+        &[
+            (0x0D82, 0x0D83),
+            (0x0D85, 0x0D96),
+            (0x1000, 0x1021),
+            (0x1200, 0x1206),
+            (0x13A0, 0x13F4),
+            (0x1780, 0x17DC),
+            (0x1820, 0x1877),
+            (0x2800, 0x28FF),
+            (0x3400, 0x4DB5),
+            (0xA000, 0xA48C),
+        ],
+    ),
+    (
+        "3.2",
+        &[
+            (0x0500, 0x050F),
+            (0x1700, 0x170C),
+            (0x1720, 0x1734),
+            (0x20B0, 0x20B1),
+        ],
+    ),
+    (
+        "4.0",
+        &[
+            (0x1900, 0x191C),
+            (0x1950, 0x196D),
+            (0x19E0, 0x19FF),
+            (0x4DC0, 0x4DFF),
+            (0x10000, 0x1000B),
+            (0x10380, 0x1039D),
+            (0x10800, 0x10805),
+        ],
+    ),
+    (
+        "4.1",
+        &[
+            (0x1980, 0x19A9),
+            (0x1A00, 0x1A1B),
+            (0x20B2, 0x20B5),
+            (0x2C00, 0x2C2E),
+            (0x2C80, 0x2CEA),
+            (0x2D00, 0x2D25),
+            (0x2D30, 0x2D65),
+            (0xA800, 0xA82B),
+            (0x103A0, 0x103C3),
+            (0x10A00, 0x10A03),
+        ],
+    ),
+    (
+        "5.0",
+        &[
+            (0x0242, 0x024F),
+            (0x07C0, 0x07FA),
+            (0x1B00, 0x1B4B),
+            (0x2C60, 0x2C6C),
+            (0xA840, 0xA877),
+            (0x10900, 0x10919),
+            (0x12000, 0x1236E),
+        ],
+    ),
+    (
+        "5.1",
+        &[
+            (0x1B80, 0x1BAA),
+            (0x1C00, 0x1C37),
+            (0x1C50, 0x1C7F),
+            (0x1E9E, 0x1E9E),
+            (0x2C6D, 0x2C6F),
+            (0x2DE0, 0x2DFF),
+            (0xA500, 0xA62B),
+            (0xA640, 0xA65F),
+            (0xA720, 0xA78C),
+            (0xA880, 0xA8C4),
+            (0xA900, 0xA953),
+            (0xAA00, 0xAA36),
+            (0x1F000, 0x1F02B),
+            (0x1F030, 0x1F093),
+        ],
+    ),
+    (
+        "5.2",
+        &[
+            (0x0800, 0x082D),
+            (0x1A20, 0x1A5E),
+            (0x1CD0, 0x1CF2),
+            (0x20B6, 0x20B8),
+            (0xA6A0, 0xA6F7),
+            (0xA980, 0xA9CD),
+            (0xAA80, 0xAAC2),
+            (0xABC0, 0xABED),
+            (0xD7B0, 0xD7C6),
+            (0x13000, 0x1342E),
+            (0x1F100, 0x1F10A),
+            (0x1F110, 0x1F12E),
+            (0x2A700, 0x2B734),
+        ],
+    ),
+    (
+        "6.0",
+        &[
+            (0x0840, 0x085B),
+            (0x1BC0, 0x1BF3),
+            (0x20B9, 0x20B9),
+            (0x11000, 0x1104D),
+            (0x16800, 0x16A38),
+            (0x1B000, 0x1B001),
+            (0x1F300, 0x1F320),
+            (0x1F330, 0x1F335),
+            (0x1F337, 0x1F37C),
+            (0x1F380, 0x1F393),
+            (0x1F3A0, 0x1F3C4),
+            (0x1F400, 0x1F43E),
+            (0x1F442, 0x1F4F7),
+            (0x1F500, 0x1F53D),
+            (0x1F601, 0x1F610),
+            (0x1F680, 0x1F6C5),
+            (0x2B740, 0x2B81D),
+        ],
+    ),
+    (
+        "6.1",
+        &[
+            (0x08A2, 0x08AC),
+            (0x1CC0, 0x1CC7),
+            (0x10980, 0x1099F),
+            (0x110D0, 0x110E8),
+            (0x11100, 0x11134),
+            (0x11180, 0x111C8),
+            (0x11680, 0x116B7),
+            (0x16F00, 0x16F44),
+            (0x1EE00, 0x1EE03),
+        ],
+    ),
+    ("6.2", &[(0x20BA, 0x20BA)]),
+    ("6.3", &[(0x061C, 0x061C), (0x2066, 0x2069)]),
+    (
+        "7.0",
+        &[
+            (0x20BB, 0x20BD),
+            (0xAB30, 0xAB5F),
+            (0x10350, 0x1037A),
+            (0x10500, 0x10527),
+            (0x10530, 0x10563),
+            (0x10600, 0x10736),
+            (0x10860, 0x1089E),
+            (0x10A80, 0x10A9F),
+            (0x10AC0, 0x10AE6),
+            (0x10B80, 0x10B91),
+            (0x11150, 0x11176),
+            (0x11200, 0x11211),
+            (0x112B0, 0x112EA),
+            (0x11480, 0x114C7),
+            (0x11580, 0x115B5),
+            (0x11600, 0x11644),
+            (0x118A0, 0x118F2),
+            (0x11AC0, 0x11AF8),
+            (0x16A40, 0x16A5E),
+            (0x16AD0, 0x16AED),
+            (0x1BC00, 0x1BC6A),
+            (0x1F321, 0x1F32C),
+            (0x1F6E0, 0x1F6EC),
+            (0x1F6F0, 0x1F6F3),
+        ],
+    ),
+    (
+        "8.0",
+        &[
+            (0x13F8, 0x13FD),
+            (0x20BE, 0x20BE),
+            (0xAB70, 0xABBF),
+            (0x108E0, 0x108F2),
+            (0x10C80, 0x10CB2),
+            (0x11280, 0x11286),
+            (0x11700, 0x11719),
+            (0x14400, 0x14646),
+            (0x1F32D, 0x1F32F),
+            (0x1F37E, 0x1F37F),
+            (0x1F3CF, 0x1F3D3),
+            (0x1F3F8, 0x1F3FF),
+            (0x1F54B, 0x1F54F),
+            (0x1F643, 0x1F644),
+            (0x1F910, 0x1F918),
+            (0x1F980, 0x1F984),
+            (0x2B820, 0x2CEA1),
+        ],
+    ),
+    (
+        "9.0",
+        &[
+            (0x104B0, 0x104D3),
+            (0x104D8, 0x104FB),
+            (0x11400, 0x11459),
+            (0x11C00, 0x11C08),
+            (0x11C70, 0x11C8F),
+            (0x17000, 0x187EC),
+            (0x18800, 0x18AF2),
+            (0x1E900, 0x1E94A),
+            (0x1F6D1, 0x1F6D2),
+            (0x1F6F4, 0x1F6F6),
+            (0x1F919, 0x1F91E),
+            (0x1F920, 0x1F927),
+            (0x1F933, 0x1F93E),
+            (0x1F940, 0x1F94B),
+            (0x1F950, 0x1F95E),
+            (0x1F985, 0x1F991),
+        ],
+    ),
+    (
+        "10.0",
+        &[
+            (0x0860, 0x086A),
+            (0x20BF, 0x20BF),
+            (0x11A00, 0x11A47),
+            (0x11A50, 0x11A83),
+            (0x11D00, 0x11D06),
+            (0x1B002, 0x1B11E),
+            (0x1B170, 0x1B2FB),
+            (0x1F6D3, 0x1F6D4),
+            (0x1F6F7, 0x1F6F8),
+            (0x1F928, 0x1F92F),
+            (0x1F95F, 0x1F96B),
+            (0x1F992, 0x1F997),
+            (0x1F9D0, 0x1F9E6),
+            (0x2CEB0, 0x2EBE0),
+        ],
+    ),
+    (
+        "11.0",
+        &[
+            (0x1C90, 0x1CBA),
+            (0x10D00, 0x10D27),
+            (0x10F00, 0x10F27),
+            (0x10F30, 0x10F59),
+            (0x11800, 0x1183B),
+            (0x11D60, 0x11D65),
+            (0x11EE0, 0x11EF8),
+            (0x16E40, 0x16E9A),
+            (0x1D2E0, 0x1D2F3),
+            (0x1F6F9, 0x1F6F9),
+            (0x1F96C, 0x1F970),
+            (0x1F973, 0x1F976),
+            (0x1F998, 0x1F9A2),
+            (0x1F9B0, 0x1F9B9),
+            (0x1F9E7, 0x1F9FF),
+            (0x1FA60, 0x1FA6D),
+        ],
+    ),
+    (
+        "12.0",
+        &[
+            (0x10FE0, 0x10FF6),
+            (0x119A0, 0x119A7),
+            (0x11FC0, 0x11FF1),
+            (0x13430, 0x13438),
+            (0x1B150, 0x1B152),
+            (0x1B164, 0x1B167),
+            (0x1E100, 0x1E12C),
+            (0x1E2C0, 0x1E2F9),
+            (0x1F7E0, 0x1F7EB),
+            (0x1F90D, 0x1F90F),
+            (0x1F9A5, 0x1F9AA),
+            (0x1F9BA, 0x1F9BF),
+            (0x1F9C3, 0x1F9CA),
+            (0x1FA70, 0x1FA73),
+            (0x1FA80, 0x1FA82),
+            (0x1FA90, 0x1FA95),
+        ],
+    ),
+    (
+        "13.0",
+        &[
+            (0x08BE, 0x08C7),
+            (0x10E80, 0x10EA9),
+            (0x10FB0, 0x10FCB),
+            (0x11900, 0x11906),
+            (0x18B00, 0x18CD5),
+            (0x1F6D6, 0x1F6D7),
+            (0x1F6FB, 0x1F6FC),
+            (0x1FA83, 0x1FA86),
+            (0x1FA96, 0x1FAA8),
+            (0x1FAB0, 0x1FAB6),
+            (0x1FAC0, 0x1FAC2),
+            (0x1FAD0, 0x1FAD6),
+            (0x1FB00, 0x1FB92),
+            (0x30000, 0x3134A),
+        ],
+    ),
+    (
+        "14.0",
+        &[
+            (0x061D, 0x061D),
+            (0x0870, 0x088E),
+            (0x20C0, 0x20C0),
+            (0x10570, 0x1057A),
+            (0x10780, 0x10785),
+            (0x10F70, 0x10F89),
+            (0x12F90, 0x12FF2),
+            (0x16A70, 0x16ABE),
+            (0x1AFF0, 0x1AFF3),
+            (0x1CF00, 0x1CF2D),
+            (0x1DF00, 0x1DF1E),
+            (0x1E290, 0x1E2AE),
+            (0x1F6DD, 0x1F6DF),
+            (0x1FAA9, 0x1FAAC),
+            (0x1FAB7, 0x1FABA),
+            (0x1FAE0, 0x1FAE7),
+            (0x1FAF0, 0x1FAF6),
+        ],
+    ),
+    (
+        "15.0",
+        &[
+            (0x11B00, 0x11B09),
+            (0x11F00, 0x11F10),
+            (0x1D2C0, 0x1D2D3),
+            (0x1E030, 0x1E06D),
+            (0x1E4D0, 0x1E4F9),
+            (0x1F6DC, 0x1F6DC),
+            (0x1FA75, 0x1FA77),
+            (0x1FA87, 0x1FA88),
+            (0x1FAAD, 0x1FAAF),
+            (0x1FABB, 0x1FABD),
+            (0x1FADA, 0x1FADB),
+            (0x1FAF7, 0x1FAF8),
+            (0x31350, 0x323AF),
+        ],
+    ),
+    (
+        "15.1",
+        &[(0x2FFC, 0x2FFF), (0x31EF, 0x31EF), (0x2EBF0, 0x2EE5D)],
+    ),
+    (
+        "16.0",
+        &[
+            (0x1C89, 0x1C8A),
+            (0x105C0, 0x105F3),
+            (0x10D40, 0x10D65),
+            (0x11380, 0x11389),
+            (0x11BC0, 0x11BE1),
+            (0x13460, 0x143FA),
+            (0x16100, 0x16139),
+            (0x16D40, 0x16D79),
+            (0x1CC00, 0x1CCF9),
+            (0x1E5D0, 0x1E5FA),
+            (0x1FA89, 0x1FA89),
+            (0x1FA8F, 0x1FA8F),
+            (0x1FABE, 0x1FABE),
+            (0x1FAC6, 0x1FAC6),
+            (0x1FADC, 0x1FADC),
+            (0x1FAE9, 0x1FAE9),
+        ],
+    ),
+];
+
+/// Characters mapped by any Unicode cmap subtable.
+pub fn covered_chars(face: &Face) -> BTreeSet<char> {
+    let mut chars = BTreeSet::new();
+    let Some(cmap) = face.tables().cmap else {
+        return chars;
+    };
+    for (index, subtable) in cmap.subtables.into_iter().enumerate() {
+        if !subtable.is_unicode() || matches!(subtable.format, Format::UnicodeVariationSequences(_))
+        {
+            continue;
+        }
+        subtable_codepoints(face, index as u16, &subtable, |code_point| {
+            if subtable.glyph_index(code_point).is_some()
+                && let Some(c) = char::from_u32(code_point)
+            {
+                chars.insert(c);
+            }
+        });
+    }
+    chars
+}
+
+/// Calls `f` for each code point up to U+10FFFF that the cmap subtable at
+/// `index` defines. ttf-parser walks format 12 and 13 groups to whatever
+/// end code they declare, so one corrupt byte can make a group span
+/// billions of values; those groups are read here instead, with ends
+/// clamped and inverted groups skipped.
+pub fn subtable_codepoints(face: &Face, index: u16, subtable: &Subtable, mut f: impl FnMut(u32)) {
+    match subtable.format {
+        Format::SegmentedCoverage(_) | Format::ManyToOneRangeMappings(_) => {
+            for (start, end) in cmap_groups(face, index).unwrap_or_default() {
+                if start <= end {
+                    (start..=end.min(MAX_CODE_POINT)).for_each(&mut f);
+                }
+            }
+        }
+        _ => subtable.codepoints(|code_point| {
+            if code_point <= MAX_CODE_POINT {
+                f(code_point);
+            }
+        }),
+    }
+}
+
+/// The start and end codes of the groups of the format 12 or 13 subtable
+/// at `index`, as stored, up to the end of the table.
+pub fn cmap_groups(face: &Face, index: u16) -> Option<Vec<(u32, u32)>> {
+    let cmap = face.raw_face().table(Tag::from_bytes(b"cmap"))?;
+    // Each encoding record is platform, encoding and a 32-bit offset.
+    let offset = Reader::at(cmap, 4 + usize::from(index) * 8 + 4).read_u32()? as usize;
+    let mut r = Reader::at(cmap, offset);
+    let format = r.read_u16()?;
+    if !matches!(format, 12 | 13) {
+        return None;
+    }
+    r.skip(10); // reserved, length, language
+    let count = r.read_u32()?;
+    // Reads fail at the end of the table, so a corrupt count stops there.
+    Some(
+        (0..count)
+            .map_while(|_| {
+                let start = r.read_u32()?;
+                let end = r.read_u32()?;
+                r.skip(4); // start glyph ID
+                Some((start, end))
+            })
+            .collect(),
+    )
+}
+
+/// How many of a block's assigned characters the font covers.
+#[derive(Serialize)]
+pub struct BlockCoverage {
+    pub name: &'static str,
+    pub covered: usize,
+    pub total: usize,
+}
+
+impl BlockCoverage {
+    /// Counts the characters in `ranges`, the block's assigned characters
+    /// as inclusive ranges.
+    pub fn new(face: &Face, name: &'static str, ranges: &[(u32, u32)]) -> Self {
+        let mut block = BlockCoverage {
+            name,
+            covered: 0,
+            total: 0,
+        };
+        for c in ranges
+            .iter()
+            .flat_map(|&(start, end)| start..=end)
+            .filter_map(char::from_u32)
+        {
+            block.total += 1;
+            if face.glyph_index(c).is_some() {
+                block.covered += 1;
+            }
+        }
+        block
+    }
+}
+
+/// A version counts as targeted once the font covers this many of its
+/// sampled additions...
+const TARGETED_MIN_CHARS: usize = 10;
+/// ...or this share of them, in percent, for versions that added few
+/// characters.
+const TARGETED_MIN_PERCENT: usize = 5;
+
+/// How many of a Unicode version's sampled additions the font covers.
+#[derive(Serialize)]
+pub struct VersionCoverage {
+    pub version: &'static str,
+    pub covered: usize,
+    pub total: usize,
+    /// Whether enough additions are covered to say the font targets the
+    /// version, rather than having picked up a stray character or two.
+    pub targeted: bool,
+}
+
+/// Coverage of the characters each Unicode version introduced, oldest
+/// version first.
+#[derive(Serialize)]
+pub struct VersionProfile {
+    pub versions: Vec<VersionCoverage>,
+}
+
+impl VersionProfile {
+    pub fn new(face: &Face) -> Self {
+        let versions = ADDITIONS
+            .iter()
+            .map(|&(version, ranges)| {
+                let chars = ranges
+                    .iter()
+                    .flat_map(|&(start, end)| start..=end)
+                    .filter_map(char::from_u32);
+                let mut covered = 0;
+                let mut total = 0;
+                for c in chars {
+                    total += 1;
+                    if face.glyph_index(c).is_some() {
+                        covered += 1;
+                    }
+                }
+                VersionCoverage {
+                    version,
+                    covered,
+                    total,
+                    targeted: covered >= TARGETED_MIN_CHARS
+                        || (covered > 0 && covered * 100 >= total * TARGETED_MIN_PERCENT),
+                }
+            })
+            .collect();
+        VersionProfile { versions }
+    }
+
+    /// The newest version the font targets, or `None` when it only covers
+    /// Unicode 1.1 repertoire.
+    pub fn newest(&self) -> Option<&VersionCoverage> {
+        self.versions.iter().rev().find(|v| v.targeted)
+    }
+
+    /// The version after `newest`, the first one the font doesn't target.
+    pub fn first_missing(&self) -> Option<&VersionCoverage> {
+        let newest = self.versions.iter().rposition(|v| v.targeted);
+        self.versions.get(newest.map_or(0, |i| i + 1))
+    }
+
+    /// Versions newer than `newest` that the font covers a few characters
+    /// of, too few to count.
+    pub fn strays(&self) -> impl Iterator<Item = &VersionCoverage> {
+        let newest = self.versions.iter().rposition(|v| v.targeted);
+        self.versions[newest.map_or(0, |i| i + 1)..]
+            .iter()
+            .filter(|v| v.covered > 0)
+    }
+}
@@ -0,0 +1,107 @@
+use crate::reader::Reader;
+use ttf_parser::{Face, Tag};
+
+/// A named instance record from the `fvar` table.
+pub struct NamedInstance {
+    pub subfamily_name_id: u16,
+    pub postscript_name_id: Option<u16>,
+    pub coordinates: Vec<f32>,
+}
+
+/// Reads the named instances from `fvar`, which ttf-parser doesn't expose.
+pub fn named_instances(face: &Face) -> Vec<NamedInstance> {
+    let Some(data) = face.raw_face().table(Tag::from_bytes(b"fvar")) else {
+        return Vec::new();
+    };
+    parse_instances(data).unwrap_or_default()
+}
+
+fn parse_instances(data: &[u8]) -> Option<Vec<NamedInstance>> {
+    let mut r = Reader::new(data);
+    r.skip(4); // majorVersion, minorVersion
+    let axes_offset = r.read_u16()? as usize;
+    r.skip(2); // reserved
+    let axis_count = r.read_u16()? as usize;
+    let axis_size = r.read_u16()? as usize;
+    let instance_count = r.read_u16()? as usize;
+    let instance_size = r.read_u16()? as usize;
+
+    let has_postscript_name = instance_size >= axis_count * 4 + 6;
+    let start = axes_offset + axis_count * axis_size;
+
+    let mut instances = Vec::with_capacity(instance_count);
+    for i in 0..instance_count {
+        let mut r = Reader::at(data, start + i * instance_size);
+        let subfamily_name_id = r.read_u16()?;
+        r.skip(2); // flags
+        let coordinates = (0..axis_count)
+            .map(|_| r.read_fixed())
+            .collect::<Option<Vec<_>>>()?;
+        let postscript_name_id = if has_postscript_name {
+            r.read_u16().filter(|&id| id != 0xFFFF)
+        } else {
+            None
+        };
+        instances.push(NamedInstance {
+            subfamily_name_id,
+            postscript_name_id,
+            coordinates,
+        });
+    }
+
+    Some(instances)
+}
+
+/// Reads the hidden flag of each `fvar` axis, in axis order. ttf-parser
+/// tests bit 3 of the axis flags, but the spec puts HIDDEN_AXIS at bit 0.
+pub fn hidden_axes(face: &Face) -> Vec<bool> {
+    let Some(data) = face.raw_face().table(Tag::from_bytes(b"fvar")) else {
+        return Vec::new();
+    };
+    parse_hidden(data).unwrap_or_default()
+}
+
+fn parse_hidden(data: &[u8]) -> Option<Vec<bool>> {
+    let mut r = Reader::new(data);
+    r.skip(4); // majorVersion, minorVersion
+    let axes_offset = r.read_u16()? as usize;
+    r.skip(2); // reserved
+    let axis_count = r.read_u16()? as usize;
+    let axis_size = r.read_u16()? as usize;
+    (0..axis_count)
+        .map(|i| {
+            // axisTag, minValue, defaultValue, maxValue
+            let mut r = Reader::at(data, axes_offset + i * axis_size + 16);
+            Some(r.read_u16()? & 1 == 1)
+        })
+        .collect()
+}
+
+/// Returns the `avar` segment map of each axis as `(from, to)` pairs of
+/// normalized coordinates, in `fvar` axis order.
+pub fn avar_mappings(face: &Face) -> Vec<Vec<(f32, f32)>> {
+    let Some(avar) = face.tables().avar else {
+        return Vec::new();
+    };
+    avar.segment_maps
+        .into_iter()
+        .map(|map| {
+            map.into_iter()
+                .map(|m| (f2dot14(m.from_coordinate), f2dot14(m.to_coordinate)))
+                .collect()
+        })
+        .collect()
+}
+
+/// Maps a normalized coordinate back into an axis' user-space range.
+pub fn denormalize(value: f32, min: f32, default: f32, max: f32) -> f32 {
+    if value < 0.0 {
+        default + value * (default - min)
+    } else {
+        default + value * (max - default)
+    }
+}
+
+fn f2dot14(value: i16) -> f32 {
+    value as f32 / 16384.0
+}
@@ -0,0 +1,116 @@
+use crate::layout::MarkAttachment;
+use crate::normalization;
+use serde::Serialize;
+use ttf_parser::Face;
+
+/// The precomposed letters of Vietnamese beyond ASCII: every vowel with
+/// each of the five tone marks, the vowels with breve, circumflex or horn,
+/// and đ, in both cases.
+const LETTERS: &str = "àáảãạăằắẳẵặâầấẩẫậèéẻẽẹêềếểễệìíỉĩịòóỏõọôồốổỗộơờớởỡợùúủũụưừứửữựỳýỷỹỵđ\
+    ÀÁẢÃẠĂẰẮẲẴẶÂẦẤẨẪẬÈÉẺẼẸÊỀẾỂỄỆÌÍỈĨỊÒÓỎÕỌÔỒỐỔỖỘƠỜỚỞỠỢÙÚỦŨỤƯỪỨỬỮỰỲÝỶỸỴĐ";
+
+/// COMBINING HORN, which NFD keeps as a separate mark under the tone.
+const HORN: char = '\u{031B}';
+
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Readiness {
+    /// Every letter renders both precomposed and decomposed.
+    Ready,
+    /// Every letter is covered precomposed, but decomposed text, as some
+    /// Vietnamese keyboards and NFD input produce, shows misplaced marks.
+    PrecomposedOnly,
+    /// Some letters are missing precomposed but render from base and
+    /// marks, which only works where the text is decomposed.
+    DecomposedOnly,
+    /// Some letters render in neither form.
+    NotReady,
+}
+
+impl Readiness {
+    pub fn description(self) -> &'static str {
+        match self {
+            Readiness::Ready => "ready",
+            Readiness::PrecomposedOnly => "precomposed text only",
+            Readiness::DecomposedOnly => "decomposed text only",
+            Readiness::NotReady => "not ready",
+        }
+    }
+}
+
+/// Whether Vietnamese text, with its stacked vowel and tone marks, renders
+/// with the font.
+#[derive(Serialize)]
+pub struct VietnameseReport {
+    pub total: usize,
+    /// Letters the cmap covers precomposed.
+    pub precomposed: usize,
+    /// Letters whose decomposition renders: base and marks covered and
+    /// composed by `ccmp` or stacked by GPOS. đ has no decomposition and
+    /// counts when covered precomposed.
+    pub decomposed: usize,
+    /// Letters with horn, with or without a tone, whose decomposition
+    /// renders, out of `horn_total`.
+    pub horn: usize,
+    pub horn_total: usize,
+    /// Letters rendering in neither form.
+    pub missing: Vec<char>,
+    pub readiness: Readiness,
+}
+
+impl VietnameseReport {
+    /// Returns `None` for fonts without a Latin a.
+    pub fn new(face: &Face) -> Option<Self> {
+        face.glyph_index('a')?;
+        let attachment = face
+            .tables()
+            .gpos
+            .map(|gpos| MarkAttachment::new(&gpos))
+            .unwrap_or_default();
+        let decompositions: Vec<(char, Vec<char>)> = normalization::decompositions()
+            .filter(|(c, _)| LETTERS.contains(*c))
+            .collect();
+
+        let mut report = VietnameseReport {
+            total: 0,
+            precomposed: 0,
+            decomposed: 0,
+            horn: 0,
+            horn_total: 0,
+            missing: Vec::new(),
+            readiness: Readiness::NotReady,
+        };
+        for c in LETTERS.chars() {
+            let precomposed = face.glyph_index(c).is_some();
+            let decomposition = decompositions
+                .iter()
+                .find(|(d, _)| *d == c)
+                .map(|(_, chars)| chars);
+            let decomposed = match decomposition {
+                Some(chars) => normalization::renders(face, &attachment, chars),
+                None => precomposed,
+            };
+            report.total += 1;
+            report.precomposed += usize::from(precomposed);
+            report.decomposed += usize::from(decomposed);
+            if decomposition.is_some_and(|chars| chars.contains(&HORN)) {
+                report.horn_total += 1;
+                report.horn += usize::from(decomposed);
+            }
+            if !precomposed && !decomposed {
+                report.missing.push(c);
+            }
+        }
+
+        report.readiness = match (
+            report.precomposed == report.total,
+            report.decomposed == report.total,
+        ) {
+            (true, true) => Readiness::Ready,
+            (true, false) => Readiness::PrecomposedOnly,
+            (false, true) => Readiness::DecomposedOnly,
+            (false, false) => Readiness::NotReady,
+        };
+        Some(report)
+    }
+}